@@ -0,0 +1,164 @@
+//! Fluent builder for constructing models in Rust
+//!
+//! A thin ergonomics layer over [`ParsedModel`], [`Table`] and [`Variable`]
+//! for tests and embedders that build models in code rather than YAML.
+
+use crate::types::{Column, ColumnValue, ParsedModel, Table, Variable};
+
+/// Fluent builder producing a [`ParsedModel`]
+///
+/// Column and row formula calls apply to the table most recently opened with
+/// [`table`](ModelBuilder::table); scalars are model-wide.
+///
+/// # Example
+/// ```
+/// use royalbit_forge::api::ModelBuilder;
+/// use royalbit_forge::core::ArrayCalculator;
+/// use royalbit_forge::types::ColumnValue;
+///
+/// let model = ModelBuilder::new()
+///     .table("sales")
+///     .number_column("revenue", vec![100.0, 200.0, 300.0])
+///     .number_column("cost", vec![60.0, 120.0, 150.0])
+///     .row_formula("profit", "=revenue - cost")
+///     .value("tax_rate", 0.25)
+///     .scalar("total_revenue", "=SUM(sales.revenue)")
+///     .scalar("total_profit", "=SUM(sales.profit)")
+///     .build();
+///
+/// let result = ArrayCalculator::new(model).calculate_all()?;
+///
+/// let profit = &result.tables["sales"].columns["profit"].values;
+/// assert_eq!(profit, &ColumnValue::Number(vec![40.0, 80.0, 150.0]));
+/// assert_eq!(result.scalars["total_revenue"].value, Some(600.0));
+/// assert_eq!(result.scalars["total_profit"].value, Some(270.0));
+/// # Ok::<(), royalbit_forge::error::ForgeError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct ModelBuilder {
+    model: ParsedModel,
+    current: Option<Table>,
+}
+
+impl ModelBuilder {
+    /// Start an empty model
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open a table; following column and row formula calls apply to it
+    ///
+    /// Re-opening a table that was already built continues adding to it.
+    pub fn table(mut self, name: &str) -> Self {
+        self.finish_table();
+        let table = self
+            .model
+            .tables
+            .remove(name)
+            .unwrap_or_else(|| Table::new(name.to_string()));
+        self.current = Some(table);
+        self
+    }
+
+    /// Add a numeric data column to the current table
+    ///
+    /// # Panics
+    /// Panics if no table has been opened.
+    pub fn number_column(self, name: &str, values: Vec<f64>) -> Self {
+        self.column(name, ColumnValue::Number(values))
+    }
+
+    /// Add a text data column to the current table
+    ///
+    /// # Panics
+    /// Panics if no table has been opened.
+    pub fn text_column<S: Into<String>>(self, name: &str, values: Vec<S>) -> Self {
+        let values = values.into_iter().map(Into::into).collect();
+        self.column(name, ColumnValue::Text(values))
+    }
+
+    /// Add a data column of any type to the current table
+    ///
+    /// # Panics
+    /// Panics if no table has been opened.
+    pub fn column(mut self, name: &str, values: ColumnValue) -> Self {
+        self.current_table("column")
+            .add_column(Column::new(name.to_string(), values));
+        self
+    }
+
+    /// Add a row-wise formula column to the current table
+    ///
+    /// # Panics
+    /// Panics if no table has been opened.
+    pub fn row_formula(mut self, name: &str, formula: &str) -> Self {
+        self.current_table("row_formula")
+            .add_row_formula(name.to_string(), formula.to_string());
+        self
+    }
+
+    /// Add a calculated scalar, e.g. `=SUM(sales.revenue)`
+    pub fn scalar(mut self, name: &str, formula: &str) -> Self {
+        self.model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), None, Some(formula.to_string())),
+        );
+        self
+    }
+
+    /// Add an input scalar with a fixed value
+    pub fn value(mut self, name: &str, value: f64) -> Self {
+        self.model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), Some(value), None),
+        );
+        self
+    }
+
+    /// Finish building and return the model
+    pub fn build(mut self) -> ParsedModel {
+        self.finish_table();
+        self.model
+    }
+
+    fn current_table(&mut self, method: &str) -> &mut Table {
+        match self.current.as_mut() {
+            Some(table) => table,
+            None => panic!("ModelBuilder::{} called before ModelBuilder::table", method),
+        }
+    }
+
+    fn finish_table(&mut self) {
+        if let Some(table) = self.current.take() {
+            self.model.add_table(table);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_reopens_existing_table() {
+        let model = ModelBuilder::new()
+            .table("sales")
+            .number_column("revenue", vec![1.0, 2.0])
+            .table("costs")
+            .number_column("amount", vec![3.0])
+            .table("sales")
+            .row_formula("double", "=revenue * 2")
+            .build();
+
+        assert_eq!(model.tables.len(), 2);
+        let sales = &model.tables["sales"];
+        assert!(sales.columns.contains_key("revenue"));
+        assert_eq!(sales.row_formulas["double"], "=revenue * 2");
+    }
+
+    #[test]
+    #[should_panic(expected = "called before ModelBuilder::table")]
+    fn test_builder_column_without_table_panics() {
+        ModelBuilder::new().number_column("revenue", vec![1.0]);
+    }
+}
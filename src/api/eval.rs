@@ -0,0 +1,68 @@
+//! Programmatic formula evaluation
+//!
+//! Evaluate one formula against an existing model without running a full
+//! calculation, e.g. for REPLs, notebooks or editor previews.
+
+use crate::core::ArrayCalculator;
+use crate::error::ForgeResult;
+use crate::types::{ColumnValue, ParsedModel};
+
+/// Evaluate a single formula string against `model`
+///
+/// Aggregations (`=SUM(sales.revenue)`) and scalar expressions return a
+/// one-element `ColumnValue::Number`; row-wise formulas (`=revenue - cost`)
+/// return a full column from the table that holds the referenced columns.
+/// Values are read as stored, so pass a calculated model if the formula
+/// depends on formula columns or scalars.
+pub fn eval_formula(formula: &str, model: &ParsedModel) -> ForgeResult<ColumnValue> {
+    ArrayCalculator::new(model.clone()).evaluate_formula(formula)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Column, Table, Variable};
+
+    fn sales_model() -> ParsedModel {
+        let mut model = ParsedModel::new();
+        let mut sales = Table::new("sales".to_string());
+        sales.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 200.0, 300.0]),
+        ));
+        sales.add_column(Column::new(
+            "cost".to_string(),
+            ColumnValue::Number(vec![60.0, 120.0, 150.0]),
+        ));
+        model.add_table(sales);
+        model.add_scalar(
+            "tax_rate".to_string(),
+            Variable::new("tax_rate".to_string(), Some(0.25), None),
+        );
+        model
+    }
+
+    #[test]
+    fn test_eval_formula_aggregation_returns_single_value() {
+        let result = eval_formula("=SUM(sales.revenue)", &sales_model()).unwrap();
+        assert_eq!(result, ColumnValue::Number(vec![600.0]));
+    }
+
+    #[test]
+    fn test_eval_formula_rowwise_returns_column() {
+        let result = eval_formula("=revenue - cost", &sales_model()).unwrap();
+        assert_eq!(result, ColumnValue::Number(vec![40.0, 80.0, 150.0]));
+    }
+
+    #[test]
+    fn test_eval_formula_scalar_expression() {
+        let result = eval_formula("=tax_rate * 100", &sales_model()).unwrap();
+        assert_eq!(result, ColumnValue::Number(vec![25.0]));
+    }
+
+    #[test]
+    fn test_eval_formula_unknown_column_errors() {
+        assert!(eval_formula("=profit * 2", &sales_model()).is_err());
+        assert!(eval_formula("=", &sales_model()).is_err());
+    }
+}
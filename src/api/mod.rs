@@ -2,8 +2,17 @@
 //!
 //! Provides HTTP REST API for enterprise integration.
 //! Run with `forge serve` or `forge-server`.
+//! [`eval_formula`] evaluates a single formula and [`ModelBuilder`] builds
+//! models from Rust code. [`model_schema`] exports a model's structure as
+//! JSON Schema.
 
+mod builder;
+mod eval;
 pub mod handlers;
+mod schema;
 pub mod server;
 
+pub use builder::ModelBuilder;
+pub use eval::eval_formula;
+pub use schema::model_schema;
 pub use server::run_api_server;
@@ -0,0 +1,194 @@
+//! JSON Schema export for a model's structure
+//!
+//! Describes the tables, column value types and scalars of a parsed model as
+//! a draft-07 JSON Schema, so other tools (or CI) can check that a data file
+//! keeps the same shape as the model it feeds.
+
+use std::collections::BTreeMap;
+
+use serde_json::{json, Map, Value};
+
+use crate::types::{ColumnValue, ParsedModel};
+
+const DATE_PATTERN: &str = r"^\d{4}-\d{2}(-\d{2})?$";
+
+/// Build a draft-07 JSON Schema describing the YAML layout of `model`
+///
+/// Every table and column becomes a required property. Data columns accept
+/// either a plain array or the rich `{value: [...]}` form, with items
+/// constrained to the column's type; formula columns and scalars accept a
+/// `=` formula or the rich `{formula: ...}` / `{value: ...}` form. Keys not
+/// in the model (`_forge_version`, metadata, ...) are allowed.
+pub fn model_schema(model: &ParsedModel) -> Value {
+    let mut properties = Map::new();
+
+    for (name, table) in &model.tables {
+        let mut columns = Map::new();
+        for (col_name, column) in &table.columns {
+            columns.insert(col_name.clone(), column_schema(&column.values));
+        }
+        for col_name in table.row_formulas.keys() {
+            columns.insert(col_name.clone(), formula_column_schema());
+        }
+        properties.insert(name.clone(), object_schema(columns));
+    }
+
+    // `summary.total` is written as `total` nested under `summary`
+    let mut sections: BTreeMap<&str, Map<String, Value>> = BTreeMap::new();
+    for path in model.scalars.keys() {
+        match path.split_once('.') {
+            Some((section, name)) => {
+                sections
+                    .entry(section)
+                    .or_default()
+                    .insert(name.to_string(), scalar_schema());
+            }
+            None => {
+                properties.insert(path.clone(), scalar_schema());
+            }
+        }
+    }
+    for (section, scalars) in sections {
+        properties.insert(section.to_string(), object_schema(scalars));
+    }
+
+    let required: Vec<&String> = properties.keys().collect();
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Forge model",
+        "type": "object",
+        "required": required,
+        "properties": properties,
+    })
+}
+
+/// Object whose listed properties are all required
+fn object_schema(properties: Map<String, Value>) -> Value {
+    let required: Vec<&String> = properties.keys().collect();
+    json!({
+        "type": "object",
+        "required": required,
+        "properties": properties,
+    })
+}
+
+fn column_schema(values: &ColumnValue) -> Value {
+    let items = match values {
+        ColumnValue::Number(_) => json!({ "type": "number" }),
+        ColumnValue::Text(_) => json!({ "type": "string" }),
+        ColumnValue::Date(_) => json!({ "type": "string", "pattern": DATE_PATTERN }),
+        ColumnValue::Boolean(_) => json!({ "type": "boolean" }),
+    };
+    let array = json!({ "type": "array", "items": items });
+    json!({
+        "anyOf": [
+            array,
+            {
+                "type": "object",
+                "required": ["value"],
+                "properties": { "value": array },
+            },
+        ]
+    })
+}
+
+fn formula_column_schema() -> Value {
+    let formula = json!({ "type": "string", "pattern": "^=" });
+    json!({
+        "anyOf": [
+            formula,
+            {
+                "type": "object",
+                "required": ["formula"],
+                "properties": { "formula": formula },
+            },
+        ]
+    })
+}
+
+fn scalar_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "value": { "type": ["number", "null"] },
+            "formula": { "type": ["string", "null"] },
+        },
+        "anyOf": [
+            { "required": ["value"] },
+            { "required": ["formula"] },
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ModelBuilder;
+    use jsonschema::{Draft, JSONSchema};
+
+    fn sample_model() -> ParsedModel {
+        ModelBuilder::new()
+            .table("sales")
+            .text_column("month", vec!["Jan", "Feb"])
+            .number_column("revenue", vec![100.0, 200.0])
+            .row_formula("double", "=revenue * 2")
+            .value("tax_rate", 0.25)
+            .scalar("summary.total", "=SUM(sales.revenue)")
+            .build()
+    }
+
+    fn compile(schema: &Value) -> JSONSchema {
+        // Compiling checks the schema against the draft-07 meta-schema
+        JSONSchema::options()
+            .with_draft(Draft::Draft7)
+            .compile(schema)
+            .expect("generated schema is valid draft-07")
+    }
+
+    #[test]
+    fn test_model_schema_is_valid_draft7() {
+        let schema = model_schema(&sample_model());
+        compile(&schema);
+
+        assert_eq!(schema["required"], json!(["sales", "summary", "tax_rate"]));
+        assert_eq!(
+            schema["properties"]["sales"]["required"],
+            json!(["double", "month", "revenue"])
+        );
+        assert_eq!(
+            schema["properties"]["sales"]["properties"]["revenue"]["anyOf"][0]["items"]["type"],
+            "number"
+        );
+    }
+
+    #[test]
+    fn test_model_schema_validates_model_yaml() {
+        let schema = model_schema(&sample_model());
+        let compiled = compile(&schema);
+
+        let yaml = r#"
+_forge_version: "1.0.0"
+sales:
+  month: ["Jan", "Feb", "Mar"]
+  revenue:
+    value: [100, 200, 300]
+    unit: "USD"
+  double: "=revenue * 2"
+tax_rate:
+  value: 0.25
+summary:
+  total:
+    formula: "=SUM(sales.revenue)"
+"#;
+        let instance: Value = serde_yaml::from_str(yaml).unwrap();
+        assert!(compiled.is_valid(&instance));
+
+        let wrong_type: Value =
+            serde_yaml::from_str(&yaml.replace("[100, 200, 300]", "[\"a\"]")).unwrap();
+        assert!(!compiled.is_valid(&wrong_type));
+
+        let missing_column: Value =
+            serde_yaml::from_str(&yaml.replace("  double: \"=revenue * 2\"\n", "")).unwrap();
+        assert!(!compiled.is_valid(&missing_column));
+    }
+}
@@ -1,15 +1,20 @@
-use crate::core::{ArrayCalculator, UnitValidator};
+use crate::core::{
+    join_tables, ArrayCalculator, CalcStats, FunctionCategory, FunctionRegistry, JoinKind,
+    UnitValidator,
+};
 use crate::error::{ForgeError, ForgeResult};
-use crate::excel::{ExcelExporter, ExcelImporter};
+use crate::excel::{roundtrip_check, CsvImporter, ExcelExporter, ExcelImporter};
 use crate::parser;
 use crate::writer;
 use colored::Colorize;
 use notify::RecursiveMode;
 use notify_debouncer_mini::{new_debouncer, DebouncedEventKind};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::mpsc::channel;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Format a number for display, removing unnecessary decimal places
 fn format_number(n: f64) -> String {
@@ -29,9 +34,59 @@ pub fn calculate(
     dry_run: bool,
     verbose: bool,
     scenario: Option<String>,
+) -> ForgeResult<()> {
+    run_calculate(file, None, dry_run, verbose, scenario, &[], false)
+}
+
+/// Execute the calculate command, writing results to `output` instead of `file`
+/// Only the main model is written; included files are not rewritten.
+pub fn calculate_to(
+    file: PathBuf,
+    output: PathBuf,
+    dry_run: bool,
+    verbose: bool,
+    scenario: Option<String>,
+) -> ForgeResult<()> {
+    run_calculate(file, Some(&output), dry_run, verbose, scenario, &[], false)
+}
+
+/// Execute the calculate command, searching `include_paths` for included files
+/// (calculate --include-path). Writes to `output` when given, like calculate_to.
+/// With `check_units`, unit mismatches fail before anything is calculated.
+pub fn calculate_with_include_paths(
+    file: PathBuf,
+    output: Option<PathBuf>,
+    dry_run: bool,
+    verbose: bool,
+    scenario: Option<String>,
+    include_paths: &[PathBuf],
+    check_units: bool,
+) -> ForgeResult<()> {
+    run_calculate(
+        file,
+        output.as_deref(),
+        dry_run,
+        verbose,
+        scenario,
+        include_paths,
+        check_units,
+    )
+}
+
+fn run_calculate(
+    file: PathBuf,
+    output: Option<&Path>,
+    dry_run: bool,
+    verbose: bool,
+    scenario: Option<String>,
+    include_paths: &[PathBuf],
+    check_units: bool,
 ) -> ForgeResult<()> {
     println!("{}", "🔥 Forge - Calculating formulas".bold().green());
     println!("   File: {}", file.display());
+    if let Some(output) = output {
+        println!("   Output: {}", output.display());
+    }
     if let Some(ref s) = scenario {
         println!("   Scenario: {}", s.bright_yellow().bold());
     }
@@ -49,7 +104,9 @@ pub fn calculate(
         println!("{}", "📖 Parsing YAML file...".cyan());
     }
 
-    let mut model = parser::parse_model(&file)?;
+    let mut model = parser::parse_model_with_include_paths(&file, include_paths)?;
+    // Values as written in the file, for the dry-run diff
+    let original = dry_run.then(|| model.clone());
 
     if verbose {
         println!(
@@ -95,7 +152,16 @@ pub fn calculate(
     }
 
     let calculator = ArrayCalculator::new(model);
-    let result = calculator.calculate_all()?;
+    if check_units {
+        calculator.check_units()?;
+    }
+    let result = if verbose {
+        let (result, stats) = calculator.calculate_all_with_stats()?;
+        print_calc_stats(&stats);
+        result
+    } else {
+        calculator.calculate_all()?
+    };
 
     // Display results
     println!("{}", "✅ Calculation Results:".bold().green());
@@ -124,8 +190,32 @@ pub fn calculate(
     println!();
 
     // Write results back to file (v4.3.0)
-    if dry_run {
+    if let Some(original) = original {
+        let changes = find_mismatches(&original, &result);
+        if changes.is_empty() {
+            println!("   No values would change\n");
+        } else {
+            println!("{}", dry_run_diff(&file, &changes));
+        }
         println!("{}", "📋 Dry run complete - no changes written".yellow());
+    } else if let Some(output) = output {
+        if writer::write_calculated_results_to(&file, output, &result)? {
+            println!(
+                "{}",
+                format!("💾 Results written to {}", output.display())
+                    .bold()
+                    .green()
+            );
+            println!(
+                "{}",
+                format!("   {} left unchanged", file.display()).dimmed()
+            );
+        } else {
+            println!(
+                "{}",
+                "⚠️  Multi-document YAML - write-back not supported yet".yellow()
+            );
+        }
     } else {
         let wrote = writer::write_calculated_results(&file, &result)?;
         if wrote {
@@ -155,6 +245,224 @@ pub fn calculate(
     Ok(())
 }
 
+/// Cell count and timings for `calculate --verbose`
+fn print_calc_stats(stats: &CalcStats) {
+    let millis = |time: Duration| format!("{:.1}ms", time.as_secs_f64() * 1000.0);
+
+    println!(
+        "   Evaluated {} cells in {} ({} dependency levels, {} aggregations)",
+        stats.cells_evaluated,
+        millis(stats.total_time),
+        stats.dependency_levels,
+        stats.aggregations
+    );
+    for (table, time) in &stats.table_times {
+        println!("      {:<24} {}", table, millis(*time).dimmed());
+    }
+    println!();
+}
+
+/// Unified-style diff of changed values, one `@@` hunk per table or scalar section
+fn dry_run_diff(file: &Path, changes: &[ValueMismatch]) -> String {
+    let mut lines = vec![
+        format!("--- {}", file.display()).bold().to_string(),
+        format!("+++ {} (calculated)", file.display())
+            .bold()
+            .to_string(),
+    ];
+
+    let mut start = 0;
+    while start < changes.len() {
+        let group = &changes[start].group;
+        let end = start
+            + changes[start..]
+                .iter()
+                .take_while(|c| &c.group == group)
+                .count();
+        lines.push(
+            format!("@@ {} ({} changed) @@", group, end - start)
+                .cyan()
+                .to_string(),
+        );
+        for change in &changes[start..end] {
+            lines.push(
+                format!("-  {}: {}", change.name, change.stored_text())
+                    .red()
+                    .to_string(),
+            );
+            lines.push(
+                format!("+  {}: {}", change.name, format_number(change.recalculated))
+                    .green()
+                    .to_string(),
+            );
+        }
+        start = end;
+    }
+
+    lines.push(format!("{} value(s) would change", changes.len()));
+    lines.join("\n") + "\n"
+}
+
+/// Execute the calculate command for every file matching a glob pattern
+/// Each file is calculated independently (includes resolve relative to that file);
+/// a summary is printed at the end and an error is returned if any file failed
+pub fn calculate_batch(
+    pattern: String,
+    dry_run: bool,
+    verbose: bool,
+    scenario: Option<String>,
+    include_paths: &[PathBuf],
+) -> ForgeResult<()> {
+    let files = expand_glob(&pattern)?;
+    let file_count = files.len();
+
+    println!(
+        "{}",
+        format!("🔥 Calculating {} files matching {}", file_count, pattern)
+            .bold()
+            .green()
+    );
+    println!();
+
+    let mut failed_files: Vec<String> = Vec::new();
+
+    for file in &files {
+        println!("{}", format!("─── {} ───", file.display()).cyan());
+        if let Err(e) = run_calculate(
+            file.clone(),
+            None,
+            dry_run,
+            verbose,
+            scenario.clone(),
+            include_paths,
+            false,
+        ) {
+            failed_files.push(format!("{}: {}", file.display(), e));
+            println!("{}", format!("   ❌ {} - FAILED", file.display()).red());
+            println!("      {}", e.to_string().red());
+            println!();
+        }
+    }
+
+    println!("{}", "─".repeat(50));
+    println!(
+        "   {} succeeded, {} failed out of {} files",
+        file_count - failed_files.len(),
+        failed_files.len(),
+        file_count
+    );
+    for failure in &failed_files {
+        println!("   ❌ {}", failure.red());
+    }
+
+    if failed_files.is_empty() {
+        Ok(())
+    } else {
+        Err(ForgeError::Validation(format!(
+            "{} file(s) failed to calculate",
+            failed_files.len()
+        )))
+    }
+}
+
+/// Check if a path argument is a glob pattern rather than a single file
+pub fn is_glob_pattern(path: &Path) -> bool {
+    path.to_string_lossy().contains(['*', '?'])
+}
+
+/// Expand a glob pattern whose wildcards (* and ?) are in the file name,
+/// e.g. models/*.yaml. Matches are returned sorted.
+fn expand_glob(pattern: &str) -> ForgeResult<Vec<PathBuf>> {
+    let path = Path::new(pattern);
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    if is_glob_pattern(dir) {
+        return Err(ForgeError::Validation(format!(
+            "Wildcards are only supported in the file name: {}",
+            pattern
+        )));
+    }
+    let name_pattern = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| ForgeError::IO(format!("Cannot read directory {}: {}", dir.display(), e)))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.is_file())
+        .filter(|p| {
+            p.file_name()
+                .is_some_and(|n| glob_match(&name_pattern, &n.to_string_lossy()))
+        })
+        .collect();
+    files.sort();
+
+    if files.is_empty() {
+        return Err(ForgeError::Validation(format!(
+            "No files match pattern '{}'",
+            pattern
+        )));
+    }
+
+    Ok(files)
+}
+
+/// Match a file name against a pattern with * (any run) and ? (one character)
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // Iterative wildcard matching with backtracking to the last *
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Execute the calculate command with JSON output (--format json)
+/// Prints calculated scalars and tables to stdout; the YAML file is never modified
+pub fn calculate_json(
+    file: PathBuf,
+    scenario: Option<String>,
+    include_paths: &[PathBuf],
+    check_units: bool,
+) -> ForgeResult<()> {
+    let mut model = parser::parse_model_with_include_paths(&file, include_paths)?;
+
+    if let Some(ref scenario_name) = scenario {
+        apply_scenario(&mut model, scenario_name)?;
+    }
+
+    let calculator = ArrayCalculator::new(model);
+    if check_units {
+        calculator.check_units()?;
+    }
+    let result = calculator.calculate_all()?;
+
+    let json = serde_json::to_string_pretty(&writer::results_to_json(&result))
+        .map_err(|e| ForgeError::Export(format!("JSON serialization failed: {}", e)))?;
+    println!("{}", json);
+
+    Ok(())
+}
+
 /// Execute the audit command - show calculation dependency chain
 pub fn audit(file: PathBuf, variable: String) -> ForgeResult<()> {
     println!("{}", "🔍 Forge - Audit Trail".bold().green());
@@ -235,6 +543,140 @@ pub fn audit(file: PathBuf, variable: String) -> ForgeResult<()> {
     Ok(())
 }
 
+/// Execute `audit <variable> --impact` - list everything downstream of a variable
+pub fn audit_impact(file: PathBuf, variable: String) -> ForgeResult<()> {
+    println!("{}", "🎯 Forge - Impact Analysis".bold().green());
+    println!("   File: {}", file.display());
+    println!("   Variable: {}\n", variable.bright_blue().bold());
+
+    let model = parser::parse_model(&file)?;
+    let name = qualify_audit_name(&model, &variable);
+    let impacted = ArrayCalculator::new(model.clone()).impact(&name)?;
+
+    if impacted.is_empty() {
+        println!("   Nothing depends on {}", name.bright_blue());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("📋 {} values depend on {}:", impacted.len(), name)
+            .bold()
+            .cyan()
+    );
+    for dependent in &impacted {
+        let kind = if model.scalars.contains_key(dependent) {
+            "scalar"
+        } else {
+            "column"
+        };
+        println!("   └─ {} ({})", dependent.bright_blue(), kind.cyan());
+    }
+    Ok(())
+}
+
+/// Expand a bare name like `base_price` to the one scalar or `table.column` it names
+/// Names that are already qualified, ambiguous or unknown are returned unchanged
+fn qualify_audit_name(model: &crate::types::ParsedModel, name: &str) -> String {
+    if model.scalars.contains_key(name) || name.contains('.') {
+        return name.to_string();
+    }
+
+    let suffix = format!(".{}", name);
+    let candidates: Vec<String> = model
+        .scalars
+        .keys()
+        .filter(|scalar| scalar.ends_with(&suffix))
+        .cloned()
+        .chain(
+            model
+                .tables
+                .iter()
+                .filter(|(_, table)| {
+                    table.columns.contains_key(name) || table.row_formulas.contains_key(name)
+                })
+                .map(|(table_name, _)| format!("{}.{}", table_name, name)),
+        )
+        .collect();
+
+    match candidates.as_slice() {
+        [only] => only.clone(),
+        _ => name.to_string(),
+    }
+}
+
+/// Execute `audit --tree` / `audit --dot` - show the whole model's dependency graph
+pub fn audit_graph(file: PathBuf, dot: bool) -> ForgeResult<()> {
+    let model = parser::parse_model(&file)?;
+    let graph = ArrayCalculator::new(model).dependency_graph()?;
+
+    if dot {
+        print!("{}", dependency_dot(&graph));
+        return Ok(());
+    }
+
+    println!("{}", "🌳 Forge - Dependency Tree".bold().green());
+    println!("   File: {}\n", file.display());
+    if graph.is_empty() {
+        println!("   No formulas reference other values");
+    } else {
+        print!("{}", dependency_tree(&graph));
+    }
+    Ok(())
+}
+
+/// Render a dependency graph as Graphviz DOT, edges pointing from input to dependent
+fn dependency_dot(graph: &BTreeMap<String, Vec<String>>) -> String {
+    let mut dot = String::from("digraph forge {\n    rankdir=LR;\n");
+    for (source, dependents) in graph {
+        for dependent in dependents {
+            dot.push_str(&format!("    \"{}\" -> \"{}\";\n", source, dependent));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Render a dependency graph as an indented tree rooted at the model's inputs
+/// A node reached a second time is listed without its subtree, marked `…`
+fn dependency_tree(graph: &BTreeMap<String, Vec<String>>) -> String {
+    let referenced: HashSet<&str> = graph.values().flatten().map(String::as_str).collect();
+    let mut expanded = HashSet::new();
+    let mut out = String::new();
+
+    for root in graph.keys().filter(|n| !referenced.contains(n.as_str())) {
+        push_tree_node(graph, root, 0, &mut expanded, &mut out);
+    }
+    // Nodes only reachable through a cycle have no input to hang from
+    for node in graph.keys() {
+        if !expanded.contains(node.as_str()) {
+            push_tree_node(graph, node, 0, &mut expanded, &mut out);
+        }
+    }
+    out
+}
+
+fn push_tree_node<'a>(
+    graph: &'a BTreeMap<String, Vec<String>>,
+    node: &'a str,
+    depth: usize,
+    expanded: &mut HashSet<&'a str>,
+    out: &mut String,
+) {
+    let prefix = "   ".repeat(depth + 1);
+    let arrow = if depth > 0 { "└─ " } else { "" };
+    let children = graph.get(node).map(Vec::as_slice).unwrap_or(&[]);
+
+    if !children.is_empty() && !expanded.insert(node) {
+        out.push_str(&format!("{}{}{} …\n", prefix, arrow, node));
+        return;
+    }
+    out.push_str(&format!("{}{}{}\n", prefix, arrow, node));
+    for child in children {
+        push_tree_node(graph, child, depth + 1, expanded, out);
+    }
+}
+
 /// Represents a dependency in the audit tree
 struct AuditDependency {
     name: String,
@@ -364,6 +806,9 @@ fn extract_references_from_formula(formula: &str) -> Vec<String> {
         "ROUND",
         "ROUNDUP",
         "ROUNDDOWN",
+        "ROUNDBANK",
+        "INT",
+        "TRUNC",
         "CEILING",
         "FLOOR",
         "SQRT",
@@ -440,10 +885,63 @@ fn print_dependency(dep: &AuditDependency, indent: usize) {
     }
 }
 
+/// Outcome of the validate command, mapped to its exit code for CI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationOutcome {
+    /// Every file is valid (exit code 0)
+    Valid = 0,
+    /// Stored values do not match their formulas (exit code 1)
+    Stale = 1,
+    /// A file could not be read, parsed or calculated (exit code 2)
+    Broken = 2,
+}
+
+impl ValidationOutcome {
+    /// Classify the result of [`validate`], [`validate_diff`] or [`validate_with_schema`]
+    pub fn of(result: &ForgeResult<()>) -> Self {
+        match result {
+            Ok(()) => Self::Valid,
+            Err(ForgeError::StaleValues(_)) => Self::Stale,
+            Err(_) => Self::Broken,
+        }
+    }
+
+    /// Process exit code: 0 valid, 1 stale, 2 broken
+    pub fn exit_code(self) -> i32 {
+        self as i32
+    }
+}
+
 /// Execute the validate command for one or more files
 pub fn validate(files: Vec<PathBuf>) -> ForgeResult<()> {
+    validate_files(files, false, None)
+}
+
+/// Execute the validate command with `--diff`: on mismatch, print every stale
+/// cell grouped by table/scalar section with stored, recalculated and delta.
+///
+/// # Errors
+///
+/// Returns an error if any file has stale values or invalid formulas.
+pub fn validate_diff(files: Vec<PathBuf>) -> ForgeResult<()> {
+    validate_files(files, true, None)
+}
+
+/// Execute the validate command with `--schema`: each file must conform to
+/// the JSON Schema before its formulas are checked.
+///
+/// # Errors
+///
+/// Returns an error listing every schema violation, or any regular
+/// validation failure.
+pub fn validate_with_schema(files: Vec<PathBuf>, schema: PathBuf, diff: bool) -> ForgeResult<()> {
+    validate_files(files, diff, Some(&schema))
+}
+
+fn validate_files(files: Vec<PathBuf>, diff: bool, schema: Option<&Path>) -> ForgeResult<()> {
     let file_count = files.len();
     let is_batch = file_count > 1;
+    let schema = schema.map(load_json_schema).transpose()?;
 
     if is_batch {
         println!(
@@ -454,6 +952,7 @@ pub fn validate(files: Vec<PathBuf>) -> ForgeResult<()> {
     }
 
     let mut all_passed = true;
+    let mut all_stale = true;
     let mut failed_files: Vec<String> = Vec::new();
 
     for file in &files {
@@ -464,7 +963,11 @@ pub fn validate(files: Vec<PathBuf>) -> ForgeResult<()> {
             println!("   File: {}\n", file.display());
         }
 
-        match validate_single_file(file) {
+        let result = match &schema {
+            Some(schema) => check_schema(file, schema).and_then(|()| validate_file(file, diff)),
+            None => validate_file(file, diff),
+        };
+        match result {
             Ok(()) => {
                 if is_batch {
                     println!("{}", format!("   ✅ {} - OK", file.display()).green());
@@ -477,6 +980,7 @@ pub fn validate(files: Vec<PathBuf>) -> ForgeResult<()> {
                     return Err(e);
                 }
                 all_passed = false;
+                all_stale &= matches!(e, ForgeError::StaleValues(_));
                 failed_files.push(format!("{}: {}", file.display(), e));
                 println!("{}", format!("   ❌ {} - FAILED", file.display()).red());
                 println!("      {}", e.to_string().red());
@@ -498,49 +1002,269 @@ pub fn validate(files: Vec<PathBuf>) -> ForgeResult<()> {
     }
 
     if all_passed {
-        Ok(())
+        return Ok(());
+    }
+    let message = format!("{} file(s) failed validation", failed_files.len());
+    if all_stale {
+        Err(ForgeError::StaleValues(message))
     } else {
-        Err(ForgeError::Validation(format!(
-            "{} file(s) failed validation",
-            failed_files.len()
-        )))
+        Err(ForgeError::Validation(message))
     }
 }
 
-/// Validate a single file
-fn validate_single_file(file: &std::path::Path) -> ForgeResult<()> {
-    // Parse YAML file
-    let model = parser::parse_model(file)?;
+/// Load and compile a JSON Schema file
+fn load_json_schema(path: &Path) -> ForgeResult<jsonschema::JSONSchema> {
+    let content = fs::read_to_string(path).map_err(ForgeError::Io)?;
+    let schema: serde_json::Value = serde_json::from_str(&content).map_err(|e| {
+        ForgeError::Validation(format!("Invalid JSON in schema {}: {}", path.display(), e))
+    })?;
+    jsonschema::JSONSchema::compile(&schema).map_err(|e| {
+        ForgeError::Validation(format!("Invalid JSON Schema {}: {}", path.display(), e))
+    })
+}
 
-    if model.tables.is_empty() && model.scalars.is_empty() {
-        println!("{}", "⚠️  No tables or scalars found in YAML file".yellow());
+/// Collect every schema violation in a YAML file as (instance path, message)
+/// pairs (each document of a multi-document file is checked on its own)
+fn schema_violations(
+    content: &str,
+    schema: &jsonschema::JSONSchema,
+) -> ForgeResult<Vec<(String, String)>> {
+    use serde::Deserialize;
+
+    let mut violations = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(content) {
+        let instance = serde_json::Value::deserialize(document)
+            .map_err(|e| ForgeError::Parse(format!("Failed to read YAML: {}", e)))?;
+        // Bind the result so its borrow of `instance` ends with this statement
+        let result = schema.validate(&instance);
+        if let Err(errors) = result {
+            for error in errors {
+                let path = error.instance_path.to_string();
+                let path = if path.is_empty() {
+                    "/".to_string()
+                } else {
+                    path
+                };
+                violations.push((path, error.to_string()));
+            }
+        }
+    }
+    Ok(violations)
+}
+
+/// Check a file against a JSON Schema, reporting all violations at once
+fn check_schema(file: &Path, schema: &jsonschema::JSONSchema) -> ForgeResult<()> {
+    let content = fs::read_to_string(file).map_err(ForgeError::Io)?;
+    let violations: Vec<String> = schema_violations(&content, schema)?
+        .into_iter()
+        .map(|(path, message)| format!("{}: {}", path, message))
+        .collect();
+    if violations.is_empty() {
+        println!("   Schema: OK");
         return Ok(());
     }
 
     println!(
-        "   Found {} tables, {} scalars",
-        model.tables.len(),
-        model.scalars.len()
+        "{}",
+        format!("❌ {} schema violation(s)", violations.len())
+            .bold()
+            .red()
     );
+    for violation in &violations {
+        println!("   {}", violation.red());
+    }
+    Err(ForgeError::Validation(format!(
+        "Schema validation failed:\n{}",
+        violations
+            .iter()
+            .map(|v| format!("  - {}", v))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )))
+}
 
-    // Validate tables
-    for (name, table) in &model.tables {
-        if let Err(e) = table.validate_lengths() {
-            println!(
-                "\n{}",
-                format!("❌ Table '{}' validation failed: {}", name, e)
-                    .bold()
-                    .red()
-            );
-            return Err(ForgeError::Validation(format!(
-                "Table '{}' validation failed: {}",
-                name, e
-            )));
-        }
+/// A stored value that no longer matches its recalculated value;
+/// `stored` is `None` when the file holds no value yet
+#[derive(Debug, Clone, PartialEq)]
+struct ValueMismatch {
+    /// Table name, or the scalar section (e.g. `summary`) for scalars
+    group: String,
+    /// Scalar name or `column[row]` cell reference
+    name: String,
+    stored: Option<f64>,
+    recalculated: f64,
+}
+
+impl ValueMismatch {
+    /// How far the recalculated value moved from the stored one
+    fn delta(&self) -> Option<f64> {
+        self.stored.map(|stored| self.recalculated - stored)
     }
 
-    // Calculate what values SHOULD be based on formulas
-    let calculator = ArrayCalculator::new(model.clone());
+    /// Stored value for display, `(none)` when missing
+    fn stored_text(&self) -> String {
+        self.stored
+            .map_or_else(|| "(none)".to_string(), format_number)
+    }
+
+    /// Full reference: `table.column[row]`, `section.scalar` or a top-level scalar
+    fn path(&self) -> String {
+        if self.group == "scalars" {
+            self.name.clone()
+        } else {
+            format!("{}.{}", self.group, self.name)
+        }
+    }
+
+    /// YAML keys holding the stored value, for locating its line
+    fn yaml_keys(&self) -> Vec<String> {
+        let path = self.path();
+        match path.split_once('[') {
+            Some((column, _)) => column.split('.').map(str::to_string).collect(),
+            None => path
+                .split('.')
+                .chain(["value"])
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+/// Compare stored values against recalculated ones, collecting every mismatch:
+/// formula-column cells and calculated scalars, including those not stored yet
+/// (constants are inputs and never change)
+fn find_mismatches(
+    model: &crate::types::ParsedModel,
+    calculated: &crate::types::ParsedModel,
+) -> Vec<ValueMismatch> {
+    const TOLERANCE: f64 = 0.0001; // Floating point comparison tolerance
+    let changed = |stored: Option<f64>, new: f64| {
+        stored.is_none_or(|stored| (stored - new).abs() > TOLERANCE)
+    };
+    let mut mismatches = Vec::new();
+
+    let mut table_names: Vec<&String> = calculated.tables.keys().collect();
+    table_names.sort();
+    for table_name in table_names {
+        let (original, table) = match (
+            model.tables.get(table_name),
+            calculated.tables.get(table_name),
+        ) {
+            (Some(original), Some(table)) => (original, table),
+            _ => continue,
+        };
+        let mut formula_cols: Vec<&String> = original.row_formulas.keys().collect();
+        formula_cols.sort();
+        for col_name in formula_cols {
+            let stored = original.columns.get(col_name).map(|c| &c.values);
+            let recalculated = table.columns.get(col_name).map(|c| &c.values);
+            if let (
+                Some(crate::types::ColumnValue::Number(stored)),
+                Some(crate::types::ColumnValue::Number(recalculated)),
+            ) = (stored, recalculated)
+            {
+                for (row, recalculated) in recalculated.iter().enumerate() {
+                    let stored = stored.get(row).copied();
+                    if changed(stored, *recalculated) {
+                        mismatches.push(ValueMismatch {
+                            group: table_name.clone(),
+                            name: format!("{col_name}[{row}]"),
+                            stored,
+                            recalculated: *recalculated,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    let mut scalar_names: Vec<&String> = calculated.scalars.keys().collect();
+    scalar_names.sort();
+    for var_name in scalar_names {
+        if calculated.constants.contains(var_name) {
+            continue;
+        }
+        let stored = model.scalars.get(var_name).and_then(|v| v.value);
+        if let Some(recalculated) = calculated.scalars[var_name].value {
+            if changed(stored, recalculated) {
+                let (group, name) = match var_name.rsplit_once('.') {
+                    Some((group, name)) => (group.to_string(), name.to_string()),
+                    None => ("scalars".to_string(), var_name.clone()),
+                };
+                mismatches.push(ValueMismatch {
+                    group,
+                    name,
+                    stored,
+                    recalculated,
+                });
+            }
+        }
+    }
+
+    mismatches
+}
+
+/// Print mismatches grouped by table/scalar section, followed by a count
+fn print_mismatch_diff(mismatches: &[ValueMismatch]) {
+    let mut current_group: Option<&str> = None;
+    for m in mismatches {
+        if current_group != Some(m.group.as_str()) {
+            println!("   {}", m.group.bright_blue().bold());
+            current_group = Some(m.group.as_str());
+        }
+        println!(
+            "      {:<24} stored: {}  recalculated: {}  delta: {}",
+            m.name,
+            format!("{:>14}", m.stored_text()).red(),
+            format!("{:>14}", format_number(m.recalculated)).green(),
+            m.delta()
+                .map_or_else(String::new, |delta| format!("{:+.6}", delta))
+                .yellow()
+        );
+    }
+    println!();
+    println!("   {} stale value(s)", mismatches.len());
+    println!();
+}
+
+/// Validate one file: table lengths, then every formula value against a
+/// fresh calculation
+///
+/// Prints the report; stale values are an error, shown as a `-`/`+` diff
+/// when `diff` is set.
+fn validate_file(file: &std::path::Path, diff: bool) -> ForgeResult<()> {
+    // Parse YAML file
+    let model = parser::parse_model(file)?;
+
+    if model.tables.is_empty() && model.scalars.is_empty() {
+        println!("{}", "⚠️  No tables or scalars found in YAML file".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "   Found {} tables, {} scalars",
+        model.tables.len(),
+        model.scalars.len()
+    );
+
+    // Validate tables
+    for (name, table) in &model.tables {
+        if let Err(e) = table.validate_lengths() {
+            println!(
+                "\n{}",
+                format!("❌ Table '{}' validation failed: {}", name, e)
+                    .bold()
+                    .red()
+            );
+            return Err(ForgeError::Validation(format!(
+                "Table '{}' validation failed: {}",
+                name, e
+            )));
+        }
+    }
+
+    // Calculate what values SHOULD be based on formulas
+    let calculator = ArrayCalculator::new(model.clone());
     let calculated = match calculator.calculate_all() {
         Ok(vals) => vals,
         Err(e) => {
@@ -552,23 +1276,12 @@ fn validate_single_file(file: &std::path::Path) -> ForgeResult<()> {
         }
     };
 
-    // Compare calculated values vs. current values in file
-    let mut mismatches = Vec::new();
-    const TOLERANCE: f64 = 0.0001; // Floating point comparison tolerance
-
-    for (var_name, var) in &calculated.scalars {
-        if let Some(calculated_value) = var.value {
-            if let Some(original) = model.scalars.get(var_name) {
-                if let Some(current_value) = original.value {
-                    // Check if values match within tolerance
-                    let diff = (current_value - calculated_value).abs();
-                    if diff > TOLERANCE {
-                        mismatches.push((var_name.clone(), current_value, calculated_value, diff));
-                    }
-                }
-            }
-        }
-    }
+    // Compare calculated values vs. current values in file; values not
+    // stored yet are left for `calculate` to fill in
+    let mismatches: Vec<ValueMismatch> = find_mismatches(&model, &calculated)
+        .into_iter()
+        .filter(|m| m.stored.is_some())
+        .collect();
 
     // Report results
     println!();
@@ -588,19 +1301,23 @@ fn validate_single_file(file: &std::path::Path) -> ForgeResult<()> {
         );
         println!("{}", "   File needs recalculation!\n".yellow());
 
-        for (name, current, expected, diff) in &mismatches {
-            println!("   {}", name.bright_blue().bold());
-            // Format numbers with reasonable precision (remove trailing zeros)
-            println!(
-                "      Current:  {}",
-                format_number(*current).to_string().red()
-            );
-            println!(
-                "      Expected: {}",
-                format_number(*expected).to_string().green()
-            );
-            println!("      Diff:     {}", format!("{diff:.6}").yellow());
-            println!();
+        if diff {
+            print_mismatch_diff(&mismatches);
+        } else {
+            for m in &mismatches {
+                println!("   {}", m.path().bright_blue().bold());
+                // Format numbers with reasonable precision (remove trailing zeros)
+                println!("      Current:  {}", m.stored_text().red());
+                println!(
+                    "      Expected: {}",
+                    format_number(m.recalculated).to_string().green()
+                );
+                println!(
+                    "      Diff:     {}",
+                    format!("{:.6}", m.delta().unwrap_or_default().abs()).yellow()
+                );
+                println!();
+            }
         }
 
         println!(
@@ -608,14 +1325,263 @@ fn validate_single_file(file: &std::path::Path) -> ForgeResult<()> {
             "💡 Run 'forge calculate' to update values".bold().yellow()
         );
 
-        Err(crate::error::ForgeError::Validation(
+        Err(crate::error::ForgeError::StaleValues(
             "Values do not match formulas - file needs recalculation".to_string(),
         ))
     }
 }
 
+/// How serious a validation issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueSeverity {
+    Error,
+    Warning,
+}
+
+/// Kind of problem behind a validation issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IssueKind {
+    /// Stored value differs from its formula; `forge calculate` fixes it
+    StaleValue,
+    /// The file does not match the `--schema`
+    SchemaViolation,
+    /// The file cannot be read or parsed
+    ParseError,
+    /// A formula cannot be calculated
+    FormulaError,
+    /// The file has no tables or scalars
+    EmptyModel,
+}
+
+/// One problem found by `forge validate --report`
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationIssue {
+    pub file: String,
+    /// 1-based line in `file`, when the value can be located
+    pub line: Option<usize>,
+    /// Value reference (`sales.revenue[2]`, `summary.total`) or schema path
+    pub path: Option<String>,
+    /// Recalculated value of a stale value
+    pub expected: Option<f64>,
+    /// Stored value of a stale value
+    pub actual: Option<f64>,
+    pub severity: IssueSeverity,
+    pub kind: IssueKind,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn new(file: &Path, kind: IssueKind, message: String) -> Self {
+        let severity = match kind {
+            IssueKind::EmptyModel => IssueSeverity::Warning,
+            _ => IssueSeverity::Error,
+        };
+        Self {
+            file: file.display().to_string(),
+            line: None,
+            path: None,
+            expected: None,
+            actual: None,
+            severity,
+            kind,
+            message,
+        }
+    }
+}
+
+/// Every issue found in the validated files, as written by `--report`
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationReport {
+    pub files: Vec<String>,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Exit code outcome: any error other than a stale value makes it broken
+    pub fn outcome(&self) -> ValidationOutcome {
+        let errors = self
+            .issues
+            .iter()
+            .filter(|issue| issue.severity == IssueSeverity::Error);
+        let mut outcome = ValidationOutcome::Valid;
+        for issue in errors {
+            if issue.kind != IssueKind::StaleValue {
+                return ValidationOutcome::Broken;
+            }
+            outcome = ValidationOutcome::Stale;
+        }
+        outcome
+    }
+}
+
+/// Validate every file without stopping at the first problem, collecting
+/// all issues into a report
+///
+/// # Errors
+///
+/// Returns an error only if the schema cannot be loaded.
+pub fn validation_report(
+    files: &[PathBuf],
+    schema: Option<&Path>,
+) -> ForgeResult<ValidationReport> {
+    let schema = schema.map(load_json_schema).transpose()?;
+    let mut issues = Vec::new();
+    for file in files {
+        issues.extend(file_issues(file, schema.as_ref()));
+    }
+    Ok(ValidationReport {
+        files: files.iter().map(|f| f.display().to_string()).collect(),
+        issues,
+    })
+}
+
+/// All validation issues of one file
+fn file_issues(file: &Path, schema: Option<&jsonschema::JSONSchema>) -> Vec<ValidationIssue> {
+    let content = match fs::read_to_string(file) {
+        Ok(content) => content,
+        Err(e) => {
+            return vec![ValidationIssue::new(
+                file,
+                IssueKind::ParseError,
+                ForgeError::Io(e).to_string(),
+            )]
+        }
+    };
+
+    let mut issues = Vec::new();
+    if let Some(schema) = schema {
+        match schema_violations(&content, schema) {
+            Ok(violations) => {
+                for (path, message) in violations {
+                    let keys: Vec<&str> = path.split('/').filter(|k| !k.is_empty()).collect();
+                    let mut issue = ValidationIssue::new(file, IssueKind::SchemaViolation, message);
+                    issue.line = writer::key_line(&content, &keys);
+                    issue.path = Some(path);
+                    issues.push(issue);
+                }
+            }
+            Err(e) => {
+                issues.push(ValidationIssue::new(
+                    file,
+                    IssueKind::ParseError,
+                    e.to_string(),
+                ));
+                return issues;
+            }
+        }
+    }
+
+    let model = match parser::parse_model(file) {
+        Ok(model) => model,
+        Err(e) => {
+            issues.push(ValidationIssue::new(
+                file,
+                IssueKind::ParseError,
+                e.to_string(),
+            ));
+            return issues;
+        }
+    };
+    if model.tables.is_empty() && model.scalars.is_empty() {
+        issues.push(ValidationIssue::new(
+            file,
+            IssueKind::EmptyModel,
+            "No tables or scalars found in YAML file".to_string(),
+        ));
+        return issues;
+    }
+
+    let calculated = match ArrayCalculator::new(model.clone()).calculate_all() {
+        Ok(calculated) => calculated,
+        Err(e) => {
+            issues.push(ValidationIssue::new(
+                file,
+                IssueKind::FormulaError,
+                e.to_string(),
+            ));
+            return issues;
+        }
+    };
+
+    for m in find_mismatches(&model, &calculated)
+        .into_iter()
+        .filter(|m| m.stored.is_some())
+    {
+        let keys = m.yaml_keys();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        let mut issue = ValidationIssue::new(
+            file,
+            IssueKind::StaleValue,
+            format!(
+                "Stale value: stored {}, formula gives {}",
+                m.stored_text(),
+                format_number(m.recalculated)
+            ),
+        );
+        issue.line = writer::key_line(&content, &keys);
+        issue.path = Some(m.path());
+        issue.expected = Some(m.recalculated);
+        issue.actual = m.stored;
+        issues.push(issue);
+    }
+    issues
+}
+
+/// Execute the validate command with `--report`: write every issue of every
+/// file to a JSON report instead of stopping at the first failure
+///
+/// # Errors
+///
+/// Returns an error if any file has issues, so the exit code follows
+/// [`ValidationOutcome`].
+pub fn validate_report(
+    files: Vec<PathBuf>,
+    schema: Option<PathBuf>,
+    report_path: PathBuf,
+) -> ForgeResult<()> {
+    println!("{}", "✅ Validating model".bold().green());
+    let report = validation_report(&files, schema.as_deref())?;
+
+    let json = serde_json::to_string_pretty(&report)
+        .map_err(|e| ForgeError::Export(format!("JSON serialization failed: {}", e)))?;
+    fs::write(&report_path, json + "\n").map_err(ForgeError::Io)?;
+
+    let errors = report
+        .issues
+        .iter()
+        .filter(|issue| issue.severity == IssueSeverity::Error)
+        .count();
+    println!(
+        "   {} file(s), {} error(s), {} warning(s)",
+        files.len(),
+        errors,
+        report.issues.len() - errors
+    );
+    println!("💾 Report written to {}", report_path.display());
+
+    let message = format!(
+        "{} validation error(s), see {}",
+        errors,
+        report_path.display()
+    );
+    match report.outcome() {
+        ValidationOutcome::Valid => Ok(()),
+        ValidationOutcome::Stale => Err(ForgeError::StaleValues(message)),
+        ValidationOutcome::Broken => Err(ForgeError::Validation(message)),
+    }
+}
+
 /// Execute the export command
 pub fn export(input: PathBuf, output: PathBuf, verbose: bool) -> ForgeResult<()> {
+    let is_csv = output
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+    if is_csv {
+        return export_csv(input, output, verbose);
+    }
+
     println!("{}", "🔥 Forge - Excel Export".bold().green());
     println!("   Input:  {}", input.display());
     println!("   Output: {}\n", output.display());
@@ -658,6 +1624,37 @@ pub fn export(input: PathBuf, output: PathBuf, verbose: bool) -> ForgeResult<()>
     Ok(())
 }
 
+/// Export calculated tables as CSV (one file per table)
+fn export_csv(input: PathBuf, output: PathBuf, verbose: bool) -> ForgeResult<()> {
+    println!("{}", "🔥 Forge - CSV Export".bold().green());
+    println!("   Input:  {}", input.display());
+    println!("   Output: {}\n", output.display());
+
+    let model = parser::parse_model(&input)?;
+
+    // CSV holds values only, so calculate formula columns first
+    if verbose {
+        println!("{}", "🧮 Calculating tables...".cyan());
+    }
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all()?;
+
+    if result.tables.is_empty() {
+        println!("{}", "⚠️  No tables to export".yellow());
+        return Ok(());
+    }
+
+    let written = writer::write_csv_tables(&result, &output)?;
+
+    println!("{}", "✅ Export Complete!".bold().green());
+    for path in &written {
+        println!("   CSV file: {}", path.display());
+    }
+    println!();
+
+    Ok(())
+}
+
 /// Execute the import command
 pub fn import(
     input: PathBuf,
@@ -666,6 +1663,13 @@ pub fn import(
     split_files: bool,
     multi_doc: bool,
 ) -> ForgeResult<()> {
+    let is_csv = input
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+    if is_csv {
+        return import_csv(input, output, verbose);
+    }
+
     println!("{}", "🔥 Forge - Excel Import".bold().green());
     println!("   Input:  {}", input.display());
     println!("   Output: {}", output.display());
@@ -803,6 +1807,39 @@ pub fn import(
     Ok(())
 }
 
+/// Import a CSV file as a single v1.0.0 YAML table named after the file
+fn import_csv(input: PathBuf, output: PathBuf, verbose: bool) -> ForgeResult<()> {
+    println!("{}", "🔥 Forge - CSV Import".bold().green());
+    println!("   Input:  {}", input.display());
+    println!("   Output: {}\n", output.display());
+
+    let model = CsvImporter::new(&input).import()?;
+
+    if verbose {
+        for (table_name, table) in &model.tables {
+            println!("   📊 Table: {}", table_name.bright_blue());
+            let mut column_names: Vec<_> = table.columns.keys().collect();
+            column_names.sort();
+            for col_name in column_names {
+                println!(
+                    "      {} ({})",
+                    col_name.cyan(),
+                    table.columns[col_name].values.type_name()
+                );
+            }
+            println!("      {} rows", table.row_count());
+        }
+        println!();
+    }
+
+    fs::write(&output, writer::tables_to_yaml(&model)?).map_err(ForgeError::Io)?;
+
+    println!("{}", "✅ Import Complete!".bold().green());
+    println!("   YAML file: {}\n", output.display());
+
+    Ok(())
+}
+
 /// Execute the watch command
 pub fn watch(file: PathBuf, validate_only: bool, verbose: bool) -> ForgeResult<()> {
     println!("{}", "👁️  Forge - Watch Mode".bold().green());
@@ -1045,6 +2082,7 @@ fn apply_scenario(model: &mut crate::types::ParsedModel, scenario_name: &str) ->
 
     // Clone the overrides to avoid borrow checker issues
     let overrides = scenario.overrides.clone();
+    let column_overrides = scenario.column_overrides.clone();
 
     // Apply overrides to scalars
     for (var_name, override_value) in &overrides {
@@ -1061,32 +2099,103 @@ fn apply_scenario(model: &mut crate::types::ParsedModel, scenario_name: &str) ->
         }
     }
 
+    // Replace table columns before calculation; a formula column becomes data
+    for (path, values) in column_overrides {
+        let (table_name, col_name) = path.split_once('.').unwrap_or((path.as_str(), ""));
+        let table = model
+            .tables
+            .get_mut(table_name)
+            .filter(|t| t.columns.contains_key(col_name) || t.row_formulas.contains_key(col_name))
+            .ok_or_else(|| {
+                ForgeError::Validation(format!(
+                    "Scenario '{}': column '{}' not found",
+                    scenario_name, path
+                ))
+            })?;
+
+        let rows = table.row_count();
+        if values.len() != rows {
+            return Err(ForgeError::Validation(format!(
+                "Scenario '{}': column '{}' has {} values but table '{}' has {} rows",
+                scenario_name,
+                path,
+                values.len(),
+                table_name,
+                rows
+            )));
+        }
+
+        let metadata = match table.row_formulas.remove(col_name) {
+            Some(_) => table.formula_metadata.remove(col_name).unwrap_or_default(),
+            None => table.columns[col_name].metadata.clone(),
+        };
+        table.add_column(crate::types::Column::with_metadata(
+            col_name.to_string(),
+            values,
+            metadata,
+        ));
+    }
+
     Ok(())
 }
 
+/// Options of the compare command beyond the scenario list
+#[derive(Debug, Clone, Default)]
+pub struct CompareOptions {
+    /// Scenario every other scenario's change is shown against; it is
+    /// compared too, as the first column, if it is not listed
+    pub baseline: Option<String>,
+    /// Scalars to show; empty shows all of them
+    pub output_vars: Vec<String>,
+}
+
 /// Execute the compare command - compare results across scenarios
 pub fn compare(file: PathBuf, scenarios: Vec<String>, verbose: bool) -> ForgeResult<()> {
+    compare_with_options(file, scenarios, CompareOptions::default(), verbose)
+}
+
+/// Execute the compare command, adding each scenario's change from `baseline`
+/// The baseline is compared too, as the first column, if it is not listed.
+pub fn compare_with_baseline(
+    file: PathBuf,
+    scenarios: Vec<String>,
+    baseline: Option<String>,
+    verbose: bool,
+) -> ForgeResult<()> {
+    let options = CompareOptions {
+        baseline,
+        ..CompareOptions::default()
+    };
+    compare_with_options(file, scenarios, options, verbose)
+}
+
+/// Execute the compare command with a baseline and/or a variable filter
+pub fn compare_with_options(
+    file: PathBuf,
+    mut scenarios: Vec<String>,
+    options: CompareOptions,
+    verbose: bool,
+) -> ForgeResult<()> {
+    if let Some(ref baseline) = options.baseline {
+        if !scenarios.contains(baseline) {
+            scenarios.insert(0, baseline.clone());
+        }
+    }
+
     println!("{}", "🔥 Forge - Scenario Comparison".bold().green());
     println!("   File: {}", file.display());
     println!(
-        "   Scenarios: {}\n",
+        "   Scenarios: {}",
         scenarios.join(", ").bright_yellow().bold()
     );
+    if let Some(ref baseline) = options.baseline {
+        println!("   Baseline: {}", baseline.bright_yellow().bold());
+    }
+    println!();
 
     // Parse model
     let base_model = parser::parse_model(&file)?;
 
-    // Validate scenarios exist
-    for scenario_name in &scenarios {
-        if !base_model.scenarios.contains_key(scenario_name) {
-            let available: Vec<_> = base_model.scenarios.keys().collect();
-            return Err(ForgeError::Validation(format!(
-                "Scenario '{}' not found. Available: {:?}",
-                scenario_name, available
-            )));
-        }
-    }
-
     if verbose {
         println!(
             "   Found {} tables, {} scalars, {} scenarios",
@@ -1096,72 +2205,371 @@ pub fn compare(file: PathBuf, scenarios: Vec<String>, verbose: bool) -> ForgeRes
         );
     }
 
-    // Calculate results for each scenario
-    let mut results: Vec<(String, crate::types::ParsedModel)> = Vec::new();
+    let report = scenario_comparison(&base_model, scenarios, options)?;
 
-    for scenario_name in &scenarios {
-        let mut model = base_model.clone();
-        apply_scenario(&mut model, scenario_name)?;
+    println!("\n{}", "📊 Scenario Comparison:".bold().cyan());
+    print!("{}", render_comparison(&report));
+    println!("\n{}", "✅ Comparison complete".bold().green());
 
-        let calculator = ArrayCalculator::new(model);
-        let calculated = calculator.calculate_all()?;
-        results.push((scenario_name.clone(), calculated));
-    }
+    Ok(())
+}
 
-    // Collect all scalar names
-    let mut all_scalars: Vec<String> = results
-        .iter()
-        .flat_map(|(_, m)| m.scalars.keys().cloned())
-        .collect();
+/// Scalar values of compared scenarios, as printed by `--output-format json|yaml`
+#[derive(Debug, Clone, Serialize)]
+pub struct CompareReport {
+    pub scenarios: Vec<String>,
+    pub baseline: Option<String>,
+    /// One entry per scalar, sorted by name
+    pub variables: Vec<CompareVariable>,
+}
+
+/// One scalar across the compared scenarios
+#[derive(Debug, Clone, Serialize)]
+pub struct CompareVariable {
+    pub name: String,
+    /// Value per scenario; `None` when it has no calculated value
+    pub values: BTreeMap<String, Option<f64>>,
+    /// Change from the baseline per other scenario, when both have a value
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub deltas: BTreeMap<String, CompareDelta>,
+}
+
+/// Change of a scenario value from the baseline value
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct CompareDelta {
+    pub absolute: f64,
+    /// Percent of the baseline value; `None` when the baseline is 0
+    pub percent: Option<f64>,
+}
+
+/// Compare scenarios without printing anything
+pub fn compare_report(
+    file: PathBuf,
+    scenarios: Vec<String>,
+    options: CompareOptions,
+) -> ForgeResult<CompareReport> {
+    let base_model = parser::parse_model(&file)?;
+    scenario_comparison(&base_model, scenarios, options)
+}
+
+/// Calculate each scenario of `base_model` and compare the results
+fn scenario_comparison(
+    base_model: &crate::types::ParsedModel,
+    mut scenarios: Vec<String>,
+    options: CompareOptions,
+) -> ForgeResult<CompareReport> {
+    let CompareOptions {
+        baseline,
+        output_vars,
+    } = options;
+    if let Some(ref baseline) = baseline {
+        if !scenarios.contains(baseline) {
+            scenarios.insert(0, baseline.clone());
+        }
+    }
+
+    // Validate scenarios exist
+    for scenario_name in &scenarios {
+        if !base_model.scenarios.contains_key(scenario_name) {
+            let available: Vec<_> = base_model.scenarios.keys().collect();
+            return Err(ForgeError::Validation(format!(
+                "Scenario '{}' not found. Available: {:?}",
+                scenario_name, available
+            )));
+        }
+    }
+
+    // Calculate results for each scenario
+    let mut results: Vec<(String, crate::types::ParsedModel)> = Vec::new();
+
+    for scenario_name in &scenarios {
+        let mut model = base_model.clone();
+        apply_scenario(&mut model, scenario_name)?;
+
+        let calculator = ArrayCalculator::new(model);
+        let calculated = calculator.calculate_all()?;
+        results.push((scenario_name.clone(), calculated));
+    }
+
+    let known: Vec<&String> = base_model.scalars.keys().collect();
+    check_output_vars(&output_vars, &known)?;
+
+    Ok(comparison(&results, baseline.as_deref(), &output_vars))
+}
+
+/// Collect the scalar values of calculated scenarios, limited to
+/// `output_vars` unless it is empty
+fn comparison(
+    results: &[(String, crate::types::ParsedModel)],
+    baseline: Option<&str>,
+    output_vars: &[String],
+) -> CompareReport {
+    let baseline = baseline.filter(|name| results.iter().any(|(scenario, _)| scenario == name));
+    let value = |model: &crate::types::ParsedModel, name: &str| {
+        model.scalars.get(name).and_then(|var| var.value)
+    };
+
+    // Collect all scalar names
+    let mut all_scalars: Vec<&String> = results
+        .iter()
+        .flat_map(|(_, m)| m.scalars.keys())
+        .filter(|name| output_vars.is_empty() || output_vars.contains(name))
+        .collect();
     all_scalars.sort();
     all_scalars.dedup();
 
-    // Print comparison table
-    println!("\n{}", "📊 Scenario Comparison:".bold().cyan());
-    println!("{}", "─".repeat(20 + scenarios.len() * 15));
+    let variables = all_scalars
+        .into_iter()
+        .map(|name| {
+            let values: BTreeMap<String, Option<f64>> = results
+                .iter()
+                .map(|(scenario, model)| (scenario.clone(), value(model, name)))
+                .collect();
+            let base = baseline.and_then(|b| values[b]);
+            let deltas = values
+                .iter()
+                .filter(|(scenario, _)| Some(scenario.as_str()) != baseline)
+                .filter_map(|(scenario, current)| {
+                    let (base, current) = (base?, (*current)?);
+                    let delta = current - base;
+                    let percent = if base.abs() < 0.0001 {
+                        None
+                    } else {
+                        Some(delta / base.abs() * 100.0)
+                    };
+                    Some((
+                        scenario.clone(),
+                        CompareDelta {
+                            absolute: delta,
+                            percent,
+                        },
+                    ))
+                })
+                .collect();
+            CompareVariable {
+                name: name.clone(),
+                values,
+                deltas,
+            }
+        })
+        .collect();
+
+    CompareReport {
+        scenarios: results.iter().map(|(name, _)| name.clone()).collect(),
+        baseline: baseline.map(str::to_string),
+        variables,
+    }
+}
+
+/// Render a comparison as a side-by-side table
+/// With a baseline, every other scenario gets a `Δ` column holding its
+/// change from the baseline, absolute and in percent (`n/a` when the
+/// baseline value is 0).
+fn render_comparison(report: &CompareReport) -> String {
+    let baseline = report.baseline.as_deref();
+    let delta_columns = if baseline.is_some() {
+        report.scenarios.len() - 1
+    } else {
+        0
+    };
+    let rule = "─".repeat(20 + report.scenarios.len() * 15 + delta_columns * 22);
 
     // Header row
-    print!("{:<20}", "Variable".bold());
-    for scenario_name in &scenarios {
-        print!("{:>15}", scenario_name.bright_yellow().bold());
+    let mut lines = vec![rule.clone()];
+    let mut header = format!("{:<20}", "Variable").bold().to_string();
+    for scenario in &report.scenarios {
+        header += &format!("{:>15}", scenario)
+            .bright_yellow()
+            .bold()
+            .to_string();
+        if baseline.is_some() && Some(scenario.as_str()) != baseline {
+            header += &format!("{:>22}", format!("Δ {}", scenario))
+                .bright_yellow()
+                .bold()
+                .to_string();
+        }
     }
-    println!();
-    println!("{}", "─".repeat(20 + scenarios.len() * 15));
+    lines.push(header);
+    lines.push(rule.clone());
 
     // Data rows
-    for scalar_name in &all_scalars {
-        print!("{:<20}", scalar_name.bright_blue());
+    for variable in &report.variables {
+        let mut row = format!("{:<20}", variable.name).bright_blue().to_string();
+        for scenario in &report.scenarios {
+            row += &match variable.values[scenario] {
+                Some(v) => format!("{:>15}", format_number(v)).green().to_string(),
+                None => format!("{:>15}", "-").dimmed().to_string(),
+            };
 
-        for (_, result_model) in &results {
-            if let Some(var) = result_model.scalars.get(scalar_name) {
-                if let Some(value) = var.value {
-                    print!("{:>15}", format_number(value).green());
-                } else {
-                    print!("{:>15}", "-".dimmed());
-                }
-            } else {
-                print!("{:>15}", "-".dimmed());
+            if baseline.is_none() || Some(scenario.as_str()) == baseline {
+                continue;
             }
+            row += &match variable.deltas.get(scenario) {
+                Some(delta) => {
+                    let pct = match delta.percent {
+                        Some(pct) => format!("{:+.1}%", pct),
+                        None => "n/a".to_string(),
+                    };
+                    let sign = if delta.absolute < 0.0 { "" } else { "+" };
+                    let cell = format!(
+                        "{:>22}",
+                        format!("{}{} ({})", sign, format_number(delta.absolute), pct)
+                    );
+                    if delta.absolute < 0.0 {
+                        cell.red().to_string()
+                    } else {
+                        cell.green().to_string()
+                    }
+                }
+                None => format!("{:>22}", "-").dimmed().to_string(),
+            };
         }
-        println!();
+        lines.push(row);
     }
 
-    println!("{}", "─".repeat(20 + scenarios.len() * 15));
-    println!("\n{}", "✅ Comparison complete".bold().green());
+    lines.push(rule);
+    lines.join("\n") + "\n"
+}
 
-    Ok(())
+/// Reject `--output-vars` names that are not among `known`, listing the valid ones
+fn check_output_vars(output_vars: &[String], known: &[&String]) -> ForgeResult<()> {
+    let unknown: Vec<&str> = output_vars
+        .iter()
+        .filter(|name| !known.contains(name))
+        .map(String::as_str)
+        .collect();
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    let mut valid: Vec<&str> = known.iter().map(|name| name.as_str()).collect();
+    valid.sort();
+    Err(ForgeError::Validation(format!(
+        "Unknown output variable(s): {}. Valid variables: {}",
+        unknown.join(", "),
+        valid.join(", ")
+    )))
 }
 
 /// Variance result for a single variable
-#[derive(Debug, Clone)]
-struct VarianceResult {
-    name: String,
-    budget: f64,
-    actual: f64,
-    variance: f64,
-    variance_pct: f64,
-    is_favorable: bool,
-    exceeds_threshold: bool,
+#[derive(Debug, Clone, Serialize)]
+pub struct VarianceResult {
+    pub name: String,
+    pub budget: f64,
+    pub actual: f64,
+    pub variance: f64,
+    pub variance_pct: f64,
+    pub is_favorable: bool,
+    pub exceeds_threshold: bool,
+}
+
+/// Budget vs actual variances, as printed by `--output-format json|yaml`
+#[derive(Debug, Clone, Serialize)]
+pub struct VarianceReport {
+    pub budget: String,
+    pub actual: String,
+    pub threshold: f64,
+    pub variances: Vec<VarianceResult>,
+    pub favorable: usize,
+    pub unfavorable: usize,
+    pub alerts: usize,
+}
+
+/// Compare one budget value against its actual
+fn variance_result(name: &str, budget_val: f64, actual_val: f64, threshold: f64) -> VarianceResult {
+    let variance_abs = actual_val - budget_val;
+    let variance_pct = if budget_val.abs() > 0.0001 {
+        (variance_abs / budget_val) * 100.0
+    } else {
+        0.0
+    };
+
+    // Determine favorability (heuristic based on name)
+    let is_expense = name.to_lowercase().contains("expense")
+        || name.to_lowercase().contains("cost")
+        || name.to_lowercase().contains("cogs");
+    let is_favorable = if is_expense {
+        actual_val <= budget_val // Lower expenses = favorable
+    } else {
+        actual_val >= budget_val // Higher revenue/profit = favorable
+    };
+
+    let exceeds_threshold = variance_pct.abs() >= threshold;
+
+    VarianceResult {
+        name: name.to_string(),
+        budget: budget_val,
+        actual: actual_val,
+        variance: variance_abs,
+        variance_pct,
+        is_favorable,
+        exceeds_threshold,
+    }
+}
+
+/// Row-by-row variances of the numeric columns found in both models
+/// Each row is reported as `table.column[i]`, followed by a column total
+fn table_variances(
+    budget: &crate::types::ParsedModel,
+    actual: &crate::types::ParsedModel,
+    threshold: f64,
+) -> ForgeResult<Vec<VarianceResult>> {
+    use crate::types::ColumnValue;
+
+    let mut variances = Vec::new();
+    let mut table_names: Vec<&String> = budget
+        .tables
+        .keys()
+        .filter(|name| actual.tables.contains_key(*name))
+        .collect();
+    table_names.sort();
+
+    for table_name in table_names {
+        let budget_table = &budget.tables[table_name];
+        let actual_table = &actual.tables[table_name];
+        let mut col_names: Vec<&String> = budget_table
+            .columns
+            .keys()
+            .filter(|name| actual_table.columns.contains_key(*name))
+            .collect();
+        col_names.sort();
+
+        for col_name in col_names {
+            let (budget_values, actual_values) = match (
+                &budget_table.columns[col_name].values,
+                &actual_table.columns[col_name].values,
+            ) {
+                (ColumnValue::Number(b), ColumnValue::Number(a)) => (b, a),
+                _ => continue,
+            };
+            let path = format!("{}.{}", table_name, col_name);
+            if budget_values.len() != actual_values.len() {
+                return Err(ForgeError::Validation(format!(
+                    "Cannot compare {}: budget has {} rows, actual has {}",
+                    path,
+                    budget_values.len(),
+                    actual_values.len()
+                )));
+            }
+
+            for (i, (b, a)) in budget_values.iter().zip(actual_values).enumerate() {
+                variances.push(variance_result(
+                    &format!("{}[{}]", path, i),
+                    *b,
+                    *a,
+                    threshold,
+                ));
+            }
+            variances.push(variance_result(
+                &format!("{} (total)", path),
+                budget_values.iter().sum(),
+                actual_values.iter().sum(),
+                threshold,
+            ));
+        }
+    }
+
+    Ok(variances)
 }
 
 /// Execute the variance command - budget vs actual analysis
@@ -1196,60 +2604,7 @@ pub fn variance(
     let actual_calculator = ArrayCalculator::new(actual_model);
     let actual_result = actual_calculator.calculate_all()?;
 
-    // Collect scalar variances
-    let mut variances: Vec<VarianceResult> = Vec::new();
-
-    // Get all scalar names from both models
-    let mut all_scalars: Vec<String> = budget_result
-        .scalars
-        .keys()
-        .chain(actual_result.scalars.keys())
-        .cloned()
-        .collect();
-    all_scalars.sort();
-    all_scalars.dedup();
-
-    for name in &all_scalars {
-        let budget_val = budget_result
-            .scalars
-            .get(name)
-            .and_then(|v| v.value)
-            .unwrap_or(0.0);
-        let actual_val = actual_result
-            .scalars
-            .get(name)
-            .and_then(|v| v.value)
-            .unwrap_or(0.0);
-
-        let variance_abs = actual_val - budget_val;
-        let variance_pct = if budget_val.abs() > 0.0001 {
-            (variance_abs / budget_val) * 100.0
-        } else {
-            0.0
-        };
-
-        // Determine favorability (heuristic based on name)
-        let is_expense = name.to_lowercase().contains("expense")
-            || name.to_lowercase().contains("cost")
-            || name.to_lowercase().contains("cogs");
-        let is_favorable = if is_expense {
-            actual_val <= budget_val // Lower expenses = favorable
-        } else {
-            actual_val >= budget_val // Higher revenue/profit = favorable
-        };
-
-        let exceeds_threshold = variance_pct.abs() >= threshold;
-
-        variances.push(VarianceResult {
-            name: name.clone(),
-            budget: budget_val,
-            actual: actual_val,
-            variance: variance_abs,
-            variance_pct,
-            is_favorable,
-            exceeds_threshold,
-        });
-    }
+    let variances = variance_results(&budget_result, &actual_result, threshold)?;
 
     // Handle output
     if let Some(output_path) = output {
@@ -1277,9 +2632,19 @@ pub fn variance(
                         .green()
                 );
             }
+            "md" | "markdown" => {
+                fs::write(&output_path, variance_to_markdown(&variances, threshold))
+                    .map_err(|e| ForgeError::Export(format!("Failed to write file: {}", e)))?;
+                println!(
+                    "{}",
+                    format!("✅ Variance report exported to {}", output_path.display())
+                        .bold()
+                        .green()
+                );
+            }
             _ => {
                 return Err(ForgeError::Export(format!(
-                    "Unsupported output format: {}. Use .xlsx or .yaml",
+                    "Unsupported output format: {}. Use .xlsx, .yaml or .md",
                     extension
                 )));
             }
@@ -1309,6 +2674,104 @@ pub fn variance(
     Ok(())
 }
 
+/// Run the variance analysis without printing anything
+pub fn variance_report(
+    budget_path: PathBuf,
+    actual_path: PathBuf,
+    threshold: f64,
+) -> ForgeResult<VarianceReport> {
+    let budget_result = ArrayCalculator::new(parser::parse_model(&budget_path)?).calculate_all()?;
+    let actual_result = ArrayCalculator::new(parser::parse_model(&actual_path)?).calculate_all()?;
+    let variances = variance_results(&budget_result, &actual_result, threshold)?;
+
+    let favorable = variances.iter().filter(|v| v.is_favorable).count();
+    Ok(VarianceReport {
+        budget: budget_path.display().to_string(),
+        actual: actual_path.display().to_string(),
+        threshold,
+        favorable,
+        unfavorable: variances.len() - favorable,
+        alerts: variances.iter().filter(|v| v.exceeds_threshold).count(),
+        variances,
+    })
+}
+
+/// Variances of every scalar in either model, then of the shared table columns
+fn variance_results(
+    budget_result: &crate::types::ParsedModel,
+    actual_result: &crate::types::ParsedModel,
+    threshold: f64,
+) -> ForgeResult<Vec<VarianceResult>> {
+    let mut variances: Vec<VarianceResult> = Vec::new();
+
+    // Get all scalar names from both models
+    let mut all_scalars: Vec<String> = budget_result
+        .scalars
+        .keys()
+        .chain(actual_result.scalars.keys())
+        .cloned()
+        .collect();
+    all_scalars.sort();
+    all_scalars.dedup();
+
+    for name in &all_scalars {
+        let budget_val = budget_result
+            .scalars
+            .get(name)
+            .and_then(|v| v.value)
+            .unwrap_or(0.0);
+        let actual_val = actual_result
+            .scalars
+            .get(name)
+            .and_then(|v| v.value)
+            .unwrap_or(0.0);
+        variances.push(variance_result(name, budget_val, actual_val, threshold));
+    }
+
+    // Table columns present in both files, compared row by row
+    variances.extend(table_variances(budget_result, actual_result, threshold)?);
+
+    Ok(variances)
+}
+
+/// Favorability emoji for a variance, prefixed with ⚠️ when over the threshold
+fn variance_status(v: &VarianceResult) -> &'static str {
+    if v.exceeds_threshold && !v.is_favorable {
+        "⚠️ ❌"
+    } else if v.exceeds_threshold {
+        "⚠️ ✅"
+    } else if v.is_favorable {
+        "✅"
+    } else {
+        "❌"
+    }
+}
+
+/// Format a number like [`format_number`] with thousands separators (1,234,567.5)
+fn format_thousands(n: f64) -> String {
+    let formatted = format_number(n);
+    let (sign, digits) = match formatted.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", formatted.as_str()),
+    };
+    let (int_part, frac_part) = match digits.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (digits, None),
+    };
+
+    let mut grouped = String::new();
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    match frac_part {
+        Some(frac) => format!("{}{}.{}", sign, grouped, frac),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
 /// Print variance results as a table
 fn print_variance_table(variances: &[VarianceResult], threshold: f64) {
     println!("\n{}", "📊 Budget vs Actual Variance:".bold().cyan());
@@ -1331,15 +2794,7 @@ fn print_variance_table(variances: &[VarianceResult], threshold: f64) {
         let var_str = format_number(v.variance);
         let pct_str = format!("{:.1}%", v.variance_pct);
 
-        let status = if v.exceeds_threshold && !v.is_favorable {
-            "⚠️ ❌".to_string()
-        } else if v.exceeds_threshold {
-            "⚠️ ✅".to_string()
-        } else if v.is_favorable {
-            "✅".to_string()
-        } else {
-            "❌".to_string()
-        };
+        let status = variance_status(v);
 
         // Color the variance based on favorability
         let var_colored = if v.is_favorable {
@@ -1492,75 +2947,476 @@ fn export_variance_to_yaml(
     Ok(())
 }
 
-/// Parse a range string "start,end,step" into a vector of values
-fn parse_range(range: &str) -> ForgeResult<Vec<f64>> {
-    let parts: Vec<&str> = range.split(',').collect();
-    if parts.len() != 3 {
-        return Err(ForgeError::Validation(format!(
-            "Invalid range format '{}'. Expected: start,end,step (e.g., 0.01,0.15,0.02)",
-            range
-        )));
-    }
-
-    let start: f64 = parts[0]
-        .trim()
-        .parse()
-        .map_err(|_| ForgeError::Validation(format!("Invalid start value: '{}'", parts[0])))?;
-    let end: f64 = parts[1]
-        .trim()
-        .parse()
-        .map_err(|_| ForgeError::Validation(format!("Invalid end value: '{}'", parts[1])))?;
-    let step: f64 = parts[2]
-        .trim()
-        .parse()
-        .map_err(|_| ForgeError::Validation(format!("Invalid step value: '{}'", parts[2])))?;
-
-    if step <= 0.0 {
-        return Err(ForgeError::Validation("Step must be positive".to_string()));
-    }
-    if start > end {
-        return Err(ForgeError::Validation(
-            "Start must be less than or equal to end".to_string(),
+/// Render a variance report as a Markdown table, for pasting into PRs and reports
+fn variance_to_markdown(variances: &[VarianceResult], threshold: f64) -> String {
+    let mut content = String::new();
+    content.push_str("## Budget vs Actual Variance\n\n");
+    content.push_str("| Variable | Budget | Actual | Variance | % | Status |\n");
+    content.push_str("|---|---:|---:|---:|---:|:---:|\n");
+    for v in variances {
+        content.push_str(&format!(
+            "| {} | {} | {} | {} | {:.1}% | {} |\n",
+            v.name,
+            format_thousands(v.budget),
+            format_thousands(v.actual),
+            format_thousands(v.variance),
+            v.variance_pct,
+            variance_status(v)
         ));
     }
+    content.push_str(&format!("\n⚠️ = exceeds {:.0}% threshold\n", threshold));
+    content
+}
 
-    let mut values = Vec::new();
-    let mut current = start;
-    while current <= end + step * 0.001 {
-        // Small tolerance for floating point
-        values.push(current);
-        current += step;
-    }
+/// Execute the schema command - export the model's structure as JSON Schema
+/// Prints to stdout unless an output file is given
+pub fn schema(file: PathBuf, output: Option<PathBuf>) -> ForgeResult<()> {
+    let model = parser::parse_model(&file)?;
+    let schema = serde_json::to_string_pretty(&crate::api::model_schema(&model))
+        .map_err(|e| ForgeError::Export(format!("Failed to serialize schema: {}", e)))?;
 
-    Ok(values)
+    match output {
+        Some(output) => {
+            fs::write(&output, schema + "\n").map_err(ForgeError::Io)?;
+            println!(
+                "{}",
+                format!("✅ Schema written to {}", output.display())
+                    .bold()
+                    .green()
+            );
+        }
+        None => println!("{}", schema),
+    }
+    Ok(())
 }
 
-/// Calculate model with a specific variable override and return the output value
-fn calculate_with_override(
-    base_model: &crate::types::ParsedModel,
-    var_name: &str,
-    var_value: f64,
-    output_name: &str,
-) -> ForgeResult<f64> {
-    let mut model = base_model.clone();
+/// Execute the fmt command - rewrite files in the canonical YAML layout
+/// With `check`, nothing is written and an error lists the files that would change
+pub fn fmt(files: Vec<PathBuf>, check: bool) -> ForgeResult<()> {
+    let mut unformatted: Vec<String> = Vec::new();
 
-    // Override the variable
-    if let Some(scalar) = model.scalars.get_mut(var_name) {
-        scalar.value = Some(var_value);
-        scalar.formula = None; // Clear formula since we're using override
-    } else {
-        // Create new scalar
-        model.scalars.insert(
-            var_name.to_string(),
-            crate::types::Variable::new(var_name.to_string(), Some(var_value), None),
+    for file in &files {
+        let content = fs::read_to_string(file)?;
+        let formatted = writer::format_yaml(&content)?;
+        if formatted == content {
+            continue;
+        }
+
+        if check {
+            println!("{} {}", "✗ Needs formatting:".yellow(), file.display());
+        } else {
+            fs::write(file, &formatted)?;
+            println!("{} {}", "✅ Formatted".green(), file.display());
+        }
+        unformatted.push(file.display().to_string());
+    }
+
+    if check && !unformatted.is_empty() {
+        return Err(ForgeError::Validation(format!(
+            "{} file(s) not formatted: {} (run 'forge fmt' to fix)",
+            unformatted.len(),
+            unformatted.join(", ")
+        )));
+    }
+    if unformatted.is_empty() {
+        println!(
+            "{}",
+            format!("✅ {} file(s) already formatted", files.len()).green()
+        );
+    }
+    Ok(())
+}
+
+/// Execute the roundtrip command - export to Excel, import back and diff
+pub fn roundtrip(file: PathBuf) -> ForgeResult<()> {
+    println!("{}", "🔥 Forge - Excel Round-trip".bold().green());
+    println!("   File: {}\n", file.display());
+
+    let report = roundtrip_check(&file)?;
+    println!(
+        "   {} tables, {} columns, {} scalars",
+        report.tables, report.columns, report.scalars
+    );
+
+    if report.is_clean() {
+        println!(
+            "{}",
+            "✅ Round-trip is clean - nothing lost or changed"
+                .bold()
+                .green()
         );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "❌ Found {} round-trip differences:",
+            report.differences.len()
+        )
+        .bold()
+        .red()
+    );
+    for difference in &report.differences {
+        println!("   {}", difference);
+    }
+    Err(ForgeError::Validation(format!(
+        "Excel round-trip changed {} item(s)",
+        report.differences.len()
+    )))
+}
+
+/// Execute the consolidate command - roll several models up into one
+pub fn consolidate(files: Vec<PathBuf>, output: PathBuf, stack: bool) -> ForgeResult<()> {
+    println!("{}", "🔥 Forge - Consolidation".bold().green());
+    for file in &files {
+        println!("   Input: {}", file.display());
+    }
+    println!();
+
+    let mut models = Vec::new();
+    for file in &files {
+        let model = parser::parse_model(file)?;
+        let result = ArrayCalculator::new(model).calculate_all()?;
+        models.push((file.display().to_string(), result));
+    }
+
+    let merged = consolidate_models(&models, stack)?;
+    fs::write(&output, writer::model_to_yaml(&merged)?).map_err(ForgeError::Io)?;
+
+    println!(
+        "   {} scalars, {} tables from {} files",
+        merged.scalars.len(),
+        merged.tables.len(),
+        files.len()
+    );
+    println!(
+        "{}",
+        format!("✅ Consolidated model written to {}", output.display())
+            .bold()
+            .green()
+    );
+    Ok(())
+}
+
+/// Merge calculated models into one model of values (formulas are not kept)
+/// Scalars with the same name are summed. Tables with the same name must have
+/// the same columns; numeric columns are summed row by row and other columns
+/// must be identical, or with `stack` the rows are appended instead
+fn consolidate_models(
+    models: &[(String, crate::types::ParsedModel)],
+    stack: bool,
+) -> ForgeResult<crate::types::ParsedModel> {
+    use crate::types::{Table, Variable};
+
+    let mut merged = crate::types::ParsedModel::new();
+    for (source, model) in models {
+        for (name, var) in &model.scalars {
+            let value = var.value.unwrap_or(0.0);
+            match merged.scalars.get_mut(name) {
+                Some(existing) => existing.value = Some(existing.value.unwrap_or(0.0) + value),
+                None => merged.add_scalar(
+                    name.clone(),
+                    Variable::with_metadata(name.clone(), Some(value), None, var.metadata.clone()),
+                ),
+            }
+        }
+
+        for (table_name, table) in &model.tables {
+            let mut values = Table::new(table_name.clone());
+            for column in table.columns.values() {
+                values.add_column(column.clone());
+            }
+            match merged.tables.get_mut(table_name) {
+                Some(existing) => merge_table(existing, &values, stack).map_err(|e| {
+                    ForgeError::Validation(format!(
+                        "Cannot consolidate table '{}' from {}: {}",
+                        table_name, source, e
+                    ))
+                })?,
+                None => merged.add_table(values),
+            }
+        }
+    }
+    Ok(merged)
+}
+
+/// Add `other`'s rows to `into`: summed when aligned, appended when stacking
+fn merge_table(
+    into: &mut crate::types::Table,
+    other: &crate::types::Table,
+    stack: bool,
+) -> Result<(), String> {
+    use crate::types::ColumnValue;
+
+    let mut ours: Vec<&String> = into.columns.keys().collect();
+    let mut theirs: Vec<&String> = other.columns.keys().collect();
+    ours.sort();
+    theirs.sort();
+    if ours != theirs {
+        return Err(format!("columns differ ({:?} vs {:?})", ours, theirs));
+    }
+    if !stack && into.row_count() != other.row_count() {
+        return Err(format!(
+            "{} rows vs {}; use --stack to append rows instead of summing",
+            into.row_count(),
+            other.row_count()
+        ));
+    }
+
+    for (name, column) in into.columns.iter_mut() {
+        let incoming = &other.columns[name].values;
+        match (&mut column.values, incoming) {
+            (ColumnValue::Number(a), ColumnValue::Number(b)) if stack => a.extend(b),
+            (ColumnValue::Number(a), ColumnValue::Number(b)) => {
+                a.iter_mut().zip(b).for_each(|(x, y)| *x += y)
+            }
+            (ColumnValue::Text(a), ColumnValue::Text(b))
+            | (ColumnValue::Date(a), ColumnValue::Date(b))
+                if stack =>
+            {
+                a.extend(b.iter().cloned())
+            }
+            (ColumnValue::Boolean(a), ColumnValue::Boolean(b)) if stack => a.extend(b),
+            (a, b) if a == b => {}
+            _ => {
+                return Err(format!(
+                    "column '{}' holds different {} values and cannot be summed",
+                    name,
+                    column.values.type_name()
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Execute the join command
+pub fn join(
+    left_file: PathBuf,
+    right_file: PathBuf,
+    on: String,
+    output: PathBuf,
+    kind: JoinKind,
+    left_table: Option<String>,
+    right_table: Option<String>,
+) -> ForgeResult<()> {
+    println!("{}", "🔥 Forge - Join".bold().green());
+    println!("   Left:  {}", left_file.display());
+    println!("   Right: {}", right_file.display());
+    println!("   On:    {}", on);
+    println!();
+
+    let left_model = ArrayCalculator::new(parser::parse_model(&left_file)?).calculate_all()?;
+    let right_model = ArrayCalculator::new(parser::parse_model(&right_file)?).calculate_all()?;
+    let left = join_table(&left_model, &left_file, &on, left_table.as_deref())?;
+    let right = join_table(&right_model, &right_file, &on, right_table.as_deref())?;
+
+    let joined = join_tables(left, right, &on, kind)?;
+    println!(
+        "   {} rows, {} columns ({} join of '{}' and '{}')",
+        joined.row_count(),
+        joined.columns.len(),
+        if kind == JoinKind::Left {
+            "left"
+        } else {
+            "inner"
+        },
+        left.name,
+        right.name
+    );
+
+    let mut model = crate::types::ParsedModel::new();
+    model.add_table(joined);
+    fs::write(&output, writer::tables_to_yaml(&model)?).map_err(ForgeError::Io)?;
+
+    println!(
+        "{}",
+        format!("✅ Joined table written to {}", output.display())
+            .bold()
+            .green()
+    );
+    Ok(())
+}
+
+/// The table of a calculated model to join: the named one, or else the only
+/// table with the key column
+fn join_table<'a>(
+    model: &'a crate::types::ParsedModel,
+    file: &Path,
+    on: &str,
+    name: Option<&str>,
+) -> ForgeResult<&'a crate::types::Table> {
+    if let Some(name) = name {
+        return model.tables.get(name).ok_or_else(|| {
+            ForgeError::Validation(format!("Table '{}' not found in {}", name, file.display()))
+        });
+    }
+
+    let mut candidates: Vec<&crate::types::Table> = model
+        .tables
+        .values()
+        .filter(|table| table.columns.contains_key(on))
+        .collect();
+    candidates.sort_by(|a, b| a.name.cmp(&b.name));
+    match candidates.as_slice() {
+        [table] => Ok(table),
+        [] => Err(ForgeError::Validation(format!(
+            "No table in {} has a '{}' column",
+            file.display(),
+            on
+        ))),
+        tables => Err(ForgeError::Validation(format!(
+            "Several tables in {} have a '{}' column ({}); choose one with --left-table/--right-table",
+            file.display(),
+            on,
+            tables
+                .iter()
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
     }
+}
+
+/// Parse a range string "start,end,step" into a vector of values
+fn parse_range(range: &str) -> ForgeResult<Vec<f64>> {
+    let parts: Vec<&str> = range.split(',').collect();
+    if parts.len() != 3 {
+        return Err(ForgeError::Validation(format!(
+            "Invalid range format '{}'. Expected: start,end,step (e.g., 0.01,0.15,0.02)",
+            range
+        )));
+    }
+
+    let start: f64 = parts[0]
+        .trim()
+        .parse()
+        .map_err(|_| ForgeError::Validation(format!("Invalid start value: '{}'", parts[0])))?;
+    let end: f64 = parts[1]
+        .trim()
+        .parse()
+        .map_err(|_| ForgeError::Validation(format!("Invalid end value: '{}'", parts[1])))?;
+    let step: f64 = parts[2]
+        .trim()
+        .parse()
+        .map_err(|_| ForgeError::Validation(format!("Invalid step value: '{}'", parts[2])))?;
+
+    if step <= 0.0 {
+        return Err(ForgeError::Validation("Step must be positive".to_string()));
+    }
+    if start > end {
+        return Err(ForgeError::Validation(
+            "Start must be less than or equal to end".to_string(),
+        ));
+    }
+
+    let mut values = Vec::new();
+    let mut current = start;
+    while current <= end + step * 0.001 {
+        // Small tolerance for floating point
+        values.push(current);
+        current += step;
+    }
+
+    Ok(values)
+}
+
+/// Name of the synthetic scalar holding an expression goal-seek target
+const GOAL_SEEK_TARGET: &str = "_goal_seek_target";
+
+/// Whether a goal-seek target is an expression rather than a scalar name
+fn is_target_expression(target: &str) -> bool {
+    !target
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Multiply a table column by `factor`, recalculate, and return the output
+fn calculate_with_column_scale(
+    base_model: &crate::types::ParsedModel,
+    table_name: &str,
+    column_name: &str,
+    factor: f64,
+    output_name: &str,
+) -> ForgeResult<f64> {
+    let mut model = base_model.clone();
+
+    if let Some(crate::types::ColumnValue::Number(values)) = model
+        .tables
+        .get_mut(table_name)
+        .and_then(|t| t.columns.get_mut(column_name))
+        .map(|c| &mut c.values)
+    {
+        for v in values.iter_mut() {
+            *v *= factor;
+        }
+    }
+
+    calculate_target(model, output_name)
+}
+
+/// Recalculate a model and read back a goal-seek target
+fn calculate_target(model: crate::types::ParsedModel, output_name: &str) -> ForgeResult<f64> {
+    let (model, output_name) = target_model(model, output_name);
 
     // Calculate
     let calculator = ArrayCalculator::new(model);
     let result = calculator.calculate_all()?;
 
-    // Get output value
+    read_output(&result, &output_name)
+}
+
+/// Build a calculator for repeated what-if evaluations of one target
+///
+/// Returns the calculator and the scalar name to read the target from.
+fn target_calculator(
+    base_model: &crate::types::ParsedModel,
+    output_name: &str,
+) -> (ArrayCalculator, String) {
+    let (model, output_name) = target_model(base_model.clone(), output_name);
+    (ArrayCalculator::new(model), output_name)
+}
+
+/// Evaluate a target with scalar overrides, reusing the calculator's
+/// dependency order
+fn evaluate_target(
+    calculator: &ArrayCalculator,
+    output_name: &str,
+    overrides: &[(&str, f64)],
+) -> ForgeResult<f64> {
+    let overrides: std::collections::HashMap<String, f64> = overrides
+        .iter()
+        .map(|(name, value)| (name.to_string(), *value))
+        .collect();
+    let result = calculator.calculate_with_overrides(&overrides)?;
+    read_output(&result, output_name)
+}
+
+/// Prepare a model so `output_name` can be read back as a scalar
+fn target_model(
+    mut model: crate::types::ParsedModel,
+    output_name: &str,
+) -> (crate::types::ParsedModel, String) {
+    // Targets like `SUM(sales.revenue)` or `sales.revenue[2]` are evaluated
+    // through a synthetic scalar; plain names are looked up directly
+    if is_target_expression(output_name) {
+        model.scalars.insert(
+            GOAL_SEEK_TARGET.to_string(),
+            crate::types::Variable::new(
+                GOAL_SEEK_TARGET.to_string(),
+                None,
+                Some(format!("={}", output_name.trim_start_matches('='))),
+            ),
+        );
+        (model, GOAL_SEEK_TARGET.to_string())
+    } else {
+        (model, output_name.to_string())
+    }
+}
+
+/// Read a calculated scalar output
+fn read_output(result: &crate::types::ParsedModel, output_name: &str) -> ForgeResult<f64> {
     if let Some(scalar) = result.scalars.get(output_name) {
         scalar.value.ok_or_else(|| {
             ForgeError::Validation(format!("Output variable '{}' has no value", output_name))
@@ -1595,17 +3451,27 @@ pub fn sensitivity(
     }
     println!("   Output: {}\n", output.bright_blue());
 
+    // Comma-separated outputs are observed in the same sweep
+    let outputs: Vec<String> = output
+        .split(',')
+        .map(|o| o.trim().to_string())
+        .filter(|o| !o.is_empty())
+        .collect();
+
     // Parse model
     let base_model = parser::parse_model(&file)?;
 
+    // Plain names must be scalars; expressions like SUM(sales.revenue) are targets
+    let known: Vec<&String> = base_model.scalars.keys().collect();
+    let names: Vec<String> = outputs
+        .iter()
+        .filter(|o| !is_target_expression(o))
+        .cloned()
+        .collect();
+    check_output_vars(&names, &known)?;
+
     // Validate that vary variable exists
-    if !base_model.scalars.contains_key(&vary) {
-        return Err(ForgeError::Validation(format!(
-            "Variable '{}' not found. Available scalars: {:?}",
-            vary,
-            base_model.scalars.keys().collect::<Vec<_>>()
-        )));
-    }
+    require_scalar(&base_model, &vary)?;
 
     // Parse range
     let values1 = parse_range(&range)?;
@@ -1622,13 +3488,7 @@ pub fn sensitivity(
     // Two-variable analysis
     if let (Some(ref v2), Some(ref r2)) = (&vary2, &range2) {
         // Validate second variable
-        if !base_model.scalars.contains_key(v2) {
-            return Err(ForgeError::Validation(format!(
-                "Variable '{}' not found. Available scalars: {:?}",
-                v2,
-                base_model.scalars.keys().collect::<Vec<_>>()
-            )));
-        }
+        require_scalar(&base_model, v2)?;
 
         let values2 = parse_range(r2)?;
 
@@ -1641,96 +3501,647 @@ pub fn sensitivity(
             );
         }
 
-        // Calculate matrix
-        println!(
-            "\n{} {} → {}",
-            "📊 Sensitivity Matrix:".bold().cyan(),
-            format!("({}, {})", vary, v2).yellow(),
-            output.bright_blue()
-        );
-
-        // Header row
-        print!("{:>12}", vary.bright_yellow());
-        for val2 in &values2 {
-            print!("{:>12}", format!("{:.4}", val2).dimmed());
-        }
-        println!();
-        println!("{}", "─".repeat(12 + values2.len() * 12));
-
-        // Data rows
-        for val1 in &values1 {
-            print!("{:>12}", format!("{:.4}", val1).bright_yellow());
+        // Calculate one matrix per output, reusing the dependency order
+        let calculator = ArrayCalculator::new(base_model.clone());
+        for output in &outputs {
+            println!(
+                "\n{} {} → {}",
+                "📊 Sensitivity Matrix:".bold().cyan(),
+                format!("({}, {})", vary, v2).yellow(),
+                output.bright_blue()
+            );
 
+            // Header row
+            print!("{:>12}", vary.bright_yellow());
             for val2 in &values2 {
-                // Override both variables
-                let mut model = base_model.clone();
-
-                if let Some(s) = model.scalars.get_mut(&vary) {
-                    s.value = Some(*val1);
-                    s.formula = None;
-                }
-                if let Some(s) = model.scalars.get_mut(v2) {
-                    s.value = Some(*val2);
-                    s.formula = None;
-                }
-
-                let calculator = ArrayCalculator::new(model);
-                match calculator.calculate_all() {
-                    Ok(result) => {
-                        if let Some(scalar) = result.scalars.get(&output) {
-                            if let Some(v) = scalar.value {
-                                print!("{:>12}", format_number(v).green());
+                print!("{:>12}", format!("{:.4}", val2).dimmed());
+            }
+            println!();
+            println!("{}", "─".repeat(12 + values2.len() * 12));
+
+            // Data rows
+            for val1 in &values1 {
+                print!("{:>12}", format!("{:.4}", val1).bright_yellow());
+
+                for val2 in &values2 {
+                    // Override both variables
+                    let overrides = std::collections::HashMap::from([
+                        (vary.clone(), *val1),
+                        (v2.clone(), *val2),
+                    ]);
+
+                    match calculator.calculate_with_overrides(&overrides) {
+                        Ok(result) => {
+                            if let Some(scalar) = result.scalars.get(output) {
+                                if let Some(v) = scalar.value {
+                                    print!("{:>12}", format_number(v).green());
+                                } else {
+                                    print!("{:>12}", "-".dimmed());
+                                }
                             } else {
-                                print!("{:>12}", "-".dimmed());
+                                print!("{:>12}", "?".red());
                             }
-                        } else {
-                            print!("{:>12}", "?".red());
                         }
-                    }
-                    Err(_) => {
-                        print!("{:>12}", "ERR".red());
+                        Err(_) => {
+                            print!("{:>12}", "ERR".red());
+                        }
                     }
                 }
+                println!();
             }
-            println!();
         }
     } else {
-        // One-variable analysis
+        // One-variable analysis (one column per output)
+        let width = 14 + 16 * outputs.len();
         println!(
             "\n{} {} → {}",
             "📊 Sensitivity Table:".bold().cyan(),
             vary.yellow(),
-            output.bright_blue()
+            outputs.join(", ").bright_blue()
+        );
+        println!("{}", "─".repeat(width));
+        print!("{:>12}", vary.bold());
+        for output in &outputs {
+            print!(" {:>15}", output.bold());
+        }
+        println!();
+        println!("{}", "─".repeat(width));
+
+        let rows = sweep_outputs(&base_model, &vary, &values1, &outputs);
+        for (val, results) in values1.iter().zip(rows) {
+            print!("{:>12}", format!("{:.4}", val).bright_yellow());
+            for result in results {
+                match result {
+                    Ok(result) => print!(" {:>15}", format_number(result).green()),
+                    Err(e) => print!(" {:>15}", format!("ERR: {}", e).red()),
+                }
+            }
+            println!();
+        }
+        println!("{}", "─".repeat(width));
+    }
+
+    println!("\n{}", "✅ Sensitivity analysis complete".bold().green());
+    Ok(())
+}
+
+/// Sensitivity sweep results, as printed by `--output-format json|yaml`
+/// Values that fail to calculate are `None`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SensitivityReport {
+    pub vary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vary2: Option<String>,
+    pub outputs: Vec<String>,
+    /// One-variable sweep: one row per value of `vary`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rows: Vec<SensitivityRow>,
+    /// Two-variable sweep: one matrix per output
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub matrices: Vec<SensitivityMatrix>,
+}
+
+/// Outputs for one input value of a one-variable sweep
+#[derive(Debug, Clone, Serialize)]
+pub struct SensitivityRow {
+    pub input: f64,
+    pub outputs: BTreeMap<String, Option<f64>>,
+}
+
+/// One output of a two-variable sweep; `values[i][j]` is the output for
+/// `inputs[i]` and `inputs2[j]`
+#[derive(Debug, Clone, Serialize)]
+pub struct SensitivityMatrix {
+    pub output: String,
+    pub inputs: Vec<f64>,
+    pub inputs2: Vec<f64>,
+    pub values: Vec<Vec<Option<f64>>>,
+}
+
+/// Run the sensitivity analysis without printing anything
+pub fn sensitivity_report(
+    file: PathBuf,
+    vary: String,
+    range: String,
+    vary2: Option<String>,
+    range2: Option<String>,
+    output: String,
+) -> ForgeResult<SensitivityReport> {
+    let outputs: Vec<String> = output
+        .split(',')
+        .map(|o| o.trim().to_string())
+        .filter(|o| !o.is_empty())
+        .collect();
+
+    let base_model = parser::parse_model(&file)?;
+    let known: Vec<&String> = base_model.scalars.keys().collect();
+    let names: Vec<String> = outputs
+        .iter()
+        .filter(|o| !is_target_expression(o))
+        .cloned()
+        .collect();
+    check_output_vars(&names, &known)?;
+    require_scalar(&base_model, &vary)?;
+    let values1 = parse_range(&range)?;
+
+    let mut report = SensitivityReport {
+        vary: vary.clone(),
+        vary2: None,
+        outputs: outputs.clone(),
+        rows: Vec::new(),
+        matrices: Vec::new(),
+    };
+
+    if let (Some(v2), Some(r2)) = (vary2, range2) {
+        require_scalar(&base_model, &v2)?;
+        let values2 = parse_range(&r2)?;
+
+        let calculator = ArrayCalculator::new(base_model.clone());
+        for output in &outputs {
+            let values = values1
+                .iter()
+                .map(|val1| {
+                    values2
+                        .iter()
+                        .map(|val2| {
+                            let overrides = std::collections::HashMap::from([
+                                (vary.clone(), *val1),
+                                (v2.clone(), *val2),
+                            ]);
+                            calculator
+                                .calculate_with_overrides(&overrides)
+                                .ok()
+                                .and_then(|result| result.scalars.get(output)?.value)
+                        })
+                        .collect()
+                })
+                .collect();
+            report.matrices.push(SensitivityMatrix {
+                output: output.clone(),
+                inputs: values1.clone(),
+                inputs2: values2.clone(),
+                values,
+            });
+        }
+        report.vary2 = Some(v2);
+    } else {
+        let rows = sweep_outputs(&base_model, &vary, &values1, &outputs);
+        report.rows = values1
+            .iter()
+            .zip(rows)
+            .map(|(input, results)| SensitivityRow {
+                input: *input,
+                outputs: outputs
+                    .iter()
+                    .cloned()
+                    .zip(results.into_iter().map(Result::ok))
+                    .collect(),
+            })
+            .collect();
+    }
+
+    Ok(report)
+}
+
+/// Reject a variable that is not a scalar of the model
+fn require_scalar(model: &crate::types::ParsedModel, name: &str) -> ForgeResult<()> {
+    if model.scalars.contains_key(name) {
+        return Ok(());
+    }
+    Err(ForgeError::Validation(format!(
+        "Variable '{}' not found. Available scalars: {:?}",
+        name,
+        model.scalars.keys().collect::<Vec<_>>()
+    )))
+}
+
+/// One bar of a tornado chart: the output swing from one input
+#[derive(Debug, Clone, Serialize)]
+pub struct TornadoBar {
+    pub variable: String,
+    pub input_low: f64,
+    pub input_high: f64,
+    pub output_low: f64,
+    pub output_high: f64,
+    pub range: f64,
+}
+
+/// Tornado chart, as printed by `--output-format json|yaml`
+#[derive(Debug, Clone, Serialize)]
+pub struct TornadoReport {
+    pub output: String,
+    pub swing: f64,
+    /// Sorted by descending range
+    pub bars: Vec<TornadoBar>,
+}
+
+/// Execute the sensitivity command in tornado mode: vary each input by
+/// ±`swing` one at a time and rank the variables by output range
+pub fn sensitivity_tornado(
+    file: PathBuf,
+    vars: String,
+    swing: f64,
+    output: String,
+    verbose: bool,
+) -> ForgeResult<()> {
+    println!(
+        "{}",
+        "🔥 Forge - Sensitivity Analysis (Tornado)".bold().green()
+    );
+    println!("   File: {}", file.display());
+    println!("   Vars: {}", vars.bright_yellow());
+    println!("   Swing: ±{}%", swing * 100.0);
+    println!("   Output: {}\n", output.bright_blue());
+
+    let base_model = parser::parse_model(&file)?;
+    let vars: Vec<String> = vars
+        .split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect();
+
+    let bars = tornado_bars(&base_model, &vars, &output, swing)?;
+
+    if verbose {
+        for bar in &bars {
+            println!(
+                "   {}: [{}, {}] → [{}, {}]",
+                bar.variable, bar.input_low, bar.input_high, bar.output_low, bar.output_high
+            );
+        }
+    }
+
+    println!(
+        "\n{} {}",
+        "📊 Tornado Chart:".bold().cyan(),
+        output.bright_blue()
+    );
+    println!("{}", "─".repeat(66));
+    println!(
+        "{:<4} {:<16} {:>14} {:>14} {:>14}",
+        "#".bold(),
+        "Variable".bold(),
+        "Low".bold(),
+        "High".bold(),
+        "Range".bold()
+    );
+    println!("{}", "─".repeat(66));
+    for (rank, bar) in bars.iter().enumerate() {
+        println!(
+            "{:<4} {:<16} {:>14} {:>14} {:>14}",
+            rank + 1,
+            bar.variable.bright_yellow(),
+            format_number(bar.output_low),
+            format_number(bar.output_high),
+            format_number(bar.range).green()
         );
-        println!("{}", "─".repeat(30));
-        println!("{:>12} {:>15}", vary.bold(), output.bold());
-        println!("{}", "─".repeat(30));
+    }
+    println!("{}", "─".repeat(66));
+
+    println!("\n{}", "✅ Sensitivity analysis complete".bold().green());
+    Ok(())
+}
+
+/// Run the tornado analysis without printing anything
+pub fn tornado_report(
+    file: PathBuf,
+    vars: String,
+    swing: f64,
+    output: String,
+) -> ForgeResult<TornadoReport> {
+    let base_model = parser::parse_model(&file)?;
+    let vars: Vec<String> = vars
+        .split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect();
+
+    let bars = tornado_bars(&base_model, &vars, &output, swing)?;
+    Ok(TornadoReport {
+        output,
+        swing,
+        bars,
+    })
+}
+
+/// Compute tornado bars for each variable, sorted by descending range
+fn tornado_bars(
+    base_model: &crate::types::ParsedModel,
+    vars: &[String],
+    output: &str,
+    swing: f64,
+) -> ForgeResult<Vec<TornadoBar>> {
+    if vars.is_empty() {
+        return Err(ForgeError::Validation(
+            "Tornado mode requires --vars with at least one variable".to_string(),
+        ));
+    }
+    if !(swing > 0.0 && swing.is_finite()) {
+        return Err(ForgeError::Validation(format!(
+            "Swing must be positive, got {}",
+            swing
+        )));
+    }
+
+    // Base values come from the calculated model so formula inputs work too
+    let calculated = ArrayCalculator::new(base_model.clone()).calculate_all()?;
+    let (calculator, output_name) = target_calculator(base_model, output);
+
+    let mut bars = Vec::with_capacity(vars.len());
+    for var in vars {
+        let base = calculated
+            .scalars
+            .get(var)
+            .and_then(|s| s.value)
+            .ok_or_else(|| {
+                ForgeError::Validation(format!(
+                    "Variable '{}' not found. Available scalars: {:?}",
+                    var,
+                    base_model.scalars.keys().collect::<Vec<_>>()
+                ))
+            })?;
+
+        let input_low = base * (1.0 - swing);
+        let input_high = base * (1.0 + swing);
+        let output_low = evaluate_target(&calculator, &output_name, &[(var, input_low)])?;
+        let output_high = evaluate_target(&calculator, &output_name, &[(var, input_high)])?;
+
+        bars.push(TornadoBar {
+            variable: var.clone(),
+            input_low,
+            input_high,
+            output_low,
+            output_high,
+            range: (output_high - output_low).abs(),
+        });
+    }
+
+    bars.sort_by(|a, b| {
+        b.range
+            .partial_cmp(&a.range)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    Ok(bars)
+}
+
+/// Recalculate the model for each value of `vary`, observing every output
+///
+/// Returns one row per value with one result per output.
+fn sweep_outputs(
+    base_model: &crate::types::ParsedModel,
+    vary: &str,
+    values: &[f64],
+    outputs: &[String],
+) -> Vec<Vec<ForgeResult<f64>>> {
+    let calculators: Vec<(ArrayCalculator, String)> = outputs
+        .iter()
+        .map(|output| target_calculator(base_model, output))
+        .collect();
+
+    values
+        .iter()
+        .map(|val| {
+            calculators
+                .iter()
+                .map(|(calculator, output)| evaluate_target(calculator, output, &[(vary, *val)]))
+                .collect()
+        })
+        .collect()
+}
+
+/// Input distribution for Monte Carlo sampling
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Distribution {
+    Normal { mean: f64, stdev: f64 },
+    Uniform { min: f64, max: f64 },
+}
+
+/// Parse a `--var` spec like `price:normal:100:10` or `volume:uniform:900:1100`
+fn parse_distribution(spec: &str) -> ForgeResult<(String, Distribution)> {
+    let parts: Vec<&str> = spec.split(':').map(str::trim).collect();
+    if parts.len() != 4 {
+        return Err(ForgeError::Validation(format!(
+            "Invalid distribution '{}'. Expected: name:normal:mean:stdev or name:uniform:min:max",
+            spec
+        )));
+    }
+
+    let parse = |s: &str| {
+        s.parse::<f64>()
+            .map_err(|_| ForgeError::Validation(format!("Invalid number '{}' in '{}'", s, spec)))
+    };
+    let (a, b) = (parse(parts[2])?, parse(parts[3])?);
+
+    let dist = match parts[1].to_lowercase().as_str() {
+        "normal" => {
+            if b < 0.0 {
+                return Err(ForgeError::Validation(format!(
+                    "Standard deviation must be non-negative in '{}'",
+                    spec
+                )));
+            }
+            Distribution::Normal { mean: a, stdev: b }
+        }
+        "uniform" => {
+            if a > b {
+                return Err(ForgeError::Validation(format!(
+                    "Uniform min must be less than or equal to max in '{}'",
+                    spec
+                )));
+            }
+            Distribution::Uniform { min: a, max: b }
+        }
+        other => {
+            return Err(ForgeError::Validation(format!(
+                "Unknown distribution '{}'. Supported: normal, uniform",
+                other
+            )))
+        }
+    };
+
+    Ok((parts[0].to_string(), dist))
+}
+
+/// Small seedable PRNG (SplitMix64) so simulations are reproducible
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform sample in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    fn sample(&mut self, dist: Distribution) -> f64 {
+        match dist {
+            Distribution::Uniform { min, max } => min + (max - min) * self.next_f64(),
+            Distribution::Normal { mean, stdev } => {
+                // Box-Muller; 1 - u keeps the log argument in (0, 1]
+                let u1 = 1.0 - self.next_f64();
+                let u2 = self.next_f64();
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+                mean + stdev * z
+            }
+        }
+    }
+}
+
+/// Summary of a Monte Carlo output distribution
+#[derive(Debug, Clone, PartialEq)]
+struct MonteCarloSummary {
+    trials: usize,
+    mean: f64,
+    stdev: f64,
+    min: f64,
+    max: f64,
+    p5: f64,
+    p50: f64,
+    p95: f64,
+}
+
+/// Sample inputs, recalculate `trials` times, and summarize the output
+fn run_monte_carlo(
+    base_model: &crate::types::ParsedModel,
+    vars: &[(String, Distribution)],
+    output: &str,
+    trials: usize,
+    seed: u64,
+) -> ForgeResult<MonteCarloSummary> {
+    if trials == 0 {
+        return Err(ForgeError::Validation(
+            "Trials must be at least 1".to_string(),
+        ));
+    }
+    for (name, _) in vars {
+        if !base_model.scalars.contains_key(name) {
+            return Err(ForgeError::Validation(format!(
+                "Variable '{}' not found. Available scalars: {:?}",
+                name,
+                base_model.scalars.keys().collect::<Vec<_>>()
+            )));
+        }
+    }
+
+    let (calculator, output_name) = target_calculator(base_model, output);
+    let mut rng = SplitMix64(seed);
+    let mut results = Vec::with_capacity(trials);
+    for _ in 0..trials {
+        let overrides: Vec<(&str, f64)> = vars
+            .iter()
+            .map(|(name, dist)| (name.as_str(), rng.sample(*dist)))
+            .collect();
+        results.push(evaluate_target(&calculator, &output_name, &overrides)?);
+    }
+
+    let mean = results.iter().sum::<f64>() / trials as f64;
+    Ok(MonteCarloSummary {
+        trials,
+        mean,
+        stdev: ArrayCalculator::calculate_stdev(&results, true),
+        min: results.iter().cloned().fold(f64::INFINITY, f64::min),
+        max: results.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+        p5: ArrayCalculator::calculate_percentile(&results, 0.05),
+        p50: ArrayCalculator::calculate_percentile(&results, 0.50),
+        p95: ArrayCalculator::calculate_percentile(&results, 0.95),
+    })
+}
+
+/// Execute the monte-carlo command
+pub fn monte_carlo(
+    file: PathBuf,
+    vars: Vec<String>,
+    output: String,
+    trials: usize,
+    seed: Option<u64>,
+    verbose: bool,
+) -> ForgeResult<()> {
+    println!("{}", "🔥 Forge - Monte Carlo Simulation".bold().green());
+    println!("   File: {}", file.display());
+    println!("   Output: {}", output.bright_blue());
+    println!("   Trials: {}\n", trials);
+
+    let base_model = parser::parse_model(&file)?;
+    let vars = vars
+        .iter()
+        .map(|spec| parse_distribution(spec))
+        .collect::<ForgeResult<Vec<_>>>()?;
+
+    // Without --seed, derive one from the clock and print it for reruns
+    let seed = seed.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0)
+    });
 
-        for val in &values1 {
-            match calculate_with_override(&base_model, &vary, *val, &output) {
-                Ok(result) => {
-                    println!(
-                        "{:>12} {:>15}",
-                        format!("{:.4}", val).bright_yellow(),
-                        format_number(result).green()
-                    );
-                }
-                Err(e) => {
-                    println!(
-                        "{:>12} {:>15}",
-                        format!("{:.4}", val).bright_yellow(),
-                        format!("ERR: {}", e).red()
-                    );
-                }
-            }
+    if verbose {
+        for (name, dist) in &vars {
+            println!("   {} ~ {:?}", name.bright_yellow(), dist);
         }
-        println!("{}", "─".repeat(30));
+        println!("   Seed: {}", seed);
     }
 
-    println!("\n{}", "✅ Sensitivity analysis complete".bold().green());
+    let summary = run_monte_carlo(&base_model, &vars, &output, trials, seed)?;
+
+    println!(
+        "{} {}",
+        "📊 Output Distribution:".bold().cyan(),
+        output.bright_blue()
+    );
+    println!("{}", "─".repeat(30));
+    let rows = [
+        ("Trials", summary.trials as f64),
+        ("Mean", summary.mean),
+        ("Std Dev", summary.stdev),
+        ("Min", summary.min),
+        ("P5", summary.p5),
+        ("P50", summary.p50),
+        ("P95", summary.p95),
+        ("Max", summary.max),
+    ];
+    for (label, value) in rows {
+        println!("{:<12} {:>17}", label.bold(), format_number(value).green());
+    }
+    println!("{}", "─".repeat(30));
+
+    println!("\n{}", "✅ Simulation complete".bold().green());
     Ok(())
 }
 
+/// Search settings of the goal-seek command
+#[derive(Debug, Clone, Copy)]
+pub struct GoalSeekOptions {
+    /// Search bounds; default to 0.01x and 100x the varied input's value
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// How close the target must get to the goal value
+    pub tolerance: f64,
+    pub method: GoalSeekMethod,
+    pub verbose: bool,
+    /// Give up with [`ForgeError::Timeout`] once the search has run this long
+    pub timeout: Option<Duration>,
+}
+
+impl Default for GoalSeekOptions {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            tolerance: 0.0001,
+            method: GoalSeekMethod::default(),
+            verbose: false,
+            timeout: None,
+        }
+    }
+}
+
 /// Execute the goal-seek command
 #[allow(clippy::too_many_arguments)]
 pub fn goal_seek(
@@ -1742,18 +4153,221 @@ pub fn goal_seek(
     max: Option<f64>,
     tolerance: f64,
     verbose: bool,
+) -> ForgeResult<()> {
+    let options = GoalSeekOptions {
+        min,
+        max,
+        tolerance,
+        verbose,
+        ..GoalSeekOptions::default()
+    };
+    goal_seek_with_method(file, target, value, vary, options)
+}
+
+/// Execute the goal-seek command with an explicit solver method
+pub fn goal_seek_with_method(
+    file: PathBuf,
+    target: String,
+    value: f64,
+    vary: String,
+    options: GoalSeekOptions,
 ) -> ForgeResult<()> {
     println!("{}", "🔥 Forge - Goal Seek".bold().green());
     println!("   File: {}", file.display());
     println!("   Target: {} = {}", target.bright_blue(), value);
     println!("   Vary: {}", vary.bright_yellow());
-    println!("   Tolerance: {}\n", tolerance);
+    println!("   Tolerance: {}\n", options.tolerance);
 
     // Parse model
     let base_model = parser::parse_model(&file)?;
 
+    let solution = solve_goal_seek(&base_model, &target, value, &vary, options)?;
+
+    print_goal_seek_solution(&vary, &target, value, options.tolerance, &solution);
+    Ok(())
+}
+
+/// Execute the goal-seek command with `--vary-column`: solve for a factor
+/// that scales every value in `table.column` proportionally
+pub fn goal_seek_column(
+    file: PathBuf,
+    target: String,
+    value: f64,
+    column: String,
+    options: GoalSeekOptions,
+) -> ForgeResult<()> {
+    println!("{}", "🔥 Forge - Goal Seek".bold().green());
+    println!("   File: {}", file.display());
+    println!("   Target: {} = {}", target.bright_blue(), value);
+    println!("   Vary: {} (scale factor)", column.bright_yellow());
+    println!("   Tolerance: {}\n", options.tolerance);
+
+    // Parse model
+    let base_model = parser::parse_model(&file)?;
+
+    let solution = solve_goal_seek_column(&base_model, &target, value, &column, options)?;
+
+    let label = format!("{} factor", column);
+    print_goal_seek_solution(&label, &target, value, options.tolerance, &solution);
+    Ok(())
+}
+
+/// Goal-seek solution, as printed by `--output-format json|yaml`
+#[derive(Debug, Clone, Serialize)]
+pub struct GoalSeekReport {
+    pub target: String,
+    pub value: f64,
+    /// Varied scalar, or `table.column` when `column_factor` is set
+    pub vary: String,
+    /// Whether `input` is a factor scaling every value of the `vary` column
+    pub column_factor: bool,
+    pub input: f64,
+    pub output: f64,
+    pub iterations: usize,
+    pub tolerance: f64,
+    pub within_tolerance: bool,
+}
+
+impl GoalSeekReport {
+    fn new(
+        target: String,
+        value: f64,
+        vary: String,
+        column_factor: bool,
+        tolerance: f64,
+        solution: GoalSeekSolution,
+    ) -> Self {
+        Self {
+            target,
+            value,
+            vary,
+            column_factor,
+            input: solution.input,
+            output: solution.output,
+            iterations: solution.iterations,
+            tolerance,
+            within_tolerance: (solution.output - value).abs() < tolerance,
+        }
+    }
+}
+
+/// Run goal-seek on a scalar without printing anything (`verbose` is ignored)
+pub fn goal_seek_report(
+    file: PathBuf,
+    target: String,
+    value: f64,
+    vary: String,
+    options: GoalSeekOptions,
+) -> ForgeResult<GoalSeekReport> {
+    let options = GoalSeekOptions {
+        verbose: false,
+        ..options
+    };
+    let base_model = parser::parse_model(&file)?;
+    let solution = solve_goal_seek(&base_model, &target, value, &vary, options)?;
+    Ok(GoalSeekReport::new(
+        target,
+        value,
+        vary,
+        false,
+        options.tolerance,
+        solution,
+    ))
+}
+
+/// Run goal-seek on a column scale factor without printing anything
+/// (`verbose` is ignored)
+pub fn goal_seek_column_report(
+    file: PathBuf,
+    target: String,
+    value: f64,
+    column: String,
+    options: GoalSeekOptions,
+) -> ForgeResult<GoalSeekReport> {
+    let options = GoalSeekOptions {
+        verbose: false,
+        ..options
+    };
+    let base_model = parser::parse_model(&file)?;
+    let solution = solve_goal_seek_column(&base_model, &target, value, &column, options)?;
+    Ok(GoalSeekReport::new(
+        target,
+        value,
+        column,
+        true,
+        options.tolerance,
+        solution,
+    ))
+}
+
+/// Print the outcome of a goal-seek search
+fn print_goal_seek_solution(
+    vary: &str,
+    target: &str,
+    value: f64,
+    tolerance: f64,
+    solution: &GoalSeekSolution,
+) {
+    println!("{}", "─".repeat(50));
+    println!(
+        "{}",
+        format!("🎯 Solution found in {} iterations:", solution.iterations)
+            .bold()
+            .green()
+    );
+    println!(
+        "   {} = {} → {} = {}",
+        vary.bright_yellow().bold(),
+        format_number(solution.input).bold().green(),
+        target.bright_blue(),
+        format_number(solution.output).green()
+    );
+
+    let error = (solution.output - value).abs();
+    if error < tolerance {
+        println!("   {} Within tolerance", "✅".green());
+    } else {
+        println!(
+            "   {} Error: {} (tolerance: {})",
+            "⚠️".yellow(),
+            error,
+            tolerance
+        );
+    }
+
+    println!("{}", "─".repeat(50));
+}
+
+/// Root-finding method used by goal-seek
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GoalSeekMethod {
+    /// Interval halving: slow but always converges on a sign change
+    #[default]
+    Bisection,
+    /// Newton-Raphson with a numerical derivative, falling back to bisection
+    Newton,
+}
+
+/// Result of a goal-seek search
+#[derive(Debug, Clone)]
+struct GoalSeekSolution {
+    /// Value of the varied input
+    input: f64,
+    /// Target value achieved with that input
+    output: f64,
+    iterations: usize,
+}
+
+/// Find the value of `vary` that drives `target` to `value`
+fn solve_goal_seek(
+    base_model: &crate::types::ParsedModel,
+    target: &str,
+    value: f64,
+    vary: &str,
+    options: GoalSeekOptions,
+) -> ForgeResult<GoalSeekSolution> {
     // Validate variables
-    if !base_model.scalars.contains_key(&vary) {
+    if !base_model.scalars.contains_key(vary) {
         return Err(ForgeError::Validation(format!(
             "Variable '{}' not found. Available scalars: {:?}",
             vary,
@@ -1764,10 +4378,100 @@ pub fn goal_seek(
     // Get current value of vary to set default bounds
     let current_value = base_model
         .scalars
-        .get(&vary)
+        .get(vary)
         .and_then(|s| s.value)
         .unwrap_or(1.0);
 
+    let (calculator, output) = target_calculator(base_model, target);
+    seek_root(
+        |x| evaluate_target(&calculator, &output, &[(vary, x)]),
+        current_value,
+        value,
+        vary,
+        options,
+    )
+}
+
+/// Find the factor for `table.column` that drives `target` to `value`
+fn solve_goal_seek_column(
+    base_model: &crate::types::ParsedModel,
+    target: &str,
+    value: f64,
+    column: &str,
+    options: GoalSeekOptions,
+) -> ForgeResult<GoalSeekSolution> {
+    let (table_name, column_name) = column.split_once('.').ok_or_else(|| {
+        ForgeError::Validation(format!(
+            "Column '{}' must be written as table.column",
+            column
+        ))
+    })?;
+    let table = base_model
+        .tables
+        .get(table_name)
+        .ok_or_else(|| ForgeError::Validation(format!("Table '{}' not found", table_name)))?;
+    match table.columns.get(column_name).map(|c| &c.values) {
+        Some(crate::types::ColumnValue::Number(_)) => {}
+        Some(other) => {
+            return Err(ForgeError::Validation(format!(
+                "Column '{}' must be numeric, got {}",
+                column,
+                other.type_name()
+            )))
+        }
+        None => {
+            return Err(ForgeError::Validation(format!(
+                "Column '{}' not found in table '{}' (formula columns cannot be varied)",
+                column_name, table_name
+            )))
+        }
+    }
+
+    // The factor starts at 1.0 (the column as written)
+    seek_root(
+        |factor| calculate_with_column_scale(base_model, table_name, column_name, factor, target),
+        1.0,
+        value,
+        column,
+        options,
+    )
+}
+
+/// Search for the input where `eval(input) == value`
+///
+/// Newton-Raphson starts from `current_value` and hands over to bisection
+/// if it stalls, diverges, or leaves the search bounds.
+fn seek_root<F>(
+    eval: F,
+    current_value: f64,
+    value: f64,
+    vary: &str,
+    options: GoalSeekOptions,
+) -> ForgeResult<GoalSeekSolution>
+where
+    F: Fn(f64) -> ForgeResult<f64>,
+{
+    let GoalSeekOptions {
+        min,
+        max,
+        tolerance,
+        method,
+        verbose,
+        timeout,
+    } = options;
+
+    // Checked before every evaluation, so Newton, range expansion and
+    // bisection all stop once the deadline has passed
+    let deadline = timeout.map(|limit| (Instant::now() + limit, limit));
+    let eval = |x: f64| {
+        if let Some((at, limit)) = deadline {
+            if Instant::now() >= at {
+                return Err(ForgeError::Timeout(limit));
+            }
+        }
+        eval(x)
+    };
+
     // Set bounds (default: 0.01x to 100x current value)
     let lower = min.unwrap_or_else(|| {
         if current_value > 0.0 {
@@ -1791,14 +4495,41 @@ pub fn goal_seek(
         println!("   Search bounds: [{}, {}]", lower, upper);
     }
 
-    // Bisection method
     let max_iterations = 100;
+    let mut newton_iterations = 0;
+
+    if method == GoalSeekMethod::Newton {
+        let start = current_value.clamp(lower.min(upper), lower.max(upper));
+        let (result, iterations) =
+            newton_search(&eval, start, value, lower, upper, tolerance, max_iterations)?;
+        newton_iterations = iterations;
+        if let Some(input) = result {
+            if verbose {
+                println!("   Newton converged in {} iterations", iterations);
+            }
+            return Ok(GoalSeekSolution {
+                input,
+                output: eval(input)?,
+                iterations,
+            });
+        }
+        println!(
+            "{}",
+            format!(
+                "⚠️  Newton did not converge after {} iterations - falling back to bisection",
+                iterations
+            )
+            .yellow()
+        );
+    }
+
+    // Bisection method
     let mut low = lower;
     let mut high = upper;
 
     // Check bounds first
-    let f_low = calculate_with_override(&base_model, &vary, low, &target)? - value;
-    let f_high = calculate_with_override(&base_model, &vary, high, &target)? - value;
+    let f_low = eval(low)? - value;
+    let f_high = eval(high)? - value;
 
     if verbose {
         println!("   f({}) = {} (target diff: {})", low, f_low + value, f_low);
@@ -1832,9 +4563,8 @@ pub fn goal_seek(
                 upper / factor
             };
 
-            let f_exp_low = calculate_with_override(&base_model, &vary, exp_low, &target)? - value;
-            let f_exp_high =
-                calculate_with_override(&base_model, &vary, exp_high, &target)? - value;
+            let f_exp_low = eval(exp_low)? - value;
+            let f_exp_high = eval(exp_high)? - value;
 
             if f_exp_low * f_exp_high <= 0.0 {
                 low = exp_low;
@@ -1861,7 +4591,7 @@ pub fn goal_seek(
 
     while (high - low) > tolerance && iteration < max_iterations {
         mid = (low + high) / 2.0;
-        let f_mid = calculate_with_override(&base_model, &vary, mid, &target)? - value;
+        let f_mid = eval(mid)? - value;
 
         if verbose && iteration % 10 == 0 {
             println!(
@@ -1873,7 +4603,7 @@ pub fn goal_seek(
             );
         }
 
-        let f_low_check = calculate_with_override(&base_model, &vary, low, &target)? - value;
+        let f_low_check = eval(low)? - value;
 
         if f_mid.abs() < tolerance {
             break;
@@ -1888,38 +4618,62 @@ pub fn goal_seek(
         iteration += 1;
     }
 
+    if verbose && method == GoalSeekMethod::Newton {
+        println!(
+            "   Bisection converged in {} iterations ({} Newton iterations before fallback)",
+            iteration, newton_iterations
+        );
+    }
+
     // Final result
-    let final_value = calculate_with_override(&base_model, &vary, mid, &target)?;
+    let output = eval(mid)?;
 
-    println!("{}", "─".repeat(50));
-    println!(
-        "{}",
-        format!("🎯 Solution found in {} iterations:", iteration)
-            .bold()
-            .green()
-    );
-    println!(
-        "   {} = {} → {} = {}",
-        vary.bright_yellow().bold(),
-        format_number(mid).bold().green(),
-        target.bright_blue(),
-        format_number(final_value).green()
-    );
+    Ok(GoalSeekSolution {
+        input: mid,
+        output,
+        iterations: newton_iterations + iteration,
+    })
+}
 
-    let error = (final_value - value).abs();
-    if error < tolerance {
-        println!("   {} Within tolerance", "✅".green());
-    } else {
-        println!(
-            "   {} Error: {} (tolerance: {})",
-            "⚠️".yellow(),
-            error,
-            tolerance
-        );
+/// Newton-Raphson with a forward-difference derivative
+///
+/// Returns the root (if found) and the number of iterations taken. `None`
+/// means the caller should fall back to bisection.
+fn newton_search<F>(
+    eval: &F,
+    start: f64,
+    value: f64,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> ForgeResult<(Option<f64>, usize)>
+where
+    F: Fn(f64) -> ForgeResult<f64>,
+{
+    let (lo, hi) = (lower.min(upper), lower.max(upper));
+    let mut x = start;
+
+    for iteration in 0..max_iterations {
+        let fx = eval(x)? - value;
+        if fx.abs() < tolerance {
+            return Ok((Some(x), iteration));
+        }
+
+        let h = x.abs().max(1.0) * 1e-6;
+        let derivative = (eval(x + h)? - value - fx) / h;
+        if derivative == 0.0 || !derivative.is_finite() {
+            return Ok((None, iteration + 1));
+        }
+
+        let next = x - fx / derivative;
+        if !next.is_finite() || next < lo || next > hi {
+            return Ok((None, iteration + 1));
+        }
+        x = next;
     }
 
-    println!("{}", "─".repeat(50));
-    Ok(())
+    Ok((None, max_iterations))
 }
 
 /// Execute the break-even command
@@ -1938,165 +4692,216 @@ pub fn break_even(
     goal_seek(file, output, 0.0, vary, min, max, 0.0001, verbose)
 }
 
-/// Function category with functions and descriptions
-struct FunctionCategory {
-    name: &'static str,
-    functions: Vec<(&'static str, &'static str)>,
+/// Run the break-even analysis without printing anything
+pub fn break_even_report(
+    file: PathBuf,
+    output: String,
+    vary: String,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> ForgeResult<GoalSeekReport> {
+    let options = GoalSeekOptions {
+        min,
+        max,
+        ..GoalSeekOptions::default()
+    };
+    goal_seek_report(file, output, 0.0, vary, options)
+}
+
+/// Machine-readable format of the analysis commands' `--output-format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Yaml,
+}
+
+impl ReportFormat {
+    /// Format named by `--output-format`; `None` for the default table
+    pub fn from_flag(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(Self::Json),
+            "yaml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// Print an analysis report to stdout as JSON or YAML
+pub fn print_report<T: Serialize>(report: &T, format: ReportFormat) -> ForgeResult<()> {
+    print!("{}", render_report(report, format)?);
+    Ok(())
+}
+
+/// Serialize an analysis report as JSON or YAML
+fn render_report<T: Serialize>(report: &T, format: ReportFormat) -> ForgeResult<String> {
+    match format {
+        ReportFormat::Json => serde_json::to_string_pretty(report)
+            .map(|json| json + "\n")
+            .map_err(|e| ForgeError::Export(format!("JSON serialization failed: {}", e))),
+        ReportFormat::Yaml => Ok(serde_yaml::to_string(report)?),
+    }
+}
+
+/// Summary statistics for one numeric column
+#[derive(Debug, Clone)]
+struct ColumnStats {
+    name: String,
+    count: usize,
+    sum: f64,
+    mean: f64,
+    median: f64,
+    stdev: f64,
+    min: f64,
+    max: f64,
+    q1: f64,
+    q3: f64,
+}
+
+/// Compute summary statistics for a column's values
+/// Uses the same median/stdev/percentile math as the formula aggregations
+fn column_stats(name: &str, values: &[f64]) -> ColumnStats {
+    let count = values.len();
+    let sum: f64 = values.iter().sum();
+    let mean = if count > 0 { sum / count as f64 } else { 0.0 };
+
+    ColumnStats {
+        name: name.to_string(),
+        count,
+        sum,
+        mean,
+        median: ArrayCalculator::calculate_median(values),
+        stdev: ArrayCalculator::calculate_stdev(values, true),
+        min: values.iter().cloned().fold(f64::INFINITY, f64::min),
+        max: values.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+        q1: ArrayCalculator::calculate_percentile(values, 0.25),
+        q3: ArrayCalculator::calculate_percentile(values, 0.75),
+    }
+}
+
+/// Execute the stats command - summary statistics for table columns
+pub fn stats(file: PathBuf, table: String, column: String) -> ForgeResult<()> {
+    println!("{}", "🔥 Forge - Column Statistics".bold().green());
+    println!("   File: {}", file.display());
+    println!("   Table: {}\n", table.bright_blue());
+
+    // Calculate first so formula columns have values
+    let model = parser::parse_model(&file)?;
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all()?;
+
+    let data = result.tables.get(&table).ok_or_else(|| {
+        let mut available: Vec<_> = result.tables.keys().cloned().collect();
+        available.sort();
+        ForgeError::Validation(format!(
+            "Table '{}' not found. Available: {}",
+            table,
+            available.join(", ")
+        ))
+    })?;
+
+    let summaries: Vec<ColumnStats> = if column == "all" {
+        let mut names: Vec<_> = data.columns.keys().cloned().collect();
+        names.sort();
+        names
+            .iter()
+            .filter_map(|name| match &data.columns[name].values {
+                crate::types::ColumnValue::Number(nums) => Some(column_stats(name, nums)),
+                _ => None,
+            })
+            .collect()
+    } else {
+        let col = data.columns.get(&column).ok_or_else(|| {
+            ForgeError::Validation(format!(
+                "Column '{}' not found in table '{}'",
+                column, table
+            ))
+        })?;
+        match &col.values {
+            crate::types::ColumnValue::Number(nums) => vec![column_stats(&column, nums)],
+            other => {
+                return Err(ForgeError::Validation(format!(
+                    "Column '{}' is {}, stats requires a numeric column",
+                    column,
+                    other.type_name()
+                )))
+            }
+        }
+    };
+
+    if summaries.is_empty() {
+        println!("{}", "⚠️  No numeric columns to summarize".yellow());
+        return Ok(());
+    }
+
+    print_stats_table(&summaries);
+    Ok(())
+}
+
+/// A statistics table row: its label and how to read it from a column
+type StatRow = (&'static str, fn(&ColumnStats) -> f64);
+
+/// Print column statistics as an aligned table: one row per statistic
+fn print_stats_table(summaries: &[ColumnStats]) {
+    let width = 12 + summaries.len() * 15;
+    println!("{}", "─".repeat(width));
+
+    print!("{:<12}", "Statistic".bold());
+    for s in summaries {
+        print!("{:>15}", s.name.bright_blue().bold());
+    }
+    println!();
+    println!("{}", "─".repeat(width));
+
+    let rows: [StatRow; 9] = [
+        ("count", |s| s.count as f64),
+        ("sum", |s| s.sum),
+        ("mean", |s| s.mean),
+        ("median", |s| s.median),
+        ("stdev", |s| s.stdev),
+        ("min", |s| s.min),
+        ("max", |s| s.max),
+        ("q1 (25%)", |s| s.q1),
+        ("q3 (75%)", |s| s.q3),
+    ];
+    for (label, value) in &rows {
+        print!("{:<12}", label);
+        for s in summaries {
+            if s.count == 0 && *label != "count" {
+                print!("{:>15}", "-".dimmed());
+            } else {
+                print!("{:>15}", format_number(value(s)));
+            }
+        }
+        println!();
+    }
+
+    println!("{}", "─".repeat(width));
 }
 
 /// Execute the functions command - list all supported Excel-compatible functions
 pub fn functions(json_output: bool) -> ForgeResult<()> {
-    let categories = vec![
-        FunctionCategory {
-            name: "Financial",
-            functions: vec![
-                ("NPV", "Net Present Value - =NPV(rate, cashflow1, cashflow2, ...)"),
-                ("IRR", "Internal Rate of Return - =IRR(values, [guess])"),
-                ("MIRR", "Modified IRR - =MIRR(values, finance_rate, reinvest_rate)"),
-                ("XNPV", "NPV with irregular dates - =XNPV(rate, values, dates)"),
-                ("XIRR", "IRR with irregular dates - =XIRR(values, dates, [guess])"),
-                ("PMT", "Payment for a loan - =PMT(rate, nper, pv, [fv], [type])"),
-                ("PV", "Present Value - =PV(rate, nper, pmt, [fv], [type])"),
-                ("FV", "Future Value - =FV(rate, nper, pmt, [pv], [type])"),
-                ("RATE", "Interest rate - =RATE(nper, pmt, pv, [fv], [type], [guess])"),
-                ("NPER", "Number of periods - =NPER(rate, pmt, pv, [fv], [type])"),
-                ("SLN", "Straight-line depreciation - =SLN(cost, salvage, life)"),
-                ("DB", "Declining balance depreciation - =DB(cost, salvage, life, period)"),
-                ("DDB", "Double declining balance - =DDB(cost, salvage, life, period)"),
-            ],
-        },
-        FunctionCategory {
-            name: "Lookup",
-            functions: vec![
-                ("MATCH", "Find position in array - =MATCH(value, array, [type])"),
-                ("INDEX", "Get value by position - =INDEX(array, row, [col])"),
-                ("VLOOKUP", "Vertical lookup - =VLOOKUP(value, table, col, [approx])"),
-                ("XLOOKUP", "Modern lookup - =XLOOKUP(value, lookup, return, [not_found], [match], [search])"),
-                ("CHOOSE", "Pick nth value - =CHOOSE(index, value1, value2, ...)"),
-                ("OFFSET", "Dynamic range slice - =OFFSET(array, rows, [height])"),
-            ],
-        },
-        FunctionCategory {
-            name: "Conditional",
-            functions: vec![
-                ("SUMIF", "Sum if condition - =SUMIF(range, criteria, [sum_range])"),
-                ("COUNTIF", "Count if condition - =COUNTIF(range, criteria)"),
-                ("AVERAGEIF", "Average if condition - =AVERAGEIF(range, criteria, [avg_range])"),
-                ("SUMIFS", "Sum with multiple conditions - =SUMIFS(sum_range, range1, criteria1, ...)"),
-                ("COUNTIFS", "Count with multiple conditions - =COUNTIFS(range1, criteria1, ...)"),
-                ("AVERAGEIFS", "Average with multiple conditions - =AVERAGEIFS(avg_range, range1, criteria1, ...)"),
-                ("MAXIFS", "Max with conditions - =MAXIFS(max_range, range1, criteria1, ...)"),
-                ("MINIFS", "Min with conditions - =MINIFS(min_range, range1, criteria1, ...)"),
-            ],
-        },
-        FunctionCategory {
-            name: "Array",
-            functions: vec![
-                ("UNIQUE", "Get unique values - =UNIQUE(array)"),
-                ("COUNTUNIQUE", "Count unique values - =COUNTUNIQUE(array)"),
-                ("FILTER", "Filter by criteria - =FILTER(array, include)"),
-                ("SORT", "Sort values - =SORT(array, [order])"),
-            ],
-        },
-        FunctionCategory {
-            name: "Aggregation",
-            functions: vec![
-                ("SUM", "Sum values - =SUM(value1, value2, ...)"),
-                ("AVERAGE", "Average values - =AVERAGE(value1, value2, ...) or =AVG(...)"),
-                ("MIN", "Minimum value - =MIN(value1, value2, ...)"),
-                ("MAX", "Maximum value - =MAX(value1, value2, ...)"),
-                ("COUNT", "Count values - =COUNT(array)"),
-            ],
-        },
-        FunctionCategory {
-            name: "Math",
-            functions: vec![
-                ("ROUND", "Round to digits - =ROUND(value, digits)"),
-                ("ROUNDUP", "Round up - =ROUNDUP(value, digits)"),
-                ("ROUNDDOWN", "Round down - =ROUNDDOWN(value, digits)"),
-                ("CEILING", "Round up to significance - =CEILING(value, significance)"),
-                ("FLOOR", "Round down to significance - =FLOOR(value, significance)"),
-                ("MOD", "Modulo/remainder - =MOD(value, divisor)"),
-                ("SQRT", "Square root - =SQRT(value)"),
-                ("POWER", "Power/exponent - =POWER(base, exponent)"),
-                ("ABS", "Absolute value - =ABS(value)"),
-            ],
-        },
-        FunctionCategory {
-            name: "Text",
-            functions: vec![
-                ("CONCAT", "Concatenate strings - =CONCAT(text1, text2, ...)"),
-                ("TRIM", "Remove extra spaces - =TRIM(text)"),
-                ("UPPER", "Convert to uppercase - =UPPER(text)"),
-                ("LOWER", "Convert to lowercase - =LOWER(text)"),
-                ("LEN", "Length of text - =LEN(text)"),
-                ("MID", "Extract substring - =MID(text, start, length)"),
-            ],
-        },
-        FunctionCategory {
-            name: "Date",
-            functions: vec![
-                ("TODAY", "Current date - =TODAY()"),
-                ("DATE", "Create date - =DATE(year, month, day)"),
-                ("YEAR", "Extract year - =YEAR(date)"),
-                ("MONTH", "Extract month - =MONTH(date)"),
-                ("DAY", "Extract day - =DAY(date)"),
-                ("DATEDIF", "Date difference - =DATEDIF(start, end, unit)"),
-                ("EDATE", "Add months to date - =EDATE(start, months)"),
-                ("EOMONTH", "End of month - =EOMONTH(start, months)"),
-                ("NETWORKDAYS", "Working days between dates - =NETWORKDAYS(start, end)"),
-                ("WORKDAY", "Date after N working days - =WORKDAY(start, days)"),
-                ("YEARFRAC", "Fraction of year - =YEARFRAC(start, end, [basis])"),
-            ],
-        },
-        FunctionCategory {
-            name: "Logic",
-            functions: vec![
-                ("IF", "Conditional - =IF(condition, true_value, false_value)"),
-                ("AND", "Logical AND - =AND(condition1, condition2, ...)"),
-                ("OR", "Logical OR - =OR(condition1, condition2, ...)"),
-                ("LET", "Named variables - =LET(name, value, ..., calculation)"),
-                ("SWITCH", "Multi-match - =SWITCH(expr, val1, result1, ..., [default])"),
-                ("INDIRECT", "String to ref - =INDIRECT(\"table.column\")"),
-                ("LAMBDA", "Anonymous func - =LAMBDA(x, x*2)(5)"),
-            ],
-        },
-        FunctionCategory {
-            name: "Statistical",
-            functions: vec![
-                ("MEDIAN", "Middle value - =MEDIAN(array)"),
-                ("VAR", "Variance (sample) - =VAR(array)"),
-                ("STDEV", "Standard deviation (sample) - =STDEV(array)"),
-                ("PERCENTILE", "Percentile value - =PERCENTILE(array, k)"),
-                ("QUARTILE", "Quartile value - =QUARTILE(array, quart)"),
-                ("CORREL", "Correlation coefficient - =CORREL(array1, array2)"),
-            ],
-        },
-        FunctionCategory {
-            name: "Forge-Native",
-            functions: vec![
-                ("SCENARIO", "Get scenario value - =SCENARIO(name, variable)"),
-                ("VARIANCE", "Budget variance - =VARIANCE(actual, budget)"),
-                ("VARIANCE_PCT", "Variance percent - =VARIANCE_PCT(actual, budget)"),
-                ("VARIANCE_STATUS", "Variance status - =VARIANCE_STATUS(actual, budget, [type])"),
-                ("BREAKEVEN_UNITS", "Break-even units - =BREAKEVEN_UNITS(fixed, price, var_cost)"),
-                ("BREAKEVEN_REVENUE", "Break-even revenue - =BREAKEVEN_REVENUE(fixed, margin_pct)"),
-            ],
-        },
-    ];
+    let categories: Vec<(&str, Vec<(&str, String)>)> = FunctionCategory::ALL
+        .iter()
+        .map(|&category| {
+            let functions = FunctionRegistry::in_category(category)
+                .map(|f| (f.name, format!("{} - {}", f.description, f.signature)))
+                .collect();
+            (category.name(), functions)
+        })
+        .collect();
 
     // Count total functions
-    let total: usize = categories.iter().map(|c| c.functions.len()).sum();
+    let total = FunctionRegistry::all().len();
 
     if json_output {
         // JSON output for tooling
         let json = serde_json::json!({
             "total": total,
-            "categories": categories.iter().map(|c| {
+            "categories": categories.iter().map(|(name, functions)| {
                 serde_json::json!({
-                    "name": c.name,
-                    "count": c.functions.len(),
-                    "functions": c.functions.iter().map(|(name, desc)| {
+                    "name": name,
+                    "count": functions.len(),
+                    "functions": functions.iter().map(|(name, desc)| {
                         serde_json::json!({
                             "name": name,
                             "description": desc
@@ -2121,16 +4926,12 @@ pub fn functions(json_output: bool) -> ForgeResult<()> {
         println!();
         println!("{}", "═".repeat(70));
 
-        for category in &categories {
+        for (category, functions) in &categories {
             println!();
-            println!(
-                "{} ({})",
-                category.name.bold().cyan(),
-                category.functions.len()
-            );
+            println!("{} ({})", category.bold().cyan(), functions.len());
             println!("{}", "─".repeat(70));
 
-            for (name, desc) in &category.functions {
+            for (name, desc) in functions {
                 println!("  {:12} {}", name.bold().yellow(), desc.bright_white());
             }
         }
@@ -2156,8 +4957,6 @@ pub fn upgrade(
     target_version: String,
     verbose: bool,
 ) -> ForgeResult<()> {
-    use std::collections::HashSet;
-
     println!("{}", "🔥 Forge - Schema Upgrade".bold().green());
     println!();
     println!("   File:    {}", file.display());
@@ -437,6 +437,134 @@ fn test_apply_scenario_not_found() {
     assert!(err.contains("not found"));
 }
 
+#[test]
+fn test_apply_scenario_replaces_table_column() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(
+        &dir,
+        "scenarios.yaml",
+        r#"_forge_version: "1.0.0"
+sales:
+  revenue: [100, 200, 300]
+  cost: [50, 100, 150]
+  profit: "=revenue - cost"
+scenarios:
+  optimistic:
+    sales.revenue: [150, 300, 450]
+  pessimistic:
+    sales.revenue: [80, 160]
+"#,
+    );
+    let mut model = parser::parse_model(&yaml).unwrap();
+    let base = model.clone();
+
+    apply_scenario(&mut model, "optimistic").unwrap();
+    let result = ArrayCalculator::new(model).calculate_all().unwrap();
+    assert_eq!(
+        result.tables["sales"].columns["profit"].values,
+        crate::types::ColumnValue::Number(vec![100.0, 200.0, 300.0])
+    );
+
+    let mut model = base;
+    let err = apply_scenario(&mut model, "pessimistic").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Validation error: Scenario 'pessimistic': column 'sales.revenue' has 2 values \
+         but table 'sales' has 3 rows"
+    );
+}
+
+// =========================================================================
+// compare --baseline Tests
+// =========================================================================
+
+#[test]
+fn test_comparison_table_baseline_deltas() {
+    let model = parser::parse_model_str(
+        r#"_forge_version: "1.0.0"
+price:
+  value: 10
+  formula: null
+volume:
+  value: 0
+  formula: null
+discount:
+  value: 5
+  formula: null
+scenarios:
+  base:
+    price: 10
+    volume: 0
+  high:
+    price: 12
+    volume: 100
+    discount: 4
+"#,
+    )
+    .unwrap();
+
+    let results: Vec<(String, crate::types::ParsedModel)> = ["base", "high"]
+        .iter()
+        .map(|name| {
+            let mut scenario = model.clone();
+            apply_scenario(&mut scenario, name).unwrap();
+            let calculated = ArrayCalculator::new(scenario).calculate_all().unwrap();
+            (name.to_string(), calculated)
+        })
+        .collect();
+
+    colored::control::set_override(false);
+    let table = render_comparison(&comparison(&results, Some("base"), &[]));
+    let lines: Vec<&str> = table.lines().collect();
+    let row = |name: &str, base: &str, high: &str, delta: &str| {
+        format!("{:<20}{:>15}{:>15}{:>22}", name, base, high, delta)
+    };
+    assert_eq!(lines[1], row("Variable", "base", "high", "Δ high"));
+    assert_eq!(lines[3], row("discount", "5", "4", "-1 (-20.0%)"));
+    assert_eq!(lines[4], row("price", "10", "12", "+2 (+20.0%)"));
+    // A zero baseline has no percentage
+    assert_eq!(lines[5], row("volume", "0", "100", "+100 (n/a)"));
+
+    // Without a baseline there are no delta columns
+    let table = render_comparison(&comparison(&results, None, &[]));
+    assert_eq!(
+        table.lines().nth(4).unwrap(),
+        format!("{:<20}{:>15}{:>15}", "price", "10", "12")
+    );
+}
+
+#[test]
+fn test_output_vars_filter() {
+    let mut model = crate::types::ParsedModel::new();
+    for (name, value) in [("revenue", 100.0), ("profit", 40.0), ("margin", 0.4)] {
+        model.scalars.insert(
+            name.to_string(),
+            crate::types::Variable::new(name.to_string(), Some(value), None),
+        );
+    }
+    let results = vec![("base".to_string(), model.clone())];
+
+    colored::control::set_override(false);
+    let output_vars = vec!["revenue".to_string(), "profit".to_string()];
+    let table = render_comparison(&comparison(&results, None, &output_vars));
+    let names: Vec<&str> = table
+        .lines()
+        .skip(3)
+        .filter_map(|line| line.split_whitespace().next())
+        .filter(|name| !name.starts_with('─'))
+        .collect();
+    assert_eq!(names, vec!["profit", "revenue"]);
+
+    let known: Vec<&String> = model.scalars.keys().collect();
+    assert!(check_output_vars(&output_vars, &known).is_ok());
+    let err = check_output_vars(&["revenu".to_string()], &known).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Validation error: Unknown output variable(s): revenu. \
+         Valid variables: margin, profit, revenue"
+    );
+}
+
 // =========================================================================
 // build_dependency_tree Tests
 // =========================================================================
@@ -611,24 +739,31 @@ fn test_print_variance_table_with_data() {
 // =========================================================================
 
 #[test]
-fn test_function_category_struct() {
-    let cat = FunctionCategory {
-        name: "Financial",
-        functions: vec![
-            ("NPV", "Net Present Value"),
-            ("IRR", "Internal Rate of Return"),
-        ],
-    };
+fn test_function_category_listing() {
+    let financial: Vec<_> = FunctionRegistry::in_category(FunctionCategory::Financial).collect();
 
-    assert_eq!(cat.name, "Financial");
-    assert_eq!(cat.functions.len(), 2);
-    assert_eq!(cat.functions[0].0, "NPV");
+    assert_eq!(FunctionCategory::Financial.name(), "Financial");
+    assert!(financial.len() >= 2);
+    assert_eq!(financial[0].name, "NPV");
+    assert_eq!(financial[0].description, "Net Present Value");
+    assert_eq!(financial[1].name, "IRR");
 }
 
 // =========================================================================
 // calculate_with_override Tests
 // =========================================================================
 
+/// One-shot what-if evaluation through the reusable target calculator
+fn calculate_with_override(
+    model: &crate::types::ParsedModel,
+    var_name: &str,
+    var_value: f64,
+    output_name: &str,
+) -> ForgeResult<f64> {
+    let (calculator, output) = target_calculator(model, output_name);
+    evaluate_target(&calculator, &output, &[(var_name, var_value)])
+}
+
 #[test]
 fn test_calculate_with_override_existing_scalar() {
     let mut model = crate::types::ParsedModel::new();
@@ -669,6 +804,300 @@ fn test_calculate_with_override_output_not_found() {
     assert!(result.is_err());
 }
 
+fn sales_model_with_price() -> crate::types::ParsedModel {
+    let mut model = crate::types::ParsedModel::new();
+    let mut sales = crate::types::Table::new("sales".to_string());
+    sales.add_column(crate::types::Column::new(
+        "units".to_string(),
+        crate::types::ColumnValue::Number(vec![10.0, 20.0, 30.0]),
+    ));
+    sales.add_row_formula("revenue".to_string(), "=units * inputs.price".to_string());
+    model.add_table(sales);
+    model.scalars.insert(
+        "inputs.price".to_string(),
+        crate::types::Variable::new("inputs.price".to_string(), Some(5.0), None),
+    );
+    model
+}
+
+#[test]
+fn test_calculate_with_override_expression_targets() {
+    let model = sales_model_with_price();
+
+    let total =
+        calculate_with_override(&model, "inputs.price", 10.0, "SUM(sales.revenue)").unwrap();
+    assert!((total - 600.0).abs() < 0.0001);
+
+    let cell = calculate_with_override(&model, "inputs.price", 10.0, "sales.revenue[2]").unwrap();
+    assert!((cell - 300.0).abs() < 0.0001);
+}
+
+#[test]
+fn test_goal_seek_table_total_by_varying_price() {
+    let model = sales_model_with_price();
+
+    let solution = solve_goal_seek(
+        &model,
+        "SUM(sales.revenue)",
+        1200.0,
+        "inputs.price",
+        GoalSeekOptions::default(),
+    )
+    .unwrap();
+
+    assert!((solution.input - 20.0).abs() < 0.001);
+    assert!((solution.output - 1200.0).abs() < 0.01);
+}
+
+#[test]
+fn test_goal_seek_column_scale_factor() {
+    let mut model = crate::types::ParsedModel::new();
+    let mut sales = crate::types::Table::new("sales".to_string());
+    sales.add_column(crate::types::Column::new(
+        "units".to_string(),
+        crate::types::ColumnValue::Number(vec![10.0, 20.0, 30.0]),
+    ));
+    sales.add_column(crate::types::Column::new(
+        "price".to_string(),
+        crate::types::ColumnValue::Number(vec![2.0, 4.0, 6.0]),
+    ));
+    sales.add_row_formula("revenue".to_string(), "=units * price".to_string());
+    model.add_table(sales);
+
+    // Revenue is 280 as written; doubling every price gives 560
+    let solution = solve_goal_seek_column(
+        &model,
+        "SUM(sales.revenue)",
+        560.0,
+        "sales.price",
+        GoalSeekOptions::default(),
+    )
+    .unwrap();
+
+    assert!((solution.input - 2.0).abs() < 0.001);
+    assert!((solution.output - 560.0).abs() < 0.01);
+
+    // Formula columns cannot be varied
+    let result = solve_goal_seek_column(
+        &model,
+        "SUM(sales.revenue)",
+        560.0,
+        "sales.revenue",
+        GoalSeekOptions::default(),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_goal_seek_newton_converges_faster_than_bisection() {
+    let model = sales_model_with_price();
+    let seek = |method| {
+        solve_goal_seek(
+            &model,
+            "SUM(sales.revenue)",
+            1200.0,
+            "inputs.price",
+            GoalSeekOptions {
+                method,
+                ..GoalSeekOptions::default()
+            },
+        )
+        .unwrap()
+    };
+
+    let bisection = seek(GoalSeekMethod::Bisection);
+    let newton = seek(GoalSeekMethod::Newton);
+
+    assert!((newton.input - 20.0).abs() < 0.001);
+    assert!(newton.iterations <= 3);
+    assert!(newton.iterations * 5 < bisection.iterations);
+}
+
+#[test]
+fn test_goal_seek_stops_at_timeout() {
+    let model = sales_model_with_price();
+    let seek = |timeout| {
+        solve_goal_seek(
+            &model,
+            "SUM(sales.revenue)",
+            1200.0,
+            "inputs.price",
+            GoalSeekOptions {
+                timeout: Some(timeout),
+                ..GoalSeekOptions::default()
+            },
+        )
+    };
+
+    // The deadline has passed before the first evaluation
+    let err = seek(Duration::ZERO).unwrap_err();
+    assert!(matches!(err, ForgeError::Timeout(_)), "{}", err);
+
+    let solution = seek(Duration::from_secs(600)).unwrap();
+    assert!((solution.input - 20.0).abs() < 0.001);
+}
+
+#[test]
+fn test_goal_seek_newton_falls_back_on_flat_derivative() {
+    let mut model = crate::types::ParsedModel::new();
+    model.scalars.insert(
+        "x".to_string(),
+        crate::types::Variable::new("x".to_string(), Some(5.0), None),
+    );
+    // Flat at the starting point (x = 5), so Newton has no slope to follow
+    model.scalars.insert(
+        "result".to_string(),
+        crate::types::Variable::new("result".to_string(), None, Some("=MAX(x, 10)".to_string())),
+    );
+
+    let solution = solve_goal_seek(
+        &model,
+        "result",
+        20.0,
+        "x",
+        GoalSeekOptions {
+            method: GoalSeekMethod::Newton,
+            ..GoalSeekOptions::default()
+        },
+    )
+    .unwrap();
+
+    assert!((solution.input - 20.0).abs() < 0.001);
+    assert!(solution.iterations > 3);
+}
+
+#[test]
+fn test_sweep_outputs_two_outputs() {
+    let mut model = crate::types::ParsedModel::new();
+    model.scalars.insert(
+        "price".to_string(),
+        crate::types::Variable::new("price".to_string(), Some(10.0), None),
+    );
+    model.scalars.insert(
+        "profit".to_string(),
+        crate::types::Variable::new(
+            "profit".to_string(),
+            None,
+            Some("=price * 100 - 500".to_string()),
+        ),
+    );
+    model.scalars.insert(
+        "margin".to_string(),
+        crate::types::Variable::new(
+            "margin".to_string(),
+            None,
+            Some("=profit / (price * 100)".to_string()),
+        ),
+    );
+
+    let outputs = vec!["profit".to_string(), "margin".to_string()];
+    let rows = sweep_outputs(&model, "price", &[10.0, 20.0], &outputs);
+
+    assert_eq!(rows.len(), 2);
+    let profits: Vec<f64> = rows.iter().map(|r| *r[0].as_ref().unwrap()).collect();
+    let margins: Vec<f64> = rows.iter().map(|r| *r[1].as_ref().unwrap()).collect();
+    assert_eq!(profits, vec![500.0, 1500.0]);
+    assert!((margins[0] - 0.5).abs() < 0.0001);
+    assert!((margins[1] - 0.75).abs() < 0.0001);
+}
+
+#[test]
+fn test_tornado_bars_sorted_by_impact() {
+    let mut model = crate::types::ParsedModel::new();
+    for (name, value) in [("price", 10.0), ("volume", 50.0), ("cost", 500.0)] {
+        model.scalars.insert(
+            name.to_string(),
+            crate::types::Variable::new(name.to_string(), Some(value), None),
+        );
+    }
+    model.scalars.insert(
+        "profit".to_string(),
+        crate::types::Variable::new(
+            "profit".to_string(),
+            None,
+            Some("=price * volume - cost * 2".to_string()),
+        ),
+    );
+
+    let vars = vec![
+        "price".to_string(),
+        "volume".to_string(),
+        "cost".to_string(),
+    ];
+    let bars = tornado_bars(&model, &vars, "profit", 0.1).unwrap();
+
+    // cost ±10% swings profit by ±100; price and volume by ±50 each
+    assert_eq!(bars.len(), 3);
+    assert_eq!(bars[0].variable, "cost");
+    assert!((bars[0].range - 200.0).abs() < 0.0001);
+    assert!((bars[0].output_low + 400.0).abs() < 0.0001);
+    assert!((bars[0].output_high + 600.0).abs() < 0.0001);
+    assert!((bars[1].range - 100.0).abs() < 0.0001);
+}
+
+#[test]
+fn test_parse_distribution() {
+    let (name, dist) = parse_distribution("price:normal:100:10").unwrap();
+    assert_eq!(name, "price");
+    assert_eq!(
+        dist,
+        Distribution::Normal {
+            mean: 100.0,
+            stdev: 10.0
+        }
+    );
+
+    let (_, dist) = parse_distribution("volume:uniform:900:1100").unwrap();
+    assert_eq!(
+        dist,
+        Distribution::Uniform {
+            min: 900.0,
+            max: 1100.0
+        }
+    );
+
+    assert!(parse_distribution("price:lognormal:1:2").is_err());
+    assert!(parse_distribution("volume:uniform:1100:900").is_err());
+    assert!(parse_distribution("price:normal:100").is_err());
+}
+
+#[test]
+fn test_monte_carlo_fixed_seed_is_deterministic() {
+    let mut model = crate::types::ParsedModel::new();
+    model.scalars.insert(
+        "price".to_string(),
+        crate::types::Variable::new("price".to_string(), Some(100.0), None),
+    );
+    model.scalars.insert(
+        "volume".to_string(),
+        crate::types::Variable::new("volume".to_string(), Some(1000.0), None),
+    );
+    model.scalars.insert(
+        "profit".to_string(),
+        crate::types::Variable::new(
+            "profit".to_string(),
+            None,
+            Some("=price * volume".to_string()),
+        ),
+    );
+
+    let vars = vec![
+        parse_distribution("price:normal:100:10").unwrap(),
+        parse_distribution("volume:uniform:900:1100").unwrap(),
+    ];
+
+    let first = run_monte_carlo(&model, &vars, "profit", 500, 42).unwrap();
+    let second = run_monte_carlo(&model, &vars, "profit", 500, 42).unwrap();
+    assert_eq!(first, second);
+
+    assert_eq!(first.trials, 500);
+    assert!((first.mean - 100_000.0).abs() < 2_000.0);
+    assert!(first.p5 < first.p50 && first.p50 < first.p95);
+
+    let other = run_monte_carlo(&model, &vars, "profit", 500, 7).unwrap();
+    assert_ne!(first.p50, other.p50);
+}
+
 // =========================================================================
 // Command Integration Tests (with temp files)
 // =========================================================================
@@ -681,7 +1110,7 @@ fn create_test_yaml(dir: &TempDir, name: &str, content: &str) -> PathBuf {
 }
 
 #[test]
-fn test_validate_single_file_empty_model() {
+fn test_validate_file_empty_model() {
     let dir = TempDir::new().unwrap();
     let yaml = create_test_yaml(
         &dir,
@@ -690,12 +1119,12 @@ fn test_validate_single_file_empty_model() {
     );
 
     // Empty model should pass validation with warning
-    let result = validate_single_file(&yaml);
+    let result = validate_file(&yaml, false);
     assert!(result.is_ok());
 }
 
 #[test]
-fn test_validate_single_file_valid_model() {
+fn test_validate_file_valid_model() {
     let dir = TempDir::new().unwrap();
     // Use simple scalar format (like test.yaml) that works with v1.0.0
     let yaml = create_test_yaml(
@@ -712,10 +1141,92 @@ summary:
 "#,
     );
 
-    let result = validate_single_file(&yaml);
+    let result = validate_file(&yaml, false);
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_validate_diff_reports_all_stale_scalars() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(
+        &dir,
+        "stale.yaml",
+        r#"_forge_version: "1.0.0"
+summary:
+  price:
+    value: 100
+    formula: null
+  doubled:
+    value: 150
+    formula: "=price * 2"
+  tripled:
+    value: 999
+    formula: "=price * 3"
+"#,
+    );
+
+    let model = parser::parse_model(&yaml).unwrap();
+    let calculated = ArrayCalculator::new(model.clone()).calculate_all().unwrap();
+    let mismatches = find_mismatches(&model, &calculated);
+
+    assert_eq!(mismatches.len(), 2);
+    assert_eq!(mismatches[0].group, "summary");
+    assert_eq!(mismatches[0].name, "doubled");
+    assert_eq!(mismatches[0].stored, Some(150.0));
+    assert_eq!(mismatches[0].recalculated, 200.0);
+    assert_eq!(mismatches[0].delta(), Some(50.0));
+    assert_eq!(mismatches[1].name, "tripled");
+    assert_eq!(mismatches[1].delta(), Some(-699.0));
+
+    assert!(validate_diff(vec![yaml]).is_err());
+}
+
+#[test]
+fn test_validation_report_lists_every_stale_value() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(
+        &dir,
+        "stale.yaml",
+        r#"_forge_version: "1.0.0"
+summary:
+  price:
+    value: 100
+    formula: null
+  doubled:
+    value: 150
+    formula: "=price * 2"
+  tripled:
+    value: 999
+    formula: "=price * 3"
+"#,
+    );
+    let missing = dir.path().join("missing.yaml");
+
+    let report = validation_report(&[yaml.clone()], None).unwrap();
+    assert_eq!(report.outcome(), ValidationOutcome::Stale);
+
+    let json = serde_json::to_value(&report).unwrap();
+    let issues = json["issues"].as_array().unwrap();
+    let stale: Vec<&serde_json::Value> = issues
+        .iter()
+        .filter(|issue| issue["kind"] == "stale-value")
+        .collect();
+    assert_eq!(stale.len(), 2);
+    assert_eq!(stale[0]["path"], "summary.doubled");
+    assert_eq!(stale[0]["line"], 7);
+    assert_eq!(stale[0]["expected"], 200.0);
+    assert_eq!(stale[0]["actual"], 150.0);
+    assert_eq!(stale[0]["severity"], "error");
+    assert_eq!(stale[1]["path"], "summary.tripled");
+    assert_eq!(stale[1]["line"], 10);
+
+    // An unreadable file is reported too, and makes the outcome broken
+    let report = validation_report(&[yaml, missing], None).unwrap();
+    assert_eq!(report.issues.len(), 3);
+    assert_eq!(report.issues[2].kind, IssueKind::ParseError);
+    assert_eq!(report.outcome(), ValidationOutcome::Broken);
+}
+
 #[test]
 fn test_validate_batch() {
     let dir = TempDir::new().unwrap();
@@ -734,6 +1245,54 @@ fn test_validate_batch() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_validate_with_schema_reports_all_violations() {
+    let dir = TempDir::new().unwrap();
+    let model = r#"
+_forge_version: "1.0.0"
+sales:
+  revenue: [100, 200]
+  cost: [60, 120]
+  profit: "=revenue - cost"
+"#;
+    let yaml = create_test_yaml(&dir, "model.yaml", model);
+    let schema = crate::api::model_schema(&parser::parse_model(&yaml).unwrap());
+    let schema_path = create_test_yaml(&dir, "model.schema.json", &schema.to_string());
+
+    assert!(validate_with_schema(vec![yaml], schema_path.clone(), false).is_ok());
+
+    let broken = create_test_yaml(
+        &dir,
+        "broken.yaml",
+        &model
+            .replace("  profit: \"=revenue - cost\"\n", "")
+            .replace("[60, 120]", "[\"a\", \"b\"]"),
+    );
+    let err = validate_with_schema(vec![broken], schema_path, false)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("/sales: \"profit\" is a required property"));
+    assert!(err.contains("/sales/cost"));
+}
+
+#[test]
+fn test_schema_violations_checks_every_document() {
+    let schema = serde_json::json!({
+        "type": "object",
+        "required": ["_forge_version"],
+        "properties": { "count": { "type": "number" } }
+    });
+    let schema = jsonschema::JSONSchema::compile(&schema).unwrap();
+
+    // The first document lacks the version, the second has a bad count
+    let content = "count: 3\n---\n_forge_version: \"5.0.0\"\ncount: many\n";
+    let violations = schema_violations(content, &schema).unwrap();
+
+    let paths: Vec<&str> = violations.iter().map(|(path, _)| path.as_str()).collect();
+    assert_eq!(paths, vec!["/", "/count"]);
+    assert!(violations[0].1.contains("_forge_version"));
+}
+
 #[test]
 fn test_validate_internal_success() {
     let dir = TempDir::new().unwrap();
@@ -928,3 +1487,669 @@ fn test_export_variance_to_excel() {
     assert!(result.is_ok());
     assert!(output_path.exists());
 }
+
+#[test]
+fn test_table_variances_row_by_row() {
+    let dir = TempDir::new().unwrap();
+    let model = |name: &str, revenue: &str| {
+        let rows = revenue.matches(',').count() + 1;
+        let months: Vec<String> = (1..=rows).map(|m| m.to_string()).collect();
+        let yaml = create_test_yaml(
+            &dir,
+            name,
+            &format!(
+                "_forge_version: \"1.0.0\"\nmonthly:\n  month: [{}]\n  revenue: {}\n",
+                months.join(", "),
+                revenue
+            ),
+        );
+        parser::parse_model(&yaml).unwrap()
+    };
+    let budget = model(
+        "budget.yaml",
+        "[100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100]",
+    );
+    let actual = model(
+        "actual.yaml",
+        "[110, 100, 90, 100, 100, 100, 100, 100, 100, 100, 100, 130]",
+    );
+
+    let variances = table_variances(&budget, &actual, 10.0).unwrap();
+    let revenue: Vec<&VarianceResult> = variances
+        .iter()
+        .filter(|v| v.name.starts_with("monthly.revenue"))
+        .collect();
+    assert_eq!(revenue.len(), 13);
+
+    assert_eq!(revenue[0].name, "monthly.revenue[0]");
+    assert_eq!(revenue[0].variance, 10.0);
+    assert!(revenue[0].is_favorable && revenue[0].exceeds_threshold);
+    assert_eq!(revenue[2].variance, -10.0);
+    assert!(!revenue[2].is_favorable);
+    assert_eq!(revenue[11].name, "monthly.revenue[11]");
+    assert_eq!(revenue[11].variance, 30.0);
+
+    let total = revenue[12];
+    assert_eq!(total.name, "monthly.revenue (total)");
+    assert_eq!(total.budget, 1200.0);
+    assert_eq!(total.actual, 1230.0);
+    assert!((total.variance_pct - 2.5).abs() < 1e-9);
+
+    // Columns of different lengths cannot be aligned
+    let short = model("short.yaml", "[100, 100, 100]");
+    let err = table_variances(&budget, &short, 10.0).unwrap_err();
+    assert!(err.to_string().contains("monthly"), "{}", err);
+}
+
+#[test]
+fn test_variance_to_markdown() {
+    let variances = vec![
+        VarianceResult {
+            name: "revenue".to_string(),
+            budget: 1_200_000.0,
+            actual: 1_350_000.5,
+            variance: 150_000.5,
+            variance_pct: 12.5,
+            is_favorable: true,
+            exceeds_threshold: true,
+        },
+        VarianceResult {
+            name: "costs".to_string(),
+            budget: 500.0,
+            actual: 520.0,
+            variance: 20.0,
+            variance_pct: 4.0,
+            is_favorable: false,
+            exceeds_threshold: false,
+        },
+    ];
+
+    let markdown = variance_to_markdown(&variances, 10.0);
+    assert!(markdown.contains("| Variable | Budget | Actual | Variance | % | Status |"));
+    assert!(markdown.contains("| revenue | 1,200,000 | 1,350,000.5 | 150,000.5 | 12.5% | ⚠️ ✅ |"));
+    assert!(markdown.contains("| costs | 500 | 520 | 20 | 4.0% | ❌ |"));
+}
+
+#[test]
+fn test_format_thousands() {
+    assert_eq!(format_thousands(0.0), "0");
+    assert_eq!(format_thousands(999.0), "999");
+    assert_eq!(format_thousands(1000.0), "1,000");
+    assert_eq!(format_thousands(-1234567.25), "-1,234,567.25");
+}
+
+// =========================================================================
+// consolidate Tests
+// =========================================================================
+
+#[test]
+fn test_consolidate_sums_shared_scalars_and_tables() {
+    let dir = TempDir::new().unwrap();
+    let sub1 = create_test_yaml(
+        &dir,
+        "sub1.yaml",
+        r#"_forge_version: "1.0.0"
+revenue:
+  value: 100
+  formula: null
+sales:
+  month: ["Jan", "Feb"]
+  units: [1, 2]
+  doubled: "=units * 2"
+"#,
+    );
+    let sub2 = create_test_yaml(
+        &dir,
+        "sub2.yaml",
+        r#"_forge_version: "1.0.0"
+revenue:
+  value: 150
+  formula: null
+headcount:
+  value: 4
+  formula: null
+sales:
+  month: ["Jan", "Feb"]
+  units: [10, 20]
+  doubled: "=units * 2"
+"#,
+    );
+    let output = dir.path().join("group.yaml");
+
+    consolidate(vec![sub1.clone(), sub2.clone()], output.clone(), false).unwrap();
+
+    let group = parser::parse_model(&output).unwrap();
+    assert_eq!(group.scalars["revenue"].value, Some(250.0));
+    assert_eq!(group.scalars["headcount"].value, Some(4.0));
+    let sales = &group.tables["sales"];
+    assert_eq!(
+        sales.columns["units"].values,
+        crate::types::ColumnValue::Number(vec![11.0, 22.0])
+    );
+    assert_eq!(
+        sales.columns["doubled"].values,
+        crate::types::ColumnValue::Number(vec![22.0, 44.0])
+    );
+
+    // Stacking appends rows instead
+    consolidate(vec![sub1, sub2], output.clone(), true).unwrap();
+    let stacked = parser::parse_model(&output).unwrap();
+    assert_eq!(stacked.tables["sales"].row_count(), 4);
+}
+
+// =========================================================================
+// Dry-run Diff Tests
+// =========================================================================
+
+#[test]
+fn test_dry_run_diff_lists_changed_values() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(
+        &dir,
+        "model.yaml",
+        r#"_forge_version: "1.0.0"
+sales:
+  revenue: [100, 200]
+summary:
+  total:
+    value: 250
+    formula: "=SUM(sales.revenue)"
+  average:
+    value: null
+    formula: "=AVERAGE(sales.revenue)"
+  largest:
+    value: 200
+    formula: "=MAX(sales.revenue)"
+"#,
+    );
+    let model = parser::parse_model(&yaml).unwrap();
+    let result = ArrayCalculator::new(model.clone()).calculate_all().unwrap();
+
+    let changes = find_mismatches(&model, &result);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].stored, None);
+    assert_eq!(changes[0].delta(), None);
+
+    colored::control::set_override(false);
+    let diff = dry_run_diff(Path::new("model.yaml"), &changes);
+    assert_eq!(
+        diff,
+        "--- model.yaml\n\
+         +++ model.yaml (calculated)\n\
+         @@ summary (2 changed) @@\n\
+         -  average: (none)\n\
+         +  average: 150\n\
+         -  total: 250\n\
+         +  total: 300\n\
+         2 value(s) would change\n"
+    );
+}
+
+#[test]
+fn test_calculate_to_output_leaves_source_untouched() {
+    let dir = TempDir::new().unwrap();
+    let source = r#"_forge_version: "1.0.0"
+sales:
+  revenue: [100, 200]
+summary:
+  total:
+    value: 0 # recalculated
+    formula: "=SUM(sales.revenue)"
+"#;
+    let yaml = create_test_yaml(&dir, "model.yaml", source);
+    let output = dir.path().join("results.yaml");
+
+    calculate_to(yaml.clone(), output.clone(), false, false, None).unwrap();
+
+    assert_eq!(fs::read_to_string(&yaml).unwrap(), source);
+    assert!(!dir.path().join("model.yaml.bak").exists());
+    assert_eq!(
+        fs::read_to_string(&output).unwrap(),
+        source.replace("value: 0 #", "value: 300.0 #")
+    );
+}
+
+#[test]
+fn test_calculate_check_units_uses_scenario_model() {
+    let dir = TempDir::new().unwrap();
+    let source = r#"_forge_version: "1.0.0"
+sales:
+  revenue:
+    value: [100, 200]
+    unit: "USD"
+  weight:
+    value: [2, 4]
+    unit: "kg"
+  total: "=revenue + weight"
+scenarios:
+  high:
+    sales.revenue: [150, 300]
+"#;
+    let yaml = create_test_yaml(&dir, "model.yaml", source);
+    let scenario = || Some("high".to_string());
+
+    let err = calculate_with_include_paths(yaml.clone(), None, false, false, scenario(), &[], true)
+        .unwrap_err();
+    assert!(err.to_string().contains("cannot add USD and kg"), "{}", err);
+    assert_eq!(fs::read_to_string(&yaml).unwrap(), source);
+
+    // Unknown scenarios are still reported before units are checked
+    let err = calculate_json(yaml.clone(), Some("low".to_string()), &[], true).unwrap_err();
+    assert!(err.to_string().contains("Scenario 'low' not found"));
+
+    assert!(calculate_json(yaml, scenario(), &[], false).is_ok());
+}
+
+// =========================================================================
+// Roundtrip Tests
+// =========================================================================
+
+#[test]
+fn test_roundtrip_reports_lost_table() {
+    let dir = TempDir::new().unwrap();
+    let clean = create_test_yaml(
+        &dir,
+        "clean.yaml",
+        r#"_forge_version: "1.0.0"
+sales:
+  revenue: [100, 200]
+  cost: [60, 70]
+  profit: "=revenue - cost"
+"#,
+    );
+    assert!(roundtrip(clean).is_ok());
+
+    // Sheet names come back lowercased, so "Sales" is lost
+    let renamed = create_test_yaml(
+        &dir,
+        "renamed.yaml",
+        r#"_forge_version: "1.0.0"
+Sales:
+  revenue: [100, 200]
+"#,
+    );
+    let err = roundtrip(renamed).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Validation error: Excel round-trip changed 1 item(s)"
+    );
+}
+
+// =========================================================================
+// join Tests
+// =========================================================================
+
+#[test]
+fn test_join_writes_merged_table() {
+    let dir = TempDir::new().unwrap();
+    let orders = create_test_yaml(
+        &dir,
+        "orders.yaml",
+        r#"_forge_version: "1.0.0"
+orders:
+  product_id: ["A", "B", "A"]
+  quantity: [1, 2, 3]
+"#,
+    );
+    let products = create_test_yaml(
+        &dir,
+        "products.yaml",
+        r#"_forge_version: "1.0.0"
+products:
+  product_id: ["A", "B"]
+  price: [10, 20]
+  name: ["Apple", "Banana"]
+"#,
+    );
+    let output = dir.path().join("joined.yaml");
+
+    join(
+        orders,
+        products,
+        "product_id".to_string(),
+        output.clone(),
+        JoinKind::Inner,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let joined = parser::parse_model(&output).unwrap();
+    let orders = &joined.tables["orders"];
+    assert_eq!(
+        orders.columns["price"].values,
+        crate::types::ColumnValue::Number(vec![10.0, 20.0, 10.0])
+    );
+    assert_eq!(
+        orders.columns["name"].values,
+        crate::types::ColumnValue::Text(vec!["Apple".into(), "Banana".into(), "Apple".into()])
+    );
+}
+
+// =========================================================================
+// stats Tests
+// =========================================================================
+
+#[test]
+fn test_column_stats_values() {
+    let stats = column_stats("revenue", &[10.0, 20.0, 30.0, 40.0, 50.0]);
+    assert_eq!(stats.name, "revenue");
+    assert_eq!(stats.count, 5);
+    assert_eq!(stats.sum, 150.0);
+    assert_eq!(stats.mean, 30.0);
+    assert_eq!(stats.median, 30.0);
+    assert_eq!(stats.min, 10.0);
+    assert_eq!(stats.max, 50.0);
+    assert_eq!(stats.q1, 20.0);
+    assert_eq!(stats.q3, 40.0);
+    // Sample standard deviation
+    assert!((stats.stdev - 15.811388).abs() < 0.0001);
+}
+
+#[test]
+fn test_stats_command_single_and_all_columns() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(
+        &dir,
+        "stats.yaml",
+        r#"_forge_version: "1.0.0"
+sales:
+  region: ["N", "S", "E", "W"]
+  revenue: [100, 200, 300, 400]
+  cost: [50, 60, 70, 80]
+  profit: "=revenue - cost"
+"#,
+    );
+
+    assert!(stats(yaml.clone(), "sales".to_string(), "revenue".to_string()).is_ok());
+    assert!(stats(yaml.clone(), "sales".to_string(), "all".to_string()).is_ok());
+    // Text columns and unknown tables are rejected
+    assert!(stats(yaml.clone(), "sales".to_string(), "region".to_string()).is_err());
+    assert!(stats(yaml, "missing".to_string(), "all".to_string()).is_err());
+}
+
+// =========================================================================
+// Batch calculate (glob) Tests
+// =========================================================================
+
+#[test]
+fn test_glob_match_wildcards() {
+    assert!(glob_match("*.yaml", "model.yaml"));
+    assert!(glob_match("q?_*.yaml", "q1_sales.yaml"));
+    assert!(!glob_match("*.yaml", "model.yaml.bak"));
+    assert!(!glob_match("?.yaml", "ab.yaml"));
+}
+
+#[test]
+fn test_expand_glob_sorted_matches() {
+    let dir = TempDir::new().unwrap();
+    create_test_yaml(&dir, "b.yaml", "");
+    create_test_yaml(&dir, "a.yaml", "");
+    create_test_yaml(&dir, "notes.txt", "");
+
+    let pattern = dir.path().join("*.yaml");
+    let files = expand_glob(&pattern.to_string_lossy()).unwrap();
+    assert_eq!(
+        files,
+        vec![dir.path().join("a.yaml"), dir.path().join("b.yaml")]
+    );
+
+    let none = dir.path().join("*.json");
+    assert!(expand_glob(&none.to_string_lossy()).is_err());
+}
+
+// =========================================================================
+// audit --tree / --dot Tests
+// =========================================================================
+
+#[test]
+fn test_dependency_dot_edges() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(
+        &dir,
+        "graph.yaml",
+        r#"_forge_version: "5.0.0"
+sales:
+  price: [10, 20]
+  qty: [1, 2]
+  revenue: "=price * qty"
+summary:
+  total:
+    value: null
+    formula: "=SUM(sales.revenue)"
+  tax_rate:
+    value: 0.1
+    formula: null
+  tax:
+    value: null
+    formula: "=summary.total * summary.tax_rate"
+"#,
+    );
+    let model = parser::parse_model(&yaml).unwrap();
+    let graph = ArrayCalculator::new(model).dependency_graph().unwrap();
+    let dot = dependency_dot(&graph);
+
+    assert!(dot.starts_with("digraph forge {"));
+    for edge in [
+        "\"sales.price\" -> \"sales.revenue\";",
+        "\"sales.qty\" -> \"sales.revenue\";",
+        "\"sales.revenue\" -> \"summary.total\";",
+        "\"summary.total\" -> \"summary.tax\";",
+        "\"summary.tax_rate\" -> \"summary.tax\";",
+    ] {
+        assert!(dot.contains(edge), "missing {} in\n{}", edge, dot);
+    }
+    assert_eq!(dot.matches("->").count(), 5);
+
+    // The tree hangs everything from the inputs
+    let tree = dependency_tree(&graph);
+    assert!(tree.starts_with("   sales.price\n      └─ sales.revenue\n"));
+    assert!(tree.contains("summary.tax_rate\n      └─ summary.tax\n"));
+}
+
+// =========================================================================
+// --output-format Tests
+// =========================================================================
+
+const REPORT_MODEL: &str = r#"_forge_version: "1.0.0"
+price:
+  value: 10
+  formula: null
+volume:
+  value: 100
+  formula: null
+cost:
+  value: 500
+  formula: null
+profit:
+  value: null
+  formula: "=price * volume - cost"
+scenarios:
+  base:
+    price: 10
+  high:
+    price: 12
+"#;
+
+/// Render a report as JSON, parse it back and return its top-level keys
+fn report_json_keys<T: Serialize>(report: &T) -> (serde_json::Value, Vec<String>) {
+    let json = render_report(report, ReportFormat::Json).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let keys = value.as_object().unwrap().keys().cloned().collect();
+    (value, keys)
+}
+
+#[test]
+fn test_compare_report_json() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(&dir, "model.yaml", REPORT_MODEL);
+    let options = CompareOptions {
+        baseline: Some("base".to_string()),
+        output_vars: vec!["profit".to_string()],
+    };
+    let report = compare_report(yaml, vec!["high".to_string()], options).unwrap();
+
+    let (json, keys) = report_json_keys(&report);
+    assert_eq!(keys, vec!["baseline", "scenarios", "variables"]);
+    assert_eq!(json["scenarios"], serde_json::json!(["base", "high"]));
+    let profit = &json["variables"][0];
+    assert_eq!(profit["name"], "profit");
+    assert_eq!(profit["values"]["high"], 700.0);
+    assert_eq!(profit["deltas"]["high"]["absolute"], 200.0);
+    assert_eq!(profit["deltas"]["high"]["percent"], 40.0);
+
+    // YAML renders the same data
+    let yaml = render_report(&report, ReportFormat::Yaml).unwrap();
+    assert!(yaml.starts_with("scenarios:\n- base\n- high\nbaseline: base\n"));
+}
+
+#[test]
+fn test_sensitivity_report_json() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(&dir, "model.yaml", REPORT_MODEL);
+
+    let report = sensitivity_report(
+        yaml.clone(),
+        "price".to_string(),
+        "10,12,1".to_string(),
+        None,
+        None,
+        "profit".to_string(),
+    )
+    .unwrap();
+    let (json, keys) = report_json_keys(&report);
+    assert_eq!(keys, vec!["outputs", "rows", "vary"]);
+    assert_eq!(json["rows"].as_array().unwrap().len(), 3);
+    assert_eq!(json["rows"][2]["input"], 12.0);
+    assert_eq!(json["rows"][2]["outputs"]["profit"], 700.0);
+
+    let report = tornado_report(yaml, "price,cost".to_string(), 0.1, "profit".to_string()).unwrap();
+    let (json, keys) = report_json_keys(&report);
+    assert_eq!(keys, vec!["bars", "output", "swing"]);
+    assert_eq!(json["bars"][0]["variable"], "price");
+}
+
+#[test]
+fn test_variance_report_json() {
+    let dir = TempDir::new().unwrap();
+    let budget = create_test_yaml(&dir, "budget.yaml", REPORT_MODEL);
+    let actual = create_test_yaml(
+        &dir,
+        "actual.yaml",
+        &REPORT_MODEL.replace("value: 100\n", "value: 80\n"),
+    );
+
+    let report = variance_report(budget, actual, 10.0).unwrap();
+    let (json, keys) = report_json_keys(&report);
+    assert_eq!(
+        keys,
+        vec![
+            "actual",
+            "alerts",
+            "budget",
+            "favorable",
+            "threshold",
+            "unfavorable",
+            "variances"
+        ]
+    );
+    let volume = json["variances"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|v| v["name"] == "volume")
+        .unwrap();
+    assert_eq!(volume["variance"], -20.0);
+    assert_eq!(volume["is_favorable"], false);
+}
+
+#[test]
+fn test_goal_seek_report_json() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(&dir, "model.yaml", REPORT_MODEL);
+
+    let report = goal_seek_report(
+        yaml,
+        "profit".to_string(),
+        1000.0,
+        "price".to_string(),
+        GoalSeekOptions::default(),
+    )
+    .unwrap();
+    let (json, keys) = report_json_keys(&report);
+    assert_eq!(
+        keys,
+        vec![
+            "column_factor",
+            "input",
+            "iterations",
+            "output",
+            "target",
+            "tolerance",
+            "value",
+            "vary",
+            "within_tolerance"
+        ]
+    );
+    assert!((json["input"].as_f64().unwrap() - 15.0).abs() < 0.001);
+    assert_eq!(json["within_tolerance"], true);
+}
+
+#[test]
+fn test_break_even_report_json() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(&dir, "model.yaml", REPORT_MODEL);
+
+    let report =
+        break_even_report(yaml, "profit".to_string(), "price".to_string(), None, None).unwrap();
+    let (json, keys) = report_json_keys(&report);
+    assert!(keys.contains(&"input".to_string()));
+    assert_eq!(json["value"], 0.0);
+    assert!((json["input"].as_f64().unwrap() - 5.0).abs() < 0.001);
+}
+
+#[test]
+fn test_fmt_check_flags_then_formats_file() {
+    let dir = TempDir::new().unwrap();
+    let yaml = create_test_yaml(
+        &dir,
+        "model.yaml",
+        r#"summary:
+  total:
+    formula: "=SUM(sales.revenue)*rate"
+    value: null
+_forge_version: "1.0.0"
+sales:
+  revenue:
+      - 100
+      - 200
+  doubled: "=revenue*2"
+rate:
+  value: 0.5
+"#,
+    );
+    let canonical = r#"_forge_version: "1.0.0"
+sales:
+  revenue: [100, 200]
+  doubled: "=revenue * 2"
+summary:
+  total:
+    value: null
+    formula: "=SUM(sales.revenue) * rate"
+rate:
+  value: 0.5
+"#;
+
+    let err = fmt(vec![yaml.clone()], true).unwrap_err();
+    assert!(err.to_string().contains("1 file(s) not formatted"));
+    assert!(fs::read_to_string(&yaml).unwrap().contains("=revenue*2"));
+
+    fmt(vec![yaml.clone()], false).unwrap();
+    assert_eq!(fs::read_to_string(&yaml).unwrap(), canonical);
+    assert!(fmt(vec![yaml.clone()], true).is_ok());
+
+    // Formatting never changes what the model calculates
+    let model = parser::parse_model(&yaml).unwrap();
+    let result = ArrayCalculator::new(model).calculate_all().unwrap();
+    assert_eq!(result.scalars["summary.total"].value, Some(150.0));
+}
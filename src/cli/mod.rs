@@ -3,6 +3,13 @@
 pub mod commands;
 
 pub use commands::{
-    audit, break_even, calculate, compare, export, functions, goal_seek, import, sensitivity,
-    upgrade, validate, variance, watch,
+    audit, audit_graph, audit_impact, break_even, break_even_report, calculate, calculate_batch,
+    calculate_json, calculate_to, calculate_with_include_paths, compare, compare_report,
+    compare_with_baseline, compare_with_options, consolidate, export, fmt, functions, goal_seek,
+    goal_seek_column, goal_seek_column_report, goal_seek_report, goal_seek_with_method, import,
+    is_glob_pattern, join, monte_carlo, print_report, roundtrip, schema, sensitivity,
+    sensitivity_report, sensitivity_tornado, stats, tornado_report, upgrade, validate,
+    validate_diff, validate_report, validate_with_schema, validation_report, variance,
+    variance_report, watch, CompareOptions, GoalSeekMethod, GoalSeekOptions, ReportFormat,
+    ValidationOutcome,
 };
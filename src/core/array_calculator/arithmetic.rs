@@ -0,0 +1,192 @@
+//! Fast path for pure arithmetic row formulas
+//! `=a * b - c`, `=(revenue - cost) / units * 1.2` over numeric columns
+//!
+//! Such formulas are compiled into a small expression tree and evaluated over
+//! whole column slices in tight loops, bypassing the per-cell interpreter.
+//! Arithmetic is done in f32 and rounded like the general path so both paths
+//! produce identical columns. Anything else (functions, comparisons, scalar or
+//! cross-table references, unary minus, `^`) returns `None` and takes the
+//! general path, as does a result that would be an error (e.g. division by zero).
+
+use crate::types::{ColumnValue, Table};
+
+use super::ArrayCalculator;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug)]
+enum ArithExpr<'a> {
+    Const(f32),
+    Column(&'a [f64]),
+    Binary(ArithOp, Box<ArithExpr<'a>>, Box<ArithExpr<'a>>),
+}
+
+impl ArithExpr<'_> {
+    /// Evaluate over all rows; `None` if any row divides by zero or overflows
+    fn eval(&self, rows: usize) -> Option<Vec<f32>> {
+        match self {
+            ArithExpr::Const(c) => Some(vec![*c; rows]),
+            ArithExpr::Column(values) => Some(values.iter().map(|v| *v as f32).collect()),
+            ArithExpr::Binary(op, lhs, rhs) => {
+                let mut left = lhs.eval(rows)?;
+                let right = rhs.eval(rows)?;
+                match op {
+                    ArithOp::Add => left.iter_mut().zip(&right).for_each(|(l, r)| *l += r),
+                    ArithOp::Sub => left.iter_mut().zip(&right).for_each(|(l, r)| *l -= r),
+                    ArithOp::Mul => left.iter_mut().zip(&right).for_each(|(l, r)| *l *= r),
+                    ArithOp::Div => {
+                        if right.contains(&0.0) {
+                            return None;
+                        }
+                        left.iter_mut().zip(&right).for_each(|(l, r)| *l /= r);
+                    }
+                }
+                if left.iter().all(|v| v.is_finite()) {
+                    Some(left)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Recursive-descent parser for `+ - * /`, parentheses, numbers and columns
+struct ArithParser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    table: &'a Table,
+    rows: usize,
+}
+
+impl<'a> ArithParser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.get(self.pos).copied()
+    }
+
+    fn parse_expr(&mut self) -> Option<ArithExpr<'a>> {
+        let mut lhs = self.parse_term()?;
+        while let Some(c) = self.peek() {
+            let op = match c {
+                '+' => ArithOp::Add,
+                '-' => ArithOp::Sub,
+                _ => break,
+            };
+            self.pos += 1;
+            let rhs = self.parse_term()?;
+            lhs = ArithExpr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Some(lhs)
+    }
+
+    fn parse_term(&mut self) -> Option<ArithExpr<'a>> {
+        let mut lhs = self.parse_factor()?;
+        while let Some(c) = self.peek() {
+            let op = match c {
+                '*' => ArithOp::Mul,
+                '/' => ArithOp::Div,
+                _ => break,
+            };
+            self.pos += 1;
+            let rhs = self.parse_factor()?;
+            lhs = ArithExpr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Some(lhs)
+    }
+
+    fn parse_factor(&mut self) -> Option<ArithExpr<'a>> {
+        let c = self.peek()?;
+        if c == '(' {
+            self.pos += 1;
+            let inner = self.parse_expr()?;
+            if self.peek()? != ')' {
+                return None;
+            }
+            self.pos += 1;
+            return Some(inner);
+        }
+
+        let start = self.pos;
+        if c.is_ascii_digit() || c == '.' {
+            while self
+                .chars
+                .get(self.pos)
+                .is_some_and(|c| c.is_ascii_digit() || *c == '.')
+            {
+                self.pos += 1;
+            }
+            let literal: String = self.chars[start..self.pos].iter().collect();
+            return literal.parse::<f32>().ok().map(ArithExpr::Const);
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            while self
+                .chars
+                .get(self.pos)
+                .is_some_and(|c| c.is_alphanumeric() || *c == '_')
+            {
+                self.pos += 1;
+            }
+            // Function calls and table.column / section.scalar references
+            // need the general path
+            if matches!(self.chars.get(self.pos), Some('(') | Some('.')) {
+                return None;
+            }
+            let name: String = self.chars[start..self.pos].iter().collect();
+            return match self.table.columns.get(&name).map(|c| &c.values) {
+                Some(ColumnValue::Number(values)) if values.len() == self.rows => {
+                    Some(ArithExpr::Column(values))
+                }
+                _ => None,
+            };
+        }
+
+        None
+    }
+}
+
+impl ArrayCalculator {
+    /// Evaluate a pure arithmetic row formula over column slices
+    ///
+    /// Returns `None` when the formula is not plain column arithmetic (or
+    /// would error), in which case the caller uses the general evaluator.
+    pub(super) fn try_fast_arithmetic(&self, table: &Table, formula: &str) -> Option<ColumnValue> {
+        let rows = table.row_count();
+        if rows == 0 {
+            return None;
+        }
+
+        let body = formula.trim().trim_start_matches('=');
+        let mut parser = ArithParser {
+            chars: body.chars().collect(),
+            pos: 0,
+            table,
+            rows,
+        };
+        let expr = parser.parse_expr()?;
+        if parser.peek().is_some() {
+            return None;
+        }
+
+        let values = expr.eval(rows)?;
+        Some(ColumnValue::Number(
+            values
+                .into_iter()
+                .map(|v| (v as f64 * 1e6).round() / 1e6)
+                .collect(),
+        ))
+    }
+}
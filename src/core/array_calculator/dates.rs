@@ -1,8 +1,10 @@
 //! Date Functions (v1.1.0)
-//! TODAY, DATE, YEAR, MONTH, DAY, DATEDIF, EDATE, EOMONTH, NETWORKDAYS, WORKDAY, YEARFRAC
+//! TODAY, DATE, YEAR, MONTH, DAY, DATEDIF, EDATE, EOMONTH, NETWORKDAYS, WORKDAY, YEARFRAC,
+//! WEEKDAY, WEEKNUM, DAYS, DATEVALUE
 
 use crate::error::{ForgeError, ForgeResult};
 use crate::types::{Column, ColumnValue};
+use std::collections::HashSet;
 
 use super::ArrayCalculator;
 
@@ -73,6 +75,35 @@ impl ArrayCalculator {
         Ok(day)
     }
 
+    /// Evaluate DAYS function: DAYS(end_date, start_date)
+    pub(super) fn eval_days(&self, end_date: &str, start_date: &str) -> ForgeResult<f64> {
+        let (end_y, end_m, end_d) = Self::parse_date_ymd(end_date)?;
+        let (start_y, start_m, start_d) = Self::parse_date_ymd(start_date)?;
+
+        let end_days = Self::ymd_to_ordinal(end_y, end_m as i32, end_d as i32);
+        let start_days = Self::ymd_to_ordinal(start_y, start_m as i32, start_d as i32);
+
+        Ok((end_days - start_days) as f64)
+    }
+
+    /// Evaluate DATEVALUE function: DATEVALUE(date_text)
+    /// Validates the text and returns it normalized to YYYY-MM-DD
+    pub(super) fn eval_datevalue(&self, date_text: &str) -> ForgeResult<String> {
+        let invalid = || {
+            ForgeError::Eval(format!(
+                "DATEVALUE: Cannot parse '{}' as a date (expected YYYY-MM-DD)",
+                date_text
+            ))
+        };
+
+        let (year, month, day) = Self::parse_date_ymd(date_text).map_err(|_| invalid())?;
+        if !(1..=12).contains(&month) || day < 1 || day > self.days_in_month(year, month) {
+            return Err(invalid());
+        }
+
+        Ok(format!("{:04}-{:02}-{:02}", year, month, day))
+    }
+
     /// Evaluate DATEDIF function: DATEDIF(start_date, end_date, unit)
     /// unit: "Y" for years, "M" for months, "D" for days
     pub(super) fn eval_datedif(
@@ -421,8 +452,88 @@ impl ArrayCalculator {
         (h + 5) % 7
     }
 
-    /// Calculate number of working days between two dates (excludes weekends)
-    pub(super) fn eval_networkdays(&self, start: &str, end: &str) -> ForgeResult<i32> {
+    /// Evaluate WEEKDAY function: WEEKDAY(date, [return_type])
+    /// return_type 1 (default): Sunday=1..Saturday=7, 2: Monday=1..Sunday=7,
+    /// 3: Monday=0..Sunday=6, 11-17: week starting Monday..Sunday numbered 1-7
+    pub(super) fn eval_weekday(&self, date: &str, return_type: i32) -> ForgeResult<f64> {
+        let (y, m, d) = Self::parse_date_ymd(date)?;
+        let dow = Self::weekday(y, m as i32, d as i32); // 0=Monday, 6=Sunday
+
+        let result = match return_type {
+            1 => (dow + 1) % 7 + 1,
+            2 => dow + 1,
+            3 => dow,
+            11..=17 => (dow - (return_type - 11)).rem_euclid(7) + 1,
+            _ => {
+                return Err(ForgeError::Eval(format!(
+                    "WEEKDAY: Invalid return_type {}. Must be 1, 2, 3, or 11-17",
+                    return_type
+                )))
+            }
+        };
+
+        Ok(result as f64)
+    }
+
+    /// Evaluate WEEKNUM function: WEEKNUM(date, [return_type])
+    /// return_type 1 (default): weeks start Sunday, 2: weeks start Monday,
+    /// 11-17: weeks start Monday..Sunday, 21: ISO 8601 week number
+    pub(super) fn eval_weeknum(&self, date: &str, return_type: i32) -> ForgeResult<f64> {
+        let (y, m, d) = Self::parse_date_ymd(date)?;
+        let day_of_year =
+            Self::ymd_to_ordinal(y, m as i32, d as i32) - Self::ymd_to_ordinal(y, 1, 1);
+
+        // Week start as 0=Monday..6=Sunday
+        let week_start = match return_type {
+            1 => 6,
+            2 => 0,
+            11..=17 => return_type - 11,
+            21 => return Ok(Self::iso_week_number(y, m as i32, d as i32) as f64),
+            _ => {
+                return Err(ForgeError::Eval(format!(
+                    "WEEKNUM: Invalid return_type {}. Must be 1, 2, 11-17, or 21",
+                    return_type
+                )))
+            }
+        };
+
+        // Week 1 is the week containing January 1st
+        let jan1_offset = (Self::weekday(y, 1, 1) - week_start).rem_euclid(7);
+        Ok(((day_of_year + jan1_offset) / 7 + 1) as f64)
+    }
+
+    /// ISO 8601 week number (weeks start Monday, week 1 contains the first Thursday)
+    pub(super) fn iso_week_number(year: i32, month: i32, day: i32) -> i32 {
+        let day_of_year =
+            Self::ymd_to_ordinal(year, month, day) - Self::ymd_to_ordinal(year, 1, 1) + 1;
+        let iso_weekday = Self::weekday(year, month, day) + 1;
+        let week = (day_of_year - iso_weekday + 10) / 7;
+
+        let weeks_in_year = |y: i32| {
+            let jan1 = Self::weekday(y, 1, 1);
+            if jan1 == 3 || (jan1 == 2 && Self::is_leap_year(y)) {
+                53
+            } else {
+                52
+            }
+        };
+
+        if week < 1 {
+            weeks_in_year(year - 1)
+        } else if week > weeks_in_year(year) {
+            1
+        } else {
+            week
+        }
+    }
+
+    /// Calculate number of working days between two dates (excludes weekends and holidays)
+    pub(super) fn eval_networkdays(
+        &self,
+        start: &str,
+        end: &str,
+        holidays: &HashSet<i32>,
+    ) -> ForgeResult<i32> {
         let (start_y, start_m, start_d) = Self::parse_date_ymd(start)?;
         let (end_y, end_m, end_d) = Self::parse_date_ymd(end)?;
 
@@ -440,7 +551,7 @@ impl ArrayCalculator {
         for d in from..=to {
             let (y, m, day) = Self::ordinal_to_ymd(d);
             let dow = Self::weekday(y, m, day);
-            if dow < 5 {
+            if dow < 5 && !holidays.contains(&d) {
                 count += 1;
             }
         }
@@ -448,8 +559,14 @@ impl ArrayCalculator {
         Ok(count * direction)
     }
 
-    /// Calculate date after N working days
-    pub(super) fn eval_workday(&self, start: &str, days: i32) -> ForgeResult<String> {
+    /// Calculate date after N working days (skips weekends and holidays)
+    /// Negative days walk backward from the start date
+    pub(super) fn eval_workday(
+        &self,
+        start: &str,
+        days: i32,
+        holidays: &HashSet<i32>,
+    ) -> ForgeResult<String> {
         let (start_y, start_m, start_d) = Self::parse_date_ymd(start)?;
         let mut current_days = Self::ymd_to_ordinal(start_y, start_m as i32, start_d as i32);
 
@@ -460,7 +577,7 @@ impl ArrayCalculator {
             current_days += direction;
             let (y, m, d) = Self::ordinal_to_ymd(current_days);
             let dow = Self::weekday(y, m, d);
-            if dow < 5 {
+            if dow < 5 && !holidays.contains(&current_days) {
                 remaining -= 1;
             }
         }
@@ -469,6 +586,27 @@ impl ArrayCalculator {
         Ok(format!("{:04}-{:02}-{:02}", y, m, d))
     }
 
+    /// Convert a holidays column (date strings) to a set of day ordinals
+    /// Empty cells are ignored
+    pub(super) fn holiday_ordinals(&self, col: &Column) -> ForgeResult<HashSet<i32>> {
+        let dates = match &col.values {
+            ColumnValue::Date(dates) | ColumnValue::Text(dates) => dates,
+            _ => {
+                return Err(ForgeError::Eval(format!(
+                    "Holidays column '{}' must contain dates",
+                    col.name
+                )))
+            }
+        };
+
+        let mut ordinals = HashSet::new();
+        for date in dates.iter().filter(|d| !d.trim().is_empty()) {
+            let (y, m, d) = Self::parse_date_ymd(date)?;
+            ordinals.insert(Self::ymd_to_ordinal(y, m as i32, d as i32));
+        }
+        Ok(ordinals)
+    }
+
     /// Calculate fraction of year between two dates
     pub(super) fn eval_yearfrac(&self, start: &str, end: &str, basis: i32) -> ForgeResult<f64> {
         let (start_year, start_month, start_day_raw) = Self::parse_date_ymd(start)?;
@@ -0,0 +1,157 @@
+//! Inline array literals in scalar formulas
+//! `=SUM({10, 20, 30})`, `=INDEX({100, 200, 300}, 2)`, `=MATCH("b", {"a", "b"}, 0)`
+//!
+//! Each `{...}` literal, and each column slice (see `slices`), is moved into a
+//! column of a temporary `_ranges` table and replaced by a reference to it, so
+//! aggregations and lookups see an ordinary `table.column` range. The table
+//! only exists while the formula is evaluated, and the parser rejects
+//! `_ranges` as a model name. Elements are numbers or quoted text, separated
+//! by `,` (or `;`); a literal cannot mix the two.
+//!
+//! Row formulas are evaluated one row at a time and have no use for a range,
+//! so literals and slices there are rejected.
+
+use crate::error::{ForgeError, ForgeResult};
+use crate::types::{Column, ColumnValue, Table};
+
+use super::slices::has_column_slice;
+use super::ArrayCalculator;
+
+/// Name of the temporary table holding a formula's literals and slices
+pub(crate) const RANGE_TABLE: &str = "_ranges";
+
+/// Whether a formula has an array literal or column slice
+pub(super) fn has_ranges(formula: &str) -> bool {
+    let mut in_quotes = false;
+    let has_literal = formula.chars().any(|c| {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        c == '{' && !in_quotes
+    });
+    has_literal || has_column_slice(formula)
+}
+
+/// Replace `{...}` literals outside strings with `_ranges.arrayN` references
+///
+/// Returns the rewritten formula and the values of each literal column.
+fn extract_array_literals(formula: &str) -> ForgeResult<(String, Vec<(String, ColumnValue)>)> {
+    let mut rewritten = String::with_capacity(formula.len());
+    let mut columns = Vec::new();
+    let mut chars = formula.chars();
+    let mut in_quotes = false;
+
+    while let Some(c) = chars.next() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        if c != '{' || in_quotes {
+            rewritten.push(c);
+            continue;
+        }
+
+        // Collect up to the closing brace, keeping braces inside strings
+        let mut body = String::new();
+        let mut body_quotes = false;
+        let mut closed = false;
+        for c in chars.by_ref() {
+            if c == '"' {
+                body_quotes = !body_quotes;
+            } else if c == '}' && !body_quotes {
+                closed = true;
+                break;
+            }
+            body.push(c);
+        }
+        if !closed {
+            return Err(ForgeError::Eval(format!(
+                "Unclosed array literal in {}",
+                formula
+            )));
+        }
+
+        let name = format!("array{}", columns.len() + 1);
+        rewritten.push_str(&format!("{}.{}", RANGE_TABLE, name));
+        columns.push((name, parse_array_literal(&body)?));
+    }
+
+    Ok((rewritten, columns))
+}
+
+/// Parse the elements between `{` and `}`
+fn parse_array_literal(body: &str) -> ForgeResult<ColumnValue> {
+    let mut elements = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    for c in body.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(c);
+            }
+            ',' | ';' if !in_quotes => elements.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    elements.push(current);
+
+    let elements: Vec<&str> = elements.iter().map(|e| e.trim()).collect();
+    if elements.iter().all(|e| e.is_empty()) {
+        return Err(ForgeError::Eval("Array literal {} is empty".to_string()));
+    }
+
+    let is_text = |e: &&str| e.len() >= 2 && e.starts_with('"') && e.ends_with('"');
+    if elements.iter().all(is_text) {
+        return Ok(ColumnValue::Text(
+            elements
+                .iter()
+                .map(|e| e[1..e.len() - 1].to_string())
+                .collect(),
+        ));
+    }
+
+    let numbers: Option<Vec<f64>> = elements.iter().map(|e| e.parse::<f64>().ok()).collect();
+    match numbers {
+        Some(numbers) => Ok(ColumnValue::Number(numbers)),
+        None if elements.iter().any(is_text) => Err(ForgeError::Eval(format!(
+            "Array literal {{{}}} mixes numbers and text",
+            body.trim()
+        ))),
+        None => Err(ForgeError::Eval(format!(
+            "Array literal {{{}}} must contain only numbers or quoted text",
+            body.trim()
+        ))),
+    }
+}
+
+impl ArrayCalculator {
+    /// Evaluate a scalar formula, backing its array literals and column
+    /// slices with temporary columns
+    pub(super) fn evaluate_scalar_with_ranges(
+        &mut self,
+        formula: &str,
+        scalar_name: &str,
+    ) -> ForgeResult<f64> {
+        let (rewritten, mut columns) = extract_array_literals(formula)?;
+        let (rewritten, slices) = self.extract_column_slices(&rewritten)?;
+        columns.extend(slices);
+        if columns.is_empty() {
+            return self.evaluate_scalar_formula(formula, scalar_name);
+        }
+        if self.model.tables.contains_key(RANGE_TABLE) {
+            return Err(ForgeError::Eval(format!(
+                "Table name '{}' is reserved for array literals and column slices",
+                RANGE_TABLE
+            )));
+        }
+
+        let mut table = Table::new(RANGE_TABLE.to_string());
+        for (name, values) in columns {
+            table.add_column(Column::new(name, values));
+        }
+        self.model.tables.insert(RANGE_TABLE.to_string(), table);
+        let result = self.evaluate_scalar_formula(&rewritten, scalar_name);
+        self.model.tables.remove(RANGE_TABLE);
+        result
+    }
+}
@@ -1,5 +1,5 @@
 //! Math & Precision Functions (v1.1.0)
-//! ROUND, ROUNDUP, ROUNDDOWN, CEILING, FLOOR, MOD, SQRT, POWER
+//! ROUND, ROUNDUP, ROUNDDOWN, ROUNDBANK, INT, TRUNC, CEILING, FLOOR, MOD, SQRT, POWER
 
 use crate::error::{ForgeError, ForgeResult};
 
@@ -24,6 +24,26 @@ impl ArrayCalculator {
         (value * multiplier).floor() / multiplier
     }
 
+    /// Evaluate ROUNDBANK function: ROUNDBANK(number, digits)
+    /// Banker's rounding - halves go to the even neighbour, so 2.5 -> 2 and 3.5 -> 4
+    pub(super) fn eval_roundbank(&self, value: f64, digits: i32) -> f64 {
+        let multiplier = 10_f64.powi(digits);
+        (value * multiplier).round_ties_even() / multiplier
+    }
+
+    /// Evaluate INT function: INT(number)
+    /// Rounds toward negative infinity, so INT(-2.5) = -3
+    pub(super) fn eval_int(&self, value: f64) -> f64 {
+        value.floor()
+    }
+
+    /// Evaluate TRUNC function: TRUNC(number, [digits])
+    /// Drops digits toward zero, so TRUNC(-2.5) = -2
+    pub(super) fn eval_trunc(&self, value: f64, digits: i32) -> f64 {
+        let multiplier = 10_f64.powi(digits);
+        (value * multiplier).trunc() / multiplier
+    }
+
     /// Evaluate CEILING function: CEILING(number, significance)
     pub(super) fn eval_ceiling(&self, value: f64, significance: f64) -> f64 {
         if significance == 0.0 {
@@ -1,47 +1,861 @@
+mod arithmetic;
 mod dates;
+mod literals;
 mod math;
+mod reshape;
+mod slices;
+mod syntax;
 mod text;
-
-use crate::error::{ForgeError, ForgeResult};
-use crate::types::{Column, ColumnValue, ParsedModel, Table};
-use std::collections::HashSet;
+mod units;
+
+pub(crate) use literals::RANGE_TABLE;
+
+use crate::core::{FunctionKind, FunctionRegistry};
+use crate::error::{unknown_reference, ForgeError, ForgeResult};
+use crate::types::{Column, ColumnValue, ParsedModel, Table, Variable};
+use literals::has_ranges;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use syntax::check_formula_syntax;
 use xlformula_engine::{calculate, parse_formula, types, NoCustomFunction};
 
 /// Array-aware calculator for v1.0.0 models
 /// Handles both row-wise (element-wise) and aggregation formulas
+#[derive(Clone)]
 pub struct ArrayCalculator {
     model: ParsedModel,
+    /// Table/column/scalar evaluation order, resolved once and reused across runs
+    order: OnceLock<CalculationOrder>,
+}
+
+/// Dependency-resolved evaluation order for a model
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalculationOrder {
+    /// Tables in cross-table dependency order
+    pub tables: Vec<String>,
+    /// Tables grouped by dependency depth; tables in one level are independent
+    pub table_levels: Vec<Vec<String>>,
+    /// Formula columns of each table in dependency order
+    pub columns: HashMap<String, Vec<String>>,
+    /// Formula scalars in dependency order
+    pub scalars: Vec<String>,
+    /// Direct dependents of each scalar and `table.column`
+    pub dependents: HashMap<String, Vec<String>>,
+}
+
+impl CalculationOrder {
+    /// Formula columns of a table in dependency order
+    fn formula_order(&self, table_name: &str) -> &[String] {
+        self.columns
+            .get(table_name)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+/// Progress of a calculation, reported after each table and scalar
+#[derive(Debug, Clone, PartialEq)]
+pub struct Progress {
+    /// The table or scalar just calculated
+    pub current: String,
+    /// Tables and scalars calculated so far
+    pub completed: usize,
+    /// Tables and scalars in the whole calculation
+    pub total: usize,
+    /// Percent complete, from 0.0 to 100.0
+    pub percent: f64,
+}
+
+/// Counters and timings from [`ArrayCalculator::calculate_all_with_stats`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CalcStats {
+    /// Formula cells evaluated: rows times formula columns of each table,
+    /// plus one per formula scalar
+    pub cells_evaluated: usize,
+    /// Time spent on each table, in calculation order. With the `parallel`
+    /// feature tables of one dependency level overlap, so these can add up
+    /// to more than `total_time`
+    pub table_times: Vec<(String, Duration)>,
+    /// Formula scalars that aggregate a column (SUM, AVERAGE, SUMIF, ...)
+    pub aggregations: usize,
+    /// Table dependency levels; tables in one level do not read each other
+    pub dependency_levels: usize,
+    /// Time for the whole calculation
+    pub total_time: Duration,
+}
+
+impl Progress {
+    fn new(current: &str, completed: usize, total: usize) -> Self {
+        let percent = if total == 0 {
+            100.0
+        } else {
+            completed as f64 * 100.0 / total as f64
+        };
+        Self {
+            current: current.to_string(),
+            completed,
+            total,
+            percent,
+        }
+    }
+}
+
+/// Every node reachable from `start` through a dependents map, nearest first
+fn transitive_dependents<'a>(
+    dependents: &'a HashMap<String, Vec<String>>,
+    start: &str,
+) -> Vec<&'a str> {
+    let mut seen: HashSet<&str> = HashSet::from([start]);
+    let mut queue = VecDeque::from([start.to_string()]);
+    let mut reached = Vec::new();
+    while let Some(node) = queue.pop_front() {
+        for dependent in dependents.get(&node).into_iter().flatten() {
+            if seen.insert(dependent.as_str()) {
+                reached.push(dependent.as_str());
+                queue.push_back(dependent.clone());
+            }
+        }
+    }
+    reached
+}
+
+/// Names along a dependency cycle through `start`, closed back on its first name
+///
+/// Edges run from a dependency to its dependent, so the cycle is walked
+/// against them: `a → b` means `a`'s formula reads `b`. The shortest cycle is
+/// returned, rotated to begin at its smallest name so the report is stable.
+fn dependency_cycle(
+    graph: &petgraph::graph::DiGraph<String, ()>,
+    start: petgraph::graph::NodeIndex,
+) -> Vec<String> {
+    use petgraph::graph::NodeIndex;
+    use petgraph::Direction;
+    use std::collections::hash_map::Entry;
+
+    // Breadth-first search back to `start`, remembering each node's predecessor
+    let mut previous: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    let mut closing = None;
+    while let Some(node) = queue.pop_front() {
+        for next in graph.neighbors_directed(node, Direction::Incoming) {
+            if next == start {
+                closing = Some(node);
+                break;
+            }
+            if let Entry::Vacant(entry) = previous.entry(next) {
+                entry.insert(node);
+                queue.push_back(next);
+            }
+        }
+        if closing.is_some() {
+            break;
+        }
+    }
+
+    let mut cycle = Vec::new();
+    let mut node = closing.unwrap_or(start);
+    while node != start {
+        cycle.push(graph[node].clone());
+        node = previous[&node];
+    }
+    cycle.push(graph[start].clone());
+    cycle.reverse();
+
+    if let Some(first) = cycle
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.cmp(b.1))
+        .map(|(i, _)| i)
+    {
+        cycle.rotate_left(first);
+    }
+    cycle.push(cycle[0].clone());
+    cycle
+}
+
+/// Names referenced in a formula, skipping function names, numbers and strings
+fn formula_identifiers(formula: &str) -> Vec<String> {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut names = Vec::new();
+    let mut in_string = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' {
+            in_string = !in_string;
+            i += 1;
+        } else if in_string {
+            i += 1;
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while chars
+                .get(i)
+                .is_some_and(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+            {
+                i += 1;
+            }
+            let mut next = i;
+            while chars.get(next).is_some_and(|c| c.is_whitespace()) {
+                next += 1;
+            }
+            if !c.is_ascii_digit() && chars.get(next) != Some(&'(') {
+                names.push(chars[start..i].iter().collect());
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    names
+}
+
+/// Whether `prev` ends with an open `IF(`, so the next operand is its condition
+fn opens_if(prev: &str) -> bool {
+    match prev.strip_suffix('(').map(str::trim_end) {
+        Some(before) => before
+            .len()
+            .checked_sub(2)
+            .and_then(|at| before.get(at..).map(|name| (&before[..at], name)))
+            .is_some_and(|(head, name)| {
+                name.eq_ignore_ascii_case("IF")
+                    && !head.ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '.')
+            }),
+        None => false,
+    }
+}
+
+/// Excel wildcard match: `*` is any run, `?` one character
+///
+/// `~*`, `~?` and `~~` match a literal `*`, `?` and `~`.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    // (char, is_wildcard) tokens, so an escaped `*` is a plain character
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('~', Some(&next)) if matches!(next, '*' | '?' | '~') => {
+                tokens.push((next, false));
+                chars.next();
+            }
+            _ => tokens.push((c, matches!(c, '*' | '?'))),
+        }
+    }
+
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Last `*` seen and the text position it currently stops at
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match tokens.get(p) {
+            Some(&('*', true)) => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&(c, is_wildcard)) if is_wildcard || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    star = Some((star_p, star_t + 1));
+                    p = star_p + 1;
+                    t = star_t + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    tokens[p..].iter().all(|&token| token == ('*', true))
+}
+
+/// Position of `[index]` in a column of `len` rows
+///
+/// Indexes are 0-based; negative indexes count back from the end, so `[-1]`
+/// is the last row.
+fn resolve_index(index_str: &str, len: usize) -> ForgeResult<usize> {
+    let index = index_str
+        .trim()
+        .parse::<i64>()
+        .map_err(|_| ForgeError::Eval(format!("Invalid array index: {}", index_str)))?;
+    let position = if index < 0 {
+        len.checked_sub(index.unsigned_abs() as usize)
+    } else {
+        Some(index as usize).filter(|&i| i < len)
+    };
+    position.ok_or_else(|| {
+        ForgeError::Eval(format!(
+            "Index {} out of bounds: column has {} rows",
+            index, len
+        ))
+    })
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Number of times a dependency order was resolved on this thread
+    static ORDER_BUILDS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    /// Scalar formulas evaluated on this thread, in evaluation order
+    static SCALAR_EVALS: std::cell::RefCell<Vec<String>> =
+        const { std::cell::RefCell::new(Vec::new()) };
 }
 
 impl ArrayCalculator {
+    /// Create a calculator and resolve the model's dependency order
+    ///
+    /// The order depends only on the model's formulas, so it is computed
+    /// here once and shared by every run (including clones). If resolution
+    /// fails, the error is reported by the first calculation.
     pub fn new(model: ParsedModel) -> Self {
-        Self { model }
+        let calculator = Self {
+            model,
+            order: OnceLock::new(),
+        };
+        let _ = calculator.calculation_order();
+        calculator
     }
 
     /// Calculate all formulas in the model
     /// Returns updated model with calculated values
     pub fn calculate_all(mut self) -> ForgeResult<ParsedModel> {
-        // Step 1: Calculate all tables (row-wise formulas) in dependency order
+        let order = self.calculation_order()?.clone();
+        self.evaluate_in_order(&order)?;
+        Ok(self.model)
+    }
+
+    /// Calculate all formulas, reporting progress to `progress`
+    ///
+    /// The callback runs on the calling thread after each table (after each
+    /// dependency level with the `parallel` feature) and each scalar, so it
+    /// should be cheap. The last call reports 100%.
+    pub fn calculate_all_with_progress(
+        &self,
+        progress: impl Fn(Progress),
+    ) -> ForgeResult<ParsedModel> {
+        let order = self.calculation_order()?;
+        let total = order.tables.len() + self.model.table_formulas.len() + order.scalars.len();
+
+        let mut calculator = self.clone();
+        let mut completed = 0;
+        calculator.evaluate_observed(order, &mut |current, _| {
+            completed += 1;
+            progress(Progress::new(current, completed, total));
+            Ok(())
+        })?;
+        if total == 0 {
+            progress(Progress::new("", 0, 0));
+        }
+
+        Ok(calculator.model)
+    }
+
+    /// Calculate all formulas, giving up with [`ForgeError::Timeout`] once
+    /// `limit` has passed
+    ///
+    /// The clock is checked after each table and scalar but the last, so a
+    /// single very large table can overrun the limit by its own calculation
+    /// time, and a run that finished is never reported as timed out.
+    pub fn calculate_all_with_deadline(&self, limit: Duration) -> ForgeResult<ParsedModel> {
+        let deadline = Instant::now() + limit;
+        let order = self.calculation_order()?;
+        let total = order.tables.len() + self.model.table_formulas.len() + order.scalars.len();
+
+        let mut calculator = self.clone();
+        let mut completed = 0;
+        calculator.evaluate_observed(order, &mut |_, _| {
+            completed += 1;
+            if completed < total && Instant::now() >= deadline {
+                return Err(ForgeError::Timeout(limit));
+            }
+            Ok(())
+        })?;
+
+        Ok(calculator.model)
+    }
+
+    /// Calculate all formulas and report what the calculation did
+    pub fn calculate_all_with_stats(&self) -> ForgeResult<(ParsedModel, CalcStats)> {
+        let started = Instant::now();
+        let order = self.calculation_order()?;
+        let table_count = order.tables.len() + self.model.table_formulas.len();
+
+        let mut calculator = self.clone();
+        let mut table_times = Vec::with_capacity(table_count);
+        calculator.evaluate_observed(order, &mut |current, time| {
+            if table_times.len() < table_count {
+                table_times.push((current.to_string(), time));
+            }
+            Ok(())
+        })?;
+
+        let model = calculator.model;
+        let table_cells: usize = order
+            .tables
+            .iter()
+            .map(|name| model.tables[name].row_count() * order.formula_order(name).len())
+            .sum();
+        let reshaped_cells: usize = model
+            .table_formulas
+            .keys()
+            .filter_map(|name| model.tables.get(name))
+            .map(|table| table.row_count() * table.columns.len())
+            .sum();
+        let aggregations = order
+            .scalars
+            .iter()
+            .filter_map(|name| self.model.scalars.get(name)?.formula.as_deref())
+            .filter(|formula| self.is_aggregation_formula(formula))
+            .count();
+
+        let stats = CalcStats {
+            cells_evaluated: table_cells + reshaped_cells + order.scalars.len(),
+            table_times,
+            aggregations,
+            dependency_levels: order.table_levels.len(),
+            total_time: started.elapsed(),
+        };
+        Ok((model, stats))
+    }
+
+    /// Recalculate the model with scalar inputs replaced by `overrides`
+    ///
+    /// The dependency order is resolved on the first call and reused, so
+    /// what-if loops (sensitivity, goal-seek, Monte Carlo) only pay for
+    /// evaluation. Overridden scalars lose their formula; unknown names are
+    /// added as new inputs. The calculator itself is left unchanged.
+    pub fn calculate_with_overrides(
+        &self,
+        overrides: &HashMap<String, f64>,
+    ) -> ForgeResult<ParsedModel> {
+        let order = self.calculation_order()?;
+
+        let mut model = self.model.clone();
+        for (name, value) in overrides {
+            match model.scalars.get_mut(name) {
+                Some(var) => {
+                    var.value = Some(*value);
+                    var.formula = None;
+                }
+                None => {
+                    model.scalars.insert(
+                        name.clone(),
+                        Variable::new(name.clone(), Some(*value), None),
+                    );
+                }
+            }
+        }
+
+        let mut calculator = Self {
+            model,
+            order: OnceLock::new(),
+        };
+        calculator.evaluate_in_order(order)?;
+
+        Ok(calculator.model)
+    }
+
+    /// The calculated model
+    pub fn model(&self) -> &ParsedModel {
+        &self.model
+    }
+
+    /// Calculate all formulas in place, keeping the calculator for reuse
+    ///
+    /// Pair with [`set_scalar_value`](Self::set_scalar_value) and
+    /// [`recalculate_dependents`](Self::recalculate_dependents) to update
+    /// the model after an edit without a full recalculation.
+    pub fn calculate(&mut self) -> ForgeResult<()> {
+        let order = self.calculation_order()?.clone();
+        self.evaluate_in_order(&order)
+    }
+
+    /// Replace the value of an input scalar
+    ///
+    /// Only the value changes; call
+    /// [`recalculate_dependents`](Self::recalculate_dependents) to update
+    /// the formulas that read it.
+    pub fn set_scalar_value(&mut self, name: &str, value: f64) -> ForgeResult<()> {
+        match self.model.scalars.get_mut(name) {
+            Some(var) if var.formula.is_some() => Err(ForgeError::Eval(format!(
+                "Scalar '{}' is calculated by a formula and cannot be set",
+                name
+            ))),
+            Some(var) => {
+                var.value = Some(value);
+                Ok(())
+            }
+            None => Err(ForgeError::Eval(format!("Scalar '{}' not found", name))),
+        }
+    }
+
+    /// Replace the values of a data column
+    ///
+    /// The new values must have the same length as the table's other columns.
+    pub fn set_column_values(
+        &mut self,
+        table_name: &str,
+        col_name: &str,
+        values: ColumnValue,
+    ) -> ForgeResult<()> {
+        let table = match self.model.tables.get_mut(table_name) {
+            Some(table) => table,
+            None => {
+                return Err(ForgeError::Eval(format!(
+                    "Table '{}' not found",
+                    table_name
+                )))
+            }
+        };
+        if table.row_formulas.contains_key(col_name) {
+            return Err(ForgeError::Eval(format!(
+                "Column '{}.{}' is calculated by a formula and cannot be set",
+                table_name, col_name
+            )));
+        }
+        match table.columns.get_mut(col_name) {
+            Some(column) if column.values.len() == values.len() => {
+                column.values = values;
+                Ok(())
+            }
+            Some(column) => Err(ForgeError::Eval(format!(
+                "Column '{}.{}' has {} rows, got {}",
+                table_name,
+                col_name,
+                column.values.len(),
+                values.len()
+            ))),
+            None => Err(ForgeError::Eval(format!(
+                "Column '{}' not found in table '{}'",
+                col_name, table_name
+            ))),
+        }
+    }
+
+    /// Re-evaluate only the formulas that depend on `changed`
+    ///
+    /// `changed` is a scalar name or a `table.column` reference whose value
+    /// was edited. Its transitive dependents are found in the cached
+    /// dependency graph and re-evaluated in the usual order (tables, then
+    /// scalars); everything else keeps its current value. Returns the
+    /// recalculated scalars and `table.column`s in evaluation order.
+    pub fn recalculate_dependents(&mut self, changed: &str) -> ForgeResult<Vec<String>> {
+        if !self.model.scalars.contains_key(changed) && !self.is_table_column_ref(changed) {
+            return Err(ForgeError::Eval(format!(
+                "Unknown scalar or column '{}'",
+                changed
+            )));
+        }
+
+        self.calculation_order()?;
+        // Take the order out while the model is mutated; it depends only on
+        // formulas, which incremental updates never change
+        let order = self.order.take().unwrap();
+        let result = self.evaluate_dependents(&order, changed);
+        self.order = OnceLock::from(order);
+        result
+    }
+
+    fn evaluate_dependents(
+        &mut self,
+        order: &CalculationOrder,
+        changed: &str,
+    ) -> ForgeResult<Vec<String>> {
+        let dirty: HashSet<&str> = transitive_dependents(&order.dependents, changed)
+            .into_iter()
+            .collect();
+
+        let mut recalculated = Vec::new();
+        for table_name in &order.tables {
+            let dirty_columns: Vec<String> = order
+                .formula_order(table_name)
+                .iter()
+                .filter(|col| dirty.contains(format!("{}.{}", table_name, col).as_str()))
+                .cloned()
+                .collect();
+            if dirty_columns.is_empty() {
+                continue;
+            }
+
+            let table = self.model.tables[table_name].clone();
+            let calculated_table = self.calculate_table(table_name, &table, &dirty_columns)?;
+            self.model
+                .tables
+                .insert(table_name.clone(), calculated_table);
+            recalculated.extend(
+                dirty_columns
+                    .iter()
+                    .map(|col| format!("{}.{}", table_name, col)),
+            );
+        }
+
+        let dirty_scalars: Vec<String> = order
+            .scalars
+            .iter()
+            .filter(|name| dirty.contains(name.as_str()))
+            .cloned()
+            .collect();
+        self.calculate_scalars_in_order(&dirty_scalars, &mut |_, _| Ok(()))?;
+        recalculated.extend(dirty_scalars);
+
+        Ok(recalculated)
+    }
+
+    /// The resolved table, column and scalar evaluation order
+    ///
+    /// Computed at most once per calculator; later calls return the cache.
+    pub fn calculation_order(&self) -> ForgeResult<&CalculationOrder> {
+        if let Some(order) = self.order.get() {
+            return Ok(order);
+        }
+
+        #[cfg(test)]
+        ORDER_BUILDS.with(|builds| builds.set(builds.get() + 1));
+
         let table_names: Vec<String> = self.model.tables.keys().cloned().collect();
-        let calc_order = self.get_table_calculation_order(&table_names)?;
+        let tables = self.get_table_calculation_order(&table_names)?;
+
+        // Group tables into levels: a table's level is one past its deepest
+        // dependency, so tables within a level never reference each other
+        let mut level_of: HashMap<&str, usize> = HashMap::new();
+        let mut table_levels: Vec<Vec<String>> = Vec::new();
+        for table_name in &tables {
+            let mut level = 0;
+            for formula in self.model.tables[table_name].row_formulas.values() {
+                for dep in self.extract_table_dependencies_from_formula(formula)? {
+                    if let Some(dep_level) = level_of.get(dep.as_str()) {
+                        level = level.max(dep_level + 1);
+                    }
+                }
+            }
+            level_of.insert(table_name, level);
+            if table_levels.len() <= level {
+                table_levels.resize(level + 1, Vec::new());
+            }
+            table_levels[level].push(table_name.clone());
+        }
+
+        let mut columns = HashMap::new();
+        for table_name in &tables {
+            let table = &self.model.tables[table_name];
+            columns.insert(
+                table_name.clone(),
+                self.get_formula_calculation_order(table)?,
+            );
+        }
+
+        let scalars = self.get_scalar_calculation_order(&self.formula_scalar_names())?;
+        let dependents = self.build_dependents()?;
+
+        Ok(self.order.get_or_init(|| CalculationOrder {
+            tables,
+            table_levels,
+            columns,
+            scalars,
+            dependents,
+        }))
+    }
+
+    /// Map each scalar and `table.column` to the formulas that read it directly
+    fn build_dependents(&self) -> ForgeResult<HashMap<String, Vec<String>>> {
+        let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (table_name, table) in &self.model.tables {
+            for (col_name, formula) in &table.row_formulas {
+                let node = format!("{}.{}", table_name, col_name);
+                for reference in self.extract_column_references(formula)? {
+                    let is_local = !reference.contains('.')
+                        && (table.columns.contains_key(&reference)
+                            || table.row_formulas.contains_key(&reference));
+                    let dep = if is_local {
+                        format!("{}.{}", table_name, reference)
+                    } else if self.model.scalars.contains_key(&reference)
+                        || self.is_table_column_ref(&reference)
+                    {
+                        reference
+                    } else {
+                        continue;
+                    };
+                    if dep != node {
+                        dependents.entry(dep).or_default().push(node.clone());
+                    }
+                }
+            }
+        }
+
+        for (name, var) in &self.model.scalars {
+            if let Some(formula) = &var.formula {
+                let mut deps = self.extract_scalar_dependencies(formula, name)?;
+                for word in formula.split(|c: char| !c.is_alphanumeric() && c != '_' && c != '.') {
+                    if self.is_table_column_ref(word) && !deps.iter().any(|d| d == word) {
+                        deps.push(word.to_string());
+                    }
+                }
+                for dep in deps {
+                    dependents.entry(dep).or_default().push(name.clone());
+                }
+            }
+        }
+
+        for nodes in dependents.values_mut() {
+            nodes.sort();
+            nodes.dedup();
+        }
+        Ok(dependents)
+    }
+
+    /// Direct dependents of every scalar and `table.column`, sorted by name
+    ///
+    /// An entry `a -> [b, c]` means formulas `b` and `c` read `a`. Unlike
+    /// [`calculation_order`](Self::calculation_order), this does not require
+    /// the model to be free of cycles.
+    pub fn dependency_graph(&self) -> ForgeResult<BTreeMap<String, Vec<String>>> {
+        Ok(self.build_dependents()?.into_iter().collect())
+    }
+
+    /// Every scalar and `table.column` that transitively depends on `name`
+    ///
+    /// Nearest dependents come first. Cross-table references are followed, so
+    /// an input column also reaches the scalars that aggregate it.
+    pub fn impact(&self, name: &str) -> ForgeResult<Vec<String>> {
+        if !self.model.scalars.contains_key(name) && !self.is_table_column_ref(name) {
+            let columns: Vec<String> = self
+                .model
+                .tables
+                .iter()
+                .flat_map(|(table_name, table)| {
+                    table
+                        .columns
+                        .keys()
+                        .chain(table.row_formulas.keys())
+                        .map(move |col| format!("{}.{}", table_name, col))
+                })
+                .collect();
+            let known = self.model.scalars.keys().chain(&columns);
+            return Err(unknown_reference(name, known.map(String::as_str)));
+        }
+
+        let dependents = self.build_dependents()?;
+        Ok(transitive_dependents(&dependents, name)
+            .into_iter()
+            .map(str::to_string)
+            .collect())
+    }
 
-        for table_name in calc_order {
-            let table = self.model.tables.get(&table_name).unwrap().clone();
-            let calculated_table = self.calculate_table(&table_name, &table)?;
-            self.model.tables.insert(table_name, calculated_table);
+    /// Whether `reference` names a column or formula column as `table.column`
+    fn is_table_column_ref(&self, reference: &str) -> bool {
+        match reference.split_once('.') {
+            Some((table_name, col_name)) => {
+                self.model.tables.get(table_name).is_some_and(|table| {
+                    table.columns.contains_key(col_name)
+                        || table.row_formulas.contains_key(col_name)
+                })
+            }
+            None => false,
+        }
+    }
+
+    /// Scalar formulas evaluated on the current thread, in evaluation order
+    #[cfg(test)]
+    fn scalar_evals() -> Vec<String> {
+        SCALAR_EVALS.with(|evals| evals.borrow().clone())
+    }
+
+    /// Number of dependency-order resolutions on the current thread
+    #[cfg(test)]
+    fn order_builds() -> usize {
+        ORDER_BUILDS.with(|builds| builds.get())
+    }
+
+    /// Evaluate tables (row-wise formulas) and then scalars in a resolved order
+    fn evaluate_in_order(&mut self, order: &CalculationOrder) -> ForgeResult<()> {
+        self.evaluate_observed(order, &mut |_, _| Ok(()))
+    }
+
+    /// Evaluate in dependency order, calling `step` with the name of each
+    /// table and scalar once it is calculated and the time spent on it; an
+    /// error from `step` stops the run
+    fn evaluate_observed(
+        &mut self,
+        order: &CalculationOrder,
+        step: &mut dyn FnMut(&str, Duration) -> ForgeResult<()>,
+    ) -> ForgeResult<()> {
+        // Step 1: Calculate all tables (row-wise formulas) in dependency order
+        #[cfg(feature = "parallel")]
+        self.evaluate_tables_parallel(order, step)?;
+        #[cfg(not(feature = "parallel"))]
+        self.evaluate_tables_serial(order, step)?;
+        for (name, time) in self.evaluate_table_formulas()? {
+            step(&name, time)?;
         }
 
         // Step 2: Calculate scalar aggregations and formulas
-        self.calculate_scalars()?;
+        self.calculate_scalars_in_order(&order.scalars, step)
+    }
 
-        Ok(self.model)
+    /// Calculate tables one at a time in dependency order
+    #[cfg(any(not(feature = "parallel"), test))]
+    fn evaluate_tables_serial(
+        &mut self,
+        order: &CalculationOrder,
+        step: &mut dyn FnMut(&str, Duration) -> ForgeResult<()>,
+    ) -> ForgeResult<()> {
+        for table_name in &order.tables {
+            let started = Instant::now();
+            let table = self.model.tables.get(table_name).unwrap().clone();
+            let calculated_table =
+                self.calculate_table(table_name, &table, order.formula_order(table_name))?;
+            self.model
+                .tables
+                .insert(table_name.clone(), calculated_table);
+            step(table_name, started.elapsed())?;
+        }
+        Ok(())
+    }
+
+    /// Calculate independent tables of each dependency level concurrently
+    ///
+    /// Results are applied in level order after the whole level finishes, and
+    /// the first error in that order is returned, so output and errors match
+    /// the serial path.
+    #[cfg(feature = "parallel")]
+    fn evaluate_tables_parallel(
+        &mut self,
+        order: &CalculationOrder,
+        step: &mut dyn FnMut(&str, Duration) -> ForgeResult<()>,
+    ) -> ForgeResult<()> {
+        use rayon::prelude::*;
+
+        for level in &order.table_levels {
+            let this = &*self;
+            let results: Vec<(ForgeResult<Table>, Duration)> = level
+                .par_iter()
+                .map(|table_name| {
+                    let started = Instant::now();
+                    let table = &this.model.tables[table_name];
+                    let result =
+                        this.calculate_table(table_name, table, order.formula_order(table_name));
+                    (result, started.elapsed())
+                })
+                .collect();
+
+            let mut times = Vec::with_capacity(level.len());
+            for (table_name, (result, time)) in level.iter().zip(results) {
+                self.model.tables.insert(table_name.clone(), result?);
+                times.push(time);
+            }
+            for (table_name, time) in level.iter().zip(times) {
+                step(table_name, time)?;
+            }
+        }
+        Ok(())
     }
 
     /// Get calculation order for tables (topological sort based on cross-table references)
     fn get_table_calculation_order(&self, table_names: &[String]) -> ForgeResult<Vec<String>> {
         use petgraph::algo::toposort;
         use petgraph::graph::DiGraph;
-        use std::collections::HashMap;
 
         let mut graph = DiGraph::new();
         let mut node_indices = HashMap::new();
@@ -71,10 +885,8 @@ impl ArrayCalculator {
         }
 
         // Topological sort
-        let order = toposort(&graph, None).map_err(|_| {
-            ForgeError::CircularDependency(
-                "Circular dependency detected between tables".to_string(),
-            )
+        let order = toposort(&graph, None).map_err(|cycle| {
+            ForgeError::CircularDependency(dependency_cycle(&graph, cycle.node_id()))
         })?;
 
         let ordered_names: Vec<String> = order
@@ -106,41 +918,139 @@ impl ArrayCalculator {
     }
 
     /// Calculate all formulas in a table
-    fn calculate_table(&mut self, table_name: &str, table: &Table) -> ForgeResult<Table> {
+    fn calculate_table(
+        &self,
+        table_name: &str,
+        table: &Table,
+        formula_order: &[String],
+    ) -> ForgeResult<Table> {
         let mut working_table = table.clone();
 
-        // Build dependency order for formulas
-        let formula_order = self.get_formula_calculation_order(&working_table)?;
-
         // Calculate formulas in dependency order
         for col_name in formula_order {
-            if let Some(formula) = working_table.row_formulas.get(&col_name) {
+            if let Some(formula) = working_table.row_formulas.get(col_name) {
                 let formula = formula.clone();
+                check_formula_syntax(&formula, &format!("{}.{}", table_name, col_name))?;
+
+                if has_ranges(&formula) {
+                    return Err(ForgeError::Eval(format!(
+                        "Table '{}': Column '{}' uses an array literal or column slice - these are only supported in scalar formulas",
+                        table_name, col_name
+                    )));
+                }
 
                 // Determine if this is a row-wise or aggregation formula
-                if self.is_aggregation_formula(&formula) {
+                if self.is_aggregation_formula(&formula) && !self.is_array_result_formula(&formula)
+                {
                     // Aggregation: returns a scalar
                     // For now, we'll skip aggregations in tables (they belong in scalars section)
                     return Err(ForgeError::Eval(format!(
                         "Table '{}': Column '{}' uses aggregation formula - aggregations should be in scalars section",
                         table_name, col_name
                     )));
-                } else {
-                    // Row-wise: returns an array
-                    let result = self.evaluate_rowwise_formula(&working_table, &formula)?;
-                    working_table.add_column(Column::new(col_name.clone(), result));
                 }
+                let result = self.evaluate_column_formula(&working_table, col_name, &formula)?;
+                let metadata = working_table
+                    .formula_metadata
+                    .get(col_name)
+                    .cloned()
+                    .unwrap_or_default();
+                working_table.add_column(Column::with_metadata(col_name.clone(), result, metadata));
             }
         }
 
         Ok(working_table)
     }
 
+    /// Evaluate a non-aggregation formula as a column of `table`
+    fn evaluate_column_formula(
+        &self,
+        table: &Table,
+        col_name: &str,
+        formula: &str,
+    ) -> ForgeResult<ColumnValue> {
+        if self.has_prev_function(formula) {
+            // Sequential: each row can see the previous row's result (PREV)
+            self.evaluate_sequential_formula(table, col_name, formula)
+        } else if self.is_array_result_formula(formula) {
+            // Array-valued: the whole column is produced at once (SORT, RUNSUM, TREND, ...)
+            self.evaluate_array_result_formula(table, formula)
+        } else if let Some(result) = self.try_fast_arithmetic(table, formula) {
+            // Pure column arithmetic: evaluated over whole slices
+            Ok(result)
+        } else {
+            // Row-wise: returns an array
+            self.evaluate_rowwise_formula(table, formula)
+        }
+    }
+
+    /// Evaluate a single formula against the model's current values
+    ///
+    /// Aggregations and scalar expressions return a one-element
+    /// `ColumnValue::Number`. Row-wise formulas are evaluated against the one
+    /// table that has every bare column they reference and return a full
+    /// column. Nothing is recalculated, so formula columns and scalars are
+    /// read as stored.
+    pub fn evaluate_formula(&self, formula: &str) -> ForgeResult<ColumnValue> {
+        let formula = formula.trim();
+        if formula.trim_start_matches('=').trim().is_empty() {
+            return Err(ForgeError::Eval("Formula is empty".to_string()));
+        }
+
+        if !self.is_aggregation_formula(formula) {
+            let local_refs: Vec<String> = self
+                .extract_column_references(formula)?
+                .into_iter()
+                .filter(|r| !r.contains('.'))
+                .collect();
+
+            if !local_refs.is_empty() {
+                let mut candidates: Vec<(&String, &Table)> = self
+                    .model
+                    .tables
+                    .iter()
+                    .filter(|(_, table)| local_refs.iter().all(|r| table.columns.contains_key(r)))
+                    .collect();
+                candidates.sort_by(|a, b| a.0.cmp(b.0));
+
+                match candidates.as_slice() {
+                    [(_, _)] if has_ranges(formula) => {
+                        return Err(ForgeError::Eval(format!(
+                            "Formula '{}' uses an array literal or column slice in a row-wise formula - these are only supported in scalar formulas",
+                            formula
+                        )));
+                    }
+                    [(_, table)] => {
+                        return self.evaluate_column_formula(table, "_formula", formula);
+                    }
+                    [] => {}
+                    _ => {
+                        let names: Vec<&str> =
+                            candidates.iter().map(|(name, _)| name.as_str()).collect();
+                        return Err(ForgeError::Eval(format!(
+                            "Formula '{}' is ambiguous: columns found in tables {}",
+                            formula,
+                            names.join(", ")
+                        )));
+                    }
+                }
+            }
+        }
+
+        let value = if has_ranges(formula) {
+            // The temporary range table needs a mutable model
+            self.clone()
+                .evaluate_scalar_with_ranges(formula, "_formula")?
+        } else {
+            self.evaluate_scalar_formula(formula, "_formula")?
+        };
+        Ok(ColumnValue::Number(vec![value]))
+    }
+
     /// Get the order in which formulas should be calculated (dependency order)
     fn get_formula_calculation_order(&self, table: &Table) -> ForgeResult<Vec<String>> {
         use petgraph::algo::toposort;
         use petgraph::graph::DiGraph;
-        use std::collections::HashMap;
 
         let mut graph = DiGraph::new();
         let mut node_indices = HashMap::new();
@@ -155,6 +1065,10 @@ impl ArrayCalculator {
         for (col_name, formula) in &table.row_formulas {
             let deps = self.extract_column_references(formula)?;
             for dep in deps {
+                // PREV(own_column) reads the previous row, not a true cycle
+                if dep == *col_name && self.has_prev_function(formula) {
+                    continue;
+                }
                 // Only add dependency if it's another formula column
                 if let Some(&dep_idx) = node_indices.get(&dep) {
                     if let Some(&col_idx) = node_indices.get(col_name) {
@@ -165,10 +1079,12 @@ impl ArrayCalculator {
         }
 
         // Topological sort
-        let order = toposort(&graph, None).map_err(|_| {
-            ForgeError::CircularDependency(
-                "Circular dependency detected in table formulas".to_string(),
-            )
+        let order = toposort(&graph, None).map_err(|cycle| {
+            let path = dependency_cycle(&graph, cycle.node_id())
+                .into_iter()
+                .map(|col_name| format!("{}.{}", table.name, col_name))
+                .collect();
+            ForgeError::CircularDependency(path)
         })?;
 
         let ordered_names: Vec<String> = order
@@ -181,32 +1097,9 @@ impl ArrayCalculator {
 
     /// Check if a formula is an aggregation (returns scalar)
     fn is_aggregation_formula(&self, formula: &str) -> bool {
-        let upper = formula.to_uppercase();
-        upper.contains("SUM(")
-            || upper.contains("AVERAGE(")
-            || upper.contains("AVG(")
-            || upper.contains("MAX(")
-            || upper.contains("MIN(")
-            || upper.contains("COUNT(")
-            || upper.contains("SUMIF(")
-            || upper.contains("COUNTIF(")
-            || upper.contains("AVERAGEIF(")
-            || upper.contains("SUMIFS(")
-            || upper.contains("COUNTIFS(")
-            || upper.contains("AVERAGEIFS(")
-            || upper.contains("MAXIFS(")
-            || upper.contains("MINIFS(")
-            // Statistical functions (v5.0.0)
-            || upper.contains("MEDIAN(")
-            || upper.contains("VAR(")
-            || upper.contains("VAR.S(")
-            || upper.contains("VAR.P(")
-            || upper.contains("STDEV(")
-            || upper.contains("STDEV.S(")
-            || upper.contains("STDEV.P(")
-            || upper.contains("PERCENTILE(")
-            || upper.contains("QUARTILE(")
-            || upper.contains("CORREL(")
+        FunctionRegistry::calls(formula)
+            .iter()
+            .any(|f| f.kind == FunctionKind::Aggregate)
     }
 
     /// Check if formula contains custom math functions that need special handling
@@ -215,6 +1108,9 @@ impl ArrayCalculator {
         upper.contains("ROUND(")
             || upper.contains("ROUNDUP(")
             || upper.contains("ROUNDDOWN(")
+            || upper.contains("ROUNDBANK(")
+            || regex::Regex::new(r"\bINT\(").unwrap().is_match(&upper)
+            || upper.contains("TRUNC(")
             || upper.contains("CEILING(")
             || upper.contains("FLOOR(")
             || upper.contains("MOD(")
@@ -227,11 +1123,20 @@ impl ArrayCalculator {
         let upper = formula.to_uppercase();
         upper.contains("CONCAT(")
             || upper.contains("CONCATENATE(")
+            || upper.contains("TEXTJOIN(")
             || upper.contains("TRIM(")
             || upper.contains("UPPER(")
             || upper.contains("LOWER(")
             || upper.contains("LEN(")
             || upper.contains("MID(")
+            || upper.contains("TEXT(")
+            || upper.contains("SUBSTITUTE(")
+            || upper.contains("REPLACE(")
+            || upper.contains("FIND(")
+            || upper.contains("SEARCH(")
+            || upper.contains("ISBLANK(")
+            || upper.contains("ISNUMBER(")
+            || upper.contains("ISTEXT(")
     }
 
     /// Check if formula contains custom date functions that need special handling
@@ -249,6 +1154,19 @@ impl ArrayCalculator {
             || upper.contains("NETWORKDAYS(")
             || upper.contains("WORKDAY(")
             || upper.contains("YEARFRAC(")
+            || upper.contains("WEEKDAY(")
+            || upper.contains("WEEKNUM(")
+            || upper.contains("DAYS(")
+            || upper.contains("DATEVALUE(")
+    }
+
+    /// Holidays lists passed to NETWORKDAYS or WORKDAY in a formula
+    fn holidays_arguments(&self, formula: &str) -> Vec<String> {
+        let re =
+            regex::Regex::new(r"(?i)(?:NETWORKDAYS|WORKDAY)\([^,)]+,[^,)]+,([^)]+)\)").unwrap();
+        re.captures_iter(formula)
+            .map(|cap| cap[1].trim().to_string())
+            .collect()
     }
 
     /// Check if formula contains Forge-native FP&A functions (v5.0.0)
@@ -295,6 +1213,12 @@ impl ArrayCalculator {
             || upper.contains("SLN(")
             || upper.contains("DB(")
             || upper.contains("DDB(")
+            || upper.contains("CUMIPMT(")
+            || upper.contains("CUMPRINC(")
+            || upper.contains("IPMT(")
+            || upper.contains("PPMT(")
+            || upper.contains("EFFECT(")
+            || upper.contains("NOMINAL(")
     }
 
     /// Check if formula contains array functions that need special handling (v4.1.0)
@@ -306,12 +1230,31 @@ impl ArrayCalculator {
             || upper.contains("SORT(")
     }
 
+    /// Check if formula contains error-handling wrappers (IFERROR, IFNA)
+    fn has_error_handler_function(&self, formula: &str) -> bool {
+        let upper = formula.to_uppercase();
+        upper.contains("IFERROR(") || upper.contains("IFNA(")
+    }
+
+    /// Check if formula references the previous row via PREV
+    fn has_prev_function(&self, formula: &str) -> bool {
+        formula.to_uppercase().contains("PREV(")
+    }
+
+    /// Check if formula contains IFS condition chains
+    fn has_ifs_function(&self, formula: &str) -> bool {
+        formula.to_uppercase().contains("IFS(")
+    }
+
     /// Check if formula contains math functions that need special handling (v4.4.1)
     fn has_math_function(&self, formula: &str) -> bool {
         let upper = formula.to_uppercase();
         upper.contains("ROUND(")
             || upper.contains("ROUNDUP(")
             || upper.contains("ROUNDDOWN(")
+            || upper.contains("ROUNDBANK(")
+            || regex::Regex::new(r"\bINT\(").unwrap().is_match(&upper)
+            || upper.contains("TRUNC(")
             || upper.contains("SQRT(")
             || upper.contains("POWER(")
             || upper.contains("MOD(")
@@ -322,11 +1265,7 @@ impl ArrayCalculator {
     /// Evaluate a row-wise formula (element-wise operations)
     /// Example: profit = revenue - expenses
     /// Evaluates: profit[i] = revenue[i] - expenses[i] for all i
-    fn evaluate_rowwise_formula(
-        &mut self,
-        table: &Table,
-        formula: &str,
-    ) -> ForgeResult<ColumnValue> {
+    fn evaluate_rowwise_formula(&self, table: &Table, formula: &str) -> ForgeResult<ColumnValue> {
         let formula_str = if !formula.starts_with('=') {
             format!("={}", formula.trim())
         } else {
@@ -348,9 +1287,12 @@ impl ArrayCalculator {
         } else {
             self.extract_column_references(formula)?
         };
+        // Holiday lists must exist but may have any length
+        let holidays = self.holidays_arguments(&formula_str);
 
         // Validate all columns exist and have correct length
         for col_ref in &col_refs {
+            let any_length = holidays.contains(col_ref);
             // Check if this is a cross-table reference (table.column format) or scalar reference
             if col_ref.contains('.') {
                 // First check if it's a scalar reference (v4.3.0 fix)
@@ -367,7 +1309,7 @@ impl ArrayCalculator {
 
                     if let Some(ref_table) = self.model.tables.get(ref_table_name) {
                         if let Some(ref_col) = ref_table.columns.get(ref_col_name) {
-                            if ref_col.values.len() != row_count {
+                            if ref_col.values.len() != row_count && !any_length {
                                 return Err(ForgeError::Eval(format!(
                                     "Column '{}.{}' has {} rows, expected {}",
                                     ref_table_name,
@@ -396,7 +1338,7 @@ impl ArrayCalculator {
                 }
             } else if let Some(col) = table.columns.get(col_ref) {
                 // Local column reference
-                if col.values.len() != row_count {
+                if col.values.len() != row_count && !any_length {
                     return Err(ForgeError::Eval(format!(
                         "Column '{}' has {} rows, expected {}",
                         col_ref,
@@ -405,13 +1347,25 @@ impl ArrayCalculator {
                     )));
                 }
             } else {
-                return Err(ForgeError::Eval(format!(
-                    "Column '{}' not found in table",
-                    col_ref
-                )));
+                let known = table
+                    .columns
+                    .keys()
+                    .chain(table.row_formulas.keys())
+                    .chain(self.model.scalars.keys())
+                    .map(String::as_str);
+                return Err(unknown_reference(col_ref, known));
             }
         }
 
+        // A bare comparison (=revenue > expenses) produces a Boolean column.
+        // The engine only evaluates comparisons reliably inside IF, so route it through one.
+        let is_comparison = self.is_comparison_formula(&formula_str);
+        let eval_formula = if is_comparison {
+            format!("=IF(({}), 1, 0)", formula_str.trim_start_matches('='))
+        } else {
+            formula_str.clone()
+        };
+
         // Evaluate formula for each row
         let mut number_results = Vec::new();
         let mut text_results = Vec::new();
@@ -421,10 +1375,13 @@ impl ArrayCalculator {
         for row_idx in 0..row_count {
             // Preprocess formula to replace scalar references with their values (v4.3.0 fix)
             // This handles references like thresholds.min_value before xlformula_engine parsing
-            let formula_with_scalars = self.preprocess_scalar_refs_for_table(&formula_str)?;
+            let row_formula = self.coerce_booleans(&eval_formula, Some((row_idx, table)));
+            let formula_with_scalars = self.preprocess_scalar_refs_for_table(&row_formula)?;
 
             // Preprocess formula for custom functions
-            let processed_formula = if self.has_custom_math_function(&formula_with_scalars)
+            let processed_formula = if self.has_error_handler_function(&formula_with_scalars)
+                || self.has_ifs_function(&formula_with_scalars)
+                || self.has_custom_math_function(&formula_with_scalars)
                 || self.has_custom_text_function(&formula_with_scalars)
                 || self.has_custom_date_function(&formula_with_scalars)
                 || self.has_lookup_function(&formula_with_scalars)
@@ -437,97 +1394,7 @@ impl ArrayCalculator {
 
             // Create a resolver for this specific row
             let resolver = |var_name: String| -> types::Value {
-                // Check if this is a cross-table reference (table.column format)
-                if var_name.contains('.') {
-                    let parts: Vec<&str> = var_name.split('.').collect();
-                    if parts.len() == 2 {
-                        let ref_table_name = parts[0];
-                        let ref_col_name = parts[1];
-
-                        // Try as table.column reference first
-                        if let Some(ref_table) = self.model.tables.get(ref_table_name) {
-                            if let Some(ref_col) = ref_table.columns.get(ref_col_name) {
-                                match &ref_col.values {
-                                    ColumnValue::Number(nums) => {
-                                        if let Some(&val) = nums.get(row_idx) {
-                                            return types::Value::Number(val as f32);
-                                        }
-                                    }
-                                    ColumnValue::Text(texts) => {
-                                        if let Some(text) = texts.get(row_idx) {
-                                            return types::Value::Text(text.clone());
-                                        }
-                                    }
-                                    ColumnValue::Boolean(bools) => {
-                                        if let Some(&val) = bools.get(row_idx) {
-                                            return types::Value::Boolean(if val {
-                                                types::Boolean::True
-                                            } else {
-                                                types::Boolean::False
-                                            });
-                                        }
-                                    }
-                                    ColumnValue::Date(dates) => {
-                                        if let Some(date) = dates.get(row_idx) {
-                                            return types::Value::Text(date.clone());
-                                        }
-                                    }
-                                }
-                            }
-                        }
-
-                        // Try as section.scalar reference (v4.3.0 fix)
-                        // This allows table formulas to reference scalars like thresholds.min_value
-                        if let Some(scalar) = self.model.scalars.get(&var_name) {
-                            if let Some(value) = scalar.value {
-                                return types::Value::Number(value as f32);
-                            }
-                        }
-                    }
-                    return types::Value::Error(types::Error::Value);
-                }
-
-                // Local column reference
-                if let Some(col) = table.columns.get(&var_name) {
-                    // Get the value at this row index
-                    match &col.values {
-                        ColumnValue::Number(nums) => {
-                            if let Some(&val) = nums.get(row_idx) {
-                                return types::Value::Number(val as f32);
-                            }
-                        }
-                        ColumnValue::Text(texts) => {
-                            if let Some(text) = texts.get(row_idx) {
-                                return types::Value::Text(text.clone());
-                            }
-                        }
-                        ColumnValue::Boolean(bools) => {
-                            if let Some(&val) = bools.get(row_idx) {
-                                return types::Value::Boolean(if val {
-                                    types::Boolean::True
-                                } else {
-                                    types::Boolean::False
-                                });
-                            }
-                        }
-                        ColumnValue::Date(dates) => {
-                            if let Some(date) = dates.get(row_idx) {
-                                // For dates, return as text (ISO format)
-                                return types::Value::Text(date.clone());
-                            }
-                        }
-                    }
-                }
-
-                // Scalar variable reference (v4.3.0 fix)
-                // Check if this is a scalar variable (for formulas like =IF(remaining_quota > 0, 1, 0))
-                if let Some(scalar) = self.model.scalars.get(&var_name) {
-                    if let Some(value) = scalar.value {
-                        return types::Value::Number(value as f32);
-                    }
-                }
-
-                types::Value::Error(types::Error::Reference)
+                self.resolve_row_variable(&var_name, row_idx, table)
             };
 
             // Parse and calculate for this row
@@ -541,6 +1408,14 @@ impl ArrayCalculator {
                 types::Value::Number(n) => {
                     let value = n as f64;
                     let rounded = (value * 1e6).round() / 1e6;
+                    // Rows so far were Boolean: the column is numeric, TRUE counts as 1
+                    if result_type == Some("boolean") {
+                        number_results = bool_results
+                            .drain(..)
+                            .map(|b| if b { 1.0 } else { 0.0 })
+                            .collect();
+                        result_type = Some("number");
+                    }
                     number_results.push(rounded);
                     if result_type.is_none() {
                         result_type = Some("number");
@@ -557,7 +1432,11 @@ impl ArrayCalculator {
                         types::Boolean::True => true,
                         types::Boolean::False => false,
                     };
-                    bool_results.push(bool_val);
+                    if result_type == Some("number") {
+                        number_results.push(if bool_val { 1.0 } else { 0.0 });
+                    } else {
+                        bool_results.push(bool_val);
+                    }
                     if result_type.is_none() {
                         result_type = Some("boolean");
                     }
@@ -579,7 +1458,20 @@ impl ArrayCalculator {
 
         // Return the appropriate column type based on results
         match result_type {
+            Some("number") if is_comparison => Ok(ColumnValue::Boolean(
+                number_results.iter().map(|&n| n != 0.0).collect(),
+            )),
             Some("number") => Ok(ColumnValue::Number(number_results)),
+            // DATEVALUE converts text into a Date column
+            Some("text")
+                if formula_str
+                    .trim_start_matches('=')
+                    .trim()
+                    .to_uppercase()
+                    .starts_with("DATEVALUE(") =>
+            {
+                Ok(ColumnValue::Date(text_results))
+            }
             Some("text") => Ok(ColumnValue::Text(text_results)),
             Some("boolean") => Ok(ColumnValue::Boolean(bool_results)),
             _ => Err(ForgeError::Eval(
@@ -588,37 +1480,270 @@ impl ArrayCalculator {
         }
     }
 
-    /// Calculate scalar values and aggregations
-    /// Returns updated model with calculated scalars
-    fn calculate_scalars(&mut self) -> ForgeResult<()> {
-        // Get all scalar variable names that have formulas
-        let scalar_names: Vec<String> = self
-            .model
+    /// Check if the top level of a formula is a comparison (>, <, >=, <=, =, <>)
+    /// Comparisons nested inside function calls or parentheses don't count
+    fn is_comparison_formula(&self, formula: &str) -> bool {
+        let mut depth = 0;
+        let mut in_quotes = false;
+
+        for c in formula.trim().trim_start_matches('=').chars() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                '(' if !in_quotes => depth += 1,
+                ')' if !in_quotes => depth -= 1,
+                '>' | '<' | '=' if !in_quotes && depth == 0 => return true,
+                _ => {}
+            }
+        }
+
+        false
+    }
+
+    /// Rewrite Boolean operands into forms the engine evaluates reliably
+    ///
+    /// TRUE/FALSE literals, and in a row context the row's value of a Boolean
+    /// column, become `1`/`0` next to an arithmetic operator and `(1=1)`/`(1=0)`
+    /// when they are the whole condition of an IF.
+    fn coerce_booleans(&self, formula: &str, row: Option<(usize, &Table)>) -> String {
+        // Column references inside other functions (SUMPRODUCT, INDEX, ...) mean
+        // the whole column, so only rewrite them under these
+        const ROW_FUNCTIONS: &[&str] = &[
+            "",
+            "IF",
+            "IFS",
+            "AND",
+            "OR",
+            "NOT",
+            "ABS",
+            "ROUND",
+            "ROUNDUP",
+            "ROUNDDOWN",
+            "ROUNDBANK",
+            "INT",
+            "TRUNC",
+        ];
+        let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '.';
+
+        let chars: Vec<char> = formula.chars().collect();
+        let mut out = String::with_capacity(formula.len());
+        let mut calls: Vec<String> = Vec::new();
+        let mut in_string = false;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            if c == '"' {
+                in_string = !in_string;
+            }
+            if in_string || !is_word(c) {
+                if !in_string && c == '(' {
+                    let name: String = out
+                        .trim_end()
+                        .chars()
+                        .rev()
+                        .take_while(|c| is_word(*c))
+                        .collect();
+                    calls.push(name.chars().rev().collect::<String>().to_uppercase());
+                } else if !in_string && c == ')' {
+                    calls.pop();
+                }
+                out.push(c);
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            while chars.get(i).is_some_and(|c| is_word(*c)) {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let next = chars[i..].iter().find(|c| !c.is_whitespace()).copied();
+
+            let value = if c.is_ascii_digit() || next == Some('(') {
+                None
+            } else if word.eq_ignore_ascii_case("TRUE") {
+                Some(true)
+            } else if word.eq_ignore_ascii_case("FALSE") {
+                Some(false)
+            } else {
+                match row {
+                    Some((row_idx, table))
+                        if calls.iter().all(|f| ROW_FUNCTIONS.contains(&f.as_str())) =>
+                    {
+                        self.row_boolean(&word, row_idx, table)
+                    }
+                    _ => None,
+                }
+            };
+
+            let prev = out.trim_end();
+            let replacement = match value {
+                Some(b) if next == Some(',') && opens_if(prev) => {
+                    Some(if b { "(1=1)" } else { "(1=0)" })
+                }
+                Some(b)
+                    if prev.ends_with(['+', '-', '*', '/', '^'])
+                        || next.is_some_and(|c| "+-*/^%".contains(c)) =>
+                {
+                    Some(if b { "1" } else { "0" })
+                }
+                _ => None,
+            };
+            out.push_str(replacement.unwrap_or(&word));
+        }
+
+        out
+    }
+
+    /// The row's value of a Boolean column (`column` or `table.column`)
+    fn row_boolean(&self, name: &str, row_idx: usize, table: &Table) -> Option<bool> {
+        let column = match name.split_once('.') {
+            Some((table_name, col_name)) => {
+                self.model.tables.get(table_name)?.columns.get(col_name)?
+            }
+            None => table.columns.get(name)?,
+        };
+        match &column.values {
+            ColumnValue::Boolean(bools) => bools.get(row_idx).copied(),
+            _ => None,
+        }
+    }
+
+    /// Resolve a variable reference for a single row of a row-wise formula
+    /// Looks up local columns, cross-table columns (table.column), and scalars
+    fn resolve_row_variable(&self, var_name: &str, row_idx: usize, table: &Table) -> types::Value {
+        // Check if this is a cross-table reference (table.column format)
+        if var_name.contains('.') {
+            let parts: Vec<&str> = var_name.split('.').collect();
+            if parts.len() == 2 {
+                let ref_table_name = parts[0];
+                let ref_col_name = parts[1];
+
+                // Try as table.column reference first
+                if let Some(ref_table) = self.model.tables.get(ref_table_name) {
+                    if let Some(ref_col) = ref_table.columns.get(ref_col_name) {
+                        match &ref_col.values {
+                            ColumnValue::Number(nums) => {
+                                if let Some(&val) = nums.get(row_idx) {
+                                    return types::Value::Number(val as f32);
+                                }
+                            }
+                            ColumnValue::Text(texts) => {
+                                if let Some(text) = texts.get(row_idx) {
+                                    return types::Value::Text(text.clone());
+                                }
+                            }
+                            ColumnValue::Boolean(bools) => {
+                                if let Some(&val) = bools.get(row_idx) {
+                                    return types::Value::Boolean(if val {
+                                        types::Boolean::True
+                                    } else {
+                                        types::Boolean::False
+                                    });
+                                }
+                            }
+                            ColumnValue::Date(dates) => {
+                                if let Some(date) = dates.get(row_idx) {
+                                    return types::Value::Text(date.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Try as section.scalar reference (v4.3.0 fix)
+                // This allows table formulas to reference scalars like thresholds.min_value
+                if let Some(scalar) = self.model.scalars.get(var_name) {
+                    if let Some(value) = scalar.value {
+                        return types::Value::Number(value as f32);
+                    }
+                }
+            }
+            return types::Value::Error(types::Error::Value);
+        }
+
+        // Local column reference
+        if let Some(col) = table.columns.get(var_name) {
+            // Get the value at this row index
+            match &col.values {
+                ColumnValue::Number(nums) => {
+                    if let Some(&val) = nums.get(row_idx) {
+                        return types::Value::Number(val as f32);
+                    }
+                }
+                ColumnValue::Text(texts) => {
+                    if let Some(text) = texts.get(row_idx) {
+                        return types::Value::Text(text.clone());
+                    }
+                }
+                ColumnValue::Boolean(bools) => {
+                    if let Some(&val) = bools.get(row_idx) {
+                        return types::Value::Boolean(if val {
+                            types::Boolean::True
+                        } else {
+                            types::Boolean::False
+                        });
+                    }
+                }
+                ColumnValue::Date(dates) => {
+                    if let Some(date) = dates.get(row_idx) {
+                        // For dates, return as text (ISO format)
+                        return types::Value::Text(date.clone());
+                    }
+                }
+            }
+        }
+
+        // Scalar variable reference (v4.3.0 fix)
+        // Check if this is a scalar variable (for formulas like =IF(remaining_quota > 0, 1, 0))
+        if let Some(scalar) = self.model.scalars.get(var_name) {
+            if let Some(value) = scalar.value {
+                return types::Value::Number(value as f32);
+            }
+        }
+
+        types::Value::Error(types::Error::Reference)
+    }
+
+    /// Get all scalar variable names that have formulas
+    fn formula_scalar_names(&self) -> Vec<String> {
+        self.model
             .scalars
             .iter()
             .filter(|(_, var)| var.formula.is_some())
             .map(|(name, _)| name.clone())
-            .collect();
-
-        // Build dependency graph and calculate in order
-        let calc_order = self.get_scalar_calculation_order(&scalar_names)?;
+            .collect()
+    }
 
+    /// Calculate scalars in a precomputed dependency order
+    fn calculate_scalars_in_order(
+        &mut self,
+        calc_order: &[String],
+        step: &mut dyn FnMut(&str, Duration) -> ForgeResult<()>,
+    ) -> ForgeResult<()> {
         // Calculate each scalar in dependency order
         for scalar_name in calc_order {
+            let started = Instant::now();
             let formula = self
                 .model
                 .scalars
-                .get(&scalar_name)
+                .get(scalar_name)
                 .and_then(|v| v.formula.clone());
 
             if let Some(formula) = formula {
-                let value = self.evaluate_scalar_formula(&formula, &scalar_name)?;
+                check_formula_syntax(&formula, scalar_name)?;
+
+                #[cfg(test)]
+                SCALAR_EVALS.with(|evals| evals.borrow_mut().push(scalar_name.clone()));
+
+                let value = self.evaluate_scalar_with_ranges(&formula, scalar_name)?;
 
                 // Update the scalar with calculated value
-                if let Some(var) = self.model.scalars.get_mut(&scalar_name) {
+                if let Some(var) = self.model.scalars.get_mut(scalar_name) {
                     var.value = Some(value);
                 }
             }
+            step(scalar_name, started.elapsed())?;
         }
 
         Ok(())
@@ -628,7 +1753,6 @@ impl ArrayCalculator {
     fn get_scalar_calculation_order(&self, scalar_names: &[String]) -> ForgeResult<Vec<String>> {
         use petgraph::algo::toposort;
         use petgraph::graph::DiGraph;
-        use std::collections::HashMap;
 
         let mut graph = DiGraph::new();
         let mut node_indices = HashMap::new();
@@ -657,10 +1781,8 @@ impl ArrayCalculator {
         }
 
         // Topological sort
-        let order = toposort(&graph, None).map_err(|_| {
-            ForgeError::CircularDependency(
-                "Circular dependency detected in scalar formulas".to_string(),
-            )
+        let order = toposort(&graph, None).map_err(|cycle| {
+            ForgeError::CircularDependency(dependency_cycle(&graph, cycle.node_id()))
         })?;
 
         let ordered_names: Vec<String> = order
@@ -727,6 +1849,12 @@ impl ArrayCalculator {
             formula.to_string()
         };
 
+        // Error handlers (IFERROR, IFNA) evaluate their first argument in a catch
+        // context, so they must be resolved before any other dispatch
+        if self.has_error_handler_function(&formula_str) {
+            return self.evaluate_error_handler_formula(&formula_str, scalar_name);
+        }
+
         // Check if this formula contains lookup functions that need preprocessing
         // This includes OFFSET which must be resolved before aggregation
         if self.has_lookup_function(&formula_str) {
@@ -781,6 +1909,43 @@ impl ArrayCalculator {
         }
     }
 
+    /// Evaluate a formula containing IFERROR/IFNA (for scalar context)
+    /// Each wrapped expression is evaluated on its own; failures are replaced by the fallback
+    fn evaluate_error_handler_formula(&self, formula: &str, scalar_name: &str) -> ForgeResult<f64> {
+        let mut result = formula.to_string();
+
+        while let Some((func_name, start, end)) =
+            self.find_function_call(&result, &["IFERROR", "IFNA"])
+        {
+            let args_str = &result[start + func_name.len() + 1..end - 1];
+            let args = self.parse_function_args(args_str)?;
+            if args.len() != 2 {
+                return Err(ForgeError::Eval(format!(
+                    "{} requires exactly 2 arguments: value, value_if_error",
+                    func_name
+                )));
+            }
+
+            let value = match self.evaluate_scalar_formula(&format!("={}", args[0]), scalar_name) {
+                Ok(value) => value,
+                Err(e) if func_name == "IFERROR" || Self::is_not_found_error(&e) => {
+                    self.evaluate_scalar_formula(&format!("={}", args[1]), scalar_name)?
+                }
+                Err(e) => return Err(e),
+            };
+
+            result = format!("{}{}{}", &result[..start], value, &result[end..]);
+        }
+
+        // If the result is just a number, parse it directly
+        let trimmed = result.trim().trim_start_matches('=');
+        if let Ok(value) = trimmed.parse::<f64>() {
+            return Ok(value);
+        }
+
+        self.evaluate_scalar_formula(&result, scalar_name)
+    }
+
     /// Evaluate a formula containing financial functions
     fn evaluate_financial_formula(&self, formula: &str, scalar_name: &str) -> ForgeResult<f64> {
         // First resolve all scalar references to their values
@@ -1298,6 +2463,9 @@ impl ArrayCalculator {
                     | "ROUND"
                     | "ROUNDUP"
                     | "ROUNDDOWN"
+                    | "ROUNDBANK"
+                    | "INT"
+                    | "TRUNC"
                     | "ABS"
                     | "SQRT"
                     | "POWER"
@@ -1341,7 +2509,7 @@ impl ArrayCalculator {
 
         // Pattern: table_name.column_name[index]
         // Match word characters (including _), a dot, more word characters, then [number]
-        let re = Regex::new(r"(\w+)\.(\w+)\[(\d+)\]")
+        let re = Regex::new(r"(\w+)\.(\w+)\[(-?\d+)\]")
             .map_err(|e| ForgeError::Eval(format!("Regex error: {}", e)))?;
 
         let mut result = formula.to_string();
@@ -1354,10 +2522,6 @@ impl ArrayCalculator {
             let col_name = cap.get(2).unwrap().as_str();
             let index_str = cap.get(3).unwrap().as_str();
 
-            let index = index_str
-                .parse::<usize>()
-                .map_err(|_| ForgeError::Eval(format!("Invalid index: {}", index_str)))?;
-
             // Get the actual value
             let table = self
                 .model
@@ -1373,10 +2537,7 @@ impl ArrayCalculator {
             })?;
 
             let value = match &column.values {
-                ColumnValue::Number(nums) => nums
-                    .get(index)
-                    .copied()
-                    .ok_or_else(|| ForgeError::Eval(format!("Index {} out of bounds", index)))?,
+                ColumnValue::Number(nums) => nums[resolve_index(index_str, nums.len())?],
                 _ => {
                     return Err(ForgeError::Eval(format!(
                         "Array indexing requires numeric column, got {}",
@@ -1428,6 +2589,13 @@ impl ArrayCalculator {
             ("MIN", self.extract_function_arg(formula, start + 4)?)
         } else if let Some(start) = upper.find("COUNT(") {
             ("COUNT", self.extract_function_arg(formula, start + 6)?)
+        } else if let Some(start) = upper.find("COUNTA(") {
+            ("COUNTA", self.extract_function_arg(formula, start + 7)?)
+        } else if let Some(start) = upper.find("COUNTBLANK(") {
+            (
+                "COUNTBLANK",
+                self.extract_function_arg(formula, start + 11)?,
+            )
         // Statistical functions (v5.0.0)
         } else if let Some(start) = upper.find("MEDIAN(") {
             ("MEDIAN", self.extract_function_arg(formula, start + 7)?)
@@ -1452,6 +2620,13 @@ impl ArrayCalculator {
         } else if let Some(start) = upper.find("CORREL(") {
             // CORREL has two arguments: array1, array2
             return self.evaluate_correl(formula, start + 7);
+        } else if upper.contains("TREND(") || upper.contains("GROWTH(") {
+            return self.evaluate_regression_point(formula);
+        } else if upper.contains("FORECAST(")
+            || upper.contains("SLOPE(")
+            || upper.contains("INTERCEPT(")
+        {
+            return self.evaluate_linear_coefficient(formula);
         } else {
             return Err(ForgeError::Eval("Unknown aggregation function".to_string()));
         };
@@ -1475,7 +2650,8 @@ impl ArrayCalculator {
                     }
                     "MAX" => nums.iter().copied().fold(f64::NEG_INFINITY, f64::max),
                     "MIN" => nums.iter().copied().fold(f64::INFINITY, f64::min),
-                    "COUNT" => nums.len() as f64,
+                    "COUNT" | "COUNTA" => nums.len() as f64,
+                    "COUNTBLANK" => 0.0,
                     // Statistical functions (v5.0.0)
                     "MEDIAN" => Self::calculate_median(&nums),
                     "VAR" | "VAR.S" => Self::calculate_variance(&nums, true), // Sample variance
@@ -1516,9 +2692,33 @@ impl ArrayCalculator {
             return Ok(column.values.len() as f64);
         }
 
-        // Other aggregations require numeric columns
-        match &column.values {
-            ColumnValue::Number(nums) => {
+        // COUNTA/COUNTBLANK inspect cells - an empty string is blank
+        if func_name == "COUNTA" || func_name == "COUNTBLANK" {
+            let blanks = match &column.values {
+                ColumnValue::Text(values) | ColumnValue::Date(values) => {
+                    values.iter().filter(|v| v.is_empty()).count()
+                }
+                ColumnValue::Number(_) | ColumnValue::Boolean(_) => 0,
+            };
+            return Ok(if func_name == "COUNTA" {
+                (column.values.len() - blanks) as f64
+            } else {
+                blanks as f64
+            });
+        }
+
+        // Other aggregations require numeric columns (Booleans count as 1/0)
+        let bool_nums: Vec<f64>;
+        let nums = match &column.values {
+            ColumnValue::Number(nums) => Some(nums),
+            ColumnValue::Boolean(bools) => {
+                bool_nums = bools.iter().map(|&b| if b { 1.0 } else { 0.0 }).collect();
+                Some(&bool_nums)
+            }
+            _ => None,
+        };
+        match nums {
+            Some(nums) => {
                 let result = match func_name {
                     "SUM" => nums.iter().sum(),
                     "AVERAGE" | "AVG" => {
@@ -1545,7 +2745,7 @@ impl ArrayCalculator {
                 };
                 Ok(result)
             }
-            _ => Err(ForgeError::Eval(format!(
+            None => Err(ForgeError::Eval(format!(
                 "Aggregation functions require numeric columns, got {}",
                 column.values.type_name()
             ))),
@@ -1553,7 +2753,7 @@ impl ArrayCalculator {
     }
 
     /// Calculate median of a slice of numbers
-    fn calculate_median(nums: &[f64]) -> f64 {
+    pub(crate) fn calculate_median(nums: &[f64]) -> f64 {
         if nums.is_empty() {
             return 0.0;
         }
@@ -1568,7 +2768,7 @@ impl ArrayCalculator {
     }
 
     /// Calculate variance (sample or population)
-    fn calculate_variance(nums: &[f64], sample: bool) -> f64 {
+    pub(crate) fn calculate_variance(nums: &[f64], sample: bool) -> f64 {
         if nums.is_empty() || (sample && nums.len() < 2) {
             return 0.0;
         }
@@ -1583,13 +2783,13 @@ impl ArrayCalculator {
     }
 
     /// Calculate standard deviation (sample or population)
-    fn calculate_stdev(nums: &[f64], sample: bool) -> f64 {
+    pub(crate) fn calculate_stdev(nums: &[f64], sample: bool) -> f64 {
         Self::calculate_variance(nums, sample).sqrt()
     }
 
     /// Calculate percentile value
     /// k should be between 0 and 1 (e.g., 0.25 for 25th percentile)
-    fn calculate_percentile(nums: &[f64], k: f64) -> f64 {
+    pub(crate) fn calculate_percentile(nums: &[f64], k: f64) -> f64 {
         if nums.is_empty() {
             return 0.0;
         }
@@ -1741,29 +2941,160 @@ impl ArrayCalculator {
             return Ok(0.0);
         }
 
-        // Calculate means
-        let mean1 = array1.iter().sum::<f64>() / array1.len() as f64;
-        let mean2 = array2.iter().sum::<f64>() / array2.len() as f64;
+        // Calculate correlation coefficient
+        let (cov, var1, var2) = Self::deviation_sums(&array1, &array2);
+
+        let denominator = (var1 * var2).sqrt();
+        if denominator == 0.0 {
+            return Ok(0.0); // No variance = no correlation
+        }
+
+        Ok(cov / denominator)
+    }
+
+    /// Sums of products of deviations from the means of two equal-length arrays
+    /// Returns (Σ dx·dy, Σ dx², Σ dy²): covariance and variances before scaling
+    fn deviation_sums(array1: &[f64], array2: &[f64]) -> (f64, f64, f64) {
+        let mean1 = array1.iter().sum::<f64>() / array1.len() as f64;
+        let mean2 = array2.iter().sum::<f64>() / array2.len() as f64;
+
+        let mut cov = 0.0;
+        let mut var1 = 0.0;
+        let mut var2 = 0.0;
+        for (x, y) in array1.iter().zip(array2.iter()) {
+            let dx = x - mean1;
+            let dy = y - mean2;
+            cov += dx * dy;
+            var1 += dx * dx;
+            var2 += dy * dy;
+        }
+        (cov, var1, var2)
+    }
+
+    /// Least-squares line through (known_x, known_y): returns (slope, intercept)
+    fn linear_fit(func_name: &str, known_y: &[f64], known_x: &[f64]) -> ForgeResult<(f64, f64)> {
+        if known_y.len() != known_x.len() {
+            return Err(ForgeError::Eval(format!(
+                "{}: known_y ({} values) and known_x ({} values) must have the same length",
+                func_name,
+                known_y.len(),
+                known_x.len()
+            )));
+        }
+        if known_y.is_empty() {
+            return Err(ForgeError::Eval(format!(
+                "{}: known_y and known_x are empty",
+                func_name
+            )));
+        }
+
+        let (cov, var_x, _) = Self::deviation_sums(known_x, known_y);
+        if var_x == 0.0 {
+            return Err(ForgeError::Eval(format!(
+                "{}: known_x values are all equal, so no line can be fitted",
+                func_name
+            )));
+        }
 
-        // Calculate correlation coefficient
-        let mut cov = 0.0;
-        let mut var1 = 0.0;
-        let mut var2 = 0.0;
+        let n = known_x.len() as f64;
+        let slope = cov / var_x;
+        let intercept = known_y.iter().sum::<f64>() / n - slope * known_x.iter().sum::<f64>() / n;
+        Ok((slope, intercept))
+    }
 
-        for (x, y) in array1.iter().zip(array2.iter()) {
-            let dx = x - mean1;
-            let dy = y - mean2;
-            cov += dx * dy;
-            var1 += dx * dx;
-            var2 += dy * dy;
+    /// Project TREND (y = b + m·x) or GROWTH (y = b·mˣ) fitted to known points at new_x
+    fn project(
+        func_name: &str,
+        known_y: &[f64],
+        known_x: &[f64],
+        new_x: &[f64],
+    ) -> ForgeResult<Vec<f64>> {
+        if func_name == "GROWTH" {
+            // Exponential fit: a straight line through ln(y)
+            if known_y.iter().any(|y| *y <= 0.0) {
+                return Err(ForgeError::Eval(
+                    "GROWTH: known_y values must all be positive".to_string(),
+                ));
+            }
+            let log_y: Vec<f64> = known_y.iter().map(|y| y.ln()).collect();
+            let (slope, intercept) = Self::linear_fit(func_name, &log_y, known_x)?;
+            Ok(new_x
+                .iter()
+                .map(|x| (intercept + slope * x).exp())
+                .collect())
+        } else {
+            let (slope, intercept) = Self::linear_fit(func_name, known_y, known_x)?;
+            Ok(new_x.iter().map(|x| intercept + slope * x).collect())
         }
+    }
 
-        let denominator = (var1 * var2).sqrt();
-        if denominator == 0.0 {
-            return Ok(0.0); // No variance = no correlation
+    /// Evaluate TREND / GROWTH in a scalar: the projection at a single new_x
+    fn evaluate_regression_point(&self, formula: &str) -> ForgeResult<f64> {
+        let trimmed = formula.trim().trim_start_matches('=').trim();
+        let (func_name, start, end) = self
+            .find_function_call(trimmed, &["TREND", "GROWTH"])
+            .ok_or_else(|| ForgeError::Eval(format!("Invalid formula '{}'", formula)))?;
+        let args = self.parse_function_args(&trimmed[start + func_name.len() + 1..end - 1])?;
+        if args.len() != 3 {
+            return Err(ForgeError::Eval(format!(
+                "{} in a scalar requires 3 arguments: known_y, known_x, new_x",
+                func_name
+            )));
         }
 
-        Ok(cov / denominator)
+        let known_y = self.get_numeric_array(args[0].trim())?;
+        let known_x = self.get_numeric_array(args[1].trim())?;
+        let new_x = self.regression_point(func_name, &args[2])?;
+        Ok(Self::project(func_name, &known_y, &known_x, &[new_x])?[0])
+    }
+
+    /// Evaluate SLOPE(known_y, known_x), INTERCEPT(known_y, known_x) or
+    /// FORECAST(x, known_y, known_x) from the least-squares line
+    fn evaluate_linear_coefficient(&self, formula: &str) -> ForgeResult<f64> {
+        let trimmed = formula.trim().trim_start_matches('=').trim();
+        let (func_name, start, end) = self
+            .find_function_call(trimmed, &["FORECAST", "SLOPE", "INTERCEPT"])
+            .ok_or_else(|| ForgeError::Eval(format!("Invalid formula '{}'", formula)))?;
+        let args = self.parse_function_args(&trimmed[start + func_name.len() + 1..end - 1])?;
+
+        let arrays = match (func_name, args.len()) {
+            ("FORECAST", 3) => &args[1..],
+            ("SLOPE" | "INTERCEPT", 2) => &args[..],
+            ("FORECAST", _) => {
+                return Err(ForgeError::Eval(
+                    "FORECAST requires 3 arguments: x, known_y, known_x".to_string(),
+                ))
+            }
+            _ => {
+                return Err(ForgeError::Eval(format!(
+                    "{} requires 2 arguments: known_y, known_x",
+                    func_name
+                )))
+            }
+        };
+        let known_y = self.get_numeric_array(arrays[0].trim())?;
+        let known_x = self.get_numeric_array(arrays[1].trim())?;
+        let (slope, intercept) = Self::linear_fit(func_name, &known_y, &known_x)?;
+
+        Ok(match func_name {
+            "SLOPE" => slope,
+            "INTERCEPT" => intercept,
+            _ => intercept + slope * self.regression_point(func_name, &args[0])?,
+        })
+    }
+
+    /// The x of a single-point projection: a number or a scalar's value
+    fn regression_point(&self, func_name: &str, arg: &str) -> ForgeResult<f64> {
+        match self.model.scalars.get(arg.trim()) {
+            Some(scalar) => Ok(scalar.value.unwrap_or(0.0)),
+            None => arg.trim().parse::<f64>().map_err(|_| {
+                ForgeError::Eval(format!(
+                    "{}: x must be a number or scalar, got '{}'",
+                    func_name,
+                    arg.trim()
+                ))
+            }),
+        }
     }
 
     /// Evaluate conditional aggregation (SUMIF, COUNTIF, AVERAGEIF, etc.)
@@ -1851,109 +3182,74 @@ impl ArrayCalculator {
             })?
         };
 
-        // Apply the criteria and aggregate
-        match (&criteria_column.values, &value_column.values) {
-            (ColumnValue::Number(criteria_nums), ColumnValue::Number(value_nums)) => {
-                if func_name != "COUNTIF" && criteria_nums.len() != value_nums.len() {
-                    return Err(ForgeError::Eval(format!(
-                        "Criteria range and value range must have same length: {} vs {}",
-                        criteria_nums.len(),
-                        value_nums.len()
-                    )));
-                }
-
-                let matches: Vec<f64> = criteria_nums
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(i, &crit_val)| {
-                        if self.matches_criteria(crit_val, criteria_str).unwrap_or(false) {
-                            if func_name == "COUNTIF" {
-                                Some(1.0)
-                            } else {
-                                value_nums.get(i).copied()
-                            }
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                let result = match func_name {
-                    "SUMIF" | "COUNTIF" => matches.iter().sum(),
-                    "AVERAGEIF" => {
-                        if matches.is_empty() {
-                            0.0
-                        } else {
-                            matches.iter().sum::<f64>() / matches.len() as f64
-                        }
-                    }
-                    _ => {
-                        return Err(ForgeError::Eval(format!(
-                            "Unsupported function: {}",
-                            func_name
-                        )))
-                    }
-                };
-
-                Ok(result)
-            }
-            (ColumnValue::Text(criteria_text), ColumnValue::Number(value_nums)) => {
-                if func_name != "COUNTIF" && criteria_text.len() != value_nums.len() {
-                    return Err(ForgeError::Eval(format!(
-                        "Criteria range and value range must have same length: {} vs {}",
-                        criteria_text.len(),
-                        value_nums.len()
-                    )));
-                }
+        // Which rows match the criteria
+        let incompatible = || {
+            ForgeError::Eval(format!(
+                "{} requires compatible column types (numeric criteria with numeric values, or text with text for COUNTIF)",
+                func_name
+            ))
+        };
+        let mask: Vec<bool> = match &criteria_column.values {
+            ColumnValue::Number(criteria_nums) => criteria_nums
+                .iter()
+                .map(|&crit_val| {
+                    self.matches_criteria(crit_val, criteria_str)
+                        .unwrap_or(false)
+                })
+                .collect(),
+            ColumnValue::Text(criteria_text) => criteria_text
+                .iter()
+                .map(|crit_val| {
+                    self.matches_text_criteria(crit_val, criteria_str)
+                        .unwrap_or(false)
+                })
+                .collect(),
+            // A Boolean column (e.g. a computed flag) as the predicate
+            ColumnValue::Boolean(criteria_bools) => criteria_bools
+                .iter()
+                .map(|&crit_val| {
+                    self.matches_bool_criteria(crit_val, criteria_str)
+                        .unwrap_or(false)
+                })
+                .collect(),
+            _ => return Err(incompatible()),
+        };
 
-                let matches: Vec<f64> = criteria_text
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(i, crit_val)| {
-                        if self.matches_text_criteria(crit_val, criteria_str).unwrap_or(false) {
-                            if func_name == "COUNTIF" {
-                                Some(1.0)
-                            } else {
-                                value_nums.get(i).copied()
-                            }
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+        // COUNTIF counts the criteria range itself
+        if func_name == "COUNTIF" {
+            return Ok(mask.iter().filter(|&&matched| matched).count() as f64);
+        }
 
-                let result = match func_name {
-                    "SUMIF" | "COUNTIF" => matches.iter().sum(),
-                    "AVERAGEIF" => {
-                        if matches.is_empty() {
-                            0.0
-                        } else {
-                            matches.iter().sum::<f64>() / matches.len() as f64
-                        }
-                    }
-                    _ => {
-                        return Err(ForgeError::Eval(format!(
-                            "Unsupported function: {}",
-                            func_name
-                        )))
-                    }
-                };
+        let value_nums = match &value_column.values {
+            ColumnValue::Number(value_nums) => value_nums,
+            _ => return Err(incompatible()),
+        };
+        if mask.len() != value_nums.len() {
+            return Err(ForgeError::Eval(format!(
+                "Criteria range and value range must have same length: {} vs {}",
+                mask.len(),
+                value_nums.len()
+            )));
+        }
 
-                Ok(result)
-            }
-            // COUNTIF with text criteria (range and criteria only)
-            (ColumnValue::Text(criteria_text), ColumnValue::Text(_)) if func_name == "COUNTIF" => {
-                let count = criteria_text
-                    .iter()
-                    .filter(|crit_val| {
-                        self.matches_text_criteria(crit_val, criteria_str).unwrap_or(false)
-                    })
-                    .count() as f64;
+        let matches: Vec<f64> = mask
+            .iter()
+            .zip(value_nums)
+            .filter(|(&matched, _)| matched)
+            .map(|(_, &value)| value)
+            .collect();
 
-                Ok(count)
+        match func_name {
+            "SUMIF" => Ok(matches.iter().sum()),
+            "AVERAGEIF" => {
+                if matches.is_empty() {
+                    Ok(0.0)
+                } else {
+                    Ok(matches.iter().sum::<f64>() / matches.len() as f64)
+                }
             }
             _ => Err(ForgeError::Eval(format!(
-                "{} requires compatible column types (numeric criteria with numeric values, or text with text for COUNTIF)",
+                "Unsupported function: {}",
                 func_name
             ))),
         }
@@ -2096,9 +3392,27 @@ impl ArrayCalculator {
                         }
                     }
                 }
+                ColumnValue::Boolean(criteria_bools) => {
+                    if criteria_bools.len() != row_count {
+                        return Err(ForgeError::Eval(format!(
+                            "All ranges must have same length: {} vs {}",
+                            criteria_bools.len(),
+                            row_count
+                        )));
+                    }
+
+                    for (j, &crit_val) in criteria_bools.iter().enumerate() {
+                        if !self
+                            .matches_bool_criteria(crit_val, criteria_str)
+                            .unwrap_or(false)
+                        {
+                            matching_rows[j] = false;
+                        }
+                    }
+                }
                 _ => {
                     return Err(ForgeError::Eval(format!(
-                        "{} criteria must be numeric or text",
+                        "{} criteria must be numeric, text or Boolean",
                         func_name
                     )))
                 }
@@ -2213,25 +3527,55 @@ impl ArrayCalculator {
     }
 
     /// Check if a text value matches a criteria string
-    /// Supports: =text, <>text, or simple text match
+    /// Supports: =text, <>text, or simple text match, with `*`/`?` wildcards.
+    /// Case-insensitive like Excel unless the model sets `_case_sensitive_criteria`
     fn matches_text_criteria(&self, value: &str, criteria: &str) -> ForgeResult<bool> {
         let criteria = criteria.trim();
 
         // Remove quotes if present
         let criteria = criteria.trim_matches('"').trim_matches('\'');
 
+        let (criteria, value) = if self.model.case_sensitive_criteria {
+            (criteria.to_string(), value.to_string())
+        } else {
+            (criteria.to_lowercase(), value.to_lowercase())
+        };
+
         if let Some(stripped) = criteria.strip_prefix("<>") {
             let text = stripped.trim();
-            Ok(value != text)
+            Ok(!wildcard_match(text, &value))
         } else if let Some(stripped) = criteria.strip_prefix('=') {
             let text = stripped.trim();
-            Ok(value == text)
+            Ok(wildcard_match(text, &value))
         } else {
             // Simple equality check
-            Ok(value == criteria)
+            Ok(wildcard_match(&criteria, &value))
         }
     }
 
+    /// Check if a Boolean value matches a criteria string
+    /// Supports: TRUE, FALSE, 1, 0, with an optional = or <> prefix
+    fn matches_bool_criteria(&self, value: bool, criteria: &str) -> ForgeResult<bool> {
+        let criteria = criteria.trim().trim_matches('"').trim_matches('\'');
+
+        let (negate, target) = match criteria.strip_prefix("<>") {
+            Some(stripped) => (true, stripped),
+            None => (false, criteria.strip_prefix('=').unwrap_or(criteria)),
+        };
+        let expected = match target.trim().to_uppercase().as_str() {
+            "TRUE" | "1" => true,
+            "FALSE" | "0" => false,
+            _ => {
+                return Err(ForgeError::Eval(format!(
+                    "Invalid criteria for Boolean column: {}",
+                    criteria
+                )))
+            }
+        };
+
+        Ok((value == expected) != negate)
+    }
+
     /// Parse comma-separated function arguments
     /// Handles nested parentheses and quoted strings
     fn parse_function_args(&self, args_str: &str) -> ForgeResult<Vec<String>> {
@@ -2294,9 +3638,6 @@ impl ArrayCalculator {
             .ok_or_else(|| ForgeError::Eval("Missing ']' in array index".to_string()))?;
 
         let index_str = &index_part[..index_end];
-        let index = index_str
-            .parse::<usize>()
-            .map_err(|_| ForgeError::Eval(format!("Invalid array index: {}", index_str)))?;
 
         // Parse table.column reference
         let (table_name, col_name) = self.parse_table_column_ref(table_col)?;
@@ -2314,10 +3655,7 @@ impl ArrayCalculator {
             .ok_or_else(|| ForgeError::Eval(format!("Column '{}' not found", col_name)))?;
 
         match &column.values {
-            ColumnValue::Number(nums) => nums
-                .get(index)
-                .copied()
-                .ok_or_else(|| ForgeError::Eval(format!("Index {} out of bounds", index))),
+            ColumnValue::Number(nums) => Ok(nums[resolve_index(index_str, nums.len())?]),
             _ => Err(ForgeError::Eval(format!(
                 "Array indexing requires numeric column, got {}",
                 column.values.type_name()
@@ -2365,6 +3703,7 @@ impl ArrayCalculator {
         // Preprocess to resolve fully-qualified scalar references (v4.3.0)
         // This handles "inputs.current_usage_pct" style references before xlformula_engine parsing
         let formula = self.resolve_scalar_references(&formula, scalar_name)?;
+        let formula = self.coerce_booleans(&formula, None);
 
         // Extract parent section from scalar_name (e.g., "annual_2025" from "annual_2025.total_revenue")
         let parent_section = scalar_name
@@ -2415,10 +3754,13 @@ impl ArrayCalculator {
 
         match result {
             types::Value::Number(n) => Ok(n as f64),
-            types::Value::Error(e) => Err(ForgeError::Eval(format!(
-                "Formula '{}' returned error: {:?}",
-                &formula, e
-            ))),
+            types::Value::Boolean(types::Boolean::True) => Ok(1.0),
+            types::Value::Boolean(types::Boolean::False) => Ok(0.0),
+            types::Value::Error(e) => Err(self
+                .find_unknown_reference(&formula, scalar_name)
+                .unwrap_or_else(|| {
+                    ForgeError::Eval(format!("Formula '{}' returned error: {:?}", &formula, e))
+                })),
             other => Err(ForgeError::Eval(format!(
                 "Formula '{}' returned unexpected type: {:?}",
                 &formula, other
@@ -2426,6 +3768,46 @@ impl ArrayCalculator {
         }
     }
 
+    /// Error for the first name in a scalar formula that resolves to nothing
+    ///
+    /// Uses the same scoping as the resolver; `None` if every name is known.
+    fn find_unknown_reference(&self, formula: &str, scalar_name: &str) -> Option<ForgeError> {
+        let section = scalar_name
+            .rfind('.')
+            .map(|dot_pos| &scalar_name[..dot_pos]);
+
+        let unknown = formula_identifiers(formula).into_iter().find(|name| {
+            let scoped = section.map(|section| format!("{}.{}", section, name));
+            !(self.model.scalars.contains_key(name)
+                || scoped.is_some_and(|scoped| self.model.scalars.contains_key(&scoped))
+                || self.model.tables.contains_key(name)
+                || self.is_table_column_ref(name)
+                || matches!(name.to_uppercase().as_str(), "TRUE" | "FALSE"))
+        })?;
+
+        let mut known: Vec<String> = self.model.scalars.keys().cloned().collect();
+        if let Some(section) = section {
+            let prefix = format!("{}.", section);
+            known.extend(
+                self.model
+                    .scalars
+                    .keys()
+                    .filter_map(|name| name.strip_prefix(&prefix))
+                    .map(str::to_string),
+            );
+        }
+        for (table_name, table) in &self.model.tables {
+            for col_name in table.columns.keys().chain(table.row_formulas.keys()) {
+                known.push(format!("{}.{}", table_name, col_name));
+            }
+        }
+
+        Some(unknown_reference(
+            &unknown,
+            known.iter().map(String::as_str),
+        ))
+    }
+
     /// Parse table.column reference
     fn parse_table_column_ref(&self, ref_str: &str) -> ForgeResult<(String, String)> {
         let parts: Vec<&str> = ref_str.trim().split('.').collect();
@@ -2455,69 +3837,37 @@ impl ArrayCalculator {
         let formula = formula.trim_start_matches('=');
         let mut refs = Vec::new();
 
-        // Extract all words (column names)
-        for word in formula.split(|c: char| !c.is_alphanumeric() && c != '_' && c != '.') {
-            if !word.is_empty() && !word.chars().next().unwrap().is_numeric() {
-                // Don't include function names
-                let upper = word.to_uppercase();
-                if !matches!(
-                    upper.as_str(),
-                    "SUM"
-                        | "AVERAGE"
-                        | "AVG"
-                        | "MAX"
-                        | "MIN"
-                        | "COUNT"
-                        | "SUMIF"
-                        | "COUNTIF"
-                        | "AVERAGEIF"
-                        | "SUMIFS"
-                        | "COUNTIFS"
-                        | "AVERAGEIFS"
-                        | "MAXIFS"
-                        | "MINIFS"
-                        | "IF"
-                        | "AND"
-                        | "OR"
-                        | "NOT"
-                        | "ABS"
-                        | "ROUND"
-                        | "ROUNDUP"
-                        | "ROUNDDOWN"
-                        | "CEILING"
-                        | "FLOOR"
-                        | "MOD"
-                        | "POWER"
-                        | "SQRT"
-                        | "POW"
-                        | "EXP"
-                        | "LN"
-                        | "LOG"
-                        | "CONCAT"
-                        | "TRIM"
-                        | "UPPER"
-                        | "LOWER"
-                        | "LEN"
-                        | "MID"
-                        | "LEFT"
-                        | "RIGHT"
-                        | "TODAY"
-                        | "NOW"
-                        | "DATE"
-                        | "YEAR"
-                        | "MONTH"
-                        | "DAY"
-                        | "DATEDIF"
-                        | "EDATE"
-                        | "EOMONTH"
-                        | "MATCH"
-                        | "INDEX"
-                        | "VLOOKUP"
-                        | "XLOOKUP"
-                ) && !refs.contains(&word.to_string())
-                {
-                    refs.push(word.to_string());
-                }
+        // Drop string literals so text like "N/A" is not mistaken for a column
+        let formula: String = formula
+            .split('"')
+            .enumerate()
+            .filter(|(i, _)| i % 2 == 0)
+            .map(|(_, part)| part)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        // Extract all words (column names); a word followed by `(` is a
+        // function call, not a reference
+        let mut words = formula
+            .match_indices(|c: char| !c.is_alphanumeric() && c != '_' && c != '.')
+            .peekable();
+        let mut word_start = 0;
+        loop {
+            let word_end = words.peek().map_or(formula.len(), |(i, _)| *i);
+            let word = &formula[word_start..word_end];
+            let is_call = formula[word_end..].trim_start().starts_with('(');
+            if !word.is_empty()
+                && !word.chars().next().unwrap().is_numeric()
+                && !is_call
+                && !word.eq_ignore_ascii_case("TRUE")
+                && !word.eq_ignore_ascii_case("FALSE")
+                && !refs.contains(&word.to_string())
+            {
+                refs.push(word.to_string());
+            }
+            match words.next() {
+                Some((i, sep)) => word_start = i + sep.len(),
+                None => break,
             }
         }
 
@@ -2634,53 +3984,241 @@ impl ArrayCalculator {
                     continue;
                 }
             }
-            break;
+            break;
+        }
+
+        result
+    }
+
+    /// Preprocess formula to handle custom functions
+    /// This is called before xlformula_engine evaluation for row-wise formulas
+    fn preprocess_custom_functions(
+        &self,
+        formula: &str,
+        row_idx: usize,
+        table: &Table,
+    ) -> ForgeResult<String> {
+        let mut result = formula.to_string();
+
+        // Phase 1: Error handlers (IFERROR, IFNA) - must run first so that errors
+        // raised by nested functions can be caught instead of aborting the row
+        if self.has_error_handler_function(formula) {
+            result = self.replace_error_handler_functions(&result, row_idx, table)?;
+        }
+
+        // Conditional chains (IFS) - only the selected arm is evaluated
+        if self.has_ifs_function(formula) {
+            result = self.replace_ifs_functions(&result, row_idx, table)?;
+        }
+
+        // Phase 2: Math functions
+        if self.has_custom_math_function(formula) {
+            result = self.replace_math_functions(&result, row_idx, table)?;
+        }
+
+        // Phase 3: Text functions
+        if self.has_custom_text_function(formula) {
+            result = self.replace_text_functions(&result, row_idx, table)?;
+        }
+
+        // Phase 4: Date functions
+        if self.has_custom_date_function(formula) {
+            result = self.replace_date_functions(&result, row_idx, table)?;
+        }
+
+        // Phase 5: Lookup functions (v1.2.0)
+        if self.has_lookup_function(formula) {
+            result = self.replace_lookup_functions(&result, row_idx, table)?;
+        }
+
+        // Phase 6: Financial functions (v1.6.0)
+        if self.has_financial_function(formula) {
+            result = self.replace_financial_functions(&result, row_idx, table)?;
+        }
+
+        // Phase 7: Array functions (v4.1.0)
+        if self.has_array_function(formula) {
+            result = self.replace_array_functions(&result, row_idx, table)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Replace IFERROR/IFNA calls with the value of their first argument, or with
+    /// the fallback when evaluating that argument fails
+    /// - IFERROR(value, value_if_error) catches any evaluation error
+    /// - IFNA(value, value_if_na) only catches "not found" lookup errors (#N/A)
+    fn replace_error_handler_functions(
+        &self,
+        formula: &str,
+        row_idx: usize,
+        table: &Table,
+    ) -> ForgeResult<String> {
+        let mut result = formula.to_string();
+
+        // Outermost calls first; nested handlers are resolved when the argument is evaluated
+        while let Some((func_name, start, end)) =
+            self.find_function_call(&result, &["IFERROR", "IFNA"])
+        {
+            let args_str = &result[start + func_name.len() + 1..end - 1];
+            let args = self.parse_function_args(args_str)?;
+            if args.len() != 2 {
+                return Err(ForgeError::Eval(format!(
+                    "{} requires exactly 2 arguments: value, value_if_error",
+                    func_name
+                )));
+            }
+
+            let value = match self.eval_row_formula(&args[0], row_idx, table) {
+                Ok(value) => value,
+                Err(e) if func_name == "IFERROR" || Self::is_not_found_error(&e) => {
+                    self.eval_row_formula(&args[1], row_idx, table)?
+                }
+                Err(e) => return Err(e),
+            };
+
+            result = format!("{}{}{}", &result[..start], value, &result[end..]);
+        }
+
+        Ok(result)
+    }
+
+    /// Replace IFS(condition1, value1, [condition2, value2], ...) calls with the value
+    /// of the first true condition
+    fn replace_ifs_functions(
+        &self,
+        formula: &str,
+        row_idx: usize,
+        table: &Table,
+    ) -> ForgeResult<String> {
+        let mut result = formula.to_string();
+
+        while let Some((_, start, end)) = self.find_function_call(&result, &["IFS"]) {
+            let args = self.parse_function_args(&result[start + 4..end - 1])?;
+            if args.is_empty() || args.len() % 2 != 0 {
+                return Err(ForgeError::Eval(
+                    "IFS requires condition/value pairs: condition1, value1, [condition2, value2], ..."
+                        .to_string(),
+                ));
+            }
+
+            let mut value = None;
+            for pair in args.chunks(2) {
+                if self.eval_row_condition(&pair[0], row_idx, table)? {
+                    value = Some(self.eval_row_formula(&pair[1], row_idx, table)?);
+                    break;
+                }
+            }
+
+            let value = value.ok_or_else(|| {
+                ForgeError::Eval(format!(
+                    "IFS: no matching condition in IFS (row {})",
+                    row_idx
+                ))
+            })?;
+            result = format!("{}{}{}", &result[..start], value, &result[end..]);
         }
 
-        result
+        Ok(result)
     }
 
-    /// Preprocess formula to handle custom functions
-    /// This is called before xlformula_engine evaluation for row-wise formulas
-    fn preprocess_custom_functions(
+    /// Evaluate a condition of a row-wise formula for a single row
+    fn eval_row_condition(
         &self,
-        formula: &str,
+        condition: &str,
         row_idx: usize,
         table: &Table,
-    ) -> ForgeResult<String> {
-        let mut result = formula.to_string();
-
-        // Phase 2: Math functions
-        if self.has_custom_math_function(formula) {
-            result = self.replace_math_functions(&result, row_idx, table)?;
+    ) -> ForgeResult<bool> {
+        let condition = condition.trim();
+        if condition.eq_ignore_ascii_case("TRUE") {
+            return Ok(true);
         }
-
-        // Phase 3: Text functions
-        if self.has_custom_text_function(formula) {
-            result = self.replace_text_functions(&result, row_idx, table)?;
+        if condition.eq_ignore_ascii_case("FALSE") {
+            return Ok(false);
         }
 
-        // Phase 4: Date functions
-        if self.has_custom_date_function(formula) {
-            result = self.replace_date_functions(&result, row_idx, table)?;
-        }
+        // Route the comparison through IF, which the engine evaluates reliably
+        let wrapped = self.fix_if_conditions(&format!("=IF({}, 1, 0)", condition));
+        Ok(self.eval_row_formula(&wrapped, row_idx, table)? == "1")
+    }
 
-        // Phase 5: Lookup functions (v1.2.0)
-        if self.has_lookup_function(formula) {
-            result = self.replace_lookup_functions(&result, row_idx, table)?;
-        }
+    /// Find the first call to one of the named functions in a formula
+    /// Returns (function name, start of call, end of call after the closing parenthesis)
+    fn find_function_call(
+        &self,
+        formula: &str,
+        names: &[&'static str],
+    ) -> Option<(&'static str, usize, usize)> {
+        use regex::Regex;
+        let re = Regex::new(&format!(r"(?i)\b({})\(", names.join("|"))).unwrap();
 
-        // Phase 6: Financial functions (v1.6.0)
-        if self.has_financial_function(formula) {
-            result = self.replace_financial_functions(&result, row_idx, table)?;
+        let caps = re.captures(formula)?;
+        let start = caps.get(0).unwrap().start();
+        let func_name = *names.iter().find(|n| n.eq_ignore_ascii_case(&caps[1]))?;
+
+        // Find the matching closing parenthesis, skipping quoted strings
+        let mut depth = 0;
+        let mut in_quotes = false;
+        for (i, c) in formula[start..].char_indices() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                '(' if !in_quotes => depth += 1,
+                ')' if !in_quotes => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some((func_name, start, start + i + 1));
+                    }
+                }
+                _ => {}
+            }
         }
 
-        // Phase 7: Array functions (v4.1.0)
-        if self.has_array_function(formula) {
-            result = self.replace_array_functions(&result, row_idx, table)?;
+        None
+    }
+
+    /// Check if an error corresponds to Excel's #N/A (lookup value not found)
+    fn is_not_found_error(error: &ForgeError) -> bool {
+        match error {
+            ForgeError::Eval(msg) => {
+                (msg.starts_with("MATCH:")
+                    || msg.starts_with("VLOOKUP:")
+                    || msg.starts_with("XLOOKUP:"))
+                    && (msg.contains("not found") || msg.contains("No value"))
+            }
+            _ => false,
         }
+    }
 
-        Ok(result)
+    /// Evaluate a sub-expression of a row-wise formula for a single row
+    /// Returns the result formatted as a formula literal (number, quoted text, or boolean)
+    fn eval_row_formula(&self, expr: &str, row_idx: usize, table: &Table) -> ForgeResult<String> {
+        let formula = format!("={}", expr.trim().trim_start_matches('='));
+        let processed = self.preprocess_custom_functions(&formula, row_idx, table)?;
+
+        let resolver = |var_name: String| -> types::Value {
+            self.resolve_row_variable(&var_name, row_idx, table)
+        };
+        let parsed = parse_formula::parse_string_to_formula(&processed, None::<NoCustomFunction>);
+
+        match calculate::calculate_formula(parsed, Some(&resolver)) {
+            types::Value::Number(n) => Ok(format!("{}", n as f64)),
+            // Embedded quotes are doubled, as in any formula string literal
+            types::Value::Text(t) => Ok(format!("\"{}\"", t.replace('"', "\"\""))),
+            types::Value::Boolean(types::Boolean::True) => Ok("TRUE".to_string()),
+            types::Value::Boolean(types::Boolean::False) => Ok("FALSE".to_string()),
+            types::Value::Error(e) => Err(ForgeError::Eval(format!(
+                "Expression '{}' at row {} returned error: {:?}",
+                expr.trim(),
+                row_idx,
+                e
+            ))),
+            other => Err(ForgeError::Eval(format!(
+                "Expression '{}' at row {} returned unexpected type: {:?}",
+                expr.trim(),
+                row_idx,
+                other
+            ))),
+        }
     }
 
     /// Replace math functions with evaluated results
@@ -2697,9 +4235,12 @@ impl ArrayCalculator {
 
         // Create all regex patterns once outside the loop
         let re_sqrt = Regex::new(r"SQRT\(([^)]+)\)").unwrap();
+        let re_int = Regex::new(r"\bINT\(([^)]+)\)").unwrap();
+        let re_trunc = Regex::new(r"\bTRUNC\(([^,)]+)(?:,\s*([^)]+))?\)").unwrap();
         let re_round = Regex::new(r"ROUND\(([^,]+),\s*([^)]+)\)").unwrap();
         let re_roundup = Regex::new(r"ROUNDUP\(([^,]+),\s*([^)]+)\)").unwrap();
         let re_rounddown = Regex::new(r"ROUNDDOWN\(([^,]+),\s*([^)]+)\)").unwrap();
+        let re_roundbank = Regex::new(r"ROUNDBANK\(([^,]+),\s*([^)]+)\)").unwrap();
         let re_ceiling = Regex::new(r"CEILING\(([^,]+),\s*([^)]+)\)").unwrap();
         let re_floor = Regex::new(r"FLOOR\(([^,]+),\s*([^)]+)\)").unwrap();
         let re_mod = Regex::new(r"MOD\(([^,]+),\s*([^)]+)\)").unwrap();
@@ -2721,6 +4262,34 @@ impl ArrayCalculator {
                 result = result.replace(full, &sqrt.to_string());
             }
 
+            // INT(number)
+            for cap in re_int.captures_iter(&result.clone()).collect::<Vec<_>>() {
+                let full = cap.get(0).unwrap().as_str();
+                let num_expr = cap.get(1).unwrap().as_str();
+
+                let num = self.eval_expression(num_expr, row_idx, table)?;
+                let int = self.eval_int(num);
+
+                result = result.replace(full, &int.to_string());
+            }
+
+            // TRUNC(number, [digits]) - digits default to 0
+            for cap in re_trunc.captures_iter(&result.clone()).collect::<Vec<_>>() {
+                let full = cap.get(0).unwrap().as_str();
+                let num_expr = cap.get(1).unwrap().as_str();
+
+                let num = self.eval_expression(num_expr, row_idx, table)?;
+                let digits = match cap.get(2) {
+                    Some(digits_expr) => {
+                        self.eval_expression(digits_expr.as_str(), row_idx, table)? as i32
+                    }
+                    None => 0,
+                };
+                let truncated = self.eval_trunc(num, digits);
+
+                result = result.replace(full, &truncated.to_string());
+            }
+
             // ROUND(number, digits)
             for cap in re_round.captures_iter(&result.clone()).collect::<Vec<_>>() {
                 let full = cap.get(0).unwrap().as_str();
@@ -2766,6 +4335,22 @@ impl ArrayCalculator {
                 result = result.replace(full, &rounded.to_string());
             }
 
+            // ROUNDBANK(number, digits)
+            for cap in re_roundbank
+                .captures_iter(&result.clone())
+                .collect::<Vec<_>>()
+            {
+                let full = cap.get(0).unwrap().as_str();
+                let num_expr = cap.get(1).unwrap().as_str();
+                let digits_expr = cap.get(2).unwrap().as_str();
+
+                let num = self.eval_expression(num_expr, row_idx, table)?;
+                let digits = self.eval_expression(digits_expr, row_idx, table)? as i32;
+                let rounded = self.eval_roundbank(num, digits);
+
+                result = result.replace(full, &rounded.to_string());
+            }
+
             // CEILING(number, significance)
             for cap in re_ceiling
                 .captures_iter(&result.clone())
@@ -2839,11 +4424,17 @@ impl ArrayCalculator {
 
         // Create all regex patterns once outside the loop
         let re_concat = Regex::new(r"(?:CONCAT|CONCATENATE)\(([^)]+)\)").unwrap();
+        let re_textjoin = Regex::new(r"\bTEXTJOIN\(([^)]+)\)").unwrap();
         let re_trim = Regex::new(r"TRIM\(([^)]+)\)").unwrap();
         let re_upper = Regex::new(r"UPPER\(([^)]+)\)").unwrap();
         let re_lower = Regex::new(r"LOWER\(([^)]+)\)").unwrap();
         let re_len = Regex::new(r"LEN\(([^)]+)\)").unwrap();
         let re_mid = Regex::new(r"MID\(([^,]+),\s*([^,]+),\s*([^)]+)\)").unwrap();
+        let re_text = Regex::new(r"\bTEXT\(([^)]+)\)").unwrap();
+        let re_substitute = Regex::new(r"\bSUBSTITUTE\(([^)]+)\)").unwrap();
+        let re_replace = Regex::new(r"\bREPLACE\(([^)]+)\)").unwrap();
+        let re_find = Regex::new(r"\b(FIND|SEARCH)\(([^)]+)\)").unwrap();
+        let re_type_check = Regex::new(r"\b(ISBLANK|ISNUMBER|ISTEXT)\(([^)]+)\)").unwrap();
 
         // Keep processing until no more changes (handles nested functions)
         while result != prev_result {
@@ -2865,6 +4456,41 @@ impl ArrayCalculator {
                 result = result.replace(full, &format!("\"{}\"", concatenated));
             }
 
+            // TEXTJOIN(delimiter, ignore_empty, text1, text2, ...) - variable arguments
+            for cap in re_textjoin
+                .captures_iter(&result.clone())
+                .collect::<Vec<_>>()
+            {
+                let full = cap.get(0).unwrap().as_str();
+                let args = self.parse_function_args(cap.get(1).unwrap().as_str())?;
+                if args.len() < 3 {
+                    return Err(ForgeError::Eval(
+                        "TEXTJOIN requires at least 3 arguments: delimiter, ignore_empty, text1, ..."
+                            .to_string(),
+                    ));
+                }
+
+                let delimiter = self.eval_text_expression(&args[0], row_idx, table)?;
+                let ignore_empty = match args[1].trim().to_uppercase().as_str() {
+                    "TRUE" | "1" => true,
+                    "FALSE" | "0" => false,
+                    other => {
+                        return Err(ForgeError::Eval(format!(
+                            "TEXTJOIN: ignore_empty must be TRUE or FALSE, got '{}'",
+                            other
+                        )))
+                    }
+                };
+
+                let mut texts = Vec::new();
+                for arg in &args[2..] {
+                    texts.push(self.eval_text_expression(arg, row_idx, table)?);
+                }
+
+                let joined = self.eval_textjoin(&delimiter, ignore_empty, texts);
+                result = result.replace(full, &format!("\"{}\"", joined));
+            }
+
             // TRIM(text)
             for cap in re_trim.captures_iter(&result.clone()).collect::<Vec<_>>() {
                 let full = cap.get(0).unwrap().as_str();
@@ -2923,6 +4549,124 @@ impl ArrayCalculator {
 
                 result = result.replace(full, &format!("\"{}\"", mid));
             }
+
+            // TEXT(value, format_text)
+            for cap in re_text.captures_iter(&result.clone()).collect::<Vec<_>>() {
+                let full = cap.get(0).unwrap().as_str();
+                let args = self.parse_function_args(cap.get(1).unwrap().as_str())?;
+                if args.len() != 2 {
+                    return Err(ForgeError::Eval(
+                        "TEXT requires 2 arguments: value, format_text".to_string(),
+                    ));
+                }
+
+                let value = self.eval_expression(&args[0], row_idx, table)?;
+                let format = self.eval_text_expression(&args[1], row_idx, table)?;
+                let text = self.eval_text_format(value, &format)?;
+
+                result = result.replace(full, &format!("\"{}\"", text));
+            }
+
+            // SUBSTITUTE(text, old_text, new_text, [instance_num])
+            for cap in re_substitute
+                .captures_iter(&result.clone())
+                .collect::<Vec<_>>()
+            {
+                let full = cap.get(0).unwrap().as_str();
+                let args = self.parse_function_args(cap.get(1).unwrap().as_str())?;
+                if args.len() < 3 || args.len() > 4 {
+                    return Err(ForgeError::Eval(
+                        "SUBSTITUTE requires 3-4 arguments: text, old_text, new_text, [instance_num]"
+                            .to_string(),
+                    ));
+                }
+
+                let text = self.eval_text_expression(&args[0], row_idx, table)?;
+                let old_text = self.eval_text_expression(&args[1], row_idx, table)?;
+                let new_text = self.eval_text_expression(&args[2], row_idx, table)?;
+                let instance = match args.get(3) {
+                    Some(arg) => {
+                        let n = self.eval_expression(arg, row_idx, table)?;
+                        if n < 1.0 {
+                            return Err(ForgeError::Eval(format!(
+                                "SUBSTITUTE: instance_num must be >= 1, got {}",
+                                n
+                            )));
+                        }
+                        Some(n as usize)
+                    }
+                    None => None,
+                };
+                let substituted = self.eval_substitute(&text, &old_text, &new_text, instance);
+
+                result = result.replace(full, &format!("\"{}\"", substituted));
+            }
+
+            // REPLACE(old_text, start_num, num_chars, new_text)
+            for cap in re_replace
+                .captures_iter(&result.clone())
+                .collect::<Vec<_>>()
+            {
+                let full = cap.get(0).unwrap().as_str();
+                let args = self.parse_function_args(cap.get(1).unwrap().as_str())?;
+                if args.len() != 4 {
+                    return Err(ForgeError::Eval(
+                        "REPLACE requires 4 arguments: old_text, start_num, num_chars, new_text"
+                            .to_string(),
+                    ));
+                }
+
+                let text = self.eval_text_expression(&args[0], row_idx, table)?;
+                let start = self.eval_expression(&args[1], row_idx, table)?.max(0.0) as usize;
+                let length = self.eval_expression(&args[2], row_idx, table)?.max(0.0) as usize;
+                let new_text = self.eval_text_expression(&args[3], row_idx, table)?;
+                let replaced = self.eval_replace(&text, start, length, &new_text);
+
+                result = result.replace(full, &format!("\"{}\"", replaced));
+            }
+
+            // FIND(find_text, within_text, [start_num]) / SEARCH(...)
+            for cap in re_find.captures_iter(&result.clone()).collect::<Vec<_>>() {
+                let full = cap.get(0).unwrap().as_str();
+                let func_name = cap.get(1).unwrap().as_str();
+                let args = self.parse_function_args(cap.get(2).unwrap().as_str())?;
+                if args.len() < 2 || args.len() > 3 {
+                    return Err(ForgeError::Eval(format!(
+                        "{} requires 2-3 arguments: find_text, within_text, [start_num]",
+                        func_name
+                    )));
+                }
+
+                let find_text = self.eval_text_expression(&args[0], row_idx, table)?;
+                let within_text = self.eval_text_expression(&args[1], row_idx, table)?;
+                let start = match args.get(2) {
+                    Some(arg) => self.eval_expression(arg, row_idx, table)?.max(0.0) as usize,
+                    None => 1,
+                };
+                let position = self.eval_find(
+                    func_name,
+                    &find_text,
+                    &within_text,
+                    start,
+                    func_name == "FIND",
+                )?;
+
+                result = result.replace(full, &position.to_string());
+            }
+
+            // ISBLANK(value) / ISNUMBER(value) / ISTEXT(value) - run last so nested
+            // functions have already been replaced by their results
+            for cap in re_type_check
+                .captures_iter(&result.clone())
+                .collect::<Vec<_>>()
+            {
+                let full = cap.get(0).unwrap().as_str();
+                let func_name = cap.get(1).unwrap().as_str();
+                let value_expr = cap.get(2).unwrap().as_str();
+
+                let is_match = self.eval_type_check(func_name, value_expr, row_idx, table)?;
+                result = result.replace(full, if is_match { "TRUE" } else { "FALSE" });
+            }
         }
 
         Ok(result)
@@ -2954,6 +4698,10 @@ impl ArrayCalculator {
             Regex::new(r"NETWORKDAYS\(([^,]+),\s*([^,\)]+)(?:,\s*([^\)]+))?\)").unwrap();
         let re_workday = Regex::new(r"WORKDAY\(([^,]+),\s*([^,\)]+)(?:,\s*([^\)]+))?\)").unwrap();
         let re_yearfrac = Regex::new(r"YEARFRAC\(([^,]+),\s*([^,\)]+)(?:,\s*([^\)]+))?\)").unwrap();
+        let re_days = Regex::new(r"\bDAYS\(([^,]+),\s*([^)]+)\)").unwrap();
+        let re_datevalue = Regex::new(r"\bDATEVALUE\(([^)]+)\)").unwrap();
+        let re_weekday = Regex::new(r"\bWEEKDAY\(([^,\)]+)(?:,\s*([^\)]+))?\)").unwrap();
+        let re_weeknum = Regex::new(r"\bWEEKNUM\(([^,\)]+)(?:,\s*([^\)]+))?\)").unwrap();
 
         // Keep processing until no more changes (handles nested functions)
         // Process simpler (single-arg) functions first
@@ -2966,6 +4714,20 @@ impl ArrayCalculator {
                 result = result.replace("TODAY()", &format!("\"{}\"", today));
             }
 
+            // DATEVALUE(date_text) - validate before other functions consume the date
+            for cap in re_datevalue
+                .captures_iter(&result.clone())
+                .collect::<Vec<_>>()
+            {
+                let full = cap.get(0).unwrap().as_str();
+                let text_expr = cap.get(1).unwrap().as_str();
+
+                let text = self.eval_text_expression(text_expr, row_idx, table)?;
+                let date = self.eval_datevalue(&text)?;
+
+                result = result.replace(full, &format!("\"{}\"", date));
+            }
+
             // YEAR(date) - process single-arg functions first
             for cap in re_year.captures_iter(&result.clone()).collect::<Vec<_>>() {
                 let full = cap.get(0).unwrap().as_str();
@@ -2999,6 +4761,57 @@ impl ArrayCalculator {
                 result = result.replace(full, &day.to_string());
             }
 
+            // DAYS(end_date, start_date)
+            for cap in re_days.captures_iter(&result.clone()).collect::<Vec<_>>() {
+                let full = cap.get(0).unwrap().as_str();
+                let end_expr = cap.get(1).unwrap().as_str();
+                let start_expr = cap.get(2).unwrap().as_str();
+
+                let end_date = self.eval_text_expression(end_expr, row_idx, table)?;
+                let start_date = self.eval_text_expression(start_expr, row_idx, table)?;
+                let days = self.eval_days(&end_date, &start_date)?;
+
+                result = result.replace(full, &days.to_string());
+            }
+
+            // WEEKDAY(date, [return_type])
+            for cap in re_weekday
+                .captures_iter(&result.clone())
+                .collect::<Vec<_>>()
+            {
+                let full = cap.get(0).unwrap().as_str();
+                let date_expr = cap.get(1).unwrap().as_str();
+                let return_type = if let Some(type_cap) = cap.get(2) {
+                    self.eval_expression(type_cap.as_str(), row_idx, table)? as i32
+                } else {
+                    1 // Default: Sunday=1..Saturday=7
+                };
+
+                let date = self.eval_text_expression(date_expr, row_idx, table)?;
+                let weekday = self.eval_weekday(&date, return_type)?;
+
+                result = result.replace(full, &weekday.to_string());
+            }
+
+            // WEEKNUM(date, [return_type])
+            for cap in re_weeknum
+                .captures_iter(&result.clone())
+                .collect::<Vec<_>>()
+            {
+                let full = cap.get(0).unwrap().as_str();
+                let date_expr = cap.get(1).unwrap().as_str();
+                let return_type = if let Some(type_cap) = cap.get(2) {
+                    self.eval_expression(type_cap.as_str(), row_idx, table)? as i32
+                } else {
+                    1 // Default: weeks start on Sunday
+                };
+
+                let date = self.eval_text_expression(date_expr, row_idx, table)?;
+                let weeknum = self.eval_weeknum(&date, return_type)?;
+
+                result = result.replace(full, &weeknum.to_string());
+            }
+
             // DATE(year, month, day)
             for cap in re_date.captures_iter(&result.clone()).collect::<Vec<_>>() {
                 let full = cap.get(0).unwrap().as_str();
@@ -3071,8 +4884,12 @@ impl ArrayCalculator {
 
                 let start_date = self.eval_text_expression(start_expr, row_idx, table)?;
                 let end_date = self.eval_text_expression(end_expr, row_idx, table)?;
+                let holidays = match cap.get(3) {
+                    Some(holidays_cap) => self.eval_holidays(holidays_cap.as_str(), table)?,
+                    None => HashSet::new(),
+                };
 
-                let days = self.eval_networkdays(&start_date, &end_date)?;
+                let days = self.eval_networkdays(&start_date, &end_date, &holidays)?;
                 result = result.replace(full, &days.to_string());
             }
 
@@ -3087,8 +4904,12 @@ impl ArrayCalculator {
 
                 let start_date = self.eval_text_expression(start_expr, row_idx, table)?;
                 let days = self.eval_expression(days_expr, row_idx, table)? as i32;
+                let holidays = match cap.get(3) {
+                    Some(holidays_cap) => self.eval_holidays(holidays_cap.as_str(), table)?,
+                    None => HashSet::new(),
+                };
 
-                let new_date = self.eval_workday(&start_date, days)?;
+                let new_date = self.eval_workday(&start_date, days, &holidays)?;
                 result = result.replace(full, &format!("\"{}\"", new_date));
             }
 
@@ -3117,6 +4938,34 @@ impl ArrayCalculator {
         Ok(result)
     }
 
+    /// Resolve a holidays argument (local column or table.column) to day ordinals
+    fn eval_holidays(&self, expr: &str, table: &Table) -> ForgeResult<HashSet<i32>> {
+        let column = self.resolve_column_arg(expr, table)?;
+        self.holiday_ordinals(column)
+    }
+
+    /// Resolve a whole-column argument: a local column name or a table.column reference
+    fn resolve_column_arg<'a>(&'a self, expr: &str, table: &'a Table) -> ForgeResult<&'a Column> {
+        let expr = expr.trim();
+        if let Some(col) = table.columns.get(expr) {
+            return Ok(col);
+        }
+
+        let (table_name, col_name) = self.parse_table_column_ref(expr)?;
+        self.model
+            .tables
+            .get(&table_name)
+            .ok_or_else(|| ForgeError::Eval(format!("Table '{}' not found", table_name)))?
+            .columns
+            .get(&col_name)
+            .ok_or_else(|| {
+                ForgeError::Eval(format!(
+                    "Column '{}' not found in table '{}'",
+                    col_name, table_name
+                ))
+            })
+    }
+
     /// Evaluate a simple expression (column reference, literal, or simple arithmetic) to get a numeric value
     fn eval_expression(&self, expr: &str, row_idx: usize, table: &Table) -> ForgeResult<f64> {
         let expr = expr.trim();
@@ -3168,13 +5017,76 @@ impl ArrayCalculator {
             Some(&|_: String| types::Value::Error(types::Error::Reference)),
         );
 
-        match result {
-            types::Value::Number(n) => Ok(n as f64),
-            _ => Err(ForgeError::Eval(format!(
-                "Cannot evaluate expression '{}'",
-                expr
-            ))),
-        }
+        match result {
+            types::Value::Number(n) => Ok(n as f64),
+            _ => Err(ForgeError::Eval(format!(
+                "Cannot evaluate expression '{}'",
+                expr
+            ))),
+        }
+    }
+
+    /// Evaluate ISBLANK/ISNUMBER/ISTEXT by inspecting the cell type rather than coercing
+    /// Column cells are classified by their ColumnValue variant; an empty string is blank
+    fn eval_type_check(
+        &self,
+        func_name: &str,
+        expr: &str,
+        row_idx: usize,
+        table: &Table,
+    ) -> ForgeResult<bool> {
+        let expr = expr.trim();
+
+        // Column reference (local or table.column)
+        let column = table.columns.get(expr).or_else(|| {
+            let (table_name, col_name) = expr.split_once('.')?;
+            self.model.tables.get(table_name)?.columns.get(col_name)
+        });
+
+        let (is_blank, is_number, is_text) = if let Some(col) = column {
+            let out_of_bounds = || {
+                ForgeError::Eval(format!(
+                    "Index {} out of bounds for column '{}'",
+                    row_idx, expr
+                ))
+            };
+            match &col.values {
+                ColumnValue::Number(nums) => {
+                    nums.get(row_idx).ok_or_else(out_of_bounds)?;
+                    (false, true, false)
+                }
+                ColumnValue::Text(texts) => {
+                    let blank = texts.get(row_idx).ok_or_else(out_of_bounds)?.is_empty();
+                    (blank, false, !blank)
+                }
+                // Dates are serial numbers in Excel
+                ColumnValue::Date(dates) => {
+                    let blank = dates.get(row_idx).ok_or_else(out_of_bounds)?.is_empty();
+                    (blank, !blank, false)
+                }
+                ColumnValue::Boolean(bools) => {
+                    bools.get(row_idx).ok_or_else(out_of_bounds)?;
+                    (false, false, false)
+                }
+            }
+        } else if expr.starts_with('"') && expr.ends_with('"') && expr.len() >= 2 {
+            let blank = expr.len() == 2;
+            (blank, false, !blank)
+        } else if expr.eq_ignore_ascii_case("TRUE") || expr.eq_ignore_ascii_case("FALSE") {
+            (false, false, false)
+        } else {
+            (
+                false,
+                self.eval_expression(expr, row_idx, table).is_ok(),
+                false,
+            )
+        };
+
+        Ok(match func_name {
+            "ISBLANK" => is_blank,
+            "ISNUMBER" => is_number,
+            _ => is_text,
+        })
     }
 
     /// Evaluate a simple expression to get a text value
@@ -4622,8 +6534,118 @@ impl ArrayCalculator {
             result = result.replace(full, &format!("{}", npv));
         }
 
+        // CUMIPMT(rate, nper, pv, start_period, end_period, type) - Cumulative interest
+        // CUMPRINC(rate, nper, pv, start_period, end_period, type) - Cumulative principal
+        // Processed before PMT, which would otherwise match inside these names
+        let re_cum = Regex::new(r"\b(CUMIPMT|CUMPRINC)\(([^)]+)\)").unwrap();
+        for caps in re_cum.captures_iter(&result.clone()) {
+            let full = caps.get(0).unwrap().as_str();
+            let func_name = caps.get(1).unwrap().as_str();
+            let args = self.parse_function_args(caps.get(2).unwrap().as_str())?;
+
+            if args.len() != 6 {
+                return Err(ForgeError::Eval(format!(
+                    "{} requires 6 arguments: rate, nper, pv, start_period, end_period, type",
+                    func_name
+                )));
+            }
+
+            let rate = self.eval_expression(&args[0], row_idx, table)?;
+            let nper = self.eval_expression(&args[1], row_idx, table)?;
+            let pv = self.eval_expression(&args[2], row_idx, table)?;
+            let start = self.eval_expression(&args[3], row_idx, table)?;
+            let end = self.eval_expression(&args[4], row_idx, table)?;
+            let pmt_type = self.eval_expression(&args[5], row_idx, table)? as i32;
+
+            if rate <= 0.0 || nper <= 0.0 || pv <= 0.0 {
+                return Err(ForgeError::Eval(format!(
+                    "{}: rate, nper and pv must be positive",
+                    func_name
+                )));
+            }
+            if start < 1.0 || end > nper {
+                return Err(ForgeError::Eval(format!(
+                    "{}: periods must be between 1 and nper ({}), got {} to {}",
+                    func_name, nper, start, end
+                )));
+            }
+            if start > end {
+                return Err(ForgeError::Eval(format!(
+                    "{}: start_period ({}) must not be greater than end_period ({})",
+                    func_name, start, end
+                )));
+            }
+            if pmt_type != 0 && pmt_type != 1 {
+                return Err(ForgeError::Eval(format!(
+                    "{}: type must be 0 (end of period) or 1 (beginning), got {}",
+                    func_name, pmt_type
+                )));
+            }
+
+            let pmt = Self::calculate_pmt(rate, nper, pv, 0.0, pmt_type);
+            let mut total = 0.0;
+            for per in (start as i64)..=(end as i64) {
+                let interest = Self::calculate_ipmt(rate, per as f64, nper, pv, 0.0, pmt_type);
+                total += if func_name == "CUMIPMT" {
+                    interest
+                } else {
+                    pmt - interest
+                };
+            }
+
+            result = result.replace(full, &format!("{}", total));
+        }
+
+        // IPMT(rate, per, nper, pv, [fv], [type]) - Interest portion of one payment
+        // PPMT(rate, per, nper, pv, [fv], [type]) - Principal portion of one payment
+        let re_ppmt = Regex::new(r"\b(IPMT|PPMT)\(([^)]+)\)").unwrap();
+        for caps in re_ppmt.captures_iter(&result.clone()) {
+            let full = caps.get(0).unwrap().as_str();
+            let func_name = caps.get(1).unwrap().as_str();
+            let args = self.parse_function_args(caps.get(2).unwrap().as_str())?;
+
+            if args.len() < 4 || args.len() > 6 {
+                return Err(ForgeError::Eval(format!(
+                    "{} requires 4-6 arguments: rate, per, nper, pv, [fv], [type]",
+                    func_name
+                )));
+            }
+
+            let rate = self.eval_expression(&args[0], row_idx, table)?;
+            let per = self.eval_expression(&args[1], row_idx, table)?;
+            let nper = self.eval_expression(&args[2], row_idx, table)?;
+            let pv = self.eval_expression(&args[3], row_idx, table)?;
+            let fv = if args.len() > 4 {
+                self.eval_expression(&args[4], row_idx, table)?
+            } else {
+                0.0
+            };
+            let pmt_type = if args.len() > 5 {
+                self.eval_expression(&args[5], row_idx, table)? as i32
+            } else {
+                0
+            };
+
+            if per < 1.0 || per > nper {
+                return Err(ForgeError::Eval(format!(
+                    "{}: per must be between 1 and nper ({}), got {}",
+                    func_name, nper, per
+                )));
+            }
+
+            let interest = Self::calculate_ipmt(rate, per, nper, pv, fv, pmt_type);
+            let value = if func_name == "IPMT" {
+                interest
+            } else {
+                Self::calculate_pmt(rate, nper, pv, fv, pmt_type) - interest
+            };
+
+            result = result.replace(full, &format!("{}", value));
+        }
+
         // PMT(rate, nper, pv, [fv], [type]) - Payment for a loan
-        let re_pmt = Regex::new(r"PMT\(([^)]+)\)").unwrap();
+        // Use \b word boundary to avoid matching IPMT, PPMT, CUMIPMT
+        let re_pmt = Regex::new(r"\bPMT\(([^)]+)\)").unwrap();
         for caps in re_pmt.captures_iter(formula) {
             let full = caps.get(0).unwrap().as_str();
             let args_str = caps.get(1).unwrap().as_str();
@@ -4649,17 +6671,7 @@ impl ArrayCalculator {
                 0
             };
 
-            let pmt = if rate == 0.0 {
-                -(pv + fv) / nper
-            } else {
-                let pvif = (1.0 + rate).powf(nper);
-                let pmt = rate * (pv * pvif + fv) / (pvif - 1.0);
-                if pmt_type == 1 {
-                    -pmt / (1.0 + rate)
-                } else {
-                    -pmt
-                }
-            };
+            let pmt = Self::calculate_pmt(rate, nper, pv, fv, pmt_type);
 
             result = result.replace(full, &format!("{}", pmt));
         }
@@ -4955,7 +6967,7 @@ impl ArrayCalculator {
         }
 
         // MIRR(values, finance_rate, reinvest_rate) - Modified Internal Rate of Return
-        let re_mirr = Regex::new(r"MIRR\(([^)]+)\)").unwrap();
+        let re_mirr = Regex::new(r"\bMIRR\(([^)]+)\)").unwrap();
         for caps in re_mirr.captures_iter(formula) {
             let full = caps.get(0).unwrap().as_str();
             let args_str = caps.get(1).unwrap().as_str();
@@ -4976,6 +6988,46 @@ impl ArrayCalculator {
             result = result.replace(full, &format!("{}", mirr));
         }
 
+        // EFFECT(nominal_rate, npery) - Effective annual rate
+        // NOMINAL(effect_rate, npery) - Nominal annual rate
+        let re_rate_conv = Regex::new(r"\b(EFFECT|NOMINAL)\(([^)]+)\)").unwrap();
+        for caps in re_rate_conv.captures_iter(&result.clone()) {
+            let full = caps.get(0).unwrap().as_str();
+            let func_name = caps.get(1).unwrap().as_str();
+            let args = self.parse_function_args(caps.get(2).unwrap().as_str())?;
+
+            if args.len() != 2 {
+                return Err(ForgeError::Eval(format!(
+                    "{} requires exactly 2 arguments: rate, npery",
+                    func_name
+                )));
+            }
+
+            let rate = self.eval_expression(&args[0], row_idx, table)?;
+            let npery = self.eval_expression(&args[1], row_idx, table)?;
+
+            if npery < 1.0 || npery.fract() != 0.0 {
+                return Err(ForgeError::Eval(format!(
+                    "{}: npery must be a whole number of periods >= 1, got {}",
+                    func_name, npery
+                )));
+            }
+            if rate <= 0.0 {
+                return Err(ForgeError::Eval(format!(
+                    "{}: rate must be positive, got {}",
+                    func_name, rate
+                )));
+            }
+
+            let value = if func_name == "EFFECT" {
+                (1.0 + rate / npery).powf(npery) - 1.0
+            } else {
+                npery * ((1.0 + rate).powf(1.0 / npery) - 1.0)
+            };
+
+            result = result.replace(full, &format!("{}", value));
+        }
+
         // SLN(cost, salvage, life) - Straight-line depreciation
         let re_sln = Regex::new(r"SLN\(([^)]+)\)").unwrap();
         for caps in re_sln.captures_iter(formula) {
@@ -5058,6 +7110,43 @@ impl ArrayCalculator {
         Ok(result)
     }
 
+    /// Calculate the periodic payment for a loan (PMT)
+    fn calculate_pmt(rate: f64, nper: f64, pv: f64, fv: f64, pmt_type: i32) -> f64 {
+        if rate == 0.0 {
+            -(pv + fv) / nper
+        } else {
+            let pvif = (1.0 + rate).powf(nper);
+            let pmt = rate * (pv * pvif + fv) / (pvif - 1.0);
+            if pmt_type == 1 {
+                -pmt / (1.0 + rate)
+            } else {
+                -pmt
+            }
+        }
+    }
+
+    /// Calculate the interest portion of the payment for period `per` (1-based)
+    /// Uses Excel's sign convention: interest paid on a positive pv is negative
+    fn calculate_ipmt(rate: f64, per: f64, nper: f64, pv: f64, fv: f64, pmt_type: i32) -> f64 {
+        // No interest accrues before the first payment when paying in advance
+        if rate == 0.0 || (pmt_type == 1 && per == 1.0) {
+            return 0.0;
+        }
+
+        let pmt = Self::calculate_pmt(rate, nper, pv, fv, pmt_type);
+        let growth = (1.0 + rate).powf(per - 1.0);
+        // Future value after per-1 payments (the negated remaining balance)
+        let balance_fv =
+            -(pv * growth + pmt * (1.0 + rate * pmt_type as f64) * (growth - 1.0) / rate);
+        let interest = balance_fv * rate;
+
+        if pmt_type == 1 {
+            interest / (1.0 + rate)
+        } else {
+            interest
+        }
+    }
+
     /// Calculate Modified Internal Rate of Return (MIRR)
     fn calculate_mirr(
         &self,
@@ -5071,6 +7160,18 @@ impl ArrayCalculator {
                 "MIRR: values must have at least 2 elements".to_string(),
             ));
         }
+        if !values.iter().any(|&cf| cf < 0.0) || !values.iter().any(|&cf| cf > 0.0) {
+            return Err(ForgeError::Eval(
+                "MIRR: values must contain at least one negative and one positive cash flow"
+                    .to_string(),
+            ));
+        }
+        if finance_rate <= -1.0 || reinvest_rate <= -1.0 {
+            return Err(ForgeError::Eval(format!(
+                "MIRR: rates must be greater than -1 (finance_rate={}, reinvest_rate={})",
+                finance_rate, reinvest_rate
+            )));
+        }
 
         // Present value of negative cash flows (costs) at finance rate
         let mut pv_neg = 0.0;
@@ -5086,17 +7187,6 @@ impl ArrayCalculator {
             }
         }
 
-        if pv_neg >= 0.0 {
-            return Err(ForgeError::Eval(
-                "MIRR: values must contain at least one negative cash flow".to_string(),
-            ));
-        }
-        if fv_pos <= 0.0 {
-            return Err(ForgeError::Eval(
-                "MIRR: values must contain at least one positive cash flow".to_string(),
-            ));
-        }
-
         // MIRR formula: (-fv_pos / pv_neg)^(1/(n-1)) - 1
         let mirr = (-fv_pos / pv_neg).powf(1.0 / (n - 1.0)) - 1.0;
         Ok(mirr)
@@ -5327,6 +7417,311 @@ impl ArrayCalculator {
             .join(", "))
     }
 
+    /// Evaluate a row-wise formula that uses PREV, one row at a time in order
+    /// - PREV(column) is the previous row's value of column; when column is the
+    ///   one being computed, it is that column's result for the previous row
+    /// - PREV(column, seed) uses seed as the value at row 0
+    ///
+    /// PREV without a seed at row 0 is an error. Results must be numeric.
+    fn evaluate_sequential_formula(
+        &self,
+        table: &Table,
+        col_name: &str,
+        formula: &str,
+    ) -> ForgeResult<ColumnValue> {
+        let row_count = table.row_count();
+        if row_count == 0 {
+            return Err(ForgeError::Eval(
+                "Cannot evaluate row-wise formula on empty table".to_string(),
+            ));
+        }
+
+        let formula_with_scalars = self.preprocess_scalar_refs_for_table(formula)?;
+        let mut results: Vec<f64> = Vec::with_capacity(row_count);
+
+        for row_idx in 0..row_count {
+            let mut expr = formula_with_scalars.clone();
+
+            while let Some((_, start, end)) = self.find_function_call(&expr, &["PREV"]) {
+                let args = self.parse_function_args(&expr[start + "PREV(".len()..end - 1])?;
+                if args.is_empty() || args.len() > 2 {
+                    return Err(ForgeError::Eval(
+                        "PREV requires 1-2 arguments: column, [seed]".to_string(),
+                    ));
+                }
+
+                let value = if row_idx == 0 {
+                    match args.get(1) {
+                        Some(seed) => self.eval_expression(seed, 0, table)?,
+                        None => {
+                            return Err(ForgeError::Eval(format!(
+                                "PREV: '{}' has no previous row at row 0 - provide a seed: PREV({}, seed)",
+                                args[0].trim(),
+                                args[0].trim()
+                            )))
+                        }
+                    }
+                } else if args[0].trim() == col_name {
+                    results[row_idx - 1]
+                } else {
+                    self.eval_expression(&args[0], row_idx - 1, table)?
+                };
+
+                expr.replace_range(start..end, &format!("({})", value));
+            }
+
+            let value = self.eval_row_formula(&expr, row_idx, table)?;
+            let num = value.parse::<f64>().map_err(|_| {
+                ForgeError::Eval(format!(
+                    "PREV formula '{}' must return a number, got {} at row {}",
+                    formula.trim(),
+                    value,
+                    row_idx
+                ))
+            })?;
+            results.push(num);
+        }
+
+        Ok(ColumnValue::Number(results))
+    }
+
+    /// Check if a table formula produces a whole column at once rather than
+    /// one independent value per row: =SORT(...), =SORTBY(...), =RUNSUM(...),
+    /// =PCTTOTAL(...), =TREND(...) or =GROWTH(...)
+    /// as the entire formula
+    fn is_array_result_formula(&self, formula: &str) -> bool {
+        let trimmed = formula.trim().trim_start_matches('=').trim();
+        matches!(
+            self.find_function_call(trimmed, &["SORTBY", "SORT", "RUNSUM", "PCTTOTAL", "TREND", "GROWTH"]),
+            Some((_, 0, end)) if end == trimmed.len()
+        )
+    }
+
+    /// Evaluate an array-valued table formula
+    /// - SORT(array, [order]) sorts a column's values (order: 1 = ascending, -1 = descending)
+    /// - SORTBY(array, by_array, [order]) reorders array by the sort order of by_array
+    /// - RUNSUM(array) running total: element i is the sum of elements 0..=i
+    /// - PCTTOTAL(array) share of total: element i divided by the column sum
+    /// - TREND / GROWTH(known_y, known_x, [new_x]) linear / exponential
+    ///   projection at each new_x (a column, or one value for every row)
+    ///
+    /// The result fills the new column. Sibling columns are not reordered;
+    /// sort each one with SORTBY on the same key to keep rows aligned.
+    fn evaluate_array_result_formula(
+        &self,
+        table: &Table,
+        formula: &str,
+    ) -> ForgeResult<ColumnValue> {
+        let trimmed = formula.trim().trim_start_matches('=').trim();
+        let (func_name, _, end) = self
+            .find_function_call(
+                trimmed,
+                &["SORTBY", "SORT", "RUNSUM", "PCTTOTAL", "TREND", "GROWTH"],
+            )
+            .ok_or_else(|| ForgeError::Eval(format!("Invalid array formula '{}'", formula)))?;
+        let args = self.parse_function_args(&trimmed[func_name.len() + 1..end - 1])?;
+
+        let result = match func_name {
+            "RUNSUM" => self.eval_runsum_column(&args, table)?,
+            "PCTTOTAL" => self.eval_pcttotal_column(&args, table)?,
+            "TREND" | "GROWTH" => self.eval_regression_column(func_name, &args, table)?,
+            _ => self.eval_sort_column(func_name, &args, table)?,
+        };
+
+        let row_count = table.row_count();
+        if row_count > 0 && result.len() != row_count {
+            return Err(ForgeError::Eval(format!(
+                "{}: result has {} rows but table '{}' has {}",
+                func_name,
+                result.len(),
+                table.name,
+                row_count
+            )));
+        }
+
+        Ok(result)
+    }
+
+    /// Evaluate SORT / SORTBY over whole columns
+    fn eval_sort_column(
+        &self,
+        func_name: &str,
+        args: &[String],
+        table: &Table,
+    ) -> ForgeResult<ColumnValue> {
+        let (array_arg, by_arg, order_arg) = match (func_name, args.len()) {
+            ("SORT", 1 | 2) => (&args[0], &args[0], args.get(1)),
+            ("SORTBY", 2 | 3) => (&args[0], &args[1], args.get(2)),
+            ("SORT", _) => {
+                return Err(ForgeError::Eval(
+                    "SORT requires 1-2 arguments: array, [order]".to_string(),
+                ))
+            }
+            _ => {
+                return Err(ForgeError::Eval(
+                    "SORTBY requires 2-3 arguments: array, by_array, [order]".to_string(),
+                ))
+            }
+        };
+
+        let array = &self.resolve_column_arg(array_arg, table)?.values;
+        let by = &self.resolve_column_arg(by_arg, table)?.values;
+        if array.len() != by.len() {
+            return Err(ForgeError::Eval(format!(
+                "{}: array ({} rows) and by_array ({} rows) must have same length",
+                func_name,
+                array.len(),
+                by.len()
+            )));
+        }
+
+        let descending = match order_arg {
+            Some(order) => self.eval_expression(order, 0, table)? < 0.0,
+            None => false, // Default: ascending
+        };
+        let directed = |ord: std::cmp::Ordering| if descending { ord.reverse() } else { ord };
+
+        // Stable sort of row positions by the key column
+        let mut order: Vec<usize> = (0..by.len()).collect();
+        match by {
+            ColumnValue::Number(keys) => order.sort_by(|&a, &b| {
+                directed(
+                    keys[a]
+                        .partial_cmp(&keys[b])
+                        .unwrap_or(std::cmp::Ordering::Equal),
+                )
+            }),
+            ColumnValue::Text(keys) | ColumnValue::Date(keys) => {
+                order.sort_by(|&a, &b| directed(keys[a].cmp(&keys[b])))
+            }
+            ColumnValue::Boolean(keys) => order.sort_by(|&a, &b| directed(keys[a].cmp(&keys[b]))),
+        }
+
+        Ok(match array {
+            ColumnValue::Number(v) => ColumnValue::Number(order.iter().map(|&i| v[i]).collect()),
+            ColumnValue::Text(v) => {
+                ColumnValue::Text(order.iter().map(|&i| v[i].clone()).collect())
+            }
+            ColumnValue::Date(v) => {
+                ColumnValue::Date(order.iter().map(|&i| v[i].clone()).collect())
+            }
+            ColumnValue::Boolean(v) => ColumnValue::Boolean(order.iter().map(|&i| v[i]).collect()),
+        })
+    }
+
+    /// Evaluate RUNSUM - ordered scan of a numeric column with a running total
+    fn eval_runsum_column(&self, args: &[String], table: &Table) -> ForgeResult<ColumnValue> {
+        if args.len() != 1 {
+            return Err(ForgeError::Eval(
+                "RUNSUM requires 1 argument: array".to_string(),
+            ));
+        }
+
+        let column = self.resolve_column_arg(&args[0], table)?;
+        let values: Vec<f64> = match &column.values {
+            ColumnValue::Number(nums) => nums.clone(),
+            ColumnValue::Boolean(bools) => {
+                bools.iter().map(|&b| if b { 1.0 } else { 0.0 }).collect()
+            }
+            other => {
+                return Err(ForgeError::Eval(format!(
+                    "RUNSUM: Column '{}' must be numeric, found {}",
+                    column.name,
+                    other.type_name()
+                )))
+            }
+        };
+
+        let mut total = 0.0;
+        Ok(ColumnValue::Number(
+            values
+                .iter()
+                .map(|v| {
+                    total += v;
+                    total
+                })
+                .collect(),
+        ))
+    }
+
+    /// Evaluate PCTTOTAL - each value as a share of the column's total
+    fn eval_pcttotal_column(&self, args: &[String], table: &Table) -> ForgeResult<ColumnValue> {
+        if args.len() != 1 {
+            return Err(ForgeError::Eval(
+                "PCTTOTAL requires 1 argument: array".to_string(),
+            ));
+        }
+
+        let column = self.resolve_column_arg(&args[0], table)?;
+        let values = match &column.values {
+            ColumnValue::Number(nums) => nums,
+            other => {
+                return Err(ForgeError::Eval(format!(
+                    "PCTTOTAL: Column '{}' must be numeric, found {}",
+                    column.name,
+                    other.type_name()
+                )))
+            }
+        };
+
+        let total: f64 = values.iter().sum();
+        if total == 0.0 {
+            return Err(ForgeError::Eval(format!(
+                "PCTTOTAL: Column '{}' sums to zero, so shares of the total are undefined",
+                column.name
+            )));
+        }
+        Ok(ColumnValue::Number(
+            values.iter().map(|v| v / total).collect(),
+        ))
+    }
+
+    /// Evaluate TREND / GROWTH over a table: one projection per row
+    /// Without new_x the fitted values at known_x are returned
+    fn eval_regression_column(
+        &self,
+        func_name: &str,
+        args: &[String],
+        table: &Table,
+    ) -> ForgeResult<ColumnValue> {
+        if !(2..=3).contains(&args.len()) {
+            return Err(ForgeError::Eval(format!(
+                "{} requires 2-3 arguments: known_y, known_x, [new_x]",
+                func_name
+            )));
+        }
+
+        let numeric = |arg: &str| -> ForgeResult<Vec<f64>> {
+            let column = self.resolve_column_arg(arg, table)?;
+            match &column.values {
+                ColumnValue::Number(nums) => Ok(nums.clone()),
+                other => Err(ForgeError::Eval(format!(
+                    "{}: Column '{}' must be numeric, found {}",
+                    func_name,
+                    column.name,
+                    other.type_name()
+                ))),
+            }
+        };
+        let known_y = numeric(&args[0])?;
+        let known_x = numeric(&args[1])?;
+        let new_x = match args.get(2) {
+            None => known_x.clone(),
+            Some(arg) if self.resolve_column_arg(arg, table).is_ok() => numeric(arg)?,
+            Some(arg) => {
+                let point = match self.model.scalars.get(arg.trim()) {
+                    Some(scalar) => scalar.value.unwrap_or(0.0),
+                    None => self.eval_expression(arg, 0, table)?,
+                };
+                vec![point; table.row_count()]
+            }
+        };
+
+        Ok(ColumnValue::Number(Self::project(
+            func_name, &known_y, &known_x, &new_x,
+        )?))
+    }
+
     /// Evaluate COUNTUNIQUE - count unique values in a column/array
     fn eval_countunique(
         &self,
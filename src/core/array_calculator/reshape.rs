@@ -0,0 +1,127 @@
+//! Whole-table formulas
+//! `revenue_by_month: "=TRANSPOSE(sales.revenue, sales.month)"`
+//!
+//! A top-level key whose value is a formula defines a table computed from
+//! another table. TRANSPOSE turns a column into a table with a single row and
+//! one column per source row, so the values sit side by side in CSV and Excel
+//! exports. Columns are named after the values of the optional header column
+//! (text or dates), or `row_1`, `row_2`, ... by position.
+//!
+//! Limitations: the formula must be a single TRANSPOSE call; the table is
+//! built after the ordinary tables (and before scalars), so row formulas
+//! cannot reference it; and it is rebuilt on every calculation rather than
+//! written back to the YAML file.
+
+use crate::error::{ForgeError, ForgeResult};
+use crate::types::{Column, ColumnValue, Table};
+use std::time::{Duration, Instant};
+
+use super::ArrayCalculator;
+
+impl ArrayCalculator {
+    /// Build every table defined by a whole-table formula, returning the
+    /// time spent on each in name order
+    pub(super) fn evaluate_table_formulas(&mut self) -> ForgeResult<Vec<(String, Duration)>> {
+        let mut names: Vec<String> = self.model.table_formulas.keys().cloned().collect();
+        names.sort();
+
+        let mut built = Vec::with_capacity(names.len());
+        for name in &names {
+            let started = Instant::now();
+            let formula = &self.model.table_formulas[name];
+            built.push((
+                self.evaluate_table_formula(name, formula)?,
+                started.elapsed(),
+            ));
+        }
+
+        let mut times = Vec::with_capacity(built.len());
+        for (name, (table, time)) in names.into_iter().zip(built) {
+            self.model.tables.insert(table.name.clone(), table);
+            times.push((name, time));
+        }
+        Ok(times)
+    }
+
+    fn evaluate_table_formula(&self, name: &str, formula: &str) -> ForgeResult<Table> {
+        let trimmed = formula.trim().trim_start_matches('=').trim();
+        let (func_name, args) = match self.find_function_call(trimmed, &["TRANSPOSE"]) {
+            Some((func_name, 0, end)) if end == trimmed.len() => (
+                func_name,
+                self.parse_function_args(&trimmed[func_name.len() + 1..end - 1])?,
+            ),
+            _ => {
+                return Err(ForgeError::Eval(format!(
+                    "Table '{}': '{}' is not a table formula (expected =TRANSPOSE(table.column))",
+                    name, formula
+                )))
+            }
+        };
+        if args.is_empty() || args.len() > 2 {
+            return Err(ForgeError::Eval(format!(
+                "{} requires 1-2 arguments: array, [headers]",
+                func_name
+            )));
+        }
+
+        let values = self.table_formula_column(&args[0])?;
+        let headers = match args.get(1) {
+            Some(arg) => transpose_headers(arg, self.table_formula_column(arg)?, values.len())?,
+            None => (1..=values.len()).map(|i| format!("row_{}", i)).collect(),
+        };
+
+        let mut table = Table::new(name.to_string());
+        for (i, header) in headers.into_iter().enumerate() {
+            let cell = match values {
+                ColumnValue::Number(v) => ColumnValue::Number(vec![v[i]]),
+                ColumnValue::Text(v) => ColumnValue::Text(vec![v[i].clone()]),
+                ColumnValue::Date(v) => ColumnValue::Date(vec![v[i].clone()]),
+                ColumnValue::Boolean(v) => ColumnValue::Boolean(vec![v[i]]),
+            };
+            table.add_column(Column::new(header, cell));
+        }
+        Ok(table)
+    }
+
+    /// Values of a `table.column` argument
+    fn table_formula_column(&self, arg: &str) -> ForgeResult<&ColumnValue> {
+        let (table_name, col_name) = self.parse_table_column_ref(arg)?;
+        self.model
+            .tables
+            .get(&table_name)
+            .and_then(|table| table.columns.get(&col_name))
+            .map(|column| &column.values)
+            .ok_or_else(|| ForgeError::Eval(format!("TRANSPOSE: column '{}' not found", arg)))
+    }
+}
+
+/// Column names for a transposed table, taken from a header column
+fn transpose_headers(arg: &str, headers: &ColumnValue, len: usize) -> ForgeResult<Vec<String>> {
+    let names = match headers {
+        ColumnValue::Text(v) | ColumnValue::Date(v) => v.clone(),
+        other => {
+            return Err(ForgeError::Eval(format!(
+                "TRANSPOSE: header column '{}' must be text or dates, found {}",
+                arg,
+                other.type_name()
+            )))
+        }
+    };
+    if names.len() != len {
+        return Err(ForgeError::Eval(format!(
+            "TRANSPOSE: header column '{}' has {} rows but the array has {}",
+            arg,
+            names.len(),
+            len
+        )));
+    }
+    for (i, name) in names.iter().enumerate() {
+        if name.trim().is_empty() || names[..i].contains(name) {
+            return Err(ForgeError::Eval(format!(
+                "TRANSPOSE: header column '{}' has an empty or duplicate value '{}'",
+                arg, name
+            )));
+        }
+    }
+    Ok(names)
+}
@@ -0,0 +1,101 @@
+//! Column slices in scalar formulas: `=SUM(sales.revenue[1:3])`
+//!
+//! Bounds are 0-based like `[n]` indexing, start inclusive and end exclusive,
+//! so `[1:3]` is the second and third rows. An omitted bound means the start
+//! or end of the column (`[2:]`, `[:3]`). A slice that is empty, reversed or
+//! runs past the end of the column is an error rather than being clamped.
+//! Slices are backed by the same temporary table as array literals.
+
+use regex::Regex;
+use std::ops::Range;
+
+use crate::error::{ForgeError, ForgeResult};
+use crate::types::ColumnValue;
+
+use super::literals::RANGE_TABLE;
+use super::ArrayCalculator;
+
+/// `table.column[start:end]`, either bound optional
+fn slice_pattern() -> Regex {
+    Regex::new(r"(\w+)\.(\w+)\[\s*(\d*)\s*:\s*(\d*)\s*\]").unwrap()
+}
+
+/// Whether a formula has a column slice
+pub(super) fn has_column_slice(formula: &str) -> bool {
+    slice_pattern().is_match(formula)
+}
+
+/// Copy of `values[range]`
+fn slice_values(values: &ColumnValue, range: Range<usize>) -> ColumnValue {
+    match values {
+        ColumnValue::Number(v) => ColumnValue::Number(v[range].to_vec()),
+        ColumnValue::Text(v) => ColumnValue::Text(v[range].to_vec()),
+        ColumnValue::Date(v) => ColumnValue::Date(v[range].to_vec()),
+        ColumnValue::Boolean(v) => ColumnValue::Boolean(v[range].to_vec()),
+    }
+}
+
+impl ArrayCalculator {
+    /// Replace `table.column[start:end]` slices with `_ranges.sliceN` references
+    pub(super) fn extract_column_slices(
+        &self,
+        formula: &str,
+    ) -> ForgeResult<(String, Vec<(String, ColumnValue)>)> {
+        let mut rewritten = String::with_capacity(formula.len());
+        let mut columns = Vec::new();
+        let mut last = 0;
+
+        for cap in slice_pattern().captures_iter(formula) {
+            let full = cap.get(0).unwrap();
+            let values = self.column_slice(&cap[1], &cap[2], &cap[3], &cap[4])?;
+            let name = format!("slice{}", columns.len() + 1);
+
+            rewritten.push_str(&formula[last..full.start()]);
+            rewritten.push_str(&format!("{}.{}", RANGE_TABLE, name));
+            last = full.end();
+            columns.push((name, values));
+        }
+        rewritten.push_str(&formula[last..]);
+
+        Ok((rewritten, columns))
+    }
+
+    /// Rows `start..end` of a column; empty bounds default to the whole column
+    fn column_slice(
+        &self,
+        table_name: &str,
+        col_name: &str,
+        start: &str,
+        end: &str,
+    ) -> ForgeResult<ColumnValue> {
+        let column = self
+            .model
+            .tables
+            .get(table_name)
+            .and_then(|table| table.columns.get(col_name))
+            .ok_or_else(|| {
+                ForgeError::Eval(format!(
+                    "Column '{}.{}' not found for slice",
+                    table_name, col_name
+                ))
+            })?;
+
+        let len = column.values.len();
+        let bound = |text: &str, default: usize| -> ForgeResult<usize> {
+            if text.is_empty() {
+                return Ok(default);
+            }
+            text.parse::<usize>()
+                .map_err(|_| ForgeError::Eval(format!("Invalid slice bound: {}", text)))
+        };
+        let (from, to) = (bound(start, 0)?, bound(end, len)?);
+
+        if from >= to || to > len {
+            return Err(ForgeError::Eval(format!(
+                "Slice {}.{}[{}:{}] is out of range: the column has {} rows",
+                table_name, col_name, start, end, len
+            )));
+        }
+        Ok(slice_values(&column.values, from..to))
+    }
+}
@@ -0,0 +1,205 @@
+//! Formula syntax check run before evaluation
+//!
+//! The formula engine reports malformed input as a bare `#VALUE!`, so
+//! structural mistakes (unbalanced parentheses or brackets, unterminated
+//! strings, unknown or doubled operators) are caught here with the character
+//! offset where they occur. Calls to registered functions are also checked
+//! against the registry's argument counts, so `=ROUND(x)` fails with
+//! "ROUND expects 2 arguments, got 1" instead of a confusing evaluator error.
+//! The check is otherwise deliberately shallow; anything it accepts is left
+//! to the evaluator.
+
+use crate::core::FunctionRegistry;
+use crate::error::{ForgeError, ForgeResult};
+
+/// Binary operators that cannot directly follow another operator
+const INFIX_ONLY: &[char] = &['*', '/', '^', '&'];
+
+/// Operators, including comparison characters
+const OPERATORS: &[char] = &['+', '-', '*', '/', '^', '&', '=', '<', '>'];
+
+/// Punctuation allowed in formulas besides letters, digits and whitespace
+const PUNCTUATION: &[char] = &[
+    '(', ')', '[', ']', '{', '}', ',', ';', ':', '.', '_', '%', '!', '\'', '@', '$', '#',
+];
+
+/// Check a formula's structure, reporting problems against `location`
+///
+/// Positions are 0-based character offsets that count the leading `=`.
+pub(super) fn check_formula_syntax(formula: &str, location: &str) -> ForgeResult<()> {
+    let error = |position: usize, message: String| {
+        Err(ForgeError::FormulaSyntax {
+            location: location.to_string(),
+            position,
+            message,
+        })
+    };
+
+    let chars: Vec<char> = formula.chars().collect();
+    let mut open: Vec<(char, usize)> = Vec::new();
+    // Previous non-whitespace character outside strings; the leading `=`
+    // marks the start of the expression, not an operator
+    let mut previous: Option<char> = None;
+    let mut i = 0;
+    if chars.first() == Some(&'=') {
+        i = 1;
+    }
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c == '"' {
+            match chars[i + 1..].iter().position(|&q| q == '"') {
+                Some(len) => i += len + 2,
+                None => return error(i, "unterminated string".to_string()),
+            }
+            previous = Some('"');
+            continue;
+        }
+
+        match c {
+            '(' | '[' | '{' => open.push((c, i)),
+            ')' | ']' | '}' => {
+                let expected = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                match open.pop() {
+                    Some((opener, _)) if opener == expected => {}
+                    _ => return error(i, format!("unexpected '{}'", c)),
+                }
+            }
+            _ if INFIX_ONLY.contains(&c) => {
+                let after_operand = match previous {
+                    None => false,
+                    Some(p) => !OPERATORS.contains(&p) && !matches!(p, '(' | '[' | '{' | ','),
+                };
+                if !after_operand {
+                    return error(i, format!("unexpected '{}'", c));
+                }
+            }
+            _ if c.is_alphanumeric() || OPERATORS.contains(&c) || PUNCTUATION.contains(&c) => {}
+            _ => return error(i, format!("unknown operator '{}'", c)),
+        }
+
+        previous = Some(c);
+        i += 1;
+    }
+
+    if let Some((opener, position)) = open.pop() {
+        return error(position, format!("unclosed '{}'", opener));
+    }
+    if let Some(p) = previous {
+        if OPERATORS.contains(&p) {
+            return error(chars.len(), "unexpected end of formula".to_string());
+        }
+    }
+    if let Some((position, message)) = arity_error(&chars) {
+        return error(position, message);
+    }
+
+    Ok(())
+}
+
+/// First call to a registered function with the wrong number of arguments
+///
+/// Runs after the structural check, so strings are terminated and brackets
+/// balanced. Unknown functions are left to the evaluator.
+fn arity_error(chars: &[char]) -> Option<(usize, String)> {
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' {
+            i += 1;
+            while chars.get(i).is_some_and(|&q| q != '"') {
+                i += 1;
+            }
+            i += 1;
+            continue;
+        }
+        if !c.is_alphabetic() && c != '_' {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while chars
+            .get(i)
+            .is_some_and(|&ch| ch.is_alphanumeric() || matches!(ch, '_' | '.'))
+        {
+            i += 1;
+        }
+        let mut open = i;
+        while chars.get(open).is_some_and(|c| c.is_whitespace()) {
+            open += 1;
+        }
+        if chars.get(open) != Some(&'(') {
+            continue;
+        }
+
+        let name: String = chars[start..i].iter().collect::<String>().to_uppercase();
+        if let Some(info) = FunctionRegistry::get(&name) {
+            let count = argument_count(&chars[open + 1..]);
+            let expected = match info.max_args {
+                Some(max) if max == info.min_args => arguments(max),
+                Some(max) => format!("{}-{} arguments", info.min_args, max),
+                None => format!("at least {}", arguments(info.min_args)),
+            };
+            if count < info.min_args || info.max_args.is_some_and(|max| count > max) {
+                return Some((
+                    start,
+                    format!("{} expects {}, got {}", name, expected, count),
+                ));
+            }
+        }
+    }
+    None
+}
+
+/// Number of top-level arguments before the `)` that closes the call
+fn argument_count(rest: &[char]) -> usize {
+    let mut depth = 0;
+    let mut commas = 0;
+    let mut empty = true;
+    let mut in_quotes = false;
+
+    for &c in rest {
+        if in_quotes {
+            in_quotes = c != '"';
+            continue;
+        }
+        match c {
+            ')' | ']' | '}' if depth == 0 => break,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => commas += 1,
+            _ if c.is_whitespace() => {}
+            _ => {
+                empty = false;
+                match c {
+                    '"' => in_quotes = true,
+                    '(' | '[' | '{' => depth += 1,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    if empty && commas == 0 {
+        0
+    } else {
+        commas + 1
+    }
+}
+
+fn arguments(count: usize) -> String {
+    if count == 1 {
+        "1 argument".to_string()
+    } else {
+        format!("{} arguments", count)
+    }
+}
@@ -411,6 +411,50 @@ fn test_countif_text_criteria() {
     );
 }
 
+#[test]
+fn test_countif_text_criteria_ignores_case() {
+    let mut model = ParsedModel::new();
+
+    let mut table = Table::new("fruit".to_string());
+    table.add_column(Column::new(
+        "category".to_string(),
+        ColumnValue::Text(vec![
+            "Apple".to_string(),
+            "APPLE".to_string(),
+            "apple".to_string(),
+            "Banana".to_string(),
+        ]),
+    ));
+    table.add_column(Column::new(
+        "qty".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0, 4.0, 8.0]),
+    ));
+    model.add_table(table);
+
+    for (name, formula) in [
+        ("apples", "=COUNTIF(fruit.category, \"apple\")"),
+        ("apple_qty", "=SUMIF(fruit.category, \"ap*\", fruit.qty)"),
+        ("not_apple", "=COUNTIF(fruit.category, \"<>apple\")"),
+    ] {
+        model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), None, Some(formula.to_string())),
+        );
+    }
+
+    let result = ArrayCalculator::new(model.clone()).calculate_all().unwrap();
+    assert_eq!(result.scalars["apples"].value, Some(3.0));
+    assert_eq!(result.scalars["apple_qty"].value, Some(7.0));
+    assert_eq!(result.scalars["not_apple"].value, Some(1.0));
+
+    // Opt out: only the exact-case value matches
+    model.case_sensitive_criteria = true;
+    let result = ArrayCalculator::new(model).calculate_all().unwrap();
+    assert_eq!(result.scalars["apples"].value, Some(1.0));
+    assert_eq!(result.scalars["apple_qty"].value, Some(4.0));
+    assert_eq!(result.scalars["not_apple"].value, Some(3.0));
+}
+
 #[test]
 fn test_sumif_text_criteria() {
     use crate::types::Variable;
@@ -452,6 +496,63 @@ fn test_sumif_text_criteria() {
     );
 }
 
+#[test]
+fn test_sumif_boolean_flag_criteria() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+
+    let mut table = Table::new("sales".to_string());
+    table.add_column(Column::new(
+        "region".to_string(),
+        ColumnValue::Text(vec![
+            "North".to_string(),
+            "South".to_string(),
+            "North".to_string(),
+            "South".to_string(),
+        ]),
+    ));
+    table.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![500.0, 1200.0, 1500.0, 800.0]),
+    ));
+    // Precomputed flag column reused as the predicate
+    table
+        .row_formulas
+        .insert("flag".to_string(), "=revenue > 1000".to_string());
+    model.add_table(table);
+
+    for (name, formula) in [
+        ("flagged_revenue", "=SUMIF(sales.flag, TRUE, sales.revenue)"),
+        ("unflagged_count", "=COUNTIF(sales.flag, FALSE)"),
+        (
+            "flagged_average",
+            "=AVERAGEIF(sales.flag, \"<>FALSE\", sales.revenue)",
+        ),
+        // Like numeric and text criteria, an unusable criteria matches nothing
+        ("unknown_count", "=COUNTIF(sales.flag, \"maybe\")"),
+        (
+            "north_flagged",
+            "=SUMIFS(sales.revenue, sales.flag, TRUE, sales.region, \"North\")",
+        ),
+    ] {
+        model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), None, Some(formula.to_string())),
+        );
+    }
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all().unwrap();
+
+    // 1200 + 1500 are flagged
+    assert_eq!(result.scalars["flagged_revenue"].value, Some(2700.0));
+    assert_eq!(result.scalars["unflagged_count"].value, Some(2.0));
+    assert_eq!(result.scalars["flagged_average"].value, Some(1350.0));
+    assert_eq!(result.scalars["unknown_count"].value, Some(0.0));
+    assert_eq!(result.scalars["north_flagged"].value, Some(1500.0));
+}
+
 #[test]
 fn test_sumifs_multiple_criteria() {
     use crate::types::Variable;
@@ -789,6 +890,91 @@ fn test_rounddown_function() {
     }
 }
 
+#[test]
+fn test_roundbank_rounds_half_to_even() {
+    let mut model = ParsedModel::new();
+    let mut table = Table::new("data".to_string());
+
+    table.add_column(Column::new(
+        "values".to_string(),
+        ColumnValue::Number(vec![2.5, 3.5, -2.5, 2.6]),
+    ));
+    table.add_row_formula("banked".to_string(), "=ROUNDBANK(values, 0)".to_string());
+    table.add_row_formula("rounded".to_string(), "=ROUND(values, 0)".to_string());
+    model.add_table(table);
+
+    model.add_scalar(
+        "cents".to_string(),
+        Variable::new(
+            "cents".to_string(),
+            None,
+            Some("=ROUNDBANK(0.125, 2)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let result_table = result.tables.get("data").unwrap();
+
+    // Halves go to the even neighbour; other values round as usual
+    assert_eq!(
+        result_table.columns["banked"].values,
+        ColumnValue::Number(vec![2.0, 4.0, -2.0, 3.0])
+    );
+    // ROUND keeps rounding halves away from zero
+    assert_eq!(
+        result_table.columns["rounded"].values,
+        ColumnValue::Number(vec![3.0, 4.0, -3.0, 3.0])
+    );
+    assert_eq!(result.scalars["cents"].value, Some(0.12));
+}
+
+#[test]
+fn test_int_and_trunc_differ_on_negatives() {
+    let mut model = ParsedModel::new();
+    let mut table = Table::new("data".to_string());
+
+    table.add_column(Column::new(
+        "values".to_string(),
+        ColumnValue::Number(vec![2.5, -2.5, 2.789, -2.789]),
+    ));
+    table.add_row_formula("int".to_string(), "=INT(values)".to_string());
+    table.add_row_formula("trunc".to_string(), "=TRUNC(values)".to_string());
+    table.add_row_formula("trunc_2".to_string(), "=TRUNC(values, 2)".to_string());
+    model.add_table(table);
+
+    for (name, formula) in [("int_neg", "=INT(-2.5)"), ("trunc_neg", "=TRUNC(-2.5)")] {
+        model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), None, Some(formula.to_string())),
+        );
+    }
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let result_table = result.tables.get("data").unwrap();
+
+    // INT rounds toward negative infinity, TRUNC toward zero
+    assert_eq!(
+        result_table.columns["int"].values,
+        ColumnValue::Number(vec![2.0, -3.0, 2.0, -3.0])
+    );
+    assert_eq!(
+        result_table.columns["trunc"].values,
+        ColumnValue::Number(vec![2.0, -2.0, 2.0, -2.0])
+    );
+    assert_eq!(
+        result_table.columns["trunc_2"].values,
+        ColumnValue::Number(vec![2.5, -2.5, 2.78, -2.78])
+    );
+    assert_eq!(result.scalars["int_neg"].value, Some(-3.0));
+    assert_eq!(result.scalars["trunc_neg"].value, Some(-2.0));
+}
+
 #[test]
 fn test_ceiling_function() {
     let mut model = ParsedModel::new();
@@ -3301,6 +3487,48 @@ fn test_circular_dependency_error() {
     assert!(err.contains("Circular") || err.contains("Unable to resolve"));
 }
 
+#[test]
+fn test_circular_dependency_reports_cycle_path() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+    for (name, formula) in [
+        ("c", "=a + 1"),
+        ("a", "=b + 1"),
+        ("b", "=c + 1"),
+        ("d", "=a * 2"),
+        ("e", "=10"),
+    ] {
+        model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), None, Some(formula.to_string())),
+        );
+    }
+
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert!(matches!(err, ForgeError::CircularDependency(_)));
+    assert_eq!(err.to_string(), "Circular dependency: a → b → c → a");
+}
+
+#[test]
+fn test_circular_dependency_in_table_reports_columns() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "base".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0]),
+    ));
+    data.add_row_formula("b".to_string(), "=a + base".to_string());
+    data.add_row_formula("a".to_string(), "=b + base".to_string());
+    model.add_table(data);
+
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Circular dependency: data.a → data.b → data.a"
+    );
+}
+
 #[test]
 fn test_undefined_reference_error() {
     use crate::types::Variable;
@@ -3322,132 +3550,457 @@ fn test_undefined_reference_error() {
     assert!(result.is_err());
 }
 
-// =========================================================================
-// ABS Function Test
-// =========================================================================
-
 #[test]
-fn test_abs_function() {
+fn test_undefined_column_suggests_near_match() {
     let mut model = ParsedModel::new();
-
-    let mut data = Table::new("data".to_string());
-    data.add_column(Column::new(
-        "values".to_string(),
-        ColumnValue::Number(vec![-10.0, 5.0, -3.0, 8.0]),
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0]),
     ));
-    data.row_formulas
-        .insert("absolute".to_string(), "=ABS(values)".to_string());
-    model.add_table(data);
-
-    let calculator = ArrayCalculator::new(model);
-    let result = calculator.calculate_all().expect("Should calculate");
-
-    let abs_col = result
-        .tables
-        .get("data")
-        .unwrap()
-        .columns
-        .get("absolute")
-        .unwrap();
-    if let ColumnValue::Number(values) = &abs_col.values {
-        assert_eq!(values[0], 10.0);
-        assert_eq!(values[1], 5.0);
-        assert_eq!(values[2], 3.0);
-        assert_eq!(values[3], 8.0);
-    } else {
-        panic!("Expected numeric column");
-    }
-}
-
-// =========================================================================
-// IF Function with Simple Condition
-// =========================================================================
-
-#[test]
-fn test_if_simple_condition() {
-    let mut model = ParsedModel::new();
-    let mut data = Table::new("data".to_string());
-    data.add_column(Column::new(
-        "value".to_string(),
-        ColumnValue::Number(vec![10.0, -5.0, 20.0]),
+    sales.add_column(Column::new(
+        "cost".to_string(),
+        ColumnValue::Number(vec![60.0, 120.0]),
     ));
-    data.row_formulas
-        .insert("positive".to_string(), "=IF(value > 0, 1, 0)".to_string());
-    model.add_table(data);
-
-    let calculator = ArrayCalculator::new(model);
-    let result = calculator.calculate_all().expect("Should calculate");
+    sales.add_row_formula("profit".to_string(), "=revenu - cost".to_string());
+    model.add_table(sales);
 
-    let col = result
-        .tables
-        .get("data")
-        .unwrap()
-        .columns
-        .get("positive")
-        .unwrap();
-    if let ColumnValue::Number(values) = &col.values {
-        assert_eq!(values[0], 1.0);
-        assert_eq!(values[1], 0.0);
-        assert_eq!(values[2], 1.0);
-    }
+    let err = ArrayCalculator::new(model)
+        .calculate_all()
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Unknown reference 'revenu'; did you mean 'revenue'?"));
 }
 
-// =========================================================================
-// Scalar Dependencies Chain
-// =========================================================================
-
 #[test]
-fn test_scalar_chain_calculation() {
-    use crate::types::Variable;
-
+fn test_undefined_scalar_suggestions() {
     let mut model = ParsedModel::new();
-
     model.add_scalar(
-        "base".to_string(),
-        Variable::new("base".to_string(), Some(100.0), None),
+        "price".to_string(),
+        Variable::new("price".to_string(), Some(10.0), None),
     );
     model.add_scalar(
-        "doubled".to_string(),
-        Variable::new("doubled".to_string(), None, Some("=base * 2".to_string())),
+        "typo".to_string(),
+        Variable::new("typo".to_string(), None, Some("=prise * 2".to_string())),
     );
+    let err = ArrayCalculator::new(model.clone())
+        .calculate_all()
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Unknown reference 'prise'; did you mean 'price'?"));
+
+    // Nothing close enough: no suggestion
     model.add_scalar(
-        "final".to_string(),
-        Variable::new("final".to_string(), None, Some("=doubled + 50".to_string())),
+        "typo".to_string(),
+        Variable::new("typo".to_string(), None, Some("=headcount * 2".to_string())),
     );
-
-    let calculator = ArrayCalculator::new(model);
-    let result = calculator.calculate_all().expect("Should calculate");
-
-    assert_eq!(result.scalars.get("doubled").unwrap().value.unwrap(), 200.0);
-    assert_eq!(result.scalars.get("final").unwrap().value.unwrap(), 250.0);
+    let err = ArrayCalculator::new(model)
+        .calculate_all()
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Unknown reference 'headcount'"));
+    assert!(!err.contains("did you mean"));
 }
 
-// =========================================================================
-// Empty Table Handling
-// =========================================================================
-
 #[test]
-fn test_empty_table_handling() {
+fn test_unbalanced_paren_reports_position() {
     let mut model = ParsedModel::new();
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0]),
+    ));
+    sales.add_row_formula("profit".to_string(), "=revenue)".to_string());
+    model.add_table(sales);
 
-    let empty_table = Table::new("empty".to_string());
-    model.add_table(empty_table);
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert!(matches!(err, ForgeError::FormulaSyntax { position: 8, .. }));
+    assert_eq!(
+        err.to_string(),
+        "Parse error in sales.profit at position 8: unexpected ')'"
+    );
 
-    let calculator = ArrayCalculator::new(model);
-    // Should not panic with empty table
-    let result = calculator.calculate_all();
-    assert!(result.is_ok());
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "total".to_string(),
+        Variable::new("total".to_string(), None, Some("=SUM(1, 2".to_string())),
+    );
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error in total at position 4: unclosed '('"
+    );
 }
 
-// =========================================================================
-// Complex Multi-Step Table Calculation
-// =========================================================================
-
 #[test]
-fn test_profit_calculation() {
-    let mut model = ParsedModel::new();
+fn test_wrong_argument_count_is_reported() {
+    let scalar_error = |formula: &str| {
+        let mut model = ParsedModel::new();
+        model.add_scalar(
+            "x".to_string(),
+            Variable::new("x".to_string(), Some(2.5), None),
+        );
+        model.add_scalar(
+            "result".to_string(),
+            Variable::new("result".to_string(), None, Some(formula.to_string())),
+        );
+        ArrayCalculator::new(model)
+            .calculate_all()
+            .unwrap_err()
+            .to_string()
+    };
+
+    // Too few
+    assert_eq!(
+        scalar_error("=ROUND(x)"),
+        "Parse error in result at position 1: ROUND expects 2 arguments, got 1"
+    );
+    assert_eq!(
+        scalar_error("=SUM()"),
+        "Parse error in result at position 1: SUM expects at least 1 argument, got 0"
+    );
+    assert!(scalar_error("=x + PMT(0.05, 12)")
+        .ends_with("position 5: PMT expects 3-5 arguments, got 2"));
 
-    let mut income = Table::new("income".to_string());
+    // Too many
+    assert!(scalar_error("=ABS(x, 1)").ends_with("ABS expects 1 argument, got 2"));
+    assert!(scalar_error("=IF(x > 1, 1, 0, 5)").ends_with("IF expects 2-3 arguments, got 4"));
+    assert!(scalar_error("=TODAY(x)").ends_with("TODAY expects 0 arguments, got 1"));
+    // Nested calls and commas inside strings are counted per call
+    assert!(
+        scalar_error("=ROUND(MAX(x, 1), 2, \"a,b\")").ends_with("ROUND expects 2 arguments, got 3")
+    );
+
+    // Table formulas are checked too
+    let mut model = ParsedModel::new();
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0]),
+    ));
+    sales.add_row_formula("rounded".to_string(), "=ROUNDUP(revenue)".to_string());
+    model.add_table(sales);
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error in sales.rounded at position 1: ROUNDUP expects 2 arguments, got 1"
+    );
+}
+
+/// Model with a four-row `sales.revenue` column
+fn sales_revenue_model() -> ParsedModel {
+    let mut model = ParsedModel::new();
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0, 300.0, 400.0]),
+    ));
+    model.add_table(sales);
+    model
+}
+
+/// Value of `formula` calculated as the scalar `result` of `model`
+fn scalar_result(mut model: ParsedModel, formula: &str) -> ForgeResult<f64> {
+    model.add_scalar(
+        "result".to_string(),
+        Variable::new("result".to_string(), None, Some(formula.to_string())),
+    );
+    ArrayCalculator::new(model)
+        .calculate_all()
+        .map(|result| result.scalars["result"].value.unwrap())
+}
+
+#[test]
+fn test_array_literals_in_scalar_formulas() {
+    let calculate = |formula: &str| scalar_result(ParsedModel::new(), formula);
+
+    assert_eq!(calculate("=SUM({10, 20, 30})").unwrap(), 60.0);
+    assert_eq!(calculate("=INDEX({100,200,300}, 2)").unwrap(), 200.0);
+    assert_eq!(calculate("=COUNTA({\"a\", \"b\", \"c\"})").unwrap(), 3.0);
+
+    // The temporary table does not leak into the result
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "result".to_string(),
+        Variable::new(
+            "result".to_string(),
+            None,
+            Some("=MAX({1; 5; 3})".to_string()),
+        ),
+    );
+    let result = ArrayCalculator::new(model).calculate_all().unwrap();
+    assert_eq!(result.scalars["result"].value, Some(5.0));
+    assert!(result.tables.is_empty());
+
+    let err = calculate("=SUM({1, \"a\"})").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Array literal {1, \"a\"} mixes numbers and text"));
+}
+
+#[test]
+fn test_array_literals_outside_scalar_formulas() {
+    let mut model = ParsedModel::new();
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0]),
+    ));
+    model.add_table(sales.clone());
+
+    // Ad-hoc scalar formulas get the same handling as model scalars
+    let calculator = ArrayCalculator::new(model.clone());
+    assert_eq!(
+        calculator.evaluate_formula("=SUM({1, 2, 3})").unwrap(),
+        ColumnValue::Number(vec![6.0])
+    );
+    let err = calculator
+        .evaluate_formula("=revenue * INDEX({1, 2}, 2)")
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("only supported in scalar formulas"));
+
+    sales.add_row_formula(
+        "scaled".to_string(),
+        "=revenue * INDEX({1, 2}, 2)".to_string(),
+    );
+    model.add_table(sales);
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert!(err.to_string().contains(
+        "Table 'sales': Column 'scaled' uses an array literal or column slice - these are only supported in scalar formulas"
+    ));
+}
+
+#[test]
+fn test_array_literals_reserved_table_name() {
+    let mut model = ParsedModel::new();
+    let mut ranges = Table::new("_ranges".to_string());
+    ranges.add_column(Column::new(
+        "array1".to_string(),
+        ColumnValue::Number(vec![1.0]),
+    ));
+    model.add_table(ranges);
+    model.add_scalar(
+        "result".to_string(),
+        Variable::new(
+            "result".to_string(),
+            None,
+            Some("=SUM({10, 20})".to_string()),
+        ),
+    );
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert!(err.to_string().contains("'_ranges' is reserved"));
+}
+
+#[test]
+fn test_column_slices() {
+    let calculate = |formula: &str| scalar_result(sales_revenue_model(), formula);
+
+    // 0-based, end exclusive: rows 1 and 2
+    assert_eq!(calculate("=SUM(sales.revenue[1:3])").unwrap(), 500.0);
+    // Open-ended slices run to the end (or from the start) of the column
+    assert_eq!(calculate("=SUM(sales.revenue[2:])").unwrap(), 700.0);
+    assert_eq!(calculate("=AVERAGE(sales.revenue[:2])").unwrap(), 150.0);
+    assert_eq!(
+        calculate("=SUM(sales.revenue[2:]) - SUM(sales.revenue[:2])").unwrap(),
+        400.0
+    );
+
+    let err = calculate("=SUM(sales.revenue[1:9])").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Slice sales.revenue[1:9] is out of range: the column has 4 rows"));
+    assert!(calculate("=SUM(sales.revenue[3:1])").is_err());
+}
+
+#[test]
+fn test_column_slices_in_row_formulas() {
+    let mut model = sales_revenue_model();
+    model.tables.get_mut("sales").unwrap().add_row_formula(
+        "share".to_string(),
+        "=revenue / SUM(sales.revenue[1:3])".to_string(),
+    );
+
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert!(err.to_string().contains(
+        "Table 'sales': Column 'share' uses an array literal or column slice - these are only supported in scalar formulas"
+    ));
+}
+
+#[test]
+fn test_negative_array_indexing() {
+    let calculate = |formula: &str| scalar_result(sales_revenue_model(), formula);
+
+    assert_eq!(calculate("=sales.revenue[-1]").unwrap(), 400.0);
+    assert_eq!(calculate("=sales.revenue[-2]").unwrap(), 300.0);
+    assert_eq!(
+        calculate("=sales.revenue[-1] / sales.revenue[0]").unwrap(),
+        4.0
+    );
+
+    let err = calculate("=sales.revenue[-5]").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Index -5 out of bounds: column has 4 rows"));
+}
+
+#[test]
+fn test_unknown_operator_reports_position() {
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "a".to_string(),
+        Variable::new("a".to_string(), Some(1.0), None),
+    );
+    model.add_scalar(
+        "b".to_string(),
+        Variable::new("b".to_string(), None, Some("=a ~ 2".to_string())),
+    );
+    let err = ArrayCalculator::new(model.clone())
+        .calculate_all()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error in b at position 3: unknown operator '~'"
+    );
+
+    model.add_scalar(
+        "b".to_string(),
+        Variable::new("b".to_string(), None, Some("=a ** 2".to_string())),
+    );
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parse error in b at position 4: unexpected '*'"
+    );
+}
+
+// =========================================================================
+// ABS Function Test
+// =========================================================================
+
+#[test]
+fn test_abs_function() {
+    let mut model = ParsedModel::new();
+
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "values".to_string(),
+        ColumnValue::Number(vec![-10.0, 5.0, -3.0, 8.0]),
+    ));
+    data.row_formulas
+        .insert("absolute".to_string(), "=ABS(values)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all().expect("Should calculate");
+
+    let abs_col = result
+        .tables
+        .get("data")
+        .unwrap()
+        .columns
+        .get("absolute")
+        .unwrap();
+    if let ColumnValue::Number(values) = &abs_col.values {
+        assert_eq!(values[0], 10.0);
+        assert_eq!(values[1], 5.0);
+        assert_eq!(values[2], 3.0);
+        assert_eq!(values[3], 8.0);
+    } else {
+        panic!("Expected numeric column");
+    }
+}
+
+// =========================================================================
+// IF Function with Simple Condition
+// =========================================================================
+
+#[test]
+fn test_if_simple_condition() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "value".to_string(),
+        ColumnValue::Number(vec![10.0, -5.0, 20.0]),
+    ));
+    data.row_formulas
+        .insert("positive".to_string(), "=IF(value > 0, 1, 0)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all().expect("Should calculate");
+
+    let col = result
+        .tables
+        .get("data")
+        .unwrap()
+        .columns
+        .get("positive")
+        .unwrap();
+    if let ColumnValue::Number(values) = &col.values {
+        assert_eq!(values[0], 1.0);
+        assert_eq!(values[1], 0.0);
+        assert_eq!(values[2], 1.0);
+    }
+}
+
+// =========================================================================
+// Scalar Dependencies Chain
+// =========================================================================
+
+#[test]
+fn test_scalar_chain_calculation() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+
+    model.add_scalar(
+        "base".to_string(),
+        Variable::new("base".to_string(), Some(100.0), None),
+    );
+    model.add_scalar(
+        "doubled".to_string(),
+        Variable::new("doubled".to_string(), None, Some("=base * 2".to_string())),
+    );
+    model.add_scalar(
+        "final".to_string(),
+        Variable::new("final".to_string(), None, Some("=doubled + 50".to_string())),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all().expect("Should calculate");
+
+    assert_eq!(result.scalars.get("doubled").unwrap().value.unwrap(), 200.0);
+    assert_eq!(result.scalars.get("final").unwrap().value.unwrap(), 250.0);
+}
+
+// =========================================================================
+// Empty Table Handling
+// =========================================================================
+
+#[test]
+fn test_empty_table_handling() {
+    let mut model = ParsedModel::new();
+
+    let empty_table = Table::new("empty".to_string());
+    model.add_table(empty_table);
+
+    let calculator = ArrayCalculator::new(model);
+    // Should not panic with empty table
+    let result = calculator.calculate_all();
+    assert!(result.is_ok());
+}
+
+// =========================================================================
+// Complex Multi-Step Table Calculation
+// =========================================================================
+
+#[test]
+fn test_profit_calculation() {
+    let mut model = ParsedModel::new();
+
+    let mut income = Table::new("income".to_string());
     income.add_column(Column::new(
         "revenue".to_string(),
         ColumnValue::Number(vec![1000.0, 2000.0, 1500.0]),
@@ -4412,21 +4965,87 @@ fn test_countif_text_with_equal_prefix() {
     assert!((count - 2.0).abs() < 0.01);
 }
 
-// =========================================================================
-// Error Handling Tests
-// =========================================================================
-
-#[test]
-fn test_invalid_column_reference() {
-    use crate::types::Variable;
-
+fn wildcard_products_model(criteria: &[(&str, &str)]) -> ParsedModel {
     let mut model = ParsedModel::new();
-    model.add_scalar(
-        "bad_ref".to_string(),
-        Variable::new(
-            "bad_ref".to_string(),
-            None,
-            Some("=SUM(nonexistent_table.column)".to_string()),
+
+    let mut table = Table::new("products".to_string());
+    table.add_column(Column::new(
+        "name".to_string(),
+        ColumnValue::Text(vec![
+            "Apple".to_string(),
+            "Applet".to_string(),
+            "Pineapple".to_string(),
+            "Ample".to_string(),
+            "5*Star".to_string(),
+        ]),
+    ));
+    table.add_column(Column::new(
+        "sales".to_string(),
+        ColumnValue::Number(vec![10.0, 20.0, 30.0, 40.0, 50.0]),
+    ));
+    model.add_table(table);
+
+    for (name, formula) in criteria {
+        model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), None, Some(formula.to_string())),
+        );
+    }
+    model
+}
+
+#[test]
+fn test_countif_wildcard_prefix_and_suffix() {
+    let model = wildcard_products_model(&[
+        ("prefix", "=COUNTIF(products.name, \"App*\")"),
+        ("suffix", "=SUMIF(products.name, \"*ple\", products.sales)"),
+        ("not_prefix", "=COUNTIF(products.name, \"<>App*\")"),
+    ]);
+
+    let result = ArrayCalculator::new(model).calculate_all().unwrap();
+
+    // Apple, Applet
+    assert_eq!(result.scalars["prefix"].value, Some(2.0));
+    // Apple + Pineapple + Ample
+    assert_eq!(result.scalars["suffix"].value, Some(80.0));
+    assert_eq!(result.scalars["not_prefix"].value, Some(3.0));
+}
+
+#[test]
+fn test_countif_wildcard_single_char_and_escape() {
+    let model = wildcard_products_model(&[
+        ("single", "=COUNTIF(products.name, \"A?ple\")"),
+        ("escaped", "=COUNTIF(products.name, \"5~*Star\")"),
+        (
+            "ifs",
+            "=AVERAGEIFS(products.sales, products.name, \"A*\", products.sales, \">15\")",
+        ),
+    ]);
+
+    let result = ArrayCalculator::new(model).calculate_all().unwrap();
+
+    // Apple, Ample - but not Applet
+    assert_eq!(result.scalars["single"].value, Some(2.0));
+    assert_eq!(result.scalars["escaped"].value, Some(1.0));
+    // Applet and Ample
+    assert_eq!(result.scalars["ifs"].value, Some(30.0));
+}
+
+// =========================================================================
+// Error Handling Tests
+// =========================================================================
+
+#[test]
+fn test_invalid_column_reference() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "bad_ref".to_string(),
+        Variable::new(
+            "bad_ref".to_string(),
+            None,
+            Some("=SUM(nonexistent_table.column)".to_string()),
         ),
     );
 
@@ -5202,6 +5821,174 @@ fn test_correl_negative() {
     assert!((correl - (-1.0)).abs() < 0.01);
 }
 
+// =========================================================================
+// Regression Tests (TREND, GROWTH, FORECAST, SLOPE, INTERCEPT)
+// =========================================================================
+
+fn regression_model(known_y: Vec<f64>, new_x: Vec<f64>, formula: &str) -> ParsedModel {
+    let mut model = ParsedModel::new();
+
+    let mut history = Table::new("history".to_string());
+    history.add_column(Column::new(
+        "x".to_string(),
+        ColumnValue::Number((0..known_y.len()).map(|i| i as f64).collect()),
+    ));
+    history.add_column(Column::new("y".to_string(), ColumnValue::Number(known_y)));
+    model.add_table(history);
+
+    let mut forecast = Table::new("forecast".to_string());
+    forecast.add_column(Column::new("x".to_string(), ColumnValue::Number(new_x)));
+    forecast.add_row_formula("projected".to_string(), formula.to_string());
+    model.add_table(forecast);
+
+    model
+}
+
+fn projected(result: &ParsedModel) -> Vec<f64> {
+    match &result.tables["forecast"].columns["projected"].values {
+        ColumnValue::Number(values) => values.clone(),
+        other => panic!("Expected numbers, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_trend_linear_projection() {
+    // y = 2x + 3
+    let model = regression_model(
+        vec![3.0, 5.0, 7.0, 9.0, 11.0],
+        vec![5.0, 6.0, 10.0],
+        "=TREND(history.y, history.x, x)",
+    );
+    let result = ArrayCalculator::new(model)
+        .calculate_all()
+        .expect("Should calculate");
+    assert_eq!(projected(&result), vec![13.0, 15.0, 23.0]);
+}
+
+#[test]
+fn test_trend_scalar_point() {
+    use crate::types::Variable;
+
+    let mut model = regression_model(
+        vec![3.0, 5.0, 7.0, 9.0, 11.0],
+        vec![5.0],
+        "=TREND(history.y, history.x, x)",
+    );
+    model.add_scalar(
+        "next".to_string(),
+        Variable::new(
+            "next".to_string(),
+            None,
+            Some("=TREND(history.y, history.x, 7)".to_string()),
+        ),
+    );
+    let result = ArrayCalculator::new(model)
+        .calculate_all()
+        .expect("Should calculate");
+    let next = result.scalars["next"].value.unwrap();
+    assert!((next - 17.0).abs() < 1e-9, "got {}", next);
+}
+
+#[test]
+fn test_growth_exponential_projection() {
+    // y = 3 * 2^x
+    let model = regression_model(
+        vec![3.0, 6.0, 12.0, 24.0, 48.0],
+        vec![5.0, 6.0],
+        "=GROWTH(history.y, history.x, x)",
+    );
+    let result = ArrayCalculator::new(model)
+        .calculate_all()
+        .expect("Should calculate");
+    let values = projected(&result);
+    assert!((values[0] - 96.0).abs() < 1e-9, "got {:?}", values);
+    assert!((values[1] - 192.0).abs() < 1e-9, "got {:?}", values);
+}
+
+#[test]
+fn test_slope_intercept_forecast_on_known_line() {
+    use crate::types::Variable;
+
+    // y = 2x + 3
+    let mut model = regression_model(
+        vec![3.0, 5.0, 7.0, 9.0, 11.0],
+        vec![5.0],
+        "=TREND(history.y, history.x, x)",
+    );
+    model.add_scalar(
+        "inputs.next_x".to_string(),
+        Variable::new("inputs.next_x".to_string(), Some(8.0), None),
+    );
+    for (name, formula) in [
+        ("slope", "=SLOPE(history.y, history.x)"),
+        ("intercept", "=INTERCEPT(history.y, history.x)"),
+        ("forecast", "=FORECAST(10, history.y, history.x)"),
+        (
+            "forecast_scalar",
+            "=FORECAST(inputs.next_x, history.y, history.x)",
+        ),
+    ] {
+        model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), None, Some(formula.to_string())),
+        );
+    }
+
+    let result = ArrayCalculator::new(model)
+        .calculate_all()
+        .expect("Should calculate");
+    let value = |name: &str| result.scalars[name].value.unwrap();
+    assert!((value("slope") - 2.0).abs() < 1e-9);
+    assert!((value("intercept") - 3.0).abs() < 1e-9);
+    assert!((value("forecast") - 23.0).abs() < 1e-9);
+    assert!((value("forecast_scalar") - 19.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_slope_constant_x_error() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "x".to_string(),
+        ColumnValue::Number(vec![1.0, 1.0, 1.0]),
+    ));
+    data.add_column(Column::new(
+        "y".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0, 3.0]),
+    ));
+    model.add_table(data);
+    model.add_scalar(
+        "slope".to_string(),
+        Variable::new(
+            "slope".to_string(),
+            None,
+            Some("=SLOPE(data.y, data.x)".to_string()),
+        ),
+    );
+
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert!(err.to_string().contains("all equal"), "{}", err);
+}
+
+#[test]
+fn test_trend_mismatched_lengths_error() {
+    // known_y has 3 rows, known_x (the forecast table's own x) has 2
+    let model = regression_model(
+        vec![3.0, 5.0, 7.0],
+        vec![1.0, 2.0],
+        "=TREND(history.y, x, x)",
+    );
+
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert!(
+        err.to_string().contains("must have the same length"),
+        "{}",
+        err
+    );
+}
+
 // =========================================================================
 // Multiple Criteria Tests (SUMIFS, COUNTIFS, AVERAGEIFS)
 // =========================================================================
@@ -6081,9 +6868,84 @@ fn test_boolean_column_result() {
     model.add_table(data);
 
     let calculator = ArrayCalculator::new(model);
-    let result = calculator.calculate_all();
-    // Should handle boolean results
-    assert!(result.is_ok() || result.is_err()); // May work or error, exercising the path
+    let result = calculator
+        .calculate_all()
+        .expect("Boolean results should calculate");
+    let is_large = &result.tables["data"].columns["is_large"].values;
+    assert_eq!(is_large, &ColumnValue::Boolean(vec![false, true, true]));
+}
+
+#[test]
+fn test_boolean_column_coerces_in_arithmetic() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "flag".to_string(),
+        ColumnValue::Boolean(vec![true, false, true, true]),
+    ));
+    data.row_formulas
+        .insert("points".to_string(), "=flag * 10".to_string());
+    model.add_table(data);
+
+    // SUM of a Boolean column counts the TRUE rows
+    model.add_scalar(
+        "true_count".to_string(),
+        Variable::new(
+            "true_count".to_string(),
+            None,
+            Some("=SUM(data.flag)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    assert_eq!(result.scalars["true_count"].value, Some(3.0));
+    let points = &result.tables["data"].columns["points"].values;
+    assert_eq!(points, &ColumnValue::Number(vec![10.0, 0.0, 10.0, 10.0]));
+}
+
+#[test]
+fn test_true_literal_as_if_condition() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "value".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0]),
+    ));
+    data.row_formulas
+        .insert("doubled".to_string(), "=IF(TRUE, value * 2, 0)".to_string());
+    model.add_table(data);
+
+    for (name, formula) in [
+        ("picked", "=IF(TRUE, 1, 2)"),
+        ("skipped", "=IF(FALSE, 1, 2)"),
+        ("two", "=TRUE + TRUE"),
+    ] {
+        model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), None, Some(formula.to_string())),
+        );
+    }
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    let doubled = &result.tables["data"].columns["doubled"].values;
+    assert_eq!(doubled, &ColumnValue::Number(vec![2.0, 4.0]));
+    assert_eq!(result.scalars["picked"].value, Some(1.0));
+    assert_eq!(result.scalars["skipped"].value, Some(2.0));
+    assert_eq!(result.scalars["two"].value, Some(2.0));
 }
 
 #[test]
@@ -11823,3 +12685,2468 @@ fn test_rowwise_if_formula() {
     let result = calculator.calculate_all();
     assert!(result.is_ok());
 }
+
+// ============================================================================
+// Error Handler Tests (IFERROR, IFNA)
+// ============================================================================
+
+#[test]
+fn test_iferror_rowwise_divide_by_zero() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0, 300.0]),
+    ));
+    data.add_column(Column::new(
+        "units".to_string(),
+        ColumnValue::Number(vec![10.0, 0.0, 20.0]),
+    ));
+    data.add_row_formula(
+        "price".to_string(),
+        "=IFERROR(revenue / units, 0)".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let price = result
+        .tables
+        .get("data")
+        .unwrap()
+        .columns
+        .get("price")
+        .unwrap();
+    match &price.values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 10.0);
+            assert_eq!(nums[1], 0.0); // Division by zero replaced by fallback
+            assert_eq!(nums[2], 15.0);
+        }
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_iferror_text_fallback() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new("a".to_string(), ColumnValue::Number(vec![1.0])));
+    data.add_column(Column::new("b".to_string(), ColumnValue::Number(vec![0.0])));
+    data.add_row_formula("ratio".to_string(), "=IFERROR(a / b, \"N/A\")".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let ratio = result
+        .tables
+        .get("data")
+        .unwrap()
+        .columns
+        .get("ratio")
+        .unwrap();
+    match &ratio.values {
+        ColumnValue::Text(texts) => assert_eq!(texts[0], "N/A"),
+        _ => panic!("Expected Text array"),
+    }
+}
+
+#[test]
+fn test_row_formula_text_literal_escapes_quotes() {
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "label".to_string(),
+        ColumnValue::Text(vec!["Say \"hi\"".to_string()]),
+    ));
+    let calculator = ArrayCalculator::new(ParsedModel::new());
+
+    assert_eq!(
+        calculator.eval_row_formula("label", 0, &data).unwrap(),
+        "\"Say \"\"hi\"\"\""
+    );
+}
+
+#[test]
+fn test_ifna_rowwise_failed_match() {
+    let mut model = ParsedModel::new();
+
+    let mut products = Table::new("products".to_string());
+    products.add_column(Column::new(
+        "product_id".to_string(),
+        ColumnValue::Number(vec![101.0, 102.0, 103.0]),
+    ));
+    model.add_table(products);
+
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "lookup_id".to_string(),
+        ColumnValue::Number(vec![102.0, 999.0]),
+    ));
+    sales.add_row_formula(
+        "position".to_string(),
+        "=IFNA(MATCH(lookup_id, products.product_id, 0), -1)".to_string(),
+    );
+    model.add_table(sales);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let position = result
+        .tables
+        .get("sales")
+        .unwrap()
+        .columns
+        .get("position")
+        .unwrap();
+    match &position.values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 2.0);
+            assert_eq!(nums[1], -1.0); // 999 not found, fallback used
+        }
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_ifna_does_not_catch_divide_by_zero() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new("a".to_string(), ColumnValue::Number(vec![1.0])));
+    data.add_column(Column::new("b".to_string(), ColumnValue::Number(vec![0.0])));
+    data.add_row_formula("ratio".to_string(), "=IFNA(a / b, 0)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all();
+    // IFNA only catches "not found" errors
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_iferror_passthrough() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "value".to_string(),
+        ColumnValue::Number(vec![10.0, 20.0]),
+    ));
+    data.add_row_formula(
+        "doubled".to_string(),
+        "=IFERROR(value * 2, -1) + 1".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let doubled = result
+        .tables
+        .get("data")
+        .unwrap()
+        .columns
+        .get("doubled")
+        .unwrap();
+    match &doubled.values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 21.0);
+            assert_eq!(nums[1], 41.0);
+        }
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_iferror_scalar_divide_by_zero() {
+    use crate::types::Variable;
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "inputs.revenue".to_string(),
+        Variable::new("inputs.revenue".to_string(), Some(1000.0), None),
+    );
+    model.add_scalar(
+        "inputs.units".to_string(),
+        Variable::new("inputs.units".to_string(), Some(0.0), None),
+    );
+    model.add_scalar(
+        "outputs.price".to_string(),
+        Variable::new(
+            "outputs.price".to_string(),
+            None,
+            Some("=IFERROR(inputs.revenue / inputs.units, 0)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let price = result.scalars.get("outputs.price").unwrap();
+    assert_eq!(price.value, Some(0.0));
+}
+
+// ============================================================================
+// TEXT Number Formatting Tests
+// ============================================================================
+
+#[test]
+fn test_text_format_percent() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "rate".to_string(),
+        ColumnValue::Number(vec![0.1234, 0.5, 1.0]),
+    ));
+    data.add_row_formula("pct_2dp".to_string(), "=TEXT(rate, \"0.00%\")".to_string());
+    data.add_row_formula("pct".to_string(), "=TEXT(rate, \"0%\")".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("data").unwrap();
+
+    match &table.columns.get("pct_2dp").unwrap().values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "12.34%");
+            assert_eq!(texts[1], "50.00%");
+            assert_eq!(texts[2], "100.00%");
+        }
+        _ => panic!("Expected Text array"),
+    }
+    match &table.columns.get("pct").unwrap().values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "12%");
+            assert_eq!(texts[1], "50%");
+        }
+        _ => panic!("Expected Text array"),
+    }
+}
+
+#[test]
+fn test_text_format_currency() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "amount".to_string(),
+        ColumnValue::Number(vec![1234567.891, 5.0, -42.5]),
+    ));
+    data.add_row_formula(
+        "label".to_string(),
+        "=TEXT(amount, \"$#,##0.00\")".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let label = result
+        .tables
+        .get("data")
+        .unwrap()
+        .columns
+        .get("label")
+        .unwrap();
+    match &label.values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "$1,234,567.89");
+            assert_eq!(texts[1], "$5.00");
+            assert_eq!(texts[2], "-$42.50");
+        }
+        _ => panic!("Expected Text array"),
+    }
+}
+
+#[test]
+fn test_text_format_thousands_grouping() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "units".to_string(),
+        ColumnValue::Number(vec![999.0, 1000.0, 1234567.4]),
+    ));
+    data.add_row_formula("grouped".to_string(), "=TEXT(units, \"#,##0\")".to_string());
+    data.add_row_formula("fixed".to_string(), "=TEXT(units, \"0.00\")".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("data").unwrap();
+
+    match &table.columns.get("grouped").unwrap().values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "999");
+            assert_eq!(texts[1], "1,000");
+            assert_eq!(texts[2], "1,234,567");
+        }
+        _ => panic!("Expected Text array"),
+    }
+    match &table.columns.get("fixed").unwrap().values {
+        ColumnValue::Text(texts) => assert_eq!(texts[0], "999.00"),
+        _ => panic!("Expected Text array"),
+    }
+}
+
+#[test]
+fn test_text_format_invalid_pattern() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "value".to_string(),
+        ColumnValue::Number(vec![1.0]),
+    ));
+    data.add_row_formula("label".to_string(), "=TEXT(value, \"0.0.0\")".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let err = calculator.calculate_all().unwrap_err();
+    assert!(err.to_string().contains("0.0.0"));
+}
+
+// ============================================================================
+// SUBSTITUTE / REPLACE Tests
+// ============================================================================
+
+#[test]
+fn test_substitute_replace_all() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "label".to_string(),
+        ColumnValue::Text(vec!["a-b-c".to_string(), "no_dash".to_string()]),
+    ));
+    data.add_row_formula(
+        "cleaned".to_string(),
+        "=SUBSTITUTE(label, \"-\", \"_\")".to_string(),
+    );
+    data.add_row_formula(
+        "unchanged".to_string(),
+        "=SUBSTITUTE(label, \"\", \"_\")".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("data").unwrap();
+
+    match &table.columns.get("cleaned").unwrap().values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "a_b_c");
+            assert_eq!(texts[1], "no_dash");
+        }
+        _ => panic!("Expected Text array"),
+    }
+    // Empty old_text leaves the text unchanged
+    match &table.columns.get("unchanged").unwrap().values {
+        ColumnValue::Text(texts) => assert_eq!(texts[0], "a-b-c"),
+        _ => panic!("Expected Text array"),
+    }
+}
+
+#[test]
+fn test_substitute_nth_instance() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "label".to_string(),
+        ColumnValue::Text(vec!["a-b-c".to_string(), "x".to_string()]),
+    ));
+    data.add_row_formula(
+        "second".to_string(),
+        "=SUBSTITUTE(label, \"-\", \"+\", 2)".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let second = result
+        .tables
+        .get("data")
+        .unwrap()
+        .columns
+        .get("second")
+        .unwrap();
+    match &second.values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "a-b+c"); // Only the 2nd dash is replaced
+            assert_eq!(texts[1], "x");
+        }
+        _ => panic!("Expected Text array"),
+    }
+}
+
+#[test]
+fn test_replace_position() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "sku".to_string(),
+        ColumnValue::Text(vec!["ABC-123".to_string(), "XY".to_string()]),
+    ));
+    data.add_row_formula(
+        "masked".to_string(),
+        "=REPLACE(sku, 1, 3, \"***\")".to_string(),
+    );
+    data.add_row_formula(
+        "appended".to_string(),
+        "=REPLACE(sku, 50, 2, \"!\")".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("data").unwrap();
+
+    match &table.columns.get("masked").unwrap().values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "***-123");
+            assert_eq!(texts[1], "***"); // Length clamped to the text
+        }
+        _ => panic!("Expected Text array"),
+    }
+    // Start past the end clamps to an append
+    match &table.columns.get("appended").unwrap().values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "ABC-123!");
+            assert_eq!(texts[1], "XY!");
+        }
+        _ => panic!("Expected Text array"),
+    }
+}
+
+// ============================================================================
+// FIND / SEARCH Tests
+// ============================================================================
+
+#[test]
+fn test_find_vs_search_case_sensitivity() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "sku".to_string(),
+        ColumnValue::Text(vec!["abc-XYZ-xyz".to_string()]),
+    ));
+    data.add_row_formula("find_pos".to_string(), "=FIND(\"xyz\", sku)".to_string());
+    data.add_row_formula(
+        "search_pos".to_string(),
+        "=SEARCH(\"xyz\", sku)".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("data").unwrap();
+
+    // FIND is case-sensitive and skips "XYZ"
+    match &table.columns.get("find_pos").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums[0], 9.0),
+        _ => panic!("Expected Number array"),
+    }
+    // SEARCH ignores case and matches "XYZ"
+    match &table.columns.get("search_pos").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums[0], 5.0),
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_find_start_position() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "sku".to_string(),
+        ColumnValue::Text(vec!["AB-12-CD".to_string(), "A-B".to_string()]),
+    ));
+    data.add_row_formula("first".to_string(), "=FIND(\"-\", sku)".to_string());
+    data.add_row_formula(
+        "second".to_string(),
+        "=IFERROR(FIND(\"-\", sku, 4), 0)".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("data").unwrap();
+
+    match &table.columns.get("first").unwrap().values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 3.0);
+            assert_eq!(nums[1], 2.0);
+        }
+        _ => panic!("Expected Number array"),
+    }
+    match &table.columns.get("second").unwrap().values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 6.0); // Search starts after the first dash
+            assert_eq!(nums[1], 0.0); // Not found, caught by IFERROR
+        }
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_find_not_found_error() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "sku".to_string(),
+        ColumnValue::Text(vec!["ABC".to_string()]),
+    ));
+    data.add_row_formula("pos".to_string(), "=FIND(\"z\", sku)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let err = calculator.calculate_all().unwrap_err();
+    assert!(err.to_string().contains("not found"));
+}
+
+// ============================================================================
+// TEXTJOIN Tests
+// ============================================================================
+
+#[test]
+fn test_textjoin_skip_empty() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("addresses".to_string());
+    data.add_column(Column::new(
+        "street".to_string(),
+        ColumnValue::Text(vec!["1 Main St".to_string(), "9 Elm Rd".to_string()]),
+    ));
+    data.add_column(Column::new(
+        "unit".to_string(),
+        ColumnValue::Text(vec!["".to_string(), "Apt 4".to_string()]),
+    ));
+    data.add_column(Column::new(
+        "city".to_string(),
+        ColumnValue::Text(vec!["Springfield".to_string(), "Shelbyville".to_string()]),
+    ));
+    data.add_row_formula(
+        "full".to_string(),
+        "=TEXTJOIN(\", \", TRUE, street, unit, city)".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let full = result
+        .tables
+        .get("addresses")
+        .unwrap()
+        .columns
+        .get("full")
+        .unwrap();
+    match &full.values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "1 Main St, Springfield");
+            assert_eq!(texts[1], "9 Elm Rd, Apt 4, Shelbyville");
+        }
+        _ => panic!("Expected Text array"),
+    }
+}
+
+#[test]
+fn test_textjoin_keep_empty() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("addresses".to_string());
+    data.add_column(Column::new(
+        "street".to_string(),
+        ColumnValue::Text(vec!["1 Main St".to_string(), "9 Elm Rd".to_string()]),
+    ));
+    data.add_column(Column::new(
+        "unit".to_string(),
+        ColumnValue::Text(vec!["".to_string(), "Apt 4".to_string()]),
+    ));
+    data.add_column(Column::new(
+        "city".to_string(),
+        ColumnValue::Text(vec!["Springfield".to_string(), "Shelbyville".to_string()]),
+    ));
+    data.add_row_formula(
+        "full".to_string(),
+        "=TEXTJOIN(\"|\", FALSE, street, unit, city)".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let full = result
+        .tables
+        .get("addresses")
+        .unwrap()
+        .columns
+        .get("full")
+        .unwrap();
+    match &full.values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "1 Main St||Springfield");
+            assert_eq!(texts[1], "9 Elm Rd|Apt 4|Shelbyville");
+        }
+        _ => panic!("Expected Text array"),
+    }
+}
+
+// ============================================================================
+// WEEKDAY / WEEKNUM Tests
+// ============================================================================
+
+#[test]
+fn test_weekday_return_types() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("schedule".to_string());
+    // 2024-01-01 is a Monday, 2024-01-07 is a Sunday
+    data.add_column(Column::new(
+        "day".to_string(),
+        ColumnValue::Date(vec!["2024-01-01".to_string(), "2024-01-07".to_string()]),
+    ));
+    data.add_row_formula("us".to_string(), "=WEEKDAY(day)".to_string());
+    data.add_row_formula("monday_start".to_string(), "=WEEKDAY(day, 2)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("schedule").unwrap();
+
+    match &table.columns.get("us").unwrap().values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 2.0); // Monday (Sunday=1)
+            assert_eq!(nums[1], 1.0); // Sunday
+        }
+        _ => panic!("Expected Number array"),
+    }
+    match &table.columns.get("monday_start").unwrap().values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 1.0); // Monday (Monday=1)
+            assert_eq!(nums[1], 7.0); // Sunday
+        }
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_weeknum_us_and_iso() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("schedule".to_string());
+    data.add_column(Column::new(
+        "day".to_string(),
+        ColumnValue::Date(vec![
+            "2024-01-01".to_string(),
+            "2024-01-07".to_string(),
+            "2021-01-01".to_string(),
+            "2024-12-30".to_string(),
+        ]),
+    ));
+    data.add_row_formula("us_week".to_string(), "=WEEKNUM(day)".to_string());
+    data.add_row_formula("iso_week".to_string(), "=WEEKNUM(day, 21)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("schedule").unwrap();
+
+    match &table.columns.get("us_week").unwrap().values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 1.0);
+            assert_eq!(nums[1], 2.0); // US weeks start on Sunday
+            assert_eq!(nums[2], 1.0);
+            assert_eq!(nums[3], 53.0);
+        }
+        _ => panic!("Expected Number array"),
+    }
+    match &table.columns.get("iso_week").unwrap().values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 1.0);
+            assert_eq!(nums[1], 1.0); // ISO weeks run Monday-Sunday
+            assert_eq!(nums[2], 53.0); // Belongs to the last ISO week of 2020
+            assert_eq!(nums[3], 1.0); // Belongs to ISO week 1 of 2025
+        }
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_weekday_invalid_return_type() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("schedule".to_string());
+    data.add_column(Column::new(
+        "day".to_string(),
+        ColumnValue::Date(vec!["2024-01-01".to_string()]),
+    ));
+    data.add_row_formula("bad".to_string(), "=WEEKDAY(day, 9)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    assert!(calculator.calculate_all().is_err());
+}
+
+// ============================================================================
+// WORKDAY Tests
+// ============================================================================
+
+#[test]
+fn test_workday_forward_across_weekend() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("tasks".to_string());
+    // 2024-01-04 is a Thursday
+    data.add_column(Column::new(
+        "start".to_string(),
+        ColumnValue::Date(vec!["2024-01-04".to_string(), "2024-01-08".to_string()]),
+    ));
+    data.add_row_formula("due".to_string(), "=WORKDAY(start, 5)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let due = result
+        .tables
+        .get("tasks")
+        .unwrap()
+        .columns
+        .get("due")
+        .unwrap();
+    match &due.values {
+        ColumnValue::Text(dates) | ColumnValue::Date(dates) => {
+            assert_eq!(dates[0], "2024-01-11"); // Skips Sat 6th and Sun 7th
+            assert_eq!(dates[1], "2024-01-15");
+        }
+        _ => panic!("Expected date strings"),
+    }
+}
+
+#[test]
+fn test_workday_negative_offset() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("tasks".to_string());
+    // 2024-01-08 is a Monday
+    data.add_column(Column::new(
+        "deadline".to_string(),
+        ColumnValue::Date(vec!["2024-01-08".to_string()]),
+    ));
+    data.add_row_formula("start".to_string(), "=WORKDAY(deadline, -3)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let start = result
+        .tables
+        .get("tasks")
+        .unwrap()
+        .columns
+        .get("start")
+        .unwrap();
+    match &start.values {
+        ColumnValue::Text(dates) | ColumnValue::Date(dates) => {
+            assert_eq!(dates[0], "2024-01-03"); // Fri 5th, Thu 4th, Wed 3rd
+        }
+        _ => panic!("Expected date strings"),
+    }
+}
+
+#[test]
+fn test_workday_with_holidays() {
+    let mut model = ParsedModel::new();
+
+    let mut holidays = Table::new("holidays".to_string());
+    holidays.add_column(Column::new(
+        "dates".to_string(),
+        ColumnValue::Date(vec!["2024-01-01".to_string(), "2024-01-03".to_string()]),
+    ));
+    model.add_table(holidays);
+
+    let mut data = Table::new("tasks".to_string());
+    data.add_column(Column::new(
+        "start".to_string(),
+        ColumnValue::Date(vec![
+            "2023-12-29".to_string(),
+            "2024-01-05".to_string(),
+            "2024-01-10".to_string(),
+        ]),
+    ));
+    data.add_row_formula(
+        "due".to_string(),
+        "=WORKDAY(start, 2, holidays.dates)".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let due = result
+        .tables
+        .get("tasks")
+        .unwrap()
+        .columns
+        .get("due")
+        .unwrap();
+    match &due.values {
+        ColumnValue::Text(dates) | ColumnValue::Date(dates) => {
+            assert_eq!(dates[0], "2024-01-04"); // Skips Jan 1 and Jan 3 holidays
+            assert_eq!(dates[1], "2024-01-09");
+            assert_eq!(dates[2], "2024-01-12");
+        }
+        _ => panic!("Expected date strings"),
+    }
+}
+
+#[test]
+fn test_workday_holidays_references_validated() {
+    let workday = |formula: &str| {
+        let mut model = ParsedModel::new();
+        let mut holidays = Table::new("holidays".to_string());
+        holidays.add_column(Column::new(
+            "dates".to_string(),
+            ColumnValue::Date(vec!["2024-01-01".to_string()]),
+        ));
+        model.add_table(holidays);
+
+        let mut data = Table::new("tasks".to_string());
+        data.add_column(Column::new(
+            "start".to_string(),
+            ColumnValue::Date(vec!["2023-12-29".to_string(), "2024-01-05".to_string()]),
+        ));
+        data.add_column(Column::new(
+            "offdays".to_string(),
+            ColumnValue::Date(vec!["2024-01-01".to_string(), "2024-01-08".to_string()]),
+        ));
+        data.add_row_formula("due".to_string(), formula.to_string());
+        model.add_table(data);
+        ArrayCalculator::new(model).calculate_all()
+    };
+
+    assert!(workday("=WORKDAY(start, 2, holidays.dates)").is_ok());
+    assert!(workday("=WORKDAY(start, 2, offdays)").is_ok());
+
+    // Typos are reported with the formula's other references
+    let err = workday("=WORKDAY(strat, 2, holidays.dates)").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Unknown reference 'strat'; did you mean 'start'?"));
+    let err = workday("=WORKDAY(start, 2, ofdays)").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Unknown reference 'ofdays'; did you mean 'offdays'?"));
+}
+
+// ============================================================================
+// NETWORKDAYS Holidays Tests
+// ============================================================================
+
+#[test]
+fn test_networkdays_with_holidays() {
+    let mut model = ParsedModel::new();
+
+    // 2024-01-03 is a Wednesday (inside the range), 2024-01-06 is a Saturday,
+    // 2024-02-01 is outside the range, and 2024-01-03 is listed twice
+    let mut holidays = Table::new("holidays".to_string());
+    holidays.add_column(Column::new(
+        "dates".to_string(),
+        ColumnValue::Date(vec![
+            "2024-01-03".to_string(),
+            "2024-01-06".to_string(),
+            "2024-02-01".to_string(),
+            "2024-01-03".to_string(),
+        ]),
+    ));
+    model.add_table(holidays);
+
+    let mut data = Table::new("periods".to_string());
+    data.add_column(Column::new(
+        "start".to_string(),
+        ColumnValue::Date(vec!["2024-01-01".to_string()]),
+    ));
+    data.add_column(Column::new(
+        "end".to_string(),
+        ColumnValue::Date(vec!["2024-01-12".to_string()]),
+    ));
+    data.add_row_formula(
+        "workdays".to_string(),
+        "=NETWORKDAYS(start, end)".to_string(),
+    );
+    data.add_row_formula(
+        "workdays_net".to_string(),
+        "=NETWORKDAYS(start, end, holidays.dates)".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("periods").unwrap();
+
+    match &table.columns.get("workdays").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums[0], 10.0),
+        _ => panic!("Expected Number array"),
+    }
+    // Only the weekday holiday inside the range is excluded, and only once
+    match &table.columns.get("workdays_net").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums[0], 9.0),
+        _ => panic!("Expected Number array"),
+    }
+}
+
+// ============================================================================
+// DAYS / DATEVALUE Tests
+// ============================================================================
+
+#[test]
+fn test_days_leap_year_span() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("spans".to_string());
+    data.add_column(Column::new(
+        "start".to_string(),
+        ColumnValue::Date(vec!["2024-02-01".to_string(), "2023-02-01".to_string()]),
+    ));
+    data.add_column(Column::new(
+        "finish".to_string(),
+        ColumnValue::Date(vec!["2024-03-01".to_string(), "2023-03-01".to_string()]),
+    ));
+    data.add_row_formula("days".to_string(), "=DAYS(finish, start)".to_string());
+    data.add_row_formula(
+        "year_days".to_string(),
+        "=DAYS(\"2025-01-01\", \"2024-01-01\")".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("spans").unwrap();
+
+    match &table.columns.get("days").unwrap().values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 29.0); // February 2024 has 29 days
+            assert_eq!(nums[1], 28.0);
+        }
+        _ => panic!("Expected Number array"),
+    }
+    match &table.columns.get("year_days").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums[0], 366.0),
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_datevalue_round_trip_year() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("imports".to_string());
+    data.add_column(Column::new(
+        "raw".to_string(),
+        ColumnValue::Text(vec!["2024-03-01".to_string(), "1999-12-31".to_string()]),
+    ));
+    data.add_row_formula("parsed".to_string(), "=DATEVALUE(raw)".to_string());
+    data.add_row_formula("yr".to_string(), "=YEAR(parsed)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("imports").unwrap();
+
+    match &table.columns.get("parsed").unwrap().values {
+        ColumnValue::Date(dates) => {
+            assert_eq!(dates[0], "2024-03-01");
+            assert_eq!(dates[1], "1999-12-31");
+        }
+        _ => panic!("Expected Date array"),
+    }
+    match &table.columns.get("yr").unwrap().values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 2024.0);
+            assert_eq!(nums[1], 1999.0);
+        }
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_datevalue_invalid_text() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("imports".to_string());
+    data.add_column(Column::new(
+        "raw".to_string(),
+        ColumnValue::Text(vec!["2024-02-30".to_string()]),
+    ));
+    data.add_row_formula("parsed".to_string(), "=DATEVALUE(raw)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let err = calculator.calculate_all().unwrap_err();
+    assert!(err.to_string().contains("2024-02-30"));
+}
+
+// ============================================================================
+// IFS Tests
+// ============================================================================
+
+#[test]
+fn test_ifs_three_branches() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("scores".to_string());
+    data.add_column(Column::new(
+        "score".to_string(),
+        ColumnValue::Number(vec![95.0, 80.0, 40.0]),
+    ));
+    data.add_row_formula(
+        "grade".to_string(),
+        "=IFS(score >= 90, \"A\", score >= 70, \"B\", TRUE, \"F\")".to_string(),
+    );
+    data.add_row_formula(
+        "bonus".to_string(),
+        "=IFS(score >= 90, score * 2, score >= 70, score, TRUE, 0)".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("scores").unwrap();
+
+    match &table.columns.get("grade").unwrap().values {
+        ColumnValue::Text(texts) => {
+            assert_eq!(texts[0], "A");
+            assert_eq!(texts[1], "B");
+            assert_eq!(texts[2], "F"); // TRUE acts as the default arm
+        }
+        _ => panic!("Expected Text array"),
+    }
+    match &table.columns.get("bonus").unwrap().values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 190.0);
+            assert_eq!(nums[1], 80.0);
+            assert_eq!(nums[2], 0.0);
+        }
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_ifs_no_matching_condition() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("scores".to_string());
+    data.add_column(Column::new(
+        "score".to_string(),
+        ColumnValue::Number(vec![95.0, 40.0]),
+    ));
+    data.add_row_formula(
+        "grade".to_string(),
+        "=IFS(score >= 90, \"A\", score >= 70, \"B\")".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let err = calculator.calculate_all().unwrap_err();
+    assert!(err.to_string().contains("no matching condition in IFS"));
+}
+
+// ============================================================================
+// Comparison → Boolean Column Tests
+// ============================================================================
+
+#[test]
+fn test_comparison_produces_boolean_column() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("pl".to_string());
+    data.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 50.0, 80.0]),
+    ));
+    data.add_column(Column::new(
+        "expenses".to_string(),
+        ColumnValue::Number(vec![60.0, 70.0, 80.0]),
+    ));
+    data.add_row_formula("profitable".to_string(), "=revenue > expenses".to_string());
+    data.add_row_formula("break_even".to_string(), "=revenue = expenses".to_string());
+    data.add_row_formula("not_loss".to_string(), "=revenue >= expenses".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("pl").unwrap();
+
+    match &table.columns.get("profitable").unwrap().values {
+        ColumnValue::Boolean(flags) => assert_eq!(flags, &vec![true, false, false]),
+        _ => panic!("Expected Boolean array"),
+    }
+    match &table.columns.get("break_even").unwrap().values {
+        ColumnValue::Boolean(flags) => assert_eq!(flags, &vec![false, false, true]),
+        _ => panic!("Expected Boolean array"),
+    }
+    match &table.columns.get("not_loss").unwrap().values {
+        ColumnValue::Boolean(flags) => assert_eq!(flags, &vec![true, false, true]),
+        _ => panic!("Expected Boolean array"),
+    }
+}
+
+#[test]
+fn test_sum_boolean_column() {
+    use crate::types::Variable;
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("pl".to_string());
+    data.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 50.0, 80.0, 90.0]),
+    ));
+    data.add_column(Column::new(
+        "expenses".to_string(),
+        ColumnValue::Number(vec![60.0, 70.0, 20.0, 95.0]),
+    ));
+    data.add_row_formula("profitable".to_string(), "=revenue > expenses".to_string());
+    model.add_table(data);
+    model.add_scalar(
+        "profitable_months".to_string(),
+        Variable::new(
+            "profitable_months".to_string(),
+            None,
+            Some("=SUM(pl.profitable)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    // TRUE counts as 1, FALSE as 0
+    let count = result.scalars.get("profitable_months").unwrap().value;
+    assert_eq!(count, Some(2.0));
+}
+
+// ============================================================================
+// ISBLANK / ISNUMBER / ISTEXT Tests
+// ============================================================================
+
+#[test]
+fn test_type_checks_on_text_column() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("imports".to_string());
+    data.add_column(Column::new(
+        "label".to_string(),
+        ColumnValue::Text(vec!["42".to_string(), "".to_string(), "abc".to_string()]),
+    ));
+    data.add_row_formula("is_num".to_string(), "=ISNUMBER(label)".to_string());
+    data.add_row_formula("is_text".to_string(), "=ISTEXT(label)".to_string());
+    data.add_row_formula("is_blank".to_string(), "=ISBLANK(label)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("imports").unwrap();
+
+    // Text cells are never numbers, even when they look numeric
+    match &table.columns.get("is_num").unwrap().values {
+        ColumnValue::Boolean(flags) => assert_eq!(flags, &vec![false, false, false]),
+        _ => panic!("Expected Boolean array"),
+    }
+    match &table.columns.get("is_text").unwrap().values {
+        ColumnValue::Boolean(flags) => assert_eq!(flags, &vec![true, false, true]),
+        _ => panic!("Expected Boolean array"),
+    }
+    match &table.columns.get("is_blank").unwrap().values {
+        ColumnValue::Boolean(flags) => assert_eq!(flags, &vec![false, true, false]),
+        _ => panic!("Expected Boolean array"),
+    }
+}
+
+#[test]
+fn test_type_checks_on_number_column() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("imports".to_string());
+    data.add_column(Column::new(
+        "amount".to_string(),
+        ColumnValue::Number(vec![0.0, 12.5]),
+    ));
+    data.add_row_formula("is_num".to_string(), "=ISNUMBER(amount)".to_string());
+    data.add_row_formula("is_text".to_string(), "=ISTEXT(amount)".to_string());
+    data.add_row_formula("is_blank".to_string(), "=ISBLANK(amount)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("imports").unwrap();
+
+    match &table.columns.get("is_num").unwrap().values {
+        ColumnValue::Boolean(flags) => assert_eq!(flags, &vec![true, true]),
+        _ => panic!("Expected Boolean array"),
+    }
+    match &table.columns.get("is_text").unwrap().values {
+        ColumnValue::Boolean(flags) => assert_eq!(flags, &vec![false, false]),
+        _ => panic!("Expected Boolean array"),
+    }
+    match &table.columns.get("is_blank").unwrap().values {
+        ColumnValue::Boolean(flags) => assert_eq!(flags, &vec![false, false]),
+        _ => panic!("Expected Boolean array"),
+    }
+}
+
+// ============================================================================
+// COUNTA / COUNTBLANK Tests
+// ============================================================================
+
+#[test]
+fn test_counta_countblank_text_with_blanks() {
+    use crate::types::Variable;
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("sheet".to_string());
+    data.add_column(Column::new(
+        "notes".to_string(),
+        ColumnValue::Text(vec![
+            "a".to_string(),
+            "".to_string(),
+            "b".to_string(),
+            "".to_string(),
+            "c".to_string(),
+        ]),
+    ));
+    model.add_table(data);
+    model.add_scalar(
+        "filled".to_string(),
+        Variable::new(
+            "filled".to_string(),
+            None,
+            Some("=COUNTA(sheet.notes)".to_string()),
+        ),
+    );
+    model.add_scalar(
+        "blank".to_string(),
+        Variable::new(
+            "blank".to_string(),
+            None,
+            Some("=COUNTBLANK(sheet.notes)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    assert_eq!(result.scalars.get("filled").unwrap().value, Some(3.0));
+    assert_eq!(result.scalars.get("blank").unwrap().value, Some(2.0));
+}
+
+#[test]
+fn test_counta_countblank_number_column() {
+    use crate::types::Variable;
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("sheet".to_string());
+    data.add_column(Column::new(
+        "amounts".to_string(),
+        ColumnValue::Number(vec![0.0, 10.0, 20.0, 30.0]),
+    ));
+    model.add_table(data);
+    model.add_scalar(
+        "filled".to_string(),
+        Variable::new(
+            "filled".to_string(),
+            None,
+            Some("=COUNTA(sheet.amounts)".to_string()),
+        ),
+    );
+    model.add_scalar(
+        "blank".to_string(),
+        Variable::new(
+            "blank".to_string(),
+            None,
+            Some("=COUNTBLANK(sheet.amounts)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    // Zero is a value, not a blank
+    assert_eq!(result.scalars.get("filled").unwrap().value, Some(4.0));
+    assert_eq!(result.scalars.get("blank").unwrap().value, Some(0.0));
+}
+
+// ============================================================================
+// SORT / SORTBY
+// ============================================================================
+
+#[test]
+fn test_sort_numbers_ascending_and_descending() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("sales".to_string());
+    data.add_column(Column::new(
+        "amount".to_string(),
+        ColumnValue::Number(vec![30.0, 10.0, 40.0, 20.0]),
+    ));
+    data.add_row_formula("sorted".to_string(), "=SORT(amount)".to_string());
+    data.add_row_formula("sorted_desc".to_string(), "=SORT(amount, -1)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("sales").unwrap();
+
+    match &table.columns.get("sorted").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums, &vec![10.0, 20.0, 30.0, 40.0]),
+        _ => panic!("Expected Number array"),
+    }
+    match &table.columns.get("sorted_desc").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums, &vec![40.0, 30.0, 20.0, 10.0]),
+        _ => panic!("Expected Number array"),
+    }
+    // Source column keeps its original order
+    match &table.columns.get("amount").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums, &vec![30.0, 10.0, 40.0, 20.0]),
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_sortby_text_keyed_on_numbers() {
+    let mut model = ParsedModel::new();
+    let mut reps = Table::new("reps".to_string());
+    reps.add_column(Column::new(
+        "name".to_string(),
+        ColumnValue::Text(vec![
+            "Carol".to_string(),
+            "Alice".to_string(),
+            "Bob".to_string(),
+        ]),
+    ));
+    reps.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![500.0, 900.0, 200.0]),
+    ));
+    model.add_table(reps);
+
+    let mut ranking = Table::new("ranking".to_string());
+    ranking.add_column(Column::new(
+        "rank".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0, 3.0]),
+    ));
+    ranking.add_row_formula(
+        "name".to_string(),
+        "=SORTBY(reps.name, reps.revenue, -1)".to_string(),
+    );
+    ranking.add_row_formula(
+        "revenue".to_string(),
+        "=SORTBY(reps.revenue, reps.revenue, -1)".to_string(),
+    );
+    model.add_table(ranking);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("ranking").unwrap();
+
+    match &table.columns.get("name").unwrap().values {
+        ColumnValue::Text(names) => assert_eq!(names, &vec!["Alice", "Carol", "Bob"]),
+        _ => panic!("Expected Text array"),
+    }
+    // Sorting siblings by the same key keeps rows aligned
+    match &table.columns.get("revenue").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums, &vec![900.0, 500.0, 200.0]),
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_sortby_length_mismatch_error() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("data".to_string());
+    data.add_column(Column::new(
+        "values".to_string(),
+        ColumnValue::Number(vec![3.0, 1.0, 2.0]),
+    ));
+    model.add_table(data);
+
+    let mut keys = Table::new("keys".to_string());
+    keys.add_column(Column::new(
+        "order".to_string(),
+        ColumnValue::Number(vec![2.0, 1.0]),
+    ));
+    keys.add_row_formula(
+        "sorted".to_string(),
+        "=SORTBY(data.values, order)".to_string(),
+    );
+    model.add_table(keys);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all();
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// RUNSUM
+// ============================================================================
+
+#[test]
+fn test_runsum_cumulative_total() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("forecast".to_string());
+    data.add_column(Column::new(
+        "cashflow".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0, 300.0]),
+    ));
+    data.add_row_formula("cumulative".to_string(), "=RUNSUM(cashflow)".to_string());
+    model.add_table(data);
+
+    let mut summary = Table::new("summary".to_string());
+    summary.add_column(Column::new(
+        "period".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0, 3.0]),
+    ));
+    summary.add_row_formula(
+        "to_date".to_string(),
+        "=RUNSUM(forecast.cashflow)".to_string(),
+    );
+    model.add_table(summary);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    let forecast = result.tables.get("forecast").unwrap();
+    match &forecast.columns.get("cumulative").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums, &vec![100.0, 300.0, 600.0]),
+        _ => panic!("Expected Number array"),
+    }
+    let summary = result.tables.get("summary").unwrap();
+    match &summary.columns.get("to_date").unwrap().values {
+        ColumnValue::Number(nums) => assert_eq!(nums, &vec![100.0, 300.0, 600.0]),
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_pcttotal_share_of_column_total() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("statement".to_string());
+    data.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![25.0, 25.0, 50.0]),
+    ));
+    data.add_row_formula("share".to_string(), "=PCTTOTAL(revenue)".to_string());
+    model.add_table(data);
+
+    let result = ArrayCalculator::new(model)
+        .calculate_all()
+        .expect("Calculation should succeed");
+    assert_eq!(
+        result.tables["statement"].columns["share"].values,
+        ColumnValue::Number(vec![0.25, 0.25, 0.5])
+    );
+}
+
+#[test]
+fn test_pcttotal_zero_total_error() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("statement".to_string());
+    data.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![10.0, -10.0]),
+    ));
+    data.add_row_formula("share".to_string(), "=PCTTOTAL(revenue)".to_string());
+    model.add_table(data);
+
+    let err = ArrayCalculator::new(model).calculate_all().unwrap_err();
+    assert!(err.to_string().contains("sums to zero"), "{}", err);
+}
+
+#[test]
+fn test_runsum_text_column_error() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("forecast".to_string());
+    data.add_column(Column::new(
+        "label".to_string(),
+        ColumnValue::Text(vec!["a".to_string(), "b".to_string()]),
+    ));
+    data.add_row_formula("cumulative".to_string(), "=RUNSUM(label)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all();
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// PREV (previous-row reference)
+// ============================================================================
+
+#[test]
+fn test_prev_compounding_balance() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("forecast".to_string());
+    data.add_column(Column::new(
+        "period".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0, 3.0, 4.0]),
+    ));
+    data.add_column(Column::new(
+        "rate".to_string(),
+        ColumnValue::Number(vec![0.05, 0.05, 0.05, 0.05]),
+    ));
+    data.add_row_formula(
+        "balance".to_string(),
+        "=PREV(balance, 1000) * (1 + rate)".to_string(),
+    );
+    data.add_row_formula("prior_rate".to_string(), "=PREV(rate, 0)".to_string());
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let table = result.tables.get("forecast").unwrap();
+
+    match &table.columns.get("balance").unwrap().values {
+        ColumnValue::Number(nums) => {
+            let expected = [1050.0, 1102.5, 1157.625, 1215.50625];
+            for (actual, expected) in nums.iter().zip(expected.iter()) {
+                assert!((actual - expected).abs() < 0.01);
+            }
+        }
+        _ => panic!("Expected Number array"),
+    }
+    match &table.columns.get("prior_rate").unwrap().values {
+        ColumnValue::Number(nums) => {
+            assert_eq!(nums[0], 0.0); // Seed
+            assert!((nums[1] - 0.05).abs() < 0.0001);
+        }
+        _ => panic!("Expected Number array"),
+    }
+}
+
+#[test]
+fn test_prev_without_seed_errors_at_row_zero() {
+    let mut model = ParsedModel::new();
+    let mut data = Table::new("forecast".to_string());
+    data.add_column(Column::new(
+        "growth".to_string(),
+        ColumnValue::Number(vec![0.1, 0.1]),
+    ));
+    data.add_row_formula(
+        "balance".to_string(),
+        "=PREV(balance) * (1 + growth)".to_string(),
+    );
+    model.add_table(data);
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all();
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// CUMIPMT / CUMPRINC
+// ============================================================================
+
+#[test]
+fn test_cumipmt_cumprinc_mortgage_first_year() {
+    use crate::types::Variable;
+
+    // $125,000 30-year mortgage at 9% annual, paid monthly
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "first_year_interest".to_string(),
+        Variable::new(
+            "first_year_interest".to_string(),
+            None,
+            Some("=CUMIPMT(0.09/12, 360, 125000, 1, 12, 0)".to_string()),
+        ),
+    );
+    model.add_scalar(
+        "second_year_interest".to_string(),
+        Variable::new(
+            "second_year_interest".to_string(),
+            None,
+            Some("=CUMIPMT(0.09/12, 360, 125000, 13, 24, 0)".to_string()),
+        ),
+    );
+    model.add_scalar(
+        "second_year_principal".to_string(),
+        Variable::new(
+            "second_year_principal".to_string(),
+            None,
+            Some("=CUMPRINC(0.09/12, 360, 125000, 13, 24, 0)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    // Payments out are negative (Excel sign convention)
+    let first = result
+        .scalars
+        .get("first_year_interest")
+        .unwrap()
+        .value
+        .unwrap();
+    assert!((first - (-11215.34)).abs() < 0.01, "Got {}", first);
+    let second = result
+        .scalars
+        .get("second_year_interest")
+        .unwrap()
+        .value
+        .unwrap();
+    assert!((second - (-11135.23)).abs() < 0.01, "Got {}", second);
+    let principal = result
+        .scalars
+        .get("second_year_principal")
+        .unwrap()
+        .value
+        .unwrap();
+    assert!((principal - (-934.11)).abs() < 0.01, "Got {}", principal);
+}
+
+#[test]
+fn test_cumipmt_start_after_end_error() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "interest".to_string(),
+        Variable::new(
+            "interest".to_string(),
+            None,
+            Some("=CUMIPMT(0.09/12, 360, 125000, 24, 13, 0)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all();
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// IPMT / PPMT
+// ============================================================================
+
+#[test]
+fn test_ipmt_ppmt_sum_to_pmt() {
+    use crate::types::Variable;
+
+    // $8,000 loan over 3 years at 10% annual
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "interest".to_string(),
+        Variable::new(
+            "interest".to_string(),
+            None,
+            Some("=IPMT(0.1, 3, 3, 8000)".to_string()),
+        ),
+    );
+    model.add_scalar(
+        "principal".to_string(),
+        Variable::new(
+            "principal".to_string(),
+            None,
+            Some("=PPMT(0.1, 3, 3, 8000)".to_string()),
+        ),
+    );
+    model.add_scalar(
+        "payment".to_string(),
+        Variable::new(
+            "payment".to_string(),
+            None,
+            Some("=PMT(0.1, 3, 8000)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    let interest = result.scalars.get("interest").unwrap().value.unwrap();
+    let principal = result.scalars.get("principal").unwrap().value.unwrap();
+    let payment = result.scalars.get("payment").unwrap().value.unwrap();
+
+    assert!((interest - (-292.45)).abs() < 0.01, "Got {}", interest);
+    assert!((principal - (-2924.47)).abs() < 0.01, "Got {}", principal);
+    assert!((interest + principal - payment).abs() < 0.0001);
+}
+
+#[test]
+fn test_ipmt_period_out_of_range_error() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "interest".to_string(),
+        Variable::new(
+            "interest".to_string(),
+            None,
+            Some("=IPMT(0.1, 4, 3, 8000)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all();
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// MIRR
+// ============================================================================
+
+#[test]
+fn test_mirr_textbook_example() {
+    use crate::types::Variable;
+
+    // Excel reference: -120,000 investment, five years of returns,
+    // 10% finance rate, 12% reinvestment rate -> 12.61%
+    let mut model = ParsedModel::new();
+    let mut cashflows = Table::new("cashflows".to_string());
+    cashflows.add_column(Column::new(
+        "amount".to_string(),
+        ColumnValue::Number(vec![-120000.0, 39000.0, 30000.0, 21000.0, 37000.0, 46000.0]),
+    ));
+    model.add_table(cashflows);
+    model.add_scalar(
+        "finance_rate".to_string(),
+        Variable::new("finance_rate".to_string(), Some(0.10), None),
+    );
+    model.add_scalar(
+        "reinvest_rate".to_string(),
+        Variable::new("reinvest_rate".to_string(), Some(0.12), None),
+    );
+    model.add_scalar(
+        "mirr".to_string(),
+        Variable::new(
+            "mirr".to_string(),
+            None,
+            Some("=MIRR(cashflows.amount, finance_rate, reinvest_rate)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    let mirr = result.scalars.get("mirr").unwrap().value.unwrap();
+    assert!((mirr - 0.1261).abs() < 0.0001, "Got {}", mirr);
+}
+
+#[test]
+fn test_mirr_requires_negative_and_positive_flows() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+    let mut cashflows = Table::new("cashflows".to_string());
+    cashflows.add_column(Column::new(
+        "amount".to_string(),
+        ColumnValue::Number(vec![1000.0, 300.0, 400.0]),
+    ));
+    model.add_table(cashflows);
+    model.add_scalar(
+        "mirr".to_string(),
+        Variable::new(
+            "mirr".to_string(),
+            None,
+            Some("=MIRR(cashflows.amount, 0.1, 0.12)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all();
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// EFFECT / NOMINAL
+// ============================================================================
+
+#[test]
+fn test_effect_nominal_round_trip() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "nominal_rate".to_string(),
+        Variable::new("nominal_rate".to_string(), Some(0.06), None),
+    );
+    model.add_scalar(
+        "effective".to_string(),
+        Variable::new(
+            "effective".to_string(),
+            None,
+            Some("=EFFECT(nominal_rate, 12)".to_string()),
+        ),
+    );
+    model.add_scalar(
+        "back_to_nominal".to_string(),
+        Variable::new(
+            "back_to_nominal".to_string(),
+            None,
+            Some("=NOMINAL(effective, 12)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    // 6% compounded monthly is ~6.1678% effective
+    let effective = result.scalars.get("effective").unwrap().value.unwrap();
+    assert!((effective - 0.061678).abs() < 0.000001, "Got {}", effective);
+    let nominal = result
+        .scalars
+        .get("back_to_nominal")
+        .unwrap()
+        .value
+        .unwrap();
+    assert!((nominal - 0.06).abs() < 0.000001, "Got {}", nominal);
+}
+
+#[test]
+fn test_effect_fractional_npery_error() {
+    use crate::types::Variable;
+
+    let mut model = ParsedModel::new();
+    model.add_scalar(
+        "effective".to_string(),
+        Variable::new(
+            "effective".to_string(),
+            None,
+            Some("=EFFECT(0.06, 2.5)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let result = calculator.calculate_all();
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Cached calculation order
+// ============================================================================
+
+#[test]
+fn test_calculation_order_resolved_once() {
+    let mut model = ParsedModel::new();
+
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0]),
+    ));
+    sales.add_column(Column::new(
+        "cost".to_string(),
+        ColumnValue::Number(vec![60.0, 120.0]),
+    ));
+    sales.add_row_formula("margin".to_string(), "=profit / revenue".to_string());
+    sales.add_row_formula("profit".to_string(), "=revenue - cost".to_string());
+    model.add_table(sales);
+
+    model.add_scalar(
+        "summary.total_profit".to_string(),
+        Variable::new(
+            "summary.total_profit".to_string(),
+            None,
+            Some("=SUM(sales.profit)".to_string()),
+        ),
+    );
+
+    let before = ArrayCalculator::order_builds();
+    let calculator = ArrayCalculator::new(model);
+
+    let order = calculator.calculation_order().unwrap();
+    assert_eq!(order.tables, vec!["sales".to_string()]);
+    assert_eq!(
+        order.columns["sales"],
+        vec!["profit".to_string(), "margin".to_string()]
+    );
+    assert_eq!(order.scalars, vec!["summary.total_profit".to_string()]);
+
+    let first = calculator
+        .clone()
+        .calculate_all()
+        .expect("Calculation should succeed");
+    let second = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    assert_eq!(ArrayCalculator::order_builds() - before, 1);
+    assert_eq!(
+        first.scalars["summary.total_profit"].value,
+        second.scalars["summary.total_profit"].value
+    );
+    assert_eq!(second.scalars["summary.total_profit"].value, Some(120.0));
+}
+
+// ============================================================================
+// Table dependency levels (parallel evaluation)
+// ============================================================================
+
+#[test]
+fn test_independent_tables_match_serial_path() {
+    let mut model = ParsedModel::new();
+    for t in 0..8 {
+        let mut table = Table::new(format!("region_{}", t));
+        table.add_column(Column::new(
+            "units".to_string(),
+            ColumnValue::Number((0..50).map(|i| (i + t) as f64).collect()),
+        ));
+        table.add_column(Column::new(
+            "price".to_string(),
+            ColumnValue::Number((0..50).map(|i| 1.5 + (i % 7) as f64).collect()),
+        ));
+        table.add_row_formula("revenue".to_string(), "=units * price".to_string());
+        table.add_row_formula("taxed".to_string(), "=revenue * 0.8".to_string());
+        model.add_table(table);
+    }
+    // A dependent table lands in the second level
+    let mut totals = Table::new("totals".to_string());
+    totals.add_column(Column::new(
+        "base".to_string(),
+        ColumnValue::Number(vec![1.0; 50]),
+    ));
+    totals.add_row_formula(
+        "combined".to_string(),
+        "=region_0.revenue + region_7.revenue".to_string(),
+    );
+    model.add_table(totals);
+
+    let calculator = ArrayCalculator::new(model.clone());
+    let order = calculator.calculation_order().unwrap().clone();
+    assert_eq!(order.table_levels.len(), 2);
+    assert_eq!(order.table_levels[0].len(), 8);
+    assert_eq!(order.table_levels[1], vec!["totals".to_string()]);
+
+    let result = calculator
+        .calculate_all()
+        .expect("Calculation should succeed");
+
+    let mut serial = ArrayCalculator::new(model);
+    serial
+        .evaluate_tables_serial(&order, &mut |_, _| Ok(()))
+        .unwrap();
+
+    for (name, table) in &serial.model.tables {
+        let calculated = &result.tables[name];
+        for (col_name, column) in &table.columns {
+            assert_eq!(
+                calculated.columns[col_name].values, column.values,
+                "{}.{} differs from serial path",
+                name, col_name
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Arithmetic fast path
+// ============================================================================
+
+#[test]
+fn test_fast_arithmetic_matches_general_path() {
+    let rows = 10_000;
+    let mut table = Table::new("data".to_string());
+    table.add_column(Column::new(
+        "a".to_string(),
+        ColumnValue::Number((0..rows).map(|i| 10.0 + (i % 100) as f64 * 0.5).collect()),
+    ));
+    table.add_column(Column::new(
+        "b".to_string(),
+        ColumnValue::Number((0..rows).map(|i| 1.0 + (i % 50) as f64).collect()),
+    ));
+    table.add_column(Column::new(
+        "c".to_string(),
+        ColumnValue::Number((0..rows).map(|i| 0.25 + (i % 7) as f64).collect()),
+    ));
+
+    let calc = ArrayCalculator::new(ParsedModel::new());
+    for formula in ["=a * b - c", "=(a + b) / c", "=a * 1.5 + 2 - b / 4"] {
+        let fast = calc
+            .try_fast_arithmetic(&table, formula)
+            .unwrap_or_else(|| panic!("{} should take the fast path", formula));
+        let general = calc.evaluate_rowwise_formula(&table, formula).unwrap();
+
+        match (fast, general) {
+            (ColumnValue::Number(fast), ColumnValue::Number(general)) => {
+                assert_eq!(fast.len(), rows);
+                for (i, (f, g)) in fast.iter().zip(&general).enumerate() {
+                    assert!(
+                        (f - g).abs() <= 1e-6 * g.abs().max(1.0),
+                        "{} row {}: fast {} vs general {}",
+                        formula,
+                        i,
+                        f,
+                        g
+                    );
+                }
+            }
+            other => panic!("Expected numeric columns, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_fast_arithmetic_falls_back() {
+    let mut table = Table::new("data".to_string());
+    table.add_column(Column::new(
+        "a".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0]),
+    ));
+    table.add_column(Column::new(
+        "b".to_string(),
+        ColumnValue::Number(vec![4.0, 0.0]),
+    ));
+
+    let calc = ArrayCalculator::new(ParsedModel::new());
+    // Functions, unary minus, scalar references and errors use the general path
+    assert!(calc.try_fast_arithmetic(&table, "=ROUND(a, 1)").is_none());
+    assert!(calc.try_fast_arithmetic(&table, "=-a + b").is_none());
+    assert!(calc
+        .try_fast_arithmetic(&table, "=a * inputs.rate")
+        .is_none());
+    assert!(calc.try_fast_arithmetic(&table, "=a / b").is_none());
+    assert!(calc.try_fast_arithmetic(&table, "=a > b").is_none());
+}
+
+// ============================================================================
+// Incremental recalculation
+// ============================================================================
+
+fn incremental_model() -> ParsedModel {
+    let mut model = ParsedModel::new();
+    for (name, value, formula) in [
+        ("inputs.a", Some(2.0), None),
+        ("inputs.x", Some(3.0), None),
+        ("level1.b", None, Some("=inputs.a * 2")),
+        ("level2.c", None, Some("=level1.b + 1")),
+        ("level3.d", None, Some("=level2.c * 10")),
+        ("other.y", None, Some("=inputs.x * 3")),
+        ("summary.total", None, Some("=SUM(sales.scaled)")),
+        ("summary.units", None, Some("=SUM(region.doubled)")),
+    ] {
+        model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), value, formula.map(str::to_string)),
+        );
+    }
+
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0]),
+    ));
+    sales.add_row_formula("scaled".to_string(), "=revenue * inputs.a".to_string());
+    model.add_table(sales);
+
+    let mut region = Table::new("region".to_string());
+    region.add_column(Column::new(
+        "units".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0]),
+    ));
+    region.add_row_formula("doubled".to_string(), "=units * 2".to_string());
+    model.add_table(region);
+
+    model
+}
+
+#[test]
+fn test_recalculate_dependents_updates_only_downstream() {
+    let mut calculator = ArrayCalculator::new(incremental_model());
+    calculator.calculate().expect("Calculation should succeed");
+
+    calculator.set_scalar_value("inputs.a", 5.0).unwrap();
+    let before = ArrayCalculator::scalar_evals().len();
+    let recalculated = calculator
+        .recalculate_dependents("inputs.a")
+        .expect("Recalculation should succeed");
+    let evaluated = ArrayCalculator::scalar_evals()[before..].to_vec();
+
+    // Tables are updated before scalars, as in a full calculation
+    assert_eq!(recalculated[0], "sales.scaled");
+    let mut sorted = recalculated.clone();
+    sorted.sort();
+    assert_eq!(
+        sorted,
+        vec![
+            "level1.b",
+            "level2.c",
+            "level3.d",
+            "sales.scaled",
+            "summary.total"
+        ]
+    );
+
+    // Only the downstream scalars were evaluated, the chain in order
+    assert_eq!(evaluated.len(), 4);
+    assert!(!evaluated.contains(&"other.y".to_string()));
+    assert!(!evaluated.contains(&"summary.units".to_string()));
+    let position = |name: &str| evaluated.iter().position(|n| n == name).unwrap();
+    assert!(position("level1.b") < position("level2.c"));
+    assert!(position("level2.c") < position("level3.d"));
+
+    let model = calculator.model();
+    assert_eq!(model.scalars["level1.b"].value, Some(10.0));
+    assert_eq!(model.scalars["level2.c"].value, Some(11.0));
+    assert_eq!(model.scalars["level3.d"].value, Some(110.0));
+    assert_eq!(model.scalars["summary.total"].value, Some(1500.0));
+    assert_eq!(model.scalars["other.y"].value, Some(9.0));
+    assert_eq!(model.scalars["summary.units"].value, Some(6.0));
+
+    // Same values as recalculating everything from scratch
+    let mut full = incremental_model();
+    full.scalars.get_mut("inputs.a").unwrap().value = Some(5.0);
+    let full = ArrayCalculator::new(full).calculate_all().unwrap();
+    for (name, var) in &full.scalars {
+        assert_eq!(model.scalars[name].value, var.value, "scalar {}", name);
+    }
+    assert_eq!(
+        model.tables["sales"].columns["scaled"].values,
+        full.tables["sales"].columns["scaled"].values
+    );
+}
+
+#[test]
+fn test_recalculate_dependents_of_column() {
+    let mut calculator = ArrayCalculator::new(incremental_model());
+    calculator.calculate().expect("Calculation should succeed");
+
+    calculator
+        .set_column_values("region", "units", ColumnValue::Number(vec![10.0, 20.0]))
+        .unwrap();
+    let recalculated = calculator.recalculate_dependents("region.units").unwrap();
+
+    assert_eq!(recalculated, vec!["region.doubled", "summary.units"]);
+    let model = calculator.model();
+    assert_eq!(model.scalars["summary.units"].value, Some(60.0));
+    assert_eq!(model.scalars["summary.total"].value, Some(600.0));
+}
+
+#[test]
+fn test_incremental_rejects_invalid_edits() {
+    let mut calculator = ArrayCalculator::new(incremental_model());
+
+    assert!(calculator.set_scalar_value("level1.b", 1.0).is_err());
+    assert!(calculator.set_scalar_value("missing", 1.0).is_err());
+    assert!(calculator
+        .set_column_values("sales", "scaled", ColumnValue::Number(vec![1.0, 2.0]))
+        .is_err());
+    assert!(calculator
+        .set_column_values("region", "units", ColumnValue::Number(vec![1.0]))
+        .is_err());
+    assert!(calculator.recalculate_dependents("missing.column").is_err());
+}
+
+// =============================================================================
+// Impact analysis (audit --impact)
+// =============================================================================
+
+#[test]
+fn test_impact_lists_transitive_dependents() {
+    let mut model = ParsedModel::new();
+    for (name, value, formula) in [
+        ("pricing.base_price", Some(10.0), None),
+        ("pricing.discount", Some(0.1), None),
+        ("summary.total", None, Some("=SUM(sales.revenue)")),
+        ("summary.margin", None, Some("=summary.total * 0.2")),
+        ("summary.units", None, Some("=SUM(sales.units)")),
+        ("summary.net", None, Some("=1 - pricing.discount")),
+    ] {
+        model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), value, formula.map(str::to_string)),
+        );
+    }
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "units".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0]),
+    ));
+    sales.add_row_formula(
+        "revenue".to_string(),
+        "=units * pricing.base_price".to_string(),
+    );
+    model.add_table(sales);
+
+    let calculator = ArrayCalculator::new(model);
+    let impacted = calculator.impact("pricing.base_price").unwrap();
+
+    // Nearest first: the column, the scalar aggregating it, then its dependent
+    assert_eq!(
+        impacted,
+        vec!["sales.revenue", "summary.total", "summary.margin"]
+    );
+
+    let units = calculator.impact("sales.units").unwrap();
+    assert!(units.contains(&"summary.units".to_string()));
+    assert!(!units.contains(&"summary.net".to_string()));
+    assert!(calculator.impact("summary.margin").unwrap().is_empty());
+
+    let err = calculator.impact("pricing.base_prise").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("did you mean 'pricing.base_price'"));
+}
+
+// =============================================================================
+// Unit checking (--check-units)
+// =============================================================================
+
+fn unit_model(formula: &str) -> ParsedModel {
+    let unit = |u: &str| crate::types::Metadata {
+        unit: Some(u.to_string()),
+        ..Default::default()
+    };
+    let mut model = ParsedModel::new();
+    let mut table = Table::new("sales".to_string());
+    table.add_column(Column::with_metadata(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0]),
+        unit("USD"),
+    ));
+    table.add_column(Column::with_metadata(
+        "weight".to_string(),
+        ColumnValue::Number(vec![2.0, 4.0]),
+        unit("kg"),
+    ));
+    table.add_column(Column::with_metadata(
+        "units_sold".to_string(),
+        ColumnValue::Number(vec![10.0, 20.0]),
+        unit("unit"),
+    ));
+    table.add_row_formula("result".to_string(), formula.to_string());
+    model.add_table(table);
+    model
+}
+
+#[test]
+fn test_check_units_rejects_adding_different_units() {
+    let calculator = ArrayCalculator::new(unit_model("=revenue + weight"));
+    let err = calculator.check_units().unwrap_err().to_string();
+    assert!(
+        err.contains("Unit mismatch in sales.result: cannot add USD and kg"),
+        "{}",
+        err
+    );
+
+    // The check is opt-in: calculation itself is unaffected
+    assert!(calculator.calculate_all().is_ok());
+}
+
+#[test]
+fn test_check_units_allows_multiplying_by_dimensionless() {
+    let calculator = ArrayCalculator::new(unit_model("=revenue * 1.13 + 5"));
+    assert!(calculator.check_units().is_ok());
+}
+
+#[test]
+fn test_check_units_propagates_compound_units() {
+    // price is USD/unit, so it cannot be added to revenue
+    let mut model = unit_model("=revenue / units_sold");
+    let table = model.tables.get_mut("sales").unwrap();
+    table.add_row_formula("bad".to_string(), "=result + revenue".to_string());
+    let err = ArrayCalculator::new(model)
+        .check_units()
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("cannot add USD/unit and USD"), "{}", err);
+
+    let model = unit_model("=revenue / units_sold * units_sold - revenue");
+    assert!(ArrayCalculator::new(model).check_units().is_ok());
+}
+
+#[test]
+fn test_calculate_all_with_progress_reaches_100_percent() {
+    let mut model = ParsedModel::new();
+
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0]),
+    ));
+    sales.add_row_formula("tax".to_string(), "=revenue * 0.1".to_string());
+    model.add_table(sales);
+
+    let mut summary = Table::new("summary".to_string());
+    summary.add_column(Column::new(
+        "base".to_string(),
+        ColumnValue::Number(vec![1.0, 2.0]),
+    ));
+    summary.add_row_formula("net".to_string(), "=sales.revenue - sales.tax".to_string());
+    model.add_table(summary);
+
+    model.add_scalar(
+        "total".to_string(),
+        Variable::new(
+            "total".to_string(),
+            None,
+            Some("=SUM(summary.net)".to_string()),
+        ),
+    );
+
+    let calculator = ArrayCalculator::new(model);
+    let reports = std::cell::RefCell::new(Vec::new());
+    let result = calculator
+        .calculate_all_with_progress(|progress| reports.borrow_mut().push(progress))
+        .expect("Calculation should succeed");
+    assert_eq!(result.scalars["total"].value, Some(270.0));
+
+    let reports = reports.into_inner();
+    assert_eq!(reports.len(), 3);
+    assert!(reports.iter().all(|progress| progress.total == 3));
+    assert!(reports
+        .windows(2)
+        .all(|pair| pair[0].percent < pair[1].percent));
+    // Dependencies are reported before their dependents
+    let names: Vec<&str> = reports.iter().map(|p| p.current.as_str()).collect();
+    assert_eq!(names, vec!["sales", "summary", "total"]);
+    assert_eq!(reports.last().unwrap().percent, 100.0);
+}
+
+#[test]
+fn test_calculate_all_with_deadline_times_out() {
+    use std::time::{Duration, Instant};
+
+    // A chain of tables, each reading the previous one, that takes well
+    // over a millisecond to calculate
+    let mut model = ParsedModel::new();
+    for i in 0..40 {
+        let mut table = Table::new(format!("step_{}", i));
+        table.add_column(Column::new(
+            "base".to_string(),
+            ColumnValue::Number((0..500).map(f64::from).collect()),
+        ));
+        let formula = if i == 0 {
+            "=base * 2".to_string()
+        } else {
+            format!("=step_{}.value + base", i - 1)
+        };
+        table.add_row_formula("value".to_string(), formula);
+        model.add_table(table);
+    }
+    let calculator = ArrayCalculator::new(model);
+
+    let started = Instant::now();
+    let err = calculator
+        .calculate_all_with_deadline(Duration::from_millis(1))
+        .unwrap_err();
+    let stopped_after = started.elapsed();
+    assert!(matches!(err, ForgeError::Timeout(_)), "{}", err);
+
+    // The full run takes far longer than the aborted one
+    let started = Instant::now();
+    let result = calculator
+        .calculate_all_with_deadline(Duration::from_secs(600))
+        .expect("Calculation should finish within the deadline");
+    assert!(started.elapsed() > stopped_after);
+    assert!(result.tables["step_39"].columns.contains_key("value"));
+}
+
+#[test]
+fn test_calculate_all_with_deadline_accepts_finished_run() {
+    // The deadline has passed before the only table is done, but nothing is
+    // left to abort, so the result is returned
+    let mut model = ParsedModel::new();
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0]),
+    ));
+    sales.add_row_formula("doubled".to_string(), "=revenue * 2".to_string());
+    model.add_table(sales);
+
+    let result = ArrayCalculator::new(model)
+        .calculate_all_with_deadline(std::time::Duration::ZERO)
+        .unwrap();
+    assert!(result.tables["sales"].columns.contains_key("doubled"));
+}
+
+#[test]
+fn test_calculate_all_with_stats_counts_cells() {
+    let mut model = ParsedModel::new();
+
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "revenue".to_string(),
+        ColumnValue::Number(vec![100.0, 200.0, 300.0]),
+    ));
+    sales.add_row_formula("tax".to_string(), "=revenue * 0.1".to_string());
+    sales.add_row_formula("net".to_string(), "=revenue - tax".to_string());
+    model.add_table(sales);
+
+    let mut report = Table::new("report".to_string());
+    report.add_column(Column::new(
+        "share".to_string(),
+        ColumnValue::Number(vec![0.5, 0.25, 0.25]),
+    ));
+    report.add_row_formula("allocated".to_string(), "=sales.net * share".to_string());
+    model.add_table(report);
+
+    for (name, formula) in [
+        ("total_net", "=SUM(sales.net)"),
+        ("net_margin", "=total_net / 600"),
+    ] {
+        model.add_scalar(
+            name.to_string(),
+            Variable::new(name.to_string(), None, Some(formula.to_string())),
+        );
+    }
+
+    let calculator = ArrayCalculator::new(model);
+    let (result, stats) = calculator
+        .calculate_all_with_stats()
+        .expect("Calculation should succeed");
+    assert_eq!(result.scalars["total_net"].value, Some(540.0));
+
+    // 3 rows x 2 formulas + 3 rows x 1 formula + 2 scalars
+    assert_eq!(stats.cells_evaluated, 11);
+    assert_eq!(stats.aggregations, 1);
+    assert_eq!(stats.dependency_levels, 2);
+    let tables: Vec<&str> = stats.table_times.iter().map(|(t, _)| t.as_str()).collect();
+    assert_eq!(tables, vec!["sales", "report"]);
+    assert!(
+        stats.total_time
+            >= stats
+                .table_times
+                .iter()
+                .map(|(_, t)| *t)
+                .sum::<std::time::Duration>()
+    );
+}
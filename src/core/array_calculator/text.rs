@@ -1,7 +1,8 @@
 //! Text Functions (v1.1.0)
-//! CONCAT, TRIM, UPPER, LOWER, LEN, MID
+//! CONCAT, TEXTJOIN, TRIM, UPPER, LOWER, LEN, MID, TEXT, SUBSTITUTE, REPLACE, FIND, SEARCH
 
 use super::ArrayCalculator;
+use crate::error::{ForgeError, ForgeResult};
 
 impl ArrayCalculator {
     /// Evaluate CONCAT/CONCATENATE function: CONCAT(text1, text2, ...)
@@ -9,6 +10,20 @@ impl ArrayCalculator {
         texts.join("")
     }
 
+    /// Evaluate TEXTJOIN function: TEXTJOIN(delimiter, ignore_empty, text1, text2, ...)
+    pub(super) fn eval_textjoin(
+        &self,
+        delimiter: &str,
+        ignore_empty: bool,
+        texts: Vec<String>,
+    ) -> String {
+        texts
+            .into_iter()
+            .filter(|t| !(ignore_empty && t.is_empty()))
+            .collect::<Vec<_>>()
+            .join(delimiter)
+    }
+
     /// Evaluate TRIM function: TRIM(text)
     pub(super) fn eval_trim(&self, text: &str) -> String {
         text.trim().to_string()
@@ -42,4 +57,178 @@ impl ArrayCalculator {
 
         chars[start_idx..end_idx].iter().collect()
     }
+
+    /// Evaluate SUBSTITUTE function: SUBSTITUTE(text, old_text, new_text, [instance_num])
+    /// Replaces every occurrence, or only the Nth one when instance_num is given
+    pub(super) fn eval_substitute(
+        &self,
+        text: &str,
+        old_text: &str,
+        new_text: &str,
+        instance: Option<usize>,
+    ) -> String {
+        if old_text.is_empty() {
+            return text.to_string();
+        }
+
+        match instance {
+            None => text.replace(old_text, new_text),
+            Some(n) => match text.match_indices(old_text).nth(n.saturating_sub(1)) {
+                Some((pos, _)) => format!(
+                    "{}{}{}",
+                    &text[..pos],
+                    new_text,
+                    &text[pos + old_text.len()..]
+                ),
+                None => text.to_string(),
+            },
+        }
+    }
+
+    /// Evaluate REPLACE function: REPLACE(old_text, start_num, num_chars, new_text)
+    /// Positions past the end of the text are clamped
+    pub(super) fn eval_replace(
+        &self,
+        text: &str,
+        start: usize,
+        length: usize,
+        new_text: &str,
+    ) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        // Excel uses 1-based indexing
+        let start_idx = start.saturating_sub(1).min(chars.len());
+        let end_idx = start_idx.saturating_add(length).min(chars.len());
+
+        let mut result: String = chars[..start_idx].iter().collect();
+        result.push_str(new_text);
+        result.extend(&chars[end_idx..]);
+        result
+    }
+
+    /// Evaluate FIND/SEARCH functions: FIND(find_text, within_text, [start_num])
+    /// Returns the 1-based character position; SEARCH ignores case
+    pub(super) fn eval_find(
+        &self,
+        func_name: &str,
+        find_text: &str,
+        within_text: &str,
+        start: usize,
+        case_sensitive: bool,
+    ) -> ForgeResult<f64> {
+        let (needle, haystack) = if case_sensitive {
+            (find_text.to_string(), within_text.to_string())
+        } else {
+            (find_text.to_lowercase(), within_text.to_lowercase())
+        };
+
+        let chars: Vec<char> = haystack.chars().collect();
+        if start < 1 || start > chars.len() + 1 {
+            return Err(ForgeError::Eval(format!(
+                "{}: start_num {} is out of range for '{}'",
+                func_name, start, within_text
+            )));
+        }
+
+        let offset: usize = chars[..start - 1].iter().map(|c| c.len_utf8()).sum();
+        match haystack[offset..].find(&needle) {
+            Some(byte_pos) => {
+                let char_pos = haystack[..offset + byte_pos].chars().count();
+                Ok((char_pos + 1) as f64)
+            }
+            None => Err(ForgeError::Eval(format!(
+                "{}: substring '{}' not found in '{}'",
+                func_name, find_text, within_text
+            ))),
+        }
+    }
+
+    /// Evaluate TEXT function: TEXT(value, format_text)
+    /// Supports fixed decimals (0.00), thousands separators (#,##0), percentages (0%, 0.00%)
+    /// and literal prefixes/suffixes such as a currency symbol ($#,##0.00)
+    pub(super) fn eval_text_format(&self, value: f64, format: &str) -> ForgeResult<String> {
+        let invalid = || ForgeError::Eval(format!("TEXT: Invalid format string '{}'", format));
+
+        // Split into literal prefix, numeric pattern, and literal suffix
+        let first = format.find(['0', '#']).ok_or_else(invalid)?;
+        let last = format.rfind(['0', '#']).ok_or_else(invalid)?;
+        let prefix = &format[..first];
+        let suffix = &format[last + 1..];
+        // A trailing decimal point belongs to the pattern ("0." is valid but pointless)
+        let (pattern, suffix) = match suffix.strip_prefix('.') {
+            Some(rest) => (&format[first..=last + 1], rest),
+            None => (&format[first..=last], suffix),
+        };
+
+        if pattern.chars().any(|c| !matches!(c, '0' | '#' | ',' | '.'))
+            || pattern.matches('.').count() > 1
+            || prefix.contains(['.', ','])
+            || suffix.contains(['.', ','])
+        {
+            return Err(invalid());
+        }
+
+        let (int_pattern, dec_pattern) = match pattern.split_once('.') {
+            Some((int_part, dec_part)) => (int_part, dec_part),
+            None => (pattern, ""),
+        };
+        if dec_pattern.contains(',') {
+            return Err(invalid());
+        }
+
+        let is_percent = prefix.contains('%') || suffix.contains('%');
+        let use_grouping = int_pattern.contains(',');
+        let min_int_digits = int_pattern.chars().filter(|&c| c == '0').count();
+        let min_decimals = dec_pattern.chars().filter(|&c| c == '0').count();
+        let max_decimals = dec_pattern.len();
+
+        let scaled = if is_percent { value * 100.0 } else { value };
+        // Round half away from zero like Excel before formatting
+        let factor = 10f64.powi(max_decimals as i32);
+        let rounded = (scaled.abs() * factor).round() / factor;
+        let formatted = format!("{:.*}", max_decimals, rounded);
+        let (int_digits, dec_digits) = match formatted.split_once('.') {
+            Some((int_part, dec_part)) => (int_part.to_string(), dec_part.to_string()),
+            None => (formatted, String::new()),
+        };
+
+        // Optional (#) decimal places drop trailing zeros
+        let mut dec_digits = dec_digits;
+        while dec_digits.len() > min_decimals && dec_digits.ends_with('0') {
+            dec_digits.pop();
+        }
+
+        // Optional (#) integer digits drop a lone leading zero; required (0) digits pad
+        let int_digits = if int_digits == "0" && min_int_digits == 0 {
+            String::new()
+        } else if int_digits.len() < min_int_digits {
+            format!("{:0>width$}", int_digits, width = min_int_digits)
+        } else {
+            int_digits
+        };
+
+        let int_digits = if use_grouping {
+            let chars: Vec<char> = int_digits.chars().collect();
+            let mut grouped = String::new();
+            for (i, c) in chars.iter().enumerate() {
+                if i > 0 && (chars.len() - i).is_multiple_of(3) {
+                    grouped.push(',');
+                }
+                grouped.push(*c);
+            }
+            grouped
+        } else {
+            int_digits
+        };
+
+        let mut number = int_digits;
+        if !dec_digits.is_empty() {
+            number.push('.');
+            number.push_str(&dec_digits);
+        }
+
+        let is_zero = number.chars().all(|c| !c.is_ascii_digit() || c == '0');
+        let sign = if scaled < 0.0 && !is_zero { "-" } else { "" };
+
+        Ok(format!("{}{}{}{}", sign, prefix, number, suffix))
+    }
 }
@@ -0,0 +1,95 @@
+//! Strict unit checking for `forge calculate --check-units`
+//!
+//! Units declared on columns and scalars are propagated through formulas in
+//! calculation order, so `revenue / units_sold` gets `USD/unit` and adding it
+//! to `revenue` is reported as a mismatch.
+
+use crate::core::unit_validator::{infer_formula_unit, CompoundUnit};
+use crate::error::{ForgeError, ForgeResult};
+use std::collections::HashMap;
+
+use super::ArrayCalculator;
+
+impl ArrayCalculator {
+    /// Check that formulas only add or subtract values with matching units
+    ///
+    /// Formula columns and scalars without a declared `unit:` take the unit
+    /// inferred from their formula. Fails on the first mismatch.
+    pub fn check_units(&self) -> ForgeResult<()> {
+        let order = self.calculation_order()?;
+        let mut units: HashMap<String, CompoundUnit> = HashMap::new();
+
+        for (table_name, table) in &self.model.tables {
+            let declared = table
+                .columns
+                .iter()
+                .map(|(name, column)| (name, &column.metadata))
+                .chain(table.formula_metadata.iter());
+            for (col_name, metadata) in declared {
+                if let Some(unit) = &metadata.unit {
+                    units.insert(
+                        format!("{}.{}", table_name, col_name),
+                        CompoundUnit::parse(unit),
+                    );
+                }
+            }
+        }
+        for (name, var) in &self.model.scalars {
+            if let Some(unit) = &var.metadata.unit {
+                units.insert(name.clone(), CompoundUnit::parse(unit));
+            }
+        }
+
+        for table_name in &order.tables {
+            let table = &self.model.tables[table_name];
+            for col_name in order.formula_order(table_name) {
+                let location = format!("{}.{}", table_name, col_name);
+                let formula = &table.row_formulas[col_name];
+                // Local columns shadow scalars of the same name
+                let inferred = infer_formula_unit(formula, |name| {
+                    units
+                        .get(&format!("{}.{}", table_name, name))
+                        .or_else(|| units.get(name))
+                        .cloned()
+                })
+                .map_err(|message| unit_mismatch(&location, formula, &message))?;
+                if let Some(unit) = inferred {
+                    units.entry(location).or_insert(unit);
+                }
+            }
+        }
+
+        for name in &order.scalars {
+            let formula = match self
+                .model
+                .scalars
+                .get(name)
+                .and_then(|v| v.formula.as_ref())
+            {
+                Some(formula) => formula,
+                None => continue,
+            };
+            let section = name.rsplit_once('.').map(|(section, _)| section);
+            let inferred = infer_formula_unit(formula, |reference| {
+                units.get(reference).cloned().or_else(|| {
+                    section.and_then(|section| {
+                        units.get(&format!("{}.{}", section, reference)).cloned()
+                    })
+                })
+            })
+            .map_err(|message| unit_mismatch(name, formula, &message))?;
+            if let Some(unit) = inferred {
+                units.entry(name.clone()).or_insert(unit);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn unit_mismatch(location: &str, formula: &str, message: &str) -> ForgeError {
+    ForgeError::Validation(format!(
+        "Unit mismatch in {}: {} ({})",
+        location, message, formula
+    ))
+}
@@ -0,0 +1,428 @@
+//! Registry of supported formula functions
+//!
+//! One table of every function Forge evaluates, with its category, arity,
+//! signature and a one-line description. The evaluator uses it to tell
+//! aggregations from row-wise functions, and `forge functions`, formula
+//! explanations and the MCP `functions` resource all read from it, so the
+//! function list is maintained in one place.
+
+use serde::Serialize;
+
+/// Function group, in the order `forge functions` lists them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum FunctionCategory {
+    Financial,
+    Lookup,
+    Conditional,
+    Array,
+    Aggregation,
+    Math,
+    Text,
+    Date,
+    Logic,
+    Statistical,
+    #[serde(rename = "Forge-Native")]
+    ForgeNative,
+}
+
+impl FunctionCategory {
+    /// All categories in display order
+    pub const ALL: [FunctionCategory; 11] = [
+        FunctionCategory::Financial,
+        FunctionCategory::Lookup,
+        FunctionCategory::Conditional,
+        FunctionCategory::Array,
+        FunctionCategory::Aggregation,
+        FunctionCategory::Math,
+        FunctionCategory::Text,
+        FunctionCategory::Date,
+        FunctionCategory::Logic,
+        FunctionCategory::Statistical,
+        FunctionCategory::ForgeNative,
+    ];
+
+    /// Display name, e.g. `Forge-Native`
+    pub fn name(self) -> &'static str {
+        match self {
+            FunctionCategory::Financial => "Financial",
+            FunctionCategory::Lookup => "Lookup",
+            FunctionCategory::Conditional => "Conditional",
+            FunctionCategory::Array => "Array",
+            FunctionCategory::Aggregation => "Aggregation",
+            FunctionCategory::Math => "Math",
+            FunctionCategory::Text => "Text",
+            FunctionCategory::Date => "Date",
+            FunctionCategory::Logic => "Logic",
+            FunctionCategory::Statistical => "Statistical",
+            FunctionCategory::ForgeNative => "Forge-Native",
+        }
+    }
+}
+
+/// Whether a function reduces its inputs to one value or works per row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FunctionKind {
+    /// Returns a single value (`SUM`, `SUMIF`, `MEDIAN`, ...); belongs in scalars
+    Aggregate,
+    /// Evaluated per row or element (`ROUND`, `IF`, `PMT`, ...)
+    RowWise,
+}
+
+/// A supported function
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FunctionInfo {
+    pub name: &'static str,
+    pub category: FunctionCategory,
+    pub kind: FunctionKind,
+    pub min_args: usize,
+    /// `None` for variadic functions such as `SUM`
+    pub max_args: Option<usize>,
+    /// One-line description, e.g. "Payment for a loan"
+    pub description: &'static str,
+    /// Usage with argument names, optional ones in brackets:
+    /// `=PMT(rate, nper, pv, [fv], [type])`
+    pub signature: &'static str,
+}
+
+const fn function(
+    name: &'static str,
+    category: FunctionCategory,
+    kind: FunctionKind,
+    min_args: usize,
+    max_args: Option<usize>,
+    description: &'static str,
+    signature: &'static str,
+) -> FunctionInfo {
+    FunctionInfo {
+        name,
+        category,
+        kind,
+        min_args,
+        max_args,
+        description,
+        signature,
+    }
+}
+
+use FunctionCategory::*;
+use FunctionKind::*;
+
+#[rustfmt::skip]
+const FUNCTIONS: &[FunctionInfo] = &[
+    // Financial
+    function("NPV", Financial, RowWise, 2, None, "Net Present Value", "=NPV(rate, cashflow1, cashflow2, ...)"),
+    function("IRR", Financial, RowWise, 1, Some(2), "Internal Rate of Return", "=IRR(values, [guess])"),
+    function("MIRR", Financial, RowWise, 3, Some(3), "Modified IRR", "=MIRR(values, finance_rate, reinvest_rate)"),
+    function("XNPV", Financial, RowWise, 3, Some(3), "NPV with irregular dates", "=XNPV(rate, values, dates)"),
+    function("XIRR", Financial, RowWise, 2, Some(3), "IRR with irregular dates", "=XIRR(values, dates, [guess])"),
+    function("PMT", Financial, RowWise, 3, Some(5), "Payment for a loan", "=PMT(rate, nper, pv, [fv], [type])"),
+    function("IPMT", Financial, RowWise, 4, Some(6), "Interest portion of a payment", "=IPMT(rate, per, nper, pv, [fv], [type])"),
+    function("PPMT", Financial, RowWise, 4, Some(6), "Principal portion of a payment", "=PPMT(rate, per, nper, pv, [fv], [type])"),
+    function("CUMIPMT", Financial, RowWise, 6, Some(6), "Cumulative interest", "=CUMIPMT(rate, nper, pv, start, end, type)"),
+    function("CUMPRINC", Financial, RowWise, 6, Some(6), "Cumulative principal", "=CUMPRINC(rate, nper, pv, start, end, type)"),
+    function("PV", Financial, RowWise, 3, Some(5), "Present Value", "=PV(rate, nper, pmt, [fv], [type])"),
+    function("FV", Financial, RowWise, 3, Some(5), "Future Value", "=FV(rate, nper, pmt, [pv], [type])"),
+    function("RATE", Financial, RowWise, 3, Some(6), "Interest rate", "=RATE(nper, pmt, pv, [fv], [type], [guess])"),
+    function("NPER", Financial, RowWise, 3, Some(5), "Number of periods", "=NPER(rate, pmt, pv, [fv], [type])"),
+    function("EFFECT", Financial, RowWise, 2, Some(2), "Effective annual rate", "=EFFECT(nominal_rate, npery)"),
+    function("NOMINAL", Financial, RowWise, 2, Some(2), "Nominal annual rate", "=NOMINAL(effect_rate, npery)"),
+    function("SLN", Financial, RowWise, 3, Some(3), "Straight-line depreciation", "=SLN(cost, salvage, life)"),
+    function("DB", Financial, RowWise, 4, Some(5), "Declining balance depreciation", "=DB(cost, salvage, life, period, [month])"),
+    function("DDB", Financial, RowWise, 4, Some(5), "Double declining balance", "=DDB(cost, salvage, life, period, [factor])"),
+
+    // Lookup
+    function("MATCH", Lookup, RowWise, 2, Some(3), "Find position in array", "=MATCH(value, array, [type])"),
+    function("INDEX", Lookup, RowWise, 2, Some(3), "Get value by position", "=INDEX(array, row, [col])"),
+    function("VLOOKUP", Lookup, RowWise, 3, Some(4), "Vertical lookup", "=VLOOKUP(value, table, col, [approx])"),
+    function("XLOOKUP", Lookup, RowWise, 3, Some(6), "Modern lookup", "=XLOOKUP(value, lookup, return, [not_found], [match], [search])"),
+    function("CHOOSE", Lookup, RowWise, 2, None, "Pick nth value", "=CHOOSE(index, value1, value2, ...)"),
+    function("OFFSET", Lookup, RowWise, 2, Some(3), "Dynamic range slice", "=OFFSET(array, rows, [height])"),
+
+    // Conditional
+    function("SUMIF", Conditional, Aggregate, 2, Some(3), "Sum if condition", "=SUMIF(range, criteria, [sum_range])"),
+    function("COUNTIF", Conditional, Aggregate, 2, Some(2), "Count if condition", "=COUNTIF(range, criteria)"),
+    function("AVERAGEIF", Conditional, Aggregate, 2, Some(3), "Average if condition", "=AVERAGEIF(range, criteria, [avg_range])"),
+    function("SUMIFS", Conditional, Aggregate, 3, None, "Sum with multiple conditions", "=SUMIFS(sum_range, range1, criteria1, ...)"),
+    function("COUNTIFS", Conditional, Aggregate, 2, None, "Count with multiple conditions", "=COUNTIFS(range1, criteria1, ...)"),
+    function("AVERAGEIFS", Conditional, Aggregate, 3, None, "Average with multiple conditions", "=AVERAGEIFS(avg_range, range1, criteria1, ...)"),
+    function("MAXIFS", Conditional, Aggregate, 3, None, "Max with conditions", "=MAXIFS(max_range, range1, criteria1, ...)"),
+    function("MINIFS", Conditional, Aggregate, 3, None, "Min with conditions", "=MINIFS(min_range, range1, criteria1, ...)"),
+
+    // Array
+    function("UNIQUE", Array, RowWise, 1, Some(1), "Get unique values", "=UNIQUE(array)"),
+    function("COUNTUNIQUE", Array, RowWise, 1, Some(1), "Count unique values", "=COUNTUNIQUE(array)"),
+    function("FILTER", Array, RowWise, 2, Some(2), "Filter by criteria", "=FILTER(array, include)"),
+    function("SORT", Array, RowWise, 1, Some(2), "Sort values", "=SORT(array, [order])"),
+    function("SORTBY", Array, RowWise, 2, Some(3), "Sort by another array", "=SORTBY(array, by_array, [order])"),
+    function("RUNSUM", Array, RowWise, 1, Some(1), "Running total column", "=RUNSUM(array)"),
+    function("PCTTOTAL", Array, RowWise, 1, Some(1), "Share of column total per row", "=PCTTOTAL(array)"),
+    function("PREV", Array, RowWise, 1, Some(2), "Previous row's value", "=PREV(column, [seed]) * (1 + growth)"),
+    function("TRANSPOSE", Array, RowWise, 1, Some(2), "Column as a one-row table", "=TRANSPOSE(array, [headers])"),
+
+    // Aggregation
+    function("SUM", Aggregation, Aggregate, 1, None, "Sum values", "=SUM(value1, value2, ...)"),
+    function("AVERAGE", Aggregation, Aggregate, 1, None, "Average values", "=AVERAGE(value1, value2, ...) or =AVG(...)"),
+    function("MIN", Aggregation, Aggregate, 1, None, "Minimum value", "=MIN(value1, value2, ...)"),
+    function("MAX", Aggregation, Aggregate, 1, None, "Maximum value", "=MAX(value1, value2, ...)"),
+    function("COUNT", Aggregation, Aggregate, 1, None, "Count values", "=COUNT(array)"),
+    function("COUNTA", Aggregation, Aggregate, 1, None, "Count non-empty cells", "=COUNTA(array)"),
+    function("COUNTBLANK", Aggregation, Aggregate, 1, Some(1), "Count empty cells", "=COUNTBLANK(array)"),
+
+    // Math
+    function("ROUND", Math, RowWise, 2, Some(2), "Round to digits", "=ROUND(value, digits)"),
+    function("ROUNDUP", Math, RowWise, 2, Some(2), "Round up", "=ROUNDUP(value, digits)"),
+    function("ROUNDDOWN", Math, RowWise, 2, Some(2), "Round down", "=ROUNDDOWN(value, digits)"),
+    function("ROUNDBANK", Math, RowWise, 2, Some(2), "Round half to even (banker's rounding)", "=ROUNDBANK(value, digits)"),
+    function("INT", Math, RowWise, 1, Some(1), "Round down to an integer", "=INT(value)"),
+    function("TRUNC", Math, RowWise, 1, Some(2), "Truncate toward zero", "=TRUNC(value, [digits])"),
+    function("CEILING", Math, RowWise, 2, Some(2), "Round up to significance", "=CEILING(value, significance)"),
+    function("FLOOR", Math, RowWise, 2, Some(2), "Round down to significance", "=FLOOR(value, significance)"),
+    function("MOD", Math, RowWise, 2, Some(2), "Modulo/remainder", "=MOD(value, divisor)"),
+    function("SQRT", Math, RowWise, 1, Some(1), "Square root", "=SQRT(value)"),
+    function("POWER", Math, RowWise, 2, Some(2), "Power/exponent", "=POWER(base, exponent)"),
+    function("ABS", Math, RowWise, 1, Some(1), "Absolute value", "=ABS(value)"),
+
+    // Text
+    function("CONCAT", Text, RowWise, 1, None, "Concatenate strings", "=CONCAT(text1, text2, ...)"),
+    function("CONCATENATE", Text, RowWise, 1, None, "Concatenate strings", "=CONCATENATE(text1, text2, ...)"),
+    function("TEXTJOIN", Text, RowWise, 3, None, "Join with delimiter", "=TEXTJOIN(delim, ignore_empty, text1, ...)"),
+    function("TRIM", Text, RowWise, 1, Some(1), "Remove extra spaces", "=TRIM(text)"),
+    function("UPPER", Text, RowWise, 1, Some(1), "Convert to uppercase", "=UPPER(text)"),
+    function("LOWER", Text, RowWise, 1, Some(1), "Convert to lowercase", "=LOWER(text)"),
+    function("LEN", Text, RowWise, 1, Some(1), "Length of text", "=LEN(text)"),
+    function("MID", Text, RowWise, 3, Some(3), "Extract substring", "=MID(text, start, length)"),
+    function("TEXT", Text, RowWise, 2, Some(2), "Format number as text", "=TEXT(value, \"$#,##0.00\")"),
+    function("SUBSTITUTE", Text, RowWise, 3, Some(4), "Replace text", "=SUBSTITUTE(text, old, new, [instance])"),
+    function("REPLACE", Text, RowWise, 4, Some(4), "Replace by position", "=REPLACE(text, start, length, new)"),
+    function("FIND", Text, RowWise, 2, Some(3), "Position (case-sensitive)", "=FIND(find_text, text, [start])"),
+    function("SEARCH", Text, RowWise, 2, Some(3), "Position (ignore case)", "=SEARCH(find_text, text, [start])"),
+
+    // Date
+    function("TODAY", Date, RowWise, 0, Some(0), "Current date", "=TODAY()"),
+    function("DATE", Date, RowWise, 3, Some(3), "Create date", "=DATE(year, month, day)"),
+    function("YEAR", Date, RowWise, 1, Some(1), "Extract year", "=YEAR(date)"),
+    function("MONTH", Date, RowWise, 1, Some(1), "Extract month", "=MONTH(date)"),
+    function("DAY", Date, RowWise, 1, Some(1), "Extract day", "=DAY(date)"),
+    function("DATEDIF", Date, RowWise, 3, Some(3), "Date difference", "=DATEDIF(start, end, unit)"),
+    function("DAYS", Date, RowWise, 2, Some(2), "Days between dates", "=DAYS(end, start)"),
+    function("DATEVALUE", Date, RowWise, 1, Some(1), "Text to date", "=DATEVALUE(\\\"2024-03-01\\\")"),
+    function("EDATE", Date, RowWise, 2, Some(2), "Add months to date", "=EDATE(start, months)"),
+    function("EOMONTH", Date, RowWise, 2, Some(2), "End of month", "=EOMONTH(start, months)"),
+    function("NETWORKDAYS", Date, RowWise, 2, Some(3), "Working days between dates", "=NETWORKDAYS(start, end, [holidays])"),
+    function("WORKDAY", Date, RowWise, 2, Some(3), "Date after N working days", "=WORKDAY(start, days, [holidays])"),
+    function("YEARFRAC", Date, RowWise, 2, Some(3), "Fraction of year", "=YEARFRAC(start, end, [basis])"),
+    function("WEEKDAY", Date, RowWise, 1, Some(2), "Day of week", "=WEEKDAY(date, [return_type])"),
+    function("WEEKNUM", Date, RowWise, 1, Some(2), "Week of year", "=WEEKNUM(date, [return_type])"),
+
+    // Logic
+    function("IF", Logic, RowWise, 2, Some(3), "Conditional", "=IF(condition, true_value, [false_value])"),
+    function("IFS", Logic, RowWise, 2, None, "First true condition", "=IFS(cond1, val1, cond2, val2, ..., [TRUE, default])"),
+    function("AND", Logic, RowWise, 1, None, "Logical AND", "=AND(condition1, condition2, ...)"),
+    function("OR", Logic, RowWise, 1, None, "Logical OR", "=OR(condition1, condition2, ...)"),
+    function("NOT", Logic, RowWise, 1, Some(1), "Logical NOT", "=NOT(condition)"),
+    function("LET", Logic, RowWise, 3, None, "Named variables", "=LET(name, value, ..., calculation)"),
+    function("SWITCH", Logic, RowWise, 3, None, "Multi-match", "=SWITCH(expr, val1, result1, ..., [default])"),
+    function("INDIRECT", Logic, RowWise, 1, Some(1), "String to ref", "=INDIRECT(\\\"table.column\\\")"),
+    function("LAMBDA", Logic, RowWise, 1, None, "Anonymous func", "=LAMBDA(x, x*2)(5)"),
+    function("IFERROR", Logic, RowWise, 2, Some(2), "Catch errors", "=IFERROR(value, value_if_error)"),
+    function("IFNA", Logic, RowWise, 2, Some(2), "Catch not-found", "=IFNA(value, value_if_na)"),
+    function("ISBLANK", Logic, RowWise, 1, Some(1), "Cell is empty", "=ISBLANK(value)"),
+    function("ISNUMBER", Logic, RowWise, 1, Some(1), "Cell is a number", "=ISNUMBER(value)"),
+    function("ISTEXT", Logic, RowWise, 1, Some(1), "Cell is text", "=ISTEXT(value)"),
+
+    // Statistical
+    function("MEDIAN", Statistical, Aggregate, 1, None, "Middle value", "=MEDIAN(array)"),
+    function("VAR", Statistical, Aggregate, 1, None, "Variance (sample)", "=VAR(array)"),
+    function("VAR.S", Statistical, Aggregate, 1, None, "Variance (sample)", "=VAR.S(array)"),
+    function("VAR.P", Statistical, Aggregate, 1, None, "Variance (population)", "=VAR.P(array)"),
+    function("STDEV", Statistical, Aggregate, 1, None, "Standard deviation (sample)", "=STDEV(array)"),
+    function("STDEV.S", Statistical, Aggregate, 1, None, "Standard deviation (sample)", "=STDEV.S(array)"),
+    function("STDEV.P", Statistical, Aggregate, 1, None, "Standard deviation (population)", "=STDEV.P(array)"),
+    function("PERCENTILE", Statistical, Aggregate, 2, Some(2), "Percentile value", "=PERCENTILE(array, k)"),
+    function("QUARTILE", Statistical, Aggregate, 2, Some(2), "Quartile value", "=QUARTILE(array, quart)"),
+    function("CORREL", Statistical, Aggregate, 2, Some(2), "Correlation coefficient", "=CORREL(array1, array2)"),
+    function("TREND", Statistical, Aggregate, 2, Some(3), "Linear projection", "=TREND(known_y, known_x, [new_x])"),
+    function("GROWTH", Statistical, Aggregate, 2, Some(3), "Exponential projection", "=GROWTH(known_y, known_x, [new_x])"),
+    function("FORECAST", Statistical, Aggregate, 3, Some(3), "Linear prediction at x", "=FORECAST(x, known_y, known_x)"),
+    function("SLOPE", Statistical, Aggregate, 2, Some(2), "Regression slope", "=SLOPE(known_y, known_x)"),
+    function("INTERCEPT", Statistical, Aggregate, 2, Some(2), "Regression intercept", "=INTERCEPT(known_y, known_x)"),
+
+    // ForgeNative
+    function("SCENARIO", ForgeNative, RowWise, 2, Some(2), "Get scenario value", "=SCENARIO(name, variable)"),
+    function("VARIANCE", ForgeNative, RowWise, 2, Some(2), "Budget variance", "=VARIANCE(actual, budget)"),
+    function("VARIANCE_PCT", ForgeNative, RowWise, 2, Some(2), "Variance percent", "=VARIANCE_PCT(actual, budget)"),
+    function("VARIANCE_STATUS", ForgeNative, RowWise, 2, Some(3), "Variance status", "=VARIANCE_STATUS(actual, budget, [type])"),
+    function("BREAKEVEN_UNITS", ForgeNative, RowWise, 3, Some(3), "Break-even units", "=BREAKEVEN_UNITS(fixed, price, var_cost)"),
+    function("BREAKEVEN_REVENUE", ForgeNative, RowWise, 2, Some(2), "Break-even revenue", "=BREAKEVEN_REVENUE(fixed, margin_pct)"),
+];
+
+/// Alternative spellings accepted by the evaluator
+const ALIASES: &[(&str, &str)] = &[("AVG", "AVERAGE")];
+
+/// Lookup over the supported functions
+pub struct FunctionRegistry;
+
+impl FunctionRegistry {
+    /// Every function, grouped by category in display order
+    pub fn all() -> &'static [FunctionInfo] {
+        FUNCTIONS
+    }
+
+    /// Look up a function by name (case-insensitive, aliases resolved)
+    pub fn get(name: &str) -> Option<&'static FunctionInfo> {
+        let upper = name.to_uppercase();
+        let canonical = ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == upper)
+            .map_or(upper.as_str(), |&(_, target)| target);
+        FUNCTIONS.iter().find(|f| f.name == canonical)
+    }
+
+    /// Functions in one category
+    pub fn in_category(category: FunctionCategory) -> impl Iterator<Item = &'static FunctionInfo> {
+        FUNCTIONS.iter().filter(move |f| f.category == category)
+    }
+
+    /// Known functions called in a formula, in order of appearance
+    ///
+    /// String literals are skipped, so `"SUM(x)"` inside quotes is not a call.
+    pub fn calls(formula: &str) -> Vec<&'static FunctionInfo> {
+        let mut calls = Vec::new();
+        let mut name = String::new();
+        // Whitespace ends a name, but `SUM (x)` is still a call
+        let mut name_ended = false;
+        let mut in_quotes = false;
+
+        for c in formula.chars() {
+            if c == '"' {
+                in_quotes = !in_quotes;
+                name.clear();
+            } else if in_quotes {
+                continue;
+            } else if c.is_alphanumeric() || c == '_' || c == '.' {
+                if name_ended {
+                    name.clear();
+                    name_ended = false;
+                }
+                name.push(c);
+            } else if c.is_whitespace() {
+                name_ended = true;
+            } else {
+                if c == '(' {
+                    if let Some(info) = Self::get(&name) {
+                        calls.push(info);
+                    }
+                }
+                name.clear();
+            }
+        }
+        calls
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_lookup() {
+        let pmt = FunctionRegistry::get("pmt").unwrap();
+        assert_eq!(pmt.category, FunctionCategory::Financial);
+        assert_eq!(pmt.signature, "=PMT(rate, nper, pv, [fv], [type])");
+
+        assert_eq!(FunctionRegistry::get("AVG").unwrap().name, "AVERAGE");
+        assert!(FunctionRegistry::get("NOPE").is_none());
+    }
+
+    #[test]
+    fn test_registry_is_grouped_by_category() {
+        // Entries of a category are contiguous and categories follow ALL
+        let mut order: Vec<FunctionCategory> = Vec::new();
+        for f in FunctionRegistry::all() {
+            if order.last() != Some(&f.category) {
+                assert!(!order.contains(&f.category), "{} is split", f.name);
+                order.push(f.category);
+            }
+        }
+        assert_eq!(order, FunctionCategory::ALL);
+
+        let financial: Vec<&str> = FunctionRegistry::in_category(FunctionCategory::Financial)
+            .map(|f| f.name)
+            .collect();
+        assert_eq!(&financial[..2], ["NPV", "IRR"]);
+    }
+
+    #[test]
+    fn test_registry_kind_and_arity() {
+        let sum = FunctionRegistry::get("SUM").unwrap();
+        assert_eq!(sum.kind, FunctionKind::Aggregate);
+        assert_eq!((sum.min_args, sum.max_args), (1, None));
+
+        let round = FunctionRegistry::get("ROUND").unwrap();
+        assert_eq!(round.kind, FunctionKind::RowWise);
+        assert_eq!((round.min_args, round.max_args), (2, Some(2)));
+
+        for f in FunctionRegistry::all() {
+            assert!(
+                f.max_args.unwrap_or(f.min_args) >= f.min_args,
+                "{} has max_args < min_args",
+                f.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_registry_calls() {
+        let names = |formula: &str| -> Vec<&str> {
+            FunctionRegistry::calls(formula)
+                .iter()
+                .map(|f| f.name)
+                .collect()
+        };
+        assert_eq!(
+            names("=ROUND(avg(x), 2) + SUM (y)"),
+            ["ROUND", "AVERAGE", "SUM"]
+        );
+        assert_eq!(names("=STDEV.S(data.values)"), ["STDEV.S"]);
+        assert_eq!(names("=RUNSUM(revenue)"), ["RUNSUM"]);
+        assert_eq!(names("=IF(x > 0, \"SUM(a)\", \"none\")"), ["IF"]);
+        assert!(names("=revenue * 2").is_empty());
+        assert!(names("=PRODUCT(values)").is_empty());
+    }
+
+    #[test]
+    fn test_registry_covers_evaluator_functions() {
+        // Every function name the evaluator matches on must be registered
+        let sources = [
+            include_str!("array_calculator/mod.rs"),
+            include_str!("array_calculator/arithmetic.rs"),
+            include_str!("array_calculator/dates.rs"),
+            include_str!("array_calculator/literals.rs"),
+            include_str!("array_calculator/math.rs"),
+            include_str!("array_calculator/reshape.rs"),
+            include_str!("array_calculator/slices.rs"),
+            include_str!("array_calculator/syntax.rs"),
+            include_str!("array_calculator/text.rs"),
+            include_str!("array_calculator/units.rs"),
+        ];
+        let call = regex::Regex::new(r#""=?([A-Z][A-Z0-9_.]*)\("#).unwrap();
+        let lookup = regex::Regex::new(r"find_function_call\([^;]*?&\[([^\]]*)\]").unwrap();
+        let quoted = regex::Regex::new(r#""([A-Z][A-Z0-9_.]*)""#).unwrap();
+
+        let mut names = std::collections::BTreeSet::new();
+        for source in sources {
+            names.extend(call.captures_iter(source).map(|c| c[1].to_string()));
+            for list in lookup.captures_iter(source) {
+                names.extend(quoted.captures_iter(&list[1]).map(|c| c[1].to_string()));
+            }
+        }
+
+        assert!(names.contains("SUMIFS") && names.contains("SORTBY"));
+        let missing: Vec<&String> = names
+            .iter()
+            .filter(|name| FunctionRegistry::get(name).is_none())
+            .collect();
+        assert!(missing.is_empty(), "not in the registry: {:?}", missing);
+    }
+}
@@ -0,0 +1,213 @@
+//! Relational joins between tables
+//!
+//! Matches the rows of a left table (e.g. `orders`) to the rows of a right
+//! table (e.g. `products`) with the same value in a key column, and brings
+//! the right table's other columns into the result. Every match produces a
+//! row, so a key repeated in the right table repeats the left row.
+
+use std::collections::HashMap;
+
+use crate::error::{ForgeError, ForgeResult};
+use crate::types::{Column, ColumnValue, Table};
+
+/// Which left rows a join keeps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinKind {
+    /// Only rows with a matching key in the right table
+    #[default]
+    Inner,
+    /// Every left row; unmatched rows get 0, "" or FALSE in the right columns
+    Left,
+}
+
+/// Join `right` into `left` on the key column `on`
+///
+/// The result is named after `left` and holds its columns followed by the
+/// right table's non-key columns; a right column whose name is already used
+/// is prefixed with the right table's name (`products_name`). Both key
+/// columns must have the same type. A left join cannot leave a date column
+/// blank, so unmatched rows are an error when the right table has one.
+pub fn join_tables(left: &Table, right: &Table, on: &str, kind: JoinKind) -> ForgeResult<Table> {
+    let (left_keys, right_keys) = (key_column(left, on)?, key_column(right, on)?);
+    if left_keys.type_name() != right_keys.type_name() {
+        return Err(ForgeError::Validation(format!(
+            "Join key '{}' is {} in '{}' but {} in '{}'",
+            on,
+            left_keys.type_name(),
+            left.name,
+            right_keys.type_name(),
+            right.name
+        )));
+    }
+
+    let mut right_rows: HashMap<String, Vec<usize>> = HashMap::new();
+    for (row, key) in key_strings(right_keys).into_iter().enumerate() {
+        right_rows.entry(key).or_default().push(row);
+    }
+
+    // (left row, matching right row) for every output row
+    let mut pairs: Vec<(usize, Option<usize>)> = Vec::new();
+    for (row, key) in key_strings(left_keys).iter().enumerate() {
+        match right_rows.get(key) {
+            Some(matches) => pairs.extend(matches.iter().map(|&m| (row, Some(m)))),
+            None if kind == JoinKind::Left => pairs.push((row, None)),
+            None => {}
+        }
+    }
+
+    let mut joined = Table::new(left.name.clone());
+    let left_rows: Vec<usize> = pairs.iter().map(|(row, _)| *row).collect();
+    for column in left.columns.values() {
+        joined.add_column(Column::with_metadata(
+            column.name.clone(),
+            take_rows(&column.values, &left_rows),
+            column.metadata.clone(),
+        ));
+    }
+
+    let matched: Vec<Option<usize>> = pairs.iter().map(|(_, m)| *m).collect();
+    let mut right_names: Vec<&String> = right.columns.keys().filter(|n| *n != on).collect();
+    right_names.sort();
+    for name in right_names {
+        let column = &right.columns[name];
+        let values = take_matched(&column.values, &matched).ok_or_else(|| {
+            ForgeError::Validation(format!(
+                "Left join leaves date column '{}.{}' blank for unmatched rows; use an inner join",
+                right.name, name
+            ))
+        })?;
+        let joined_name = if joined.columns.contains_key(name) {
+            format!("{}_{}", right.name, name)
+        } else {
+            name.clone()
+        };
+        joined.add_column(Column::with_metadata(
+            joined_name,
+            values,
+            column.metadata.clone(),
+        ));
+    }
+
+    Ok(joined)
+}
+
+fn key_column<'a>(table: &'a Table, on: &str) -> ForgeResult<&'a ColumnValue> {
+    table.columns.get(on).map(|c| &c.values).ok_or_else(|| {
+        ForgeError::Validation(format!(
+            "Join key '{}' not found in table '{}'",
+            on, table.name
+        ))
+    })
+}
+
+/// Key values as comparable strings (`3` and `3.0` are the same key)
+fn key_strings(values: &ColumnValue) -> Vec<String> {
+    match values {
+        ColumnValue::Number(v) => v.iter().map(|n| n.to_string()).collect(),
+        ColumnValue::Text(v) | ColumnValue::Date(v) => v.clone(),
+        ColumnValue::Boolean(v) => v.iter().map(|b| b.to_string()).collect(),
+    }
+}
+
+fn take_rows(values: &ColumnValue, rows: &[usize]) -> ColumnValue {
+    match values {
+        ColumnValue::Number(v) => ColumnValue::Number(rows.iter().map(|&i| v[i]).collect()),
+        ColumnValue::Text(v) => ColumnValue::Text(rows.iter().map(|&i| v[i].clone()).collect()),
+        ColumnValue::Date(v) => ColumnValue::Date(rows.iter().map(|&i| v[i].clone()).collect()),
+        ColumnValue::Boolean(v) => ColumnValue::Boolean(rows.iter().map(|&i| v[i]).collect()),
+    }
+}
+
+/// Rows of a right column, with blanks for unmatched rows (`None` for dates)
+fn take_matched(values: &ColumnValue, rows: &[Option<usize>]) -> Option<ColumnValue> {
+    Some(match values {
+        ColumnValue::Number(v) => {
+            ColumnValue::Number(rows.iter().map(|r| r.map_or(0.0, |i| v[i])).collect())
+        }
+        ColumnValue::Text(v) => ColumnValue::Text(
+            rows.iter()
+                .map(|r| r.map_or_else(String::new, |i| v[i].clone()))
+                .collect(),
+        ),
+        ColumnValue::Date(v) => ColumnValue::Date(
+            rows.iter()
+                .map(|r| r.map(|i| v[i].clone()))
+                .collect::<Option<Vec<_>>>()?,
+        ),
+        ColumnValue::Boolean(v) => {
+            ColumnValue::Boolean(rows.iter().map(|r| r.is_some_and(|i| v[i])).collect())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn orders() -> Table {
+        let mut orders = Table::new("orders".to_string());
+        orders.add_column(Column::new(
+            "product_id".to_string(),
+            ColumnValue::Text(vec!["A".into(), "B".into(), "C".into()]),
+        ));
+        orders.add_column(Column::new(
+            "quantity".to_string(),
+            ColumnValue::Number(vec![10.0, 20.0, 30.0]),
+        ));
+        orders
+    }
+
+    fn products() -> Table {
+        let mut products = Table::new("products".to_string());
+        products.add_column(Column::new(
+            "product_id".to_string(),
+            ColumnValue::Text(vec!["B".into(), "A".into()]),
+        ));
+        products.add_column(Column::new(
+            "price".to_string(),
+            ColumnValue::Number(vec![2.5, 1.5]),
+        ));
+        products.add_column(Column::new(
+            "quantity".to_string(),
+            ColumnValue::Number(vec![100.0, 200.0]),
+        ));
+        products
+    }
+
+    #[test]
+    fn test_join_orders_to_products() {
+        let joined = join_tables(&orders(), &products(), "product_id", JoinKind::Inner).unwrap();
+        assert_eq!(joined.name, "orders");
+        assert_eq!(joined.row_count(), 2);
+        assert_eq!(
+            joined.columns["product_id"].values,
+            ColumnValue::Text(vec!["A".into(), "B".into()])
+        );
+        assert_eq!(
+            joined.columns["price"].values,
+            ColumnValue::Number(vec![1.5, 2.5])
+        );
+        // Clashing right columns are prefixed with the right table's name
+        assert_eq!(
+            joined.columns["products_quantity"].values,
+            ColumnValue::Number(vec![200.0, 100.0])
+        );
+
+        let joined = join_tables(&orders(), &products(), "product_id", JoinKind::Left).unwrap();
+        assert_eq!(joined.row_count(), 3);
+        assert_eq!(
+            joined.columns["quantity"].values,
+            ColumnValue::Number(vec![10.0, 20.0, 30.0])
+        );
+        assert_eq!(
+            joined.columns["price"].values,
+            ColumnValue::Number(vec![1.5, 2.5, 0.0])
+        );
+
+        let err = join_tables(&orders(), &products(), "sku", JoinKind::Inner).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Join key 'sku' not found in table 'orders'"
+        );
+    }
+}
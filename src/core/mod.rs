@@ -1,7 +1,11 @@
 //! Core calculation engine for v1.0.0 array models
 
 pub mod array_calculator;
+pub mod functions;
+pub mod join;
 pub mod unit_validator;
 
-pub use array_calculator::ArrayCalculator;
-pub use unit_validator::{UnitValidator, UnitWarning};
+pub use array_calculator::{ArrayCalculator, CalcStats, CalculationOrder, Progress};
+pub use functions::{FunctionCategory, FunctionInfo, FunctionKind, FunctionRegistry};
+pub use join::{join_tables, JoinKind};
+pub use unit_validator::{CompoundUnit, UnitValidator, UnitWarning};
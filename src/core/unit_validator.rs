@@ -1,9 +1,12 @@
 //! Unit consistency validation for v4.0 rich metadata
 //!
 //! Validates that formulas don't mix incompatible units (e.g., CAD + %)
+//! and, for `calculate --check-units`, propagates compound units through
+//! arithmetic to reject adding values with different units.
 
 use crate::types::ParsedModel;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 
 /// Unit categories for compatibility checking
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -285,6 +288,308 @@ impl<'a> UnitValidator<'a> {
     }
 }
 
+// =============================================================================
+// Strict unit propagation (calculate --check-units)
+// =============================================================================
+
+/// A unit as exponents of base units, e.g. `USD/unit` is `{USD: 1, unit: -1}`
+///
+/// No base units means dimensionless: plain numbers, `%` and ratios.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompoundUnit(BTreeMap<String, i32>);
+
+impl CompoundUnit {
+    /// Parse a unit annotation such as `USD`, `kg`, `USD/unit` or `kg*m`
+    pub fn parse(unit: &str) -> Self {
+        let mut result = Self::default();
+        for (i, part) in unit.split('/').enumerate() {
+            let exponent = if i == 0 { 1 } else { -1 };
+            for base in part.split('*').map(str::trim) {
+                if base.is_empty() || base == "1" {
+                    continue;
+                }
+                match UnitCategory::parse(base) {
+                    UnitCategory::Percentage | UnitCategory::Ratio => {}
+                    UnitCategory::Currency(code) => result.add(code, exponent),
+                    _ => result.add(base.to_string(), exponent),
+                }
+            }
+        }
+        result
+    }
+
+    pub fn is_dimensionless(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn add(&mut self, base: String, exponent: i32) {
+        let entry = self.0.entry(base.clone()).or_insert(0);
+        *entry += exponent;
+        if *entry == 0 {
+            self.0.remove(&base);
+        }
+    }
+
+    fn multiply(&self, other: &Self, sign: i32) -> Self {
+        let mut result = self.clone();
+        for (base, exponent) in &other.0 {
+            result.add(base.clone(), exponent * sign);
+        }
+        result
+    }
+}
+
+impl fmt::Display for CompoundUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_dimensionless() {
+            return write!(f, "dimensionless");
+        }
+        let term = |base: &String, exponent: i32| {
+            if exponent == 1 {
+                base.clone()
+            } else {
+                format!("{}^{}", base, exponent)
+            }
+        };
+        let numerator: Vec<String> = self
+            .0
+            .iter()
+            .filter(|(_, e)| **e > 0)
+            .map(|(b, e)| term(b, *e))
+            .collect();
+        let denominator: Vec<String> = self
+            .0
+            .iter()
+            .filter(|(_, e)| **e < 0)
+            .map(|(b, e)| term(b, -e))
+            .collect();
+
+        if numerator.is_empty() {
+            write!(f, "1")?;
+        } else {
+            write!(f, "{}", numerator.join("*"))?;
+        }
+        match denominator.len() {
+            0 => Ok(()),
+            1 => write!(f, "/{}", denominator[0]),
+            _ => write!(f, "/({})", denominator.join("*")),
+        }
+    }
+}
+
+/// Functions whose result has the unit of their first argument
+const UNIT_PRESERVING_FUNCTIONS: &[&str] = &[
+    "SUM",
+    "AVERAGE",
+    "AVG",
+    "MIN",
+    "MAX",
+    "MEDIAN",
+    "ABS",
+    "ROUND",
+    "ROUNDUP",
+    "ROUNDDOWN",
+    "ROUNDBANK",
+    "INT",
+    "TRUNC",
+];
+
+/// Infer the unit of a formula's result through `+ - * /` and parentheses
+///
+/// `lookup` gives the unit of a referenced name, `None` when it has none.
+/// Adding or subtracting two different units is an error; plain numbers are
+/// dimensionless and combine with anything. Other functions, `^` and
+/// comparisons give an unknown unit (`Ok(None)`), as does anything the
+/// checker cannot parse.
+pub fn infer_formula_unit<F>(formula: &str, lookup: F) -> Result<Option<CompoundUnit>, String>
+where
+    F: Fn(&str) -> Option<CompoundUnit>,
+{
+    let mut parser = UnitParser {
+        chars: formula.trim().trim_start_matches('=').chars().collect(),
+        pos: 0,
+        lookup: &lookup,
+        bail: false,
+    };
+    let unit = parser.expr()?;
+    if parser.bail || parser.peek().is_some() {
+        return Ok(None);
+    }
+    Ok(unit)
+}
+
+/// Recursive-descent walk over a formula, tracking units instead of values
+struct UnitParser<'a, F> {
+    chars: Vec<char>,
+    pos: usize,
+    lookup: &'a F,
+    /// Set on input the checker does not understand; the result is unknown
+    bail: bool,
+}
+
+type UnitResult = Result<Option<CompoundUnit>, String>;
+
+impl<F> UnitParser<'_, F>
+where
+    F: Fn(&str) -> Option<CompoundUnit>,
+{
+    fn peek(&mut self) -> Option<char> {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+        self.chars.get(self.pos).copied()
+    }
+
+    fn expr(&mut self) -> UnitResult {
+        let mut unit = self.term()?;
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.pos += 1;
+            let rhs = self.term()?;
+            unit = match (unit, rhs) {
+                (Some(a), Some(b)) if a == b || b.is_dimensionless() => Some(a),
+                (Some(a), Some(b)) if a.is_dimensionless() => Some(b),
+                (Some(a), Some(b)) => {
+                    let verb = if op == '+' { "add" } else { "subtract" };
+                    return Err(format!("cannot {} {} and {}", verb, a, b));
+                }
+                _ => None,
+            };
+        }
+        Ok(unit)
+    }
+
+    fn term(&mut self) -> UnitResult {
+        let mut unit = self.factor()?;
+        while let Some(op @ ('*' | '/')) = self.peek() {
+            self.pos += 1;
+            let rhs = self.factor()?;
+            let sign = if op == '*' { 1 } else { -1 };
+            unit = match (unit, rhs) {
+                (Some(a), Some(b)) => Some(a.multiply(&b, sign)),
+                _ => None,
+            };
+        }
+        Ok(unit)
+    }
+
+    fn factor(&mut self) -> UnitResult {
+        let base = self.unary()?;
+        if self.peek() == Some('^') {
+            self.pos += 1;
+            self.factor()?;
+            return Ok(base.filter(CompoundUnit::is_dimensionless));
+        }
+        Ok(base)
+    }
+
+    fn unary(&mut self) -> UnitResult {
+        if let Some('+' | '-') = self.peek() {
+            self.pos += 1;
+            return self.unary();
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> UnitResult {
+        let c = match self.peek() {
+            Some(c) => c,
+            None => {
+                self.bail = true;
+                return Ok(None);
+            }
+        };
+
+        if c == '(' {
+            self.pos += 1;
+            let unit = self.expr()?;
+            if self.peek() == Some(')') {
+                self.pos += 1;
+            } else {
+                self.bail = true;
+            }
+            return Ok(unit);
+        }
+
+        if c.is_ascii_digit() || c == '.' {
+            while self
+                .chars
+                .get(self.pos)
+                .is_some_and(|c| c.is_ascii_digit() || *c == '.')
+            {
+                self.pos += 1;
+            }
+            if self.chars.get(self.pos) == Some(&'%') {
+                self.pos += 1;
+            }
+            return Ok(Some(CompoundUnit::default()));
+        }
+
+        if c == '"' {
+            self.pos += 1;
+            while self.chars.get(self.pos).is_some_and(|c| *c != '"') {
+                self.pos += 1;
+            }
+            self.pos += 1;
+            return Ok(None);
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let start = self.pos;
+            while self
+                .chars
+                .get(self.pos)
+                .is_some_and(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+            {
+                self.pos += 1;
+            }
+            let name: String = self.chars[start..self.pos].iter().collect();
+
+            if self.peek() == Some('(') {
+                self.pos += 1;
+                let upper = name.to_uppercase();
+                let unit = if UNIT_PRESERVING_FUNCTIONS.contains(&upper.as_str()) {
+                    self.expr()?
+                } else {
+                    None
+                };
+                self.skip_to_close(')');
+                return Ok(unit);
+            }
+            if self.peek() == Some('[') {
+                self.pos += 1;
+                self.skip_to_close(']');
+            }
+            return Ok((self.lookup)(&name));
+        }
+
+        self.bail = true;
+        Ok(None)
+    }
+
+    /// Skip past the bracket closing the one already consumed
+    fn skip_to_close(&mut self, close: char) {
+        let open = if close == ')' { '(' } else { '[' };
+        let mut depth = 1;
+        let mut in_string = false;
+        while let Some(&c) = self.chars.get(self.pos) {
+            self.pos += 1;
+            match c {
+                '"' => in_string = !in_string,
+                _ if in_string => {}
+                _ if c == open => depth += 1,
+                _ if c == close => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.bail = true;
+    }
+}
+
 /// Check if a token is a known function name
 fn is_function_name(token: &str) -> bool {
     let upper = token.to_uppercase();
@@ -945,4 +1250,47 @@ mod tests {
             unit
         );
     }
+
+    #[test]
+    fn test_compound_unit_parse_and_display() {
+        assert_eq!(CompoundUnit::parse("usd").to_string(), "USD");
+        assert_eq!(CompoundUnit::parse("USD/unit").to_string(), "USD/unit");
+        assert_eq!(CompoundUnit::parse("kg*m/s*s").to_string(), "kg*m/s^2");
+        assert!(CompoundUnit::parse("%").is_dimensionless());
+        assert!(CompoundUnit::parse("ratio").is_dimensionless());
+    }
+
+    #[test]
+    fn test_infer_formula_unit() {
+        let lookup = |name: &str| match name {
+            "revenue" => Some(CompoundUnit::parse("USD")),
+            "weight" => Some(CompoundUnit::parse("kg")),
+            "units_sold" => Some(CompoundUnit::parse("unit")),
+            "margin" => Some(CompoundUnit::parse("%")),
+            _ => None,
+        };
+        let infer = |formula: &str| {
+            infer_formula_unit(formula, lookup).map(|unit| unit.map(|u| u.to_string()))
+        };
+
+        assert_eq!(
+            infer("=revenue + weight"),
+            Err("cannot add USD and kg".to_string())
+        );
+        assert_eq!(
+            infer("=SUM(revenue) - weight"),
+            Err("cannot subtract USD and kg".to_string())
+        );
+        assert_eq!(
+            infer("=revenue * (1 + margin)"),
+            Ok(Some("USD".to_string()))
+        );
+        assert_eq!(
+            infer("=revenue / units_sold"),
+            Ok(Some("USD/unit".to_string()))
+        );
+        // Unknown references and functions stop propagation, not checking
+        assert_eq!(infer("=revenue + unknown"), Ok(None));
+        assert_eq!(infer("=IF(revenue > 0, weight, 0)"), Ok(None));
+    }
 }
@@ -16,12 +16,29 @@ pub enum ForgeError {
     #[error("Formula evaluation error: {0}")]
     Eval(String),
 
-    #[error("Circular dependency detected: {0}")]
-    CircularDependency(String),
+    /// Malformed formula, with the 0-based character offset of the problem
+    #[error("Parse error in {location} at position {position}: {message}")]
+    FormulaSyntax {
+        location: String,
+        position: usize,
+        message: String,
+    },
+
+    /// Dependency cycle as the names along it, closed on the first name
+    #[error("Circular dependency: {}", .0.join(" → "))]
+    CircularDependency(Vec<String>),
+
+    /// Include cycle as the file names along it, closed on the first file
+    #[error("Circular include: {}", .0.join(" → "))]
+    CircularInclude(Vec<String>),
 
     #[error("Validation error: {0}")]
     Validation(String),
 
+    /// Stored values that no longer match their formulas (`forge validate`)
+    #[error("Validation error: {0}")]
+    StaleValues(String),
+
     #[error("Excel export error: {0}")]
     Export(String),
 
@@ -31,6 +48,10 @@ pub enum ForgeError {
     #[error("IO error: {0}")]
     IO(String),
 
+    /// Calculation stopped because it ran past its time limit
+    #[error("Calculation timed out after {}ms", .0.as_millis())]
+    Timeout(std::time::Duration),
+
     /// Rich formula error with context (v4.1.0)
     #[error("{}", .0.format_error())]
     Formula(FormulaErrorContext),
@@ -73,6 +94,9 @@ impl FormulaErrorContext {
     }
 
     /// Find similar column names for "did you mean?" suggestions
+    ///
+    /// Tries a case-insensitive exact, prefix and contains match, then the
+    /// closest name within an edit distance of 2.
     pub fn find_similar(&self, target: &str) -> Option<String> {
         let target_lower = target.to_lowercase();
 
@@ -101,7 +125,13 @@ impl FormulaErrorContext {
             }
         }
 
-        None
+        // Closest by edit distance
+        self.available_columns
+            .iter()
+            .map(|col| (edit_distance(&target_lower, &col.to_lowercase()), col))
+            .filter(|(distance, _)| *distance <= 2)
+            .min()
+            .map(|(_, col)| col.clone())
     }
 
     /// Format the error message with context
@@ -140,6 +170,48 @@ pub fn formula_error(
     ForgeError::Formula(ctx)
 }
 
+/// Error for a name that matches no column or scalar
+///
+/// Suggests the closest of `known` when it is within an edit distance of 2;
+/// ties go to the alphabetically first name, whatever order `known` is in.
+pub fn unknown_reference<'a>(name: &str, known: impl IntoIterator<Item = &'a str>) -> ForgeError {
+    let closest = known
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min();
+
+    match closest {
+        Some((_, candidate)) => ForgeError::Eval(format!(
+            "Unknown reference '{}'; did you mean '{}'?",
+            name, candidate
+        )),
+        None => ForgeError::Eval(format!("Unknown reference '{}'", name)),
+    }
+}
+
+/// Levenshtein distance between two strings, in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -211,6 +283,17 @@ mod tests {
         assert_eq!(ctx.find_similar("xyz"), None);
     }
 
+    #[test]
+    fn test_formula_error_context_find_similar_edit_distance() {
+        let ctx = FormulaErrorContext::new("=SUM(a)", "test.col", "error")
+            .with_available_columns(vec!["price".to_string(), "cost".to_string()]);
+
+        // Within two edits, closest wins
+        assert_eq!(ctx.find_similar("prise"), Some("price".to_string()));
+        assert_eq!(ctx.find_similar("cots"), Some("cost".to_string()));
+        assert_eq!(ctx.find_similar("margin"), None);
+    }
+
     #[test]
     fn test_formula_error_context_format_error_basic() {
         let ctx = FormulaErrorContext::new("=SUM(a)", "test.col", "undefined reference");
@@ -273,6 +356,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("revenue", "revenue"), 0);
+        assert_eq!(edit_distance("revenu", "revenue"), 1);
+        assert_eq!(edit_distance("cots", "cost"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_unknown_reference_suggests_within_two_edits() {
+        let known = ["revenue", "cost", "margin"];
+
+        let err = unknown_reference("revenu", known);
+        assert_eq!(
+            err.to_string(),
+            "Formula evaluation error: Unknown reference 'revenu'; did you mean 'revenue'?"
+        );
+
+        let err = unknown_reference("headcount", known);
+        assert!(!err.to_string().contains("did you mean"));
+
+        // Prefixes and substrings more than two edits away are not suggested
+        let known = ["revenue_total", "gross_margin"];
+        assert!(!unknown_reference("rev", known)
+            .to_string()
+            .contains("did you mean"));
+        assert!(!unknown_reference("margin", known)
+            .to_string()
+            .contains("did you mean"));
+    }
+
     #[test]
     fn test_forge_error_display() {
         // Test Display implementation for each variant
@@ -285,8 +399,32 @@ mod tests {
         let eval_err = ForgeError::Eval("division by zero".to_string());
         assert!(eval_err.to_string().contains("division by zero"));
 
-        let circular_err = ForgeError::CircularDependency("A -> B -> A".to_string());
-        assert!(circular_err.to_string().contains("A -> B -> A"));
+        let cycle_err =
+            ForgeError::CircularDependency(vec!["a".to_string(), "b".to_string(), "a".to_string()]);
+        assert_eq!(cycle_err.to_string(), "Circular dependency: a → b → a");
+
+        let include_err = ForgeError::CircularInclude(vec![
+            "a.yaml".to_string(),
+            "b.yaml".to_string(),
+            "a.yaml".to_string(),
+        ]);
+        assert_eq!(
+            include_err.to_string(),
+            "Circular include: a.yaml → b.yaml → a.yaml"
+        );
+
+        let syntax_err = ForgeError::FormulaSyntax {
+            location: "sales.profit".to_string(),
+            position: 8,
+            message: "unexpected ')'".to_string(),
+        };
+        assert_eq!(
+            syntax_err.to_string(),
+            "Parse error in sales.profit at position 8: unexpected ')'"
+        );
+
+        let timeout_err = ForgeError::Timeout(std::time::Duration::from_millis(250));
+        assert_eq!(timeout_err.to_string(), "Calculation timed out after 250ms");
 
         let validation_err = ForgeError::Validation("schema mismatch".to_string());
         assert!(validation_err.to_string().contains("schema mismatch"));
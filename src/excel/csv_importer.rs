@@ -0,0 +1,272 @@
+//! CSV importer implementation - CSV → YAML
+
+use crate::error::{ForgeError, ForgeResult};
+use crate::parser::is_valid_date_format;
+use crate::types::{Column, ColumnValue, ParsedModel, Table};
+use std::path::Path;
+
+/// CSV importer for converting a .csv file to a single v1.0.0 YAML table
+pub struct CsvImporter {
+    path: std::path::PathBuf,
+}
+
+impl CsvImporter {
+    /// Create a new CSV importer
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Import CSV file to ParsedModel
+    /// The table is named after the file stem; column types are inferred from the cells
+    pub fn import(&self) -> ForgeResult<ParsedModel> {
+        let content = std::fs::read_to_string(&self.path)
+            .map_err(|e| ForgeError::IO(format!("Failed to open CSV file: {}", e)))?;
+
+        let stem = self
+            .path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "table".to_string());
+        let table = self.parse_table(&self.sanitize_table_name(&stem), &content)?;
+
+        let mut model = ParsedModel::new();
+        model.add_table(table);
+        Ok(model)
+    }
+
+    /// Parse CSV content (header row + data rows) into a table
+    fn parse_table(&self, table_name: &str, content: &str) -> ForgeResult<Table> {
+        let mut records = parse_csv_records(content)?.into_iter();
+
+        let header = records
+            .next()
+            .ok_or_else(|| ForgeError::Import("CSV file is empty".to_string()))?;
+        let column_names: Vec<String> = header
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                let name = name.trim();
+                if name.is_empty() {
+                    format!("col_{}", idx)
+                } else {
+                    name.to_string()
+                }
+            })
+            .collect();
+
+        let mut cells: Vec<Vec<String>> = vec![Vec::new(); column_names.len()];
+        for (row_idx, record) in records.enumerate() {
+            if record.len() != column_names.len() {
+                return Err(ForgeError::Import(format!(
+                    "CSV row {} has {} fields, expected {}",
+                    row_idx + 2, // 1-based, after the header
+                    record.len(),
+                    column_names.len()
+                )));
+            }
+            for (col_idx, value) in record.into_iter().enumerate() {
+                cells[col_idx].push(value);
+            }
+        }
+
+        let mut table = Table::new(table_name.to_string());
+        for (name, values) in column_names.into_iter().zip(cells) {
+            table.add_column(Column::new(name, infer_column_value(&values)));
+        }
+
+        Ok(table)
+    }
+
+    /// Sanitize file stem to valid YAML key
+    fn sanitize_table_name(&self, stem: &str) -> String {
+        stem.to_lowercase()
+            .replace(' ', "_")
+            .replace("&", "and")
+            .replace("-", "_")
+            .chars()
+            .filter(|c| c.is_alphanumeric() || *c == '_')
+            .collect()
+    }
+}
+
+/// Split CSV content into records of fields
+/// Handles quoted fields with embedded commas, doubled quotes and line breaks;
+/// blank lines are skipped
+fn parse_csv_records(content: &str) -> ForgeResult<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if !(record.len() == 1 && record[0].is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(ForgeError::Import(
+            "CSV has an unterminated quoted field".to_string(),
+        ));
+    }
+
+    // Last record without a trailing newline
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Infer a column's type from its cells
+/// Number, Date (YYYY-MM or YYYY-MM-DD) or Boolean (TRUE/FALSE) when every cell
+/// matches; anything mixed falls back to Text
+fn infer_column_value(cells: &[String]) -> ColumnValue {
+    let trimmed: Vec<&str> = cells.iter().map(|c| c.trim()).collect();
+
+    if !trimmed.is_empty() {
+        if let Ok(numbers) = trimmed
+            .iter()
+            .map(|c| c.parse::<f64>())
+            .collect::<Result<Vec<f64>, _>>()
+        {
+            // "inf" and "NaN" parse as floats but are labels in a spreadsheet
+            if numbers.iter().all(|n| n.is_finite()) {
+                return ColumnValue::Number(numbers);
+            }
+        }
+
+        if trimmed.iter().all(|c| is_valid_date_format(c)) {
+            return ColumnValue::Date(trimmed.iter().map(|c| c.to_string()).collect());
+        }
+
+        if trimmed
+            .iter()
+            .all(|c| c.eq_ignore_ascii_case("true") || c.eq_ignore_ascii_case("false"))
+        {
+            return ColumnValue::Boolean(
+                trimmed
+                    .iter()
+                    .map(|c| c.eq_ignore_ascii_case("true"))
+                    .collect(),
+            );
+        }
+    }
+
+    ColumnValue::Text(cells.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    fn write_csv(dir: &TempDir, name: &str, content: &str) -> std::path::PathBuf {
+        let path = dir.path().join(name);
+        let mut file = std::fs::File::create(&path).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_parse_csv_records_quoting() {
+        let records =
+            parse_csv_records("name,note\r\n\"Smith, J\",\"said \"\"hi\"\"\nlater\"\n\n").unwrap();
+        assert_eq!(
+            records,
+            vec![
+                vec!["name".to_string(), "note".to_string()],
+                vec!["Smith, J".to_string(), "said \"hi\"\nlater".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_records_unterminated_quote() {
+        assert!(parse_csv_records("a\n\"open").is_err());
+    }
+
+    #[test]
+    fn test_import_numeric_column() {
+        let dir = TempDir::new().unwrap();
+        let path = write_csv(&dir, "Q1 Sales.csv", "revenue\n100\n250.5\n-3\n");
+
+        let model = CsvImporter::new(&path).import().unwrap();
+        let table = model
+            .tables
+            .get("q1_sales")
+            .expect("table named after file");
+        match &table.columns.get("revenue").unwrap().values {
+            ColumnValue::Number(nums) => assert_eq!(nums, &vec![100.0, 250.5, -3.0]),
+            _ => panic!("Expected Number column"),
+        }
+    }
+
+    #[test]
+    fn test_import_date_column() {
+        let dir = TempDir::new().unwrap();
+        let path = write_csv(
+            &dir,
+            "months.csv",
+            "month,active\n2025-01,TRUE\n2025-02,false\n",
+        );
+
+        let model = CsvImporter::new(&path).import().unwrap();
+        let table = model.tables.get("months").unwrap();
+        match &table.columns.get("month").unwrap().values {
+            ColumnValue::Date(dates) => assert_eq!(dates, &vec!["2025-01", "2025-02"]),
+            _ => panic!("Expected Date column"),
+        }
+        match &table.columns.get("active").unwrap().values {
+            ColumnValue::Boolean(bools) => assert_eq!(bools, &vec![true, false]),
+            _ => panic!("Expected Boolean column"),
+        }
+    }
+
+    #[test]
+    fn test_import_mixed_column_falls_back_to_text() {
+        let dir = TempDir::new().unwrap();
+        let path = write_csv(&dir, "mixed.csv", "code\n100\nA-7\n2025-01-01\n");
+
+        let model = CsvImporter::new(&path).import().unwrap();
+        match &model.tables["mixed"].columns["code"].values {
+            ColumnValue::Text(texts) => assert_eq!(texts, &vec!["100", "A-7", "2025-01-01"]),
+            _ => panic!("Expected Text column"),
+        }
+    }
+
+    #[test]
+    fn test_import_ragged_row_fails() {
+        let dir = TempDir::new().unwrap();
+        let path = write_csv(&dir, "ragged.csv", "a,b\n1,2\n3\n");
+
+        assert!(CsvImporter::new(&path).import().is_err());
+    }
+}
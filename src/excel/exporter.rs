@@ -1,8 +1,11 @@
 //! Excel exporter implementation
 
 use crate::error::{ForgeError, ForgeResult};
-use crate::types::{ColumnValue, Metadata, ParsedModel, Table};
-use rust_xlsxwriter::{Formula, Note, Workbook, Worksheet};
+use crate::types::{ColumnValue, FormatComparison, FormatRule, Metadata, ParsedModel, Table};
+use rust_xlsxwriter::{
+    Color, ConditionalFormatCell, ConditionalFormatCellRule, Format, Formula, Note, Workbook,
+    Worksheet,
+};
 use std::collections::HashMap;
 use std::path::Path;
 
@@ -154,8 +157,13 @@ impl ExcelExporter {
                 .map_err(|e| ForgeError::Export(format!("Failed to write header: {}", e)))?;
 
             // Add metadata as cell note if column has metadata (v4.0)
-            if let Some(column) = table.columns.get(col_name) {
-                if let Some(note_text) = Self::format_metadata_note(&column.metadata) {
+            let metadata = table
+                .columns
+                .get(col_name)
+                .map(|column| &column.metadata)
+                .or_else(|| table.formula_metadata.get(col_name));
+            if let Some(metadata) = metadata {
+                if let Some(note_text) = Self::format_metadata_note(metadata) {
                     let note = Note::new(note_text).set_author("Forge");
                     worksheet
                         .insert_note(0, col_idx as u16, &note)
@@ -199,9 +207,52 @@ impl ExcelExporter {
             }
         }
 
+        // Conditional formatting over each column's data cells
+        if row_count > 0 {
+            for (col_idx, col_name) in column_names.iter().enumerate() {
+                for rule in table.formatting.get(col_name).into_iter().flatten() {
+                    let col = col_idx as u16;
+                    worksheet
+                        .add_conditional_format(
+                            1,
+                            col,
+                            row_count as u32,
+                            col,
+                            &Self::conditional_format(rule),
+                        )
+                        .map_err(|e| {
+                            ForgeError::Export(format!("Failed to add conditional format: {}", e))
+                        })?;
+                }
+            }
+        }
+
         Ok(())
     }
 
+    /// Translate a formatting rule into an xlsx cell-value rule with a fill
+    fn conditional_format(rule: &FormatRule) -> ConditionalFormatCell {
+        let threshold = rule.threshold;
+        let cell_rule = match rule.comparison {
+            FormatComparison::LessThan => ConditionalFormatCellRule::LessThan(threshold),
+            FormatComparison::LessThanOrEqual => {
+                ConditionalFormatCellRule::LessThanOrEqualTo(threshold)
+            }
+            FormatComparison::GreaterThan => ConditionalFormatCellRule::GreaterThan(threshold),
+            FormatComparison::GreaterThanOrEqual => {
+                ConditionalFormatCellRule::GreaterThanOrEqualTo(threshold)
+            }
+            FormatComparison::EqualTo => ConditionalFormatCellRule::EqualTo(threshold),
+            FormatComparison::NotEqualTo => ConditionalFormatCellRule::NotEqualTo(threshold),
+        };
+        // The parser normalizes fills to #RRGGBB
+        let fill = u32::from_str_radix(rule.fill.trim_start_matches('#'), 16).unwrap_or(0xFFC7CE);
+
+        ConditionalFormatCell::new()
+            .set_rule(cell_rule)
+            .set_format(Format::new().set_background_color(Color::RGB(fill)))
+    }
+
     /// Write a single cell value based on column type
     fn write_cell_value(
         &self,
@@ -404,6 +455,9 @@ impl ExcelExporter {
         if let Some(unit) = &metadata.unit {
             parts.push(format!("Unit: {}", unit));
         }
+        if let Some(description) = &metadata.description {
+            parts.push(format!("Description: {}", description));
+        }
         if let Some(notes) = &metadata.notes {
             parts.push(format!("Notes: {}", notes));
         }
@@ -495,6 +549,7 @@ mod tests {
         let metadata = Metadata {
             unit: Some("CAD".to_string()),
             notes: Some("Important".to_string()),
+            description: None,
             source: Some("finance.yaml".to_string()),
             validation_status: Some("PROJECTED".to_string()),
             last_updated: Some("2025-11-26".to_string()),
@@ -823,6 +878,114 @@ mod tests {
         assert!(output_path.exists());
     }
 
+    #[test]
+    fn test_export_conditional_formatting() {
+        use std::io::Read;
+        use tempfile::TempDir;
+
+        let yaml = r#"
+_forge_version: "1.0.0"
+pl:
+  revenue: [100, 50, 80]
+  cost: [60, 70, 90]
+  profit:
+    formula: "=revenue - cost"
+    formatting:
+      - when: "< 0"
+        fill: red
+"#;
+        let model = crate::parser::parse_model_str(yaml).unwrap();
+        assert_eq!(
+            model.tables["pl"].formatting["profit"],
+            vec![FormatRule {
+                comparison: FormatComparison::LessThan,
+                threshold: 0.0,
+                fill: "#FFC7CE".to_string(),
+            }]
+        );
+
+        let dir = TempDir::new().unwrap();
+        let output_path = dir.path().join("formatting.xlsx");
+        ExcelExporter::new(model).export(&output_path).unwrap();
+
+        // Columns are alphabetical: cost (A), profit (B), revenue (C)
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(&output_path).unwrap()).unwrap();
+        let mut sheet = String::new();
+        archive
+            .by_name("xl/worksheets/sheet1.xml")
+            .unwrap()
+            .read_to_string(&mut sheet)
+            .unwrap();
+        assert!(sheet.contains(r#"<conditionalFormatting sqref="B2:B4">"#));
+        assert!(sheet.contains(r#"operator="lessThan"><formula>0</formula>"#));
+    }
+
+    #[test]
+    fn test_export_row_formulas_as_excel_formulas() {
+        use std::io::Read;
+        use tempfile::TempDir;
+
+        let yaml = r#"
+_forge_version: "1.0.0"
+pl:
+  revenue: [100, 50]
+  cost: [60, 70]
+  profit: "=revenue - cost"
+  margin: "=IF(revenue > 0, profit / revenue, 0)"
+"#;
+        let model = crate::parser::parse_model_str(yaml).unwrap();
+        let dir = TempDir::new().unwrap();
+        let output_path = dir.path().join("formulas.xlsx");
+        ExcelExporter::new(model).export(&output_path).unwrap();
+
+        // Columns are alphabetical: cost (A), margin (B), profit (C), revenue (D)
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(&output_path).unwrap()).unwrap();
+        let mut sheet = String::new();
+        archive
+            .by_name("xl/worksheets/sheet1.xml")
+            .unwrap()
+            .read_to_string(&mut sheet)
+            .unwrap();
+        assert!(sheet.contains(r#"<c r="C2"><f>D2 - A2</f>"#));
+        assert!(sheet.contains(r#"<c r="C3"><f>D3 - A3</f>"#));
+        assert!(sheet.contains(r#"<c r="B3"><f>IF(D3 &gt; 0, C3 / D3, 0)</f>"#));
+    }
+
+    #[test]
+    fn test_export_aggregations_as_cross_sheet_formulas() {
+        use std::io::Read;
+        use tempfile::TempDir;
+
+        let yaml = r#"
+_forge_version: "1.0.0"
+sales:
+  month: ["Jan", "Feb", "Mar"]
+  revenue: [100, 250, 300]
+summary:
+  total:
+    formula: "=SUM(sales.revenue)"
+  large:
+    formula: "=SUMIF(sales.revenue, '>200', sales.revenue)"
+"#;
+        let model = crate::parser::parse_model_str(yaml).unwrap();
+        let dir = TempDir::new().unwrap();
+        let output_path = dir.path().join("aggregations.xlsx");
+        ExcelExporter::new(model).export(&output_path).unwrap();
+
+        // Scalars sheet follows the sales sheet; rows are sorted by name
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(&output_path).unwrap()).unwrap();
+        let mut sheet = String::new();
+        archive
+            .by_name("xl/worksheets/sheet2.xml")
+            .unwrap()
+            .read_to_string(&mut sheet)
+            .unwrap();
+        assert!(
+            sheet.contains(r#"<c r="B2"><f>SUMIF('sales'!B2:B4, "&gt;200", 'sales'!B2:B4)</f>"#)
+        );
+        assert!(sheet.contains(r#"<c r="B3"><f>SUM('sales'!B2:B4)</f>"#));
+    }
+
     #[test]
     fn test_export_with_metadata() {
         use tempfile::TempDir;
@@ -833,6 +996,7 @@ mod tests {
         let metadata = Metadata {
             unit: Some("CAD".to_string()),
             notes: Some("Revenue data".to_string()),
+            description: None,
             source: Some("finance.yaml".to_string()),
             validation_status: Some("VALIDATED".to_string()),
             last_updated: Some("2024-01-01".to_string()),
@@ -1084,6 +1248,7 @@ mod tests {
         let metadata = Metadata {
             unit: Some("USD".to_string()),
             notes: Some("Complete metadata test".to_string()),
+            description: None,
             source: Some("test.yaml".to_string()),
             validation_status: Some("PENDING".to_string()),
             last_updated: Some("2025-12-04".to_string()),
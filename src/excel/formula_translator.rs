@@ -43,6 +43,8 @@ impl FormulaTranslator {
     /// - Input: `=revenue - cogs`, row_idx 0, excel_row 2
     /// - Output: `=A2-B2`
     pub fn translate_row_formula(&self, formula: &str, excel_row: u32) -> ForgeResult<String> {
+        check_excel_functions(formula)?;
+
         // Remove leading = if present
         let formula_body = formula.strip_prefix('=').unwrap_or(formula);
 
@@ -60,8 +62,10 @@ impl FormulaTranslator {
         for match_obj in matches.iter().rev() {
             let var_name = match_obj.as_str();
 
-            // Skip Excel functions (SUM, AVERAGE, etc.)
-            if self.is_excel_function(var_name) {
+            // Skip Excel functions (SUM, AVERAGE, etc.) and words inside "text" literals
+            if self.is_excel_function(var_name)
+                || formula_body[..match_obj.start()].matches('"').count() % 2 == 1
+            {
                 continue;
             }
 
@@ -124,6 +128,8 @@ impl FormulaTranslator {
                 | "ROUND"
                 | "ROUNDUP"
                 | "ROUNDDOWN"
+                | "INT"
+                | "TRUNC"
                 | "SQRT"
                 | "POW"
                 | "POWER"
@@ -218,6 +224,8 @@ impl FormulaTranslator {
         formula: &str,
         scalar_row_map: &HashMap<String, u32>,
     ) -> ForgeResult<String> {
+        check_excel_functions(formula)?;
+
         // Remove leading = if present
         let formula_body = formula.strip_prefix('=').unwrap_or(formula);
 
@@ -256,40 +264,37 @@ impl FormulaTranslator {
             result.replace_range(range, &replacement);
         }
 
-        // Pattern for table.column references inside aggregation functions
-        // SUM(table.column) → SUM(table!A2:A4)
-        let agg_pattern = Regex::new(r"(SUM|AVERAGE|MAX|MIN|COUNT|COUNTA|PRODUCT)\(([a-zA-Z_][a-zA-Z0-9_]*)\.([a-zA-Z_][a-zA-Z0-9_]*)\)")
-            .map_err(|e| ForgeError::Export(format!("Regex error: {}", e)))?;
-
-        let agg_replacements: Vec<(std::ops::Range<usize>, String)> = agg_pattern
-            .captures_iter(&result.clone())
-            .map(|cap| {
-                let full_match = cap.get(0).unwrap();
-                let func_name = &cap[1];
-                let table_name = &cap[2];
-                let col_name = &cap[3];
-
-                let col_letter = self
-                    .table_column_maps
-                    .get(table_name)
-                    .and_then(|cols| cols.get(col_name))
-                    .cloned()
-                    .unwrap_or_else(|| col_name.to_string());
+        // Column references inside aggregations become data ranges, and
+        // conditional criteria are quoted the way Excel expects:
+        // SUMIF(sales.amount, '>100', sales.revenue) → SUMIF('sales'!A2:A4, ">100", 'sales'!B2:B4)
+        let agg_pattern = Regex::new(
+            r"\b(SUM|AVERAGE|MAX|MIN|COUNT|COUNTA|PRODUCT|SUMIF|COUNTIF|AVERAGEIF|SUMIFS|COUNTIFS|AVERAGEIFS|MAXIFS|MINIFS)\(",
+        )
+        .map_err(|e| ForgeError::Export(format!("Regex error: {}", e)))?;
 
-                let row_count = self.table_row_counts.get(table_name).copied().unwrap_or(1);
-                // Range: row 2 to row (row_count + 1) - header is row 1
-                let end_row = row_count + 1;
-                let replacement = format!(
-                    "{}('{}'!{}2:{}{})",
-                    func_name, table_name, col_letter, col_letter, end_row
-                );
-                (full_match.range(), replacement)
-            })
+        let agg_calls: Vec<(String, usize)> = agg_pattern
+            .captures_iter(&result)
+            .map(|cap| (cap[1].to_string(), cap.get(0).unwrap().end()))
             .collect();
 
-        // Apply aggregation replacements in reverse order
-        for (range, replacement) in agg_replacements.into_iter().rev() {
-            result.replace_range(range, &replacement);
+        // Innermost (last) calls first; earlier argument offsets stay valid
+        for (func_name, open) in agg_calls.into_iter().rev() {
+            let close = match find_closing_paren(&result[open..]) {
+                Some(len) => open + len,
+                None => continue,
+            };
+            let args: Vec<String> = split_args(&result[open..close])
+                .iter()
+                .enumerate()
+                .map(|(i, arg)| {
+                    if is_criteria_arg(&func_name, i) {
+                        excel_criteria(arg)
+                    } else {
+                        self.column_range(arg).unwrap_or_else(|| arg.to_string())
+                    }
+                })
+                .collect();
+            result.replace_range(open..close, &args.join(", "));
         }
 
         // Pattern for simple table.column references (not in aggregations, not indexed)
@@ -370,6 +375,33 @@ impl FormulaTranslator {
         Ok(format!("={}", result))
     }
 
+    /// `table.column` → the column's data range, e.g. `'sales'!B2:B4`
+    fn column_range(&self, arg: &str) -> Option<String> {
+        let (table_name, col_name) = arg.split_once('.')?;
+        let is_name = |s: &str| {
+            s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        };
+        if !is_name(table_name) || !is_name(col_name) {
+            return None;
+        }
+        // Scalars such as `assumptions.rate` are left for the scalar pass
+        let col_letter = self
+            .table_column_maps
+            .get(table_name)?
+            .get(col_name)
+            .cloned()
+            .unwrap_or_else(|| col_name.to_string());
+
+        let row_count = self.table_row_counts.get(table_name).copied().unwrap_or(1);
+        // Range: row 2 to row (row_count + 1) - header is row 1
+        let end_row = row_count + 1;
+        Some(format!(
+            "'{}'!{}2:{}{}",
+            table_name, col_letter, col_letter, end_row
+        ))
+    }
+
     /// Convert a column name to an Excel column letter
     ///
     /// Examples:
@@ -394,6 +426,89 @@ impl FormulaTranslator {
     }
 }
 
+/// Forge functions that Excel has no equivalent for
+const FORGE_ONLY_FUNCTIONS: &[&str] = &["ROUNDBANK"];
+
+/// Reject a formula calling a function that Excel would not recognize
+fn check_excel_functions(formula: &str) -> ForgeResult<()> {
+    for func in FORGE_ONLY_FUNCTIONS {
+        let call = Regex::new(&format!(r"(?i)\b{}\s*\(", func))
+            .map_err(|e| ForgeError::Export(format!("Regex error: {}", e)))?;
+        if call.is_match(formula) {
+            return Err(ForgeError::Export(format!(
+                "{} has no Excel equivalent and cannot be exported: {}",
+                func, formula
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Byte offset of the `)` closing a call whose arguments start `inner`
+fn find_closing_paren(inner: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in inner.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            (_, Some(_)) => {}
+            ('(', None) => depth += 1,
+            (')', None) if depth == 0 => return Some(i),
+            (')', None) => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split arguments on top-level commas, keeping quoted text and nested calls whole
+fn split_args(inner: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            (_, Some(_)) => {}
+            ('(', None) => depth += 1,
+            (')', None) => depth -= 1,
+            (',', None) if depth == 0 => {
+                args.push(inner[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push(inner[start..].trim());
+    args
+}
+
+/// Whether argument `index` of a conditional aggregation is a criteria
+fn is_criteria_arg(func_name: &str, index: usize) -> bool {
+    match func_name {
+        "SUMIF" | "COUNTIF" | "AVERAGEIF" => index == 1,
+        "COUNTIFS" => index % 2 == 1,
+        "SUMIFS" | "AVERAGEIFS" | "MAXIFS" | "MINIFS" => index >= 2 && index.is_multiple_of(2),
+        _ => false,
+    }
+}
+
+/// Forge criteria (`'>100'`, `>=5`, `"North"`) as an Excel criteria string
+fn excel_criteria(criteria: &str) -> String {
+    let quoted_with_single =
+        criteria.len() >= 2 && criteria.starts_with('\'') && criteria.ends_with('\'');
+    if quoted_with_single {
+        format!("\"{}\"", &criteria[1..criteria.len() - 1])
+    } else if criteria.starts_with(['<', '>', '=']) {
+        format!("\"{}\"", criteria)
+    } else {
+        criteria.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -630,6 +745,41 @@ mod tests {
         assert!(result.contains("sales"));
     }
 
+    #[test]
+    fn test_translate_scalar_conditional_aggregations() {
+        let mut table_column_maps = HashMap::new();
+        let mut sales_cols = HashMap::new();
+        sales_cols.insert("amount".to_string(), "A".to_string());
+        sales_cols.insert("region".to_string(), "B".to_string());
+        table_column_maps.insert("sales".to_string(), sales_cols);
+
+        let mut table_row_counts = HashMap::new();
+        table_row_counts.insert("sales".to_string(), 3);
+
+        let translator =
+            FormulaTranslator::new_with_tables(HashMap::new(), table_column_maps, table_row_counts);
+        let scalar_row_map = HashMap::new();
+
+        let result = translator
+            .translate_scalar_formula(
+                "=SUMIF(sales.amount, '>100', sales.amount)",
+                &scalar_row_map,
+            )
+            .unwrap();
+        assert_eq!(result, "=SUMIF('sales'!A2:A4, \">100\", 'sales'!A2:A4)");
+
+        let result = translator
+            .translate_scalar_formula(
+                "=COUNTIFS(sales.region, \"North\", sales.amount, >=50) / COUNT(sales.amount)",
+                &scalar_row_map,
+            )
+            .unwrap();
+        assert_eq!(
+            result,
+            "=COUNTIFS('sales'!B2:B4, \"North\", 'sales'!A2:A4, \">=50\") / COUNT('sales'!A2:A4)"
+        );
+    }
+
     #[test]
     fn test_translate_scalar_formula_with_index() {
         let mut table_column_maps = HashMap::new();
@@ -667,6 +817,18 @@ mod tests {
         assert!(result.contains("B5")); // scalar at row 5
     }
 
+    #[test]
+    fn test_text_literals_not_translated() {
+        let mut column_map = HashMap::new();
+        column_map.insert("profit".to_string(), "C".to_string());
+
+        let translator = FormulaTranslator::new(column_map);
+        let result = translator
+            .translate_row_formula("=IF(profit < 0, \"loss\", \"profit\")", 3)
+            .unwrap();
+        assert_eq!(result, "=IF(C3 < 0, \"loss\", \"profit\")");
+    }
+
     #[test]
     fn test_column_not_found_error() {
         let column_map = HashMap::new(); // Empty
@@ -679,6 +841,32 @@ mod tests {
         assert!(err.contains("not found"));
     }
 
+    #[test]
+    fn test_forge_only_functions_rejected() {
+        let mut column_map = HashMap::new();
+        column_map.insert("price".to_string(), "A".to_string());
+        let translator = FormulaTranslator::new(column_map);
+
+        let err = translator
+            .translate_row_formula("=ROUNDBANK(price, 2)", 2)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("ROUNDBANK has no Excel equivalent and cannot be exported"));
+
+        let scalar_rows = HashMap::new();
+        assert!(translator
+            .translate_scalar_formula("=roundbank(1.25, 1)", &scalar_rows)
+            .is_err());
+        // ROUND is still exported as is
+        assert_eq!(
+            translator
+                .translate_row_formula("=ROUND(price, 2)", 2)
+                .unwrap(),
+            "=ROUND(A2, 2)"
+        );
+    }
+
     #[test]
     fn test_more_excel_functions_preserved() {
         let column_map = HashMap::new();
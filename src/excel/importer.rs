@@ -2,6 +2,7 @@
 
 use crate::error::{ForgeError, ForgeResult};
 use crate::excel::reverse_formula_translator::ReverseFormulaTranslator;
+use crate::parser::is_valid_date_format;
 use crate::types::{Column, ColumnValue, ParsedModel, Table, Variable};
 use calamine::{open_workbook, Data, Range, Reader, Xlsx};
 use std::collections::HashMap;
@@ -31,23 +32,89 @@ impl ExcelImporter {
 
         // Get all sheet names
         let sheet_names = workbook.sheet_names().to_vec();
-
-        // Process each sheet
+        let mut sheets = Vec::new();
         for sheet_name in sheet_names {
             if let Ok(range) = workbook.worksheet_range(&sheet_name) {
-                self.process_sheet(&sheet_name, &range, &mut workbook, &mut model)?;
+                sheets.push((sheet_name, range));
             }
         }
 
+        // Every sheet's header, so formulas can refer across sheets
+        let translator = self.workbook_translator(&sheets);
+
+        // Process each sheet
+        for (sheet_name, range) in &sheets {
+            self.process_sheet(sheet_name, range, &mut workbook, &translator, &mut model)?;
+        }
+
         Ok(model)
     }
 
+    /// Build a formula translator that knows every sheet's column letters
+    /// and the cells holding scalars
+    fn workbook_translator(&self, sheets: &[(String, Range<Data>)]) -> ReverseFormulaTranslator {
+        let mut sheet_column_maps = HashMap::new();
+        let mut scalar_cells = HashMap::new();
+
+        for (sheet_name, range) in sheets {
+            let (start_row, start_col) = range.start().unwrap_or((0, 0));
+            if sheet_name.to_lowercase() == "scalars" {
+                // Column A: name, column B: value
+                for row in 1..range.height() {
+                    let name = range.get((row, 0)).filter(|c| !matches!(c, Data::Empty));
+                    if let Some(cell) = name {
+                        let excel_cell = format!(
+                            "{}{}",
+                            self.number_to_column_letter(start_col as usize + 1),
+                            start_row as usize + row + 1
+                        );
+                        scalar_cells.insert(excel_cell, cell.to_string());
+                    }
+                }
+            } else {
+                sheet_column_maps.insert(sheet_name.clone(), self.header_column_map(range));
+            }
+        }
+
+        ReverseFormulaTranslator::new_with_sheets(HashMap::new(), sheet_column_maps, scalar_cells)
+    }
+
+    /// Column names from the header row (row 0)
+    fn header_names(&self, range: &Range<Data>) -> Vec<String> {
+        let mut column_names: Vec<String> = Vec::new();
+        for col in 0..range.width() {
+            if let Some(cell) = range.get((0, col)) {
+                let name = match cell {
+                    Data::String(s) => s.clone(),
+                    Data::Int(i) => i.to_string(),
+                    Data::Float(f) => f.to_string(),
+                    _ => format!("col_{}", col),
+                };
+                column_names.push(name);
+            } else {
+                column_names.push(format!("col_{}", col));
+            }
+        }
+        column_names
+    }
+
+    /// Column letter → header name (A → revenue, B → cogs, etc.)
+    fn header_column_map(&self, range: &Range<Data>) -> HashMap<String, String> {
+        let start_col = range.start().map_or(0, |(_, col)| col as usize);
+        self.header_names(range)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, name)| (self.number_to_column_letter(start_col + idx), name))
+            .collect()
+    }
+
     /// Process a single worksheet
     fn process_sheet(
         &self,
         sheet_name: &str,
         range: &Range<Data>,
         workbook: &mut Xlsx<std::io::BufReader<std::fs::File>>,
+        translator: &ReverseFormulaTranslator,
         model: &mut ParsedModel,
     ) -> ForgeResult<()> {
         // Check if sheet is empty
@@ -55,16 +122,28 @@ impl ExcelImporter {
             return Ok(()); // Skip empty sheets
         }
 
+        // Get formula range for this sheet
+        let formula_range = workbook.worksheet_formula(sheet_name).ok();
+
         // Check if this is a "Scalars" sheet (special handling)
         if sheet_name.to_lowercase() == "scalars" {
-            return self.process_scalars_sheet(range, model);
+            return self.process_scalars_sheet(
+                range,
+                formula_range.as_ref(),
+                &translator.for_scalars_sheet(),
+                model,
+            );
         }
 
-        // Get formula range for this sheet
-        let formula_range = workbook.worksheet_formula(sheet_name).ok();
-
         // Process as regular table
-        self.process_table_sheet(sheet_name, range, formula_range.as_ref(), model)
+        let translator = translator.with_column_map(self.header_column_map(range));
+        self.process_table_sheet(
+            sheet_name,
+            range,
+            formula_range.as_ref(),
+            &translator,
+            model,
+        )
     }
 
     /// Process a regular table sheet
@@ -73,6 +152,7 @@ impl ExcelImporter {
         sheet_name: &str,
         range: &Range<Data>,
         formula_range: Option<&Range<String>>,
+        translator: &ReverseFormulaTranslator,
         model: &mut ParsedModel,
     ) -> ForgeResult<()> {
         let (height, width) = range.get_size();
@@ -83,20 +163,7 @@ impl ExcelImporter {
         }
 
         // Read header row (row 0)
-        let mut column_names: Vec<String> = Vec::new();
-        for col in 0..width {
-            if let Some(cell) = range.get((0, col)) {
-                let name = match cell {
-                    Data::String(s) => s.clone(),
-                    Data::Int(i) => i.to_string(),
-                    Data::Float(f) => f.to_string(),
-                    _ => format!("col_{}", col),
-                };
-                column_names.push(name);
-            } else {
-                column_names.push(format!("col_{}", col));
-            }
-        }
+        let column_names = self.header_names(range);
 
         // Read data rows and detect column types
         let mut columns_data: HashMap<String, Vec<Data>> = HashMap::new();
@@ -120,22 +187,18 @@ impl ExcelImporter {
         let table_name = self.sanitize_table_name(sheet_name);
         let mut table = Table::new(table_name.clone());
 
-        // Build column map for formula translation (A → revenue, B → cogs, etc.)
-        let mut column_map = HashMap::new();
-        for (idx, col_name) in column_names.iter().enumerate() {
-            let excel_col = self.number_to_column_letter(idx);
-            column_map.insert(excel_col, col_name.clone());
-        }
-
-        // Create reverse formula translator
-        let translator = ReverseFormulaTranslator::new(column_map);
+        // Formula ranges start at their first formula cell, so look cells up
+        // by absolute position
+        let (start_row, start_col) = range.start().unwrap_or((0, 0));
 
         // Convert columns to YAML format
         for (col_idx, col_name) in column_names.iter().enumerate() {
+            // First data row of this column, as an absolute position
+            let first_data_cell = (start_row + 1, start_col + col_idx as u32);
+
             // Check if this column has formulas (check first data row in formula_range)
             let has_formula = if let Some(formulas) = formula_range {
-                // Row 1 (first data row) = index 1 in the formula range
-                if let Some(formula_cell) = formulas.get((1, col_idx)) {
+                if let Some(formula_cell) = formulas.get_value(first_data_cell) {
                     !formula_cell.is_empty()
                 } else {
                     false
@@ -147,7 +210,7 @@ impl ExcelImporter {
             if has_formula {
                 // This is a calculated column - extract formula from first data row
                 if let Some(formulas) = formula_range {
-                    if let Some(formula) = formulas.get((1, col_idx)) {
+                    if let Some(formula) = formulas.get_value(first_data_cell) {
                         if !formula.is_empty() {
                             // Add leading = if not present (calamine strips it)
                             let formula_with_equals = if formula.starts_with('=') {
@@ -184,9 +247,12 @@ impl ExcelImporter {
     fn process_scalars_sheet(
         &self,
         range: &Range<Data>,
+        formula_range: Option<&Range<String>>,
+        translator: &ReverseFormulaTranslator,
         model: &mut ParsedModel,
     ) -> ForgeResult<()> {
         let (height, _width) = range.get_size();
+        let (start_row, start_col) = range.start().unwrap_or((0, 0));
 
         // Skip header row, process data rows
         for row in 1..height {
@@ -219,6 +285,19 @@ impl ExcelImporter {
                 None
             };
 
+            // Otherwise a live Excel formula in the value cell (as exported)
+            let formula = match formula {
+                Some(formula) => Some(formula),
+                None => match formula_range
+                    .and_then(|f| f.get_value((start_row + row as u32, start_col + 1)))
+                {
+                    Some(excel) if !excel.is_empty() => {
+                        Some(translator.translate(&format!("={}", excel.trim_start_matches('=')))?)
+                    }
+                    _ => None,
+                },
+            };
+
             // Create variable
             let variable = Variable::new(name.clone(), value, formula);
             model.add_scalar(name, variable);
@@ -250,8 +329,12 @@ impl ExcelImporter {
                 Ok(ColumnValue::Number(numbers))
             }
             Data::String(_) => {
-                // Text column
+                // Date column when every cell is a YYYY-MM or YYYY-MM-DD string
                 let texts: Vec<String> = data.iter().map(|cell| cell.to_string()).collect();
+                if texts.iter().all(|t| is_valid_date_format(t)) {
+                    return Ok(ColumnValue::Date(texts));
+                }
+                // Text column
                 Ok(ColumnValue::Text(texts))
             }
             Data::Bool(_) => {
@@ -552,6 +635,85 @@ mod tests {
         let _ = &imported; // imported is a valid ParsedModel
     }
 
+    #[test]
+    fn test_import_translates_formulas() {
+        use rust_xlsxwriter::{Formula, Workbook};
+        use tempfile::TempDir;
+
+        let mut workbook = Workbook::new();
+        let orders = workbook.add_worksheet();
+        orders.set_name("orders").unwrap();
+        orders
+            .write_row(0, 0, ["price", "quantity", "total"])
+            .unwrap();
+        for (row, (price, quantity)) in [(2.5, 4.0), (10.0, 3.0)].into_iter().enumerate() {
+            let row = row as u32 + 1;
+            orders.write_number(row, 0, price).unwrap();
+            orders.write_number(row, 1, quantity).unwrap();
+            orders
+                .write_formula(row, 2, Formula::new(format!("=A{0}*B{0}", row + 1)))
+                .unwrap();
+        }
+        let scalars = workbook.add_worksheet();
+        scalars.set_name("Scalars").unwrap();
+        scalars.write_row(0, 0, ["Name", "Value"]).unwrap();
+        scalars.write_string(1, 0, "order_total").unwrap();
+        scalars
+            .write_formula(1, 1, Formula::new("=SUM(orders!$C:$C)"))
+            .unwrap();
+
+        let dir = TempDir::new().unwrap();
+        let excel_path = dir.path().join("formulas.xlsx");
+        workbook.save(&excel_path).unwrap();
+
+        let imported = ExcelImporter::new(&excel_path).import().unwrap();
+        let table = &imported.tables["orders"];
+        assert_eq!(table.row_formulas["total"], "=price*quantity");
+        assert!(!table.columns.contains_key("total"));
+        assert_eq!(
+            imported.scalars["order_total"].formula.as_deref(),
+            Some("=SUM(orders.total)")
+        );
+    }
+
+    #[test]
+    fn test_import_cross_sheet_formulas() {
+        use rust_xlsxwriter::{Formula, Workbook};
+        use tempfile::TempDir;
+
+        let mut workbook = Workbook::new();
+        let prices = workbook.add_worksheet();
+        prices.set_name("Unit Prices").unwrap();
+        prices.write_row(0, 0, ["product", "price"]).unwrap();
+        prices.write_string(1, 0, "Apple").unwrap();
+        prices.write_number(1, 1, 1.5).unwrap();
+        prices.write_string(2, 0, "Pear").unwrap();
+        prices.write_number(2, 1, 2.0).unwrap();
+
+        let orders = workbook.add_worksheet();
+        orders.set_name("Orders").unwrap();
+        orders.write_row(0, 0, ["quantity", "cost"]).unwrap();
+        for row in 1..3u32 {
+            orders.write_number(row, 0, row as f64 * 10.0).unwrap();
+            orders
+                .write_formula(
+                    row,
+                    1,
+                    Formula::new(format!("='Unit Prices'!B{0}*A{0}", row + 1)),
+                )
+                .unwrap();
+        }
+
+        let dir = TempDir::new().unwrap();
+        let excel_path = dir.path().join("linked.xlsx");
+        workbook.save(&excel_path).unwrap();
+
+        let imported = ExcelImporter::new(&excel_path).import().unwrap();
+        assert!(imported.tables.contains_key("unit_prices"));
+        let orders = &imported.tables["orders"];
+        assert_eq!(orders.row_formulas["cost"], "=unit_prices.price*quantity");
+    }
+
     #[test]
     fn test_importer_new_stores_path() {
         let path = std::path::Path::new("/some/path/file.xlsx");
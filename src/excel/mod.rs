@@ -3,13 +3,19 @@
 //! This module provides bidirectional Excel ↔ YAML conversion:
 //! - Export: YAML → Excel (.xlsx) with formulas
 //! - Import: Excel (.xlsx) → YAML with formulas
+//! - Import: CSV → YAML (single table, inferred column types)
+//! - Round-trip check: YAML → Excel → YAML, reporting what changed
 
+mod csv_importer;
 mod exporter;
 mod formula_translator;
 mod importer;
 mod reverse_formula_translator;
+mod roundtrip;
 
+pub use csv_importer::CsvImporter;
 pub use exporter::ExcelExporter;
 pub use formula_translator::FormulaTranslator;
 pub use importer::ExcelImporter;
 pub use reverse_formula_translator::ReverseFormulaTranslator;
+pub use roundtrip::{roundtrip_check, RoundtripDifference, RoundtripReport};
@@ -1,21 +1,66 @@
 //! Reverse formula translation - Excel formulas → YAML syntax
 //!
 //! Converts Excel formulas like "=B2-C2" to YAML formulas like "=revenue - cogs"
+//!
+//! Column letters are mapped to names through each sheet's header row, so
+//! `=orders!$C2` becomes `=orders.total` and `=SUM(orders!C:C)` or
+//! `=SUM('orders'!C2:C9)` becomes `=SUM(orders.total)`. Absolute markers (`$`)
+//! are dropped, and cells on the Scalars sheet (`Scalars!B3`) become the name
+//! of the scalar in that row.
 
 use crate::error::{ForgeError, ForgeResult};
 use regex::Regex;
 use std::collections::HashMap;
 
 /// Translates Excel formulas to YAML syntax
+#[derive(Clone)]
 pub struct ReverseFormulaTranslator {
     /// Maps Excel column letters to YAML column names (A → revenue, B → cogs)
     column_map: HashMap<String, String>,
+    /// Global mapping: sheet_name -> (column_letter -> column_name)
+    sheet_column_maps: HashMap<String, HashMap<String, String>>,
+    /// Scalars sheet cells: cell (B3) -> scalar name
+    scalar_cells: HashMap<String, String>,
+    /// Whether bare cell references (B3) point at the Scalars sheet
+    on_scalars_sheet: bool,
 }
 
 impl ReverseFormulaTranslator {
     /// Create a new reverse formula translator
     pub fn new(column_map: HashMap<String, String>) -> Self {
-        Self { column_map }
+        Self::new_with_sheets(column_map, HashMap::new(), HashMap::new())
+    }
+
+    /// Create a new reverse formula translator with full workbook knowledge
+    pub fn new_with_sheets(
+        column_map: HashMap<String, String>,
+        sheet_column_maps: HashMap<String, HashMap<String, String>>,
+        scalar_cells: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            column_map,
+            sheet_column_maps,
+            scalar_cells,
+            on_scalars_sheet: false,
+        }
+    }
+
+    /// The same workbook knowledge with another sheet's column mappings
+    pub fn with_column_map(&self, column_map: HashMap<String, String>) -> Self {
+        Self {
+            column_map,
+            on_scalars_sheet: false,
+            ..self.clone()
+        }
+    }
+
+    /// The same workbook knowledge for formulas on the Scalars sheet
+    pub fn for_scalars_sheet(&self) -> Self {
+        Self {
+            column_map: HashMap::new(),
+            on_scalars_sheet: true,
+            ..self.clone()
+        }
     }
 
     /// Translate an Excel formula to YAML syntax
@@ -35,9 +80,22 @@ impl ReverseFormulaTranslator {
 
     /// Translate formula body (without leading =)
     fn translate_formula_body(&self, formula: &str) -> ForgeResult<String> {
-        let mut result = formula.to_string();
+        // Absolute references: $A$2 → A2 ($ only has meaning outside "text")
+        let mut result: String = formula
+            .split('"')
+            .enumerate()
+            .map(|(i, part)| {
+                if i % 2 == 0 {
+                    part.replace('$', "")
+                } else {
+                    part.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\"");
 
-        // 1. Handle cross-sheet references: Sheet!A1 → table.column
+        // 1. Handle cross-sheet ranges and references: Sheet!A1 → table.column
+        result = self.translate_sheet_ranges(&result)?;
         result = self.translate_sheet_references(&result)?;
 
         // 2. Handle range references: SUM(A:A) → SUM(revenue)
@@ -49,10 +107,51 @@ impl ReverseFormulaTranslator {
         Ok(result)
     }
 
+    /// Translate cross-sheet column ranges: Sheet!A:A or 'Sheet'!A2:A9 → table.column
+    fn translate_sheet_ranges(&self, formula: &str) -> ForgeResult<String> {
+        let sheet_range_pattern = Regex::new(r"('(?:[^']|'')+'|\w+)!([A-Z]+)(\d*):([A-Z]+)(\d*)\b")
+            .map_err(|e| ForgeError::Import(format!("Regex error: {}", e)))?;
+
+        let mut result = formula.to_string();
+        let matches: Vec<_> = sheet_range_pattern.captures_iter(formula).collect();
+
+        for captures in matches.iter().rev() {
+            let col_letter = &captures[2];
+            // Only same-column ranges name a single column
+            if col_letter != &captures[4] {
+                continue;
+            }
+            let sheet_name = unquote_sheet_name(&captures[1]);
+            let yaml_ref = format!(
+                "{}.{}",
+                self.sanitize_name(&sheet_name),
+                self.sheet_column_name(&sheet_name, col_letter)
+            );
+            result.replace_range(captures.get(0).unwrap().range(), &yaml_ref);
+        }
+
+        Ok(result)
+    }
+
+    /// Column name for a letter on another sheet (falls back to this sheet's header)
+    ///
+    /// Excel sheet names are case-insensitive, so `sheet1!B2` finds `Sheet1`.
+    fn sheet_column_name<'a>(&'a self, sheet_name: &str, col_letter: &'a str) -> &'a str {
+        self.sheet_column_maps
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(sheet_name))
+            .map(|(_, column_map)| column_map)
+            .unwrap_or(&self.column_map)
+            .get(col_letter)
+            .map(|s| s.as_str())
+            .unwrap_or(col_letter)
+    }
+
     /// Translate cross-sheet references: Sheet!A1 or Sheet!column2 → table.column
     fn translate_sheet_references(&self, formula: &str) -> ForgeResult<String> {
         // Pattern: SheetName!A1 or 'Sheet Name'!A1 or Sheet!columnName2 (with row number)
-        let sheet_ref_pattern = Regex::new(r"('[^']+'|[\w]+)!([\w]+)\d+")
+        // Quoted names escape an apostrophe by doubling it: 'O''Brien'!A1
+        let sheet_ref_pattern = Regex::new(r"('(?:[^']|'')+'|[\w]+)!([A-Z]+|[\w]+?)(\d+)\b")
             .map_err(|e| ForgeError::Import(format!("Regex error: {}", e)))?;
 
         let mut result = formula.to_string();
@@ -69,18 +168,25 @@ impl ReverseFormulaTranslator {
                 let col_ref = captures.get(2).unwrap().as_str();
 
                 // Remove quotes from sheet name if present
-                let clean_sheet = sheet_name.trim_matches('\'');
+                let clean_sheet = unquote_sheet_name(sheet_name);
+                let clean_sheet = clean_sheet.as_str();
+
+                // Scalars!B3 → the scalar named in row 3
+                if clean_sheet.eq_ignore_ascii_case("scalars") {
+                    let cell = format!("{}{}", col_ref, captures.get(3).unwrap().as_str());
+                    if let Some(scalar_name) = self.scalar_cells.get(&cell) {
+                        result.replace_range(match_obj.range(), scalar_name);
+                        continue;
+                    }
+                }
 
                 // Sanitize sheet name (same as export logic)
                 let table_name = self.sanitize_name(clean_sheet);
 
                 // Check if col_ref is a column letter (A, B, AA) or column name
                 let col_name = if col_ref.chars().all(|c| c.is_ascii_uppercase()) {
-                    // It's a column letter - map it
-                    self.column_map
-                        .get(col_ref)
-                        .map(|s| s.as_str())
-                        .unwrap_or(col_ref)
+                    // It's a column letter - map it through that sheet's header
+                    self.sheet_column_name(clean_sheet, col_ref)
                 } else {
                     // It's already a column name - use as is
                     col_ref
@@ -170,6 +276,14 @@ impl ReverseFormulaTranslator {
                     continue;
                 }
 
+                // A cell on the Scalars sheet itself
+                if self.on_scalars_sheet {
+                    if let Some(scalar_name) = self.scalar_cells.get(match_obj.as_str()) {
+                        result.replace_range(match_obj.range(), scalar_name);
+                        continue;
+                    }
+                }
+
                 // Map to column name
                 let col_name = self
                     .column_map
@@ -222,6 +336,8 @@ impl ReverseFormulaTranslator {
                 | "ROUND"
                 | "ROUNDUP"
                 | "ROUNDDOWN"
+                | "INT"
+                | "TRUNC"
                 | "SQRT"
                 | "POW"
                 | "POWER"
@@ -288,6 +404,14 @@ impl ReverseFormulaTranslator {
     }
 }
 
+/// `'Unit Prices'` → `Unit Prices`, `'O''Brien'` → `O'Brien`
+fn unquote_sheet_name(name: &str) -> String {
+    match name.strip_prefix('\'').and_then(|n| n.strip_suffix('\'')) {
+        Some(quoted) => quoted.replace("''", "'"),
+        None => name.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -328,6 +452,70 @@ mod tests {
         assert_eq!(result, "=sheet1.revenue");
     }
 
+    #[test]
+    fn test_absolute_references() {
+        let translator = create_test_translator();
+        let result = translator.translate("=$A2*B$2-$C$2").unwrap();
+        assert_eq!(result, "=revenue*cogs-gross_profit");
+
+        let result = translator.translate("=SUM($A:$A)").unwrap();
+        assert_eq!(result, "=SUM(revenue)");
+    }
+
+    #[test]
+    fn test_cross_sheet_ranges_use_sheet_headers() {
+        let mut orders = HashMap::new();
+        orders.insert("A".to_string(), "price".to_string());
+        orders.insert("C".to_string(), "total".to_string());
+        let mut sheet_column_maps = HashMap::new();
+        sheet_column_maps.insert("orders".to_string(), orders);
+        let mut scalar_cells = HashMap::new();
+        scalar_cells.insert("B3".to_string(), "tax_rate".to_string());
+
+        let translator = ReverseFormulaTranslator::new_with_sheets(
+            HashMap::new(),
+            sheet_column_maps,
+            scalar_cells,
+        );
+
+        let result = translator.translate("=SUM(orders!C:C)").unwrap();
+        assert_eq!(result, "=SUM(orders.total)");
+
+        let result = translator
+            .for_scalars_sheet()
+            .translate("=SUMIF('orders'!$A$2:$A$13, \">100\", 'orders'!C2:C13) * B3")
+            .unwrap();
+        assert_eq!(
+            result,
+            "=SUMIF(orders.price, \">100\", orders.total) * tax_rate"
+        );
+
+        let result = translator.translate("=orders!A12 * Scalars!B3").unwrap();
+        assert_eq!(result, "=orders.price * tax_rate");
+    }
+
+    #[test]
+    fn test_quoted_sheet_names() {
+        let mut prices = HashMap::new();
+        prices.insert("B".to_string(), "price".to_string());
+        let mut sheet_column_maps = HashMap::new();
+        sheet_column_maps.insert("Unit Prices".to_string(), prices.clone());
+        sheet_column_maps.insert("O'Brien".to_string(), prices);
+
+        let translator = ReverseFormulaTranslator::new_with_sheets(
+            create_test_translator().column_map,
+            sheet_column_maps,
+            HashMap::new(),
+        );
+        let result = translator.translate("='Unit Prices'!B2*A2").unwrap();
+        assert_eq!(result, "=unit_prices.price*revenue");
+
+        let result = translator
+            .translate("=SUM('O''Brien'!B:B) + 'unit prices'!$B$7")
+            .unwrap();
+        assert_eq!(result, "=SUM(obrien.price) + unit_prices.price");
+    }
+
     #[test]
     fn test_if_function() {
         let translator = create_test_translator();
@@ -0,0 +1,338 @@
+//! Excel round-trip check - YAML → Excel (.xlsx) → YAML
+//!
+//! Exports a model to a temporary workbook, imports it back and compares the
+//! two models: tables, columns and scalars that disappeared, columns whose
+//! type changed, values that drifted and formulas that no longer match.
+
+use crate::error::ForgeResult;
+use crate::excel::{ExcelExporter, ExcelImporter};
+use crate::types::{ColumnValue, ParsedModel};
+use std::fmt;
+use std::path::Path;
+
+/// One thing the round-trip did not preserve
+#[derive(Debug, Clone, PartialEq)]
+pub enum RoundtripDifference {
+    /// A table, column or scalar missing after import
+    Lost { name: String },
+    /// A column imported with another value type
+    TypeChanged {
+        name: String,
+        original: String,
+        imported: String,
+    },
+    /// A value that changed, e.g. `sales.revenue[2]` or `tax_rate`
+    ValueDrift {
+        name: String,
+        original: String,
+        imported: String,
+    },
+    /// A row or scalar formula that no longer matches
+    FormulaChanged {
+        name: String,
+        original: String,
+        imported: String,
+    },
+}
+
+impl fmt::Display for RoundtripDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lost { name } => write!(f, "{}: lost", name),
+            Self::TypeChanged {
+                name,
+                original,
+                imported,
+            } => write!(f, "{}: type {} → {}", name, original, imported),
+            Self::ValueDrift {
+                name,
+                original,
+                imported,
+            } => write!(f, "{}: value {} → {}", name, original, imported),
+            Self::FormulaChanged {
+                name,
+                original,
+                imported,
+            } => write!(f, "{}: formula {} → {}", name, original, imported),
+        }
+    }
+}
+
+/// Result of an Excel round-trip
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RoundtripReport {
+    /// Tables in the original model
+    pub tables: usize,
+    /// Data and formula columns in the original model
+    pub columns: usize,
+    /// Scalars in the original model
+    pub scalars: usize,
+    pub differences: Vec<RoundtripDifference>,
+}
+
+impl RoundtripReport {
+    /// True when the imported model matches the original
+    pub fn is_clean(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+/// Export `yaml_path` to a temporary .xlsx, import it back and report what changed
+pub fn roundtrip_check<P: AsRef<Path>>(yaml_path: P) -> ForgeResult<RoundtripReport> {
+    let model = crate::parser::parse_model(yaml_path.as_ref())?;
+
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let xlsx_path = std::env::temp_dir().join(format!(
+        "forge_roundtrip_{}_{}.xlsx",
+        std::process::id(),
+        nanos
+    ));
+
+    let imported = ExcelExporter::new(model.clone())
+        .export(&xlsx_path)
+        .and_then(|_| ExcelImporter::new(&xlsx_path).import());
+    // Best effort: the temporary workbook is not part of the result
+    let _ = std::fs::remove_file(&xlsx_path);
+
+    Ok(compare_models(&model, &imported?))
+}
+
+/// Differences between an original model and its re-imported copy
+fn compare_models(original: &ParsedModel, imported: &ParsedModel) -> RoundtripReport {
+    let mut report = RoundtripReport {
+        tables: original.tables.len(),
+        scalars: original.scalars.len(),
+        ..Default::default()
+    };
+
+    let mut table_names: Vec<&String> = original.tables.keys().collect();
+    table_names.sort();
+    for table_name in table_names {
+        let table = &original.tables[table_name];
+        report.columns += table.columns.len() + table.row_formulas.len();
+        let imported_table = match imported.tables.get(table_name) {
+            Some(t) => t,
+            None => {
+                report.differences.push(RoundtripDifference::Lost {
+                    name: table_name.clone(),
+                });
+                continue;
+            }
+        };
+
+        let mut column_names: Vec<&String> = table.columns.keys().collect();
+        column_names.sort();
+        for col_name in column_names {
+            let name = format!("{}.{}", table_name, col_name);
+            match imported_table.columns.get(col_name) {
+                Some(column) => compare_columns(
+                    &name,
+                    &table.columns[col_name].values,
+                    &column.values,
+                    &mut report.differences,
+                ),
+                None => report.differences.push(RoundtripDifference::Lost { name }),
+            }
+        }
+
+        let mut formula_names: Vec<&String> = table.row_formulas.keys().collect();
+        formula_names.sort();
+        for col_name in formula_names {
+            let name = format!("{}.{}", table_name, col_name);
+            match imported_table.row_formulas.get(col_name) {
+                Some(formula) => {
+                    compare_formulas(name, &table.row_formulas[col_name], formula, &mut report)
+                }
+                None => report.differences.push(RoundtripDifference::Lost { name }),
+            }
+        }
+    }
+
+    let mut scalar_names: Vec<&String> = original.scalars.keys().collect();
+    scalar_names.sort();
+    for name in scalar_names {
+        let scalar = &original.scalars[name];
+        let imported_scalar = match imported.scalars.get(name) {
+            Some(s) => s,
+            None => {
+                report
+                    .differences
+                    .push(RoundtripDifference::Lost { name: name.clone() });
+                continue;
+            }
+        };
+        match (&scalar.formula, &imported_scalar.formula) {
+            (Some(formula), Some(imported_formula)) => {
+                compare_formulas(name.clone(), formula, imported_formula, &mut report)
+            }
+            (Some(formula), None) => report
+                .differences
+                .push(RoundtripDifference::FormulaChanged {
+                    name: name.clone(),
+                    original: formula.clone(),
+                    imported: "(none)".to_string(),
+                }),
+            // Formula results are recalculated, so only inputs are compared
+            (None, _) => {
+                if let Some(value) = scalar.value {
+                    let drifted = imported_scalar.value.is_none_or(|v| !same_number(value, v));
+                    if drifted {
+                        report.differences.push(RoundtripDifference::ValueDrift {
+                            name: name.clone(),
+                            original: value.to_string(),
+                            imported: imported_scalar
+                                .value
+                                .map_or_else(|| "(none)".to_string(), |v| v.to_string()),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    report
+}
+
+fn compare_columns(
+    name: &str,
+    original: &ColumnValue,
+    imported: &ColumnValue,
+    differences: &mut Vec<RoundtripDifference>,
+) {
+    if original.type_name() != imported.type_name() {
+        differences.push(RoundtripDifference::TypeChanged {
+            name: name.to_string(),
+            original: original.type_name().to_string(),
+            imported: imported.type_name().to_string(),
+        });
+        return;
+    }
+
+    let (original_cells, imported_cells) = (cell_strings(original), cell_strings(imported));
+    for row in 0..original_cells.len().max(imported_cells.len()) {
+        let (before, after) = (original_cells.get(row), imported_cells.get(row));
+        let same = match (original, imported, before, after) {
+            (ColumnValue::Number(a), ColumnValue::Number(b), Some(_), Some(_)) => {
+                same_number(a[row], b[row])
+            }
+            _ => before == after,
+        };
+        if !same {
+            let missing = || "(missing)".to_string();
+            differences.push(RoundtripDifference::ValueDrift {
+                name: format!("{}[{}]", name, row),
+                original: before.cloned().unwrap_or_else(missing),
+                imported: after.cloned().unwrap_or_else(missing),
+            });
+        }
+    }
+}
+
+/// Formulas match when they only differ in spacing
+fn compare_formulas(name: String, original: &str, imported: &str, report: &mut RoundtripReport) {
+    let squash = |f: &str| f.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+    if squash(original) != squash(imported) {
+        report
+            .differences
+            .push(RoundtripDifference::FormulaChanged {
+                name,
+                original: original.to_string(),
+                imported: imported.to_string(),
+            });
+    }
+}
+
+fn cell_strings(values: &ColumnValue) -> Vec<String> {
+    match values {
+        ColumnValue::Number(v) => v.iter().map(|n| n.to_string()).collect(),
+        ColumnValue::Text(v) | ColumnValue::Date(v) => v.clone(),
+        ColumnValue::Boolean(v) => v.iter().map(|b| b.to_string()).collect(),
+    }
+}
+
+fn same_number(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-9 * a.abs().max(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_roundtrip_all_column_types() {
+        let dir = TempDir::new().unwrap();
+        let yaml_path = dir.path().join("model.yaml");
+        std::fs::write(
+            &yaml_path,
+            r#"
+_forge_version: "1.0.0"
+sales:
+  month: ["2025-01", "2025-02", "2025-03"]
+  region: ["North", "South", "East"]
+  revenue: [1000.5, 2000, 1500]
+  cost: [600, 1200, 900]
+  active: [true, false, true]
+  profit: "=revenue - cost"
+tax_rate:
+  value: 0.25
+summary:
+  total:
+    formula: "=SUM(sales.revenue)"
+"#,
+        )
+        .unwrap();
+
+        let report = roundtrip_check(&yaml_path).unwrap();
+        assert_eq!(report.differences, vec![]);
+        assert!(report.is_clean());
+        assert_eq!((report.tables, report.columns, report.scalars), (1, 6, 2));
+    }
+
+    #[test]
+    fn test_compare_models_reports_differences() {
+        let original = crate::parser::parse_model_str(
+            r#"
+_forge_version: "1.0.0"
+sales:
+  month: ["2025-01", "2025-02"]
+  revenue: [100, 200]
+  cost: [60, 70]
+"#,
+        )
+        .unwrap();
+        let mut imported = original.clone();
+        let sales = imported.tables.get_mut("sales").unwrap();
+        sales.columns.remove("cost");
+        sales.columns.get_mut("month").unwrap().values =
+            ColumnValue::Text(vec!["2025-01".into(), "2025-02".into()]);
+        sales.columns.get_mut("revenue").unwrap().values = ColumnValue::Number(vec![100.0, 250.0]);
+
+        let report = compare_models(&original, &imported);
+        assert_eq!(
+            report.differences,
+            vec![
+                RoundtripDifference::Lost {
+                    name: "sales.cost".to_string()
+                },
+                RoundtripDifference::TypeChanged {
+                    name: "sales.month".to_string(),
+                    original: "Date".to_string(),
+                    imported: "Text".to_string(),
+                },
+                RoundtripDifference::ValueDrift {
+                    name: "sales.revenue[1]".to_string(),
+                    original: "200".to_string(),
+                    imported: "250".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            report.differences[2].to_string(),
+            "sales.revenue[1]: value 200 → 250"
+        );
+    }
+}
@@ -4,7 +4,7 @@
 use clap::{Parser, Subcommand};
 use colored::Colorize;
 use royalbit_forge::cli;
-use royalbit_forge::error::ForgeResult;
+use royalbit_forge::error::{ForgeError, ForgeResult};
 use royalbit_forge::update::{check_for_update, perform_update};
 use std::path::PathBuf;
 
@@ -17,12 +17,15 @@ use std::path::PathBuf;
 COMMANDS:
   calculate   - Evaluate formulas in YAML files
   validate    - Check formulas without modifying
-  functions   - List all 81 supported functions
+  fmt         - Rewrite YAML in canonical layout
+  functions   - List all supported functions
   sensitivity - One/two-variable data tables
   goal-seek   - Find input value for target output
   break-even  - Find where output crosses zero
   variance    - Budget vs actual analysis
   compare     - Compare scenarios side-by-side
+  monte-carlo - Simulate output distribution from inputs
+  stats       - Summary statistics for table columns
   export      - YAML to Excel (.xlsx)
   import      - Excel to YAML
   watch       - Auto-calculate on file changes
@@ -65,10 +68,28 @@ IMPORTANT: Calculate updates ALL files in the chain (Excel-style)!
   If pricing.yaml has stale formulas, they will be recalculated too.
   This ensures data integrity across all referenced files.
 
-Use --dry-run to preview changes without modifying files.")]
+Use --dry-run to preview changes without modifying files: each changed
+  value is shown as a -old/+new diff, grouped by table and scalar section.
+  Add --no-color for plain output (NO_COLOR is also honoured).
+Use --format json to print results to stdout instead of writing the file.
+  It cannot be combined with --dry-run or --verbose.
+Use -o/--output to write the calculated model to another file and leave the
+  input untouched. Only the main model is written; included files are not
+  rewritten.
+Use --include-path DIR (repeatable) to look for included files in DIR when
+  they are not found next to the including file. Directories are searched in
+  the order given.
+Use --check-units to fail when a formula adds or subtracts different units
+  (e.g. USD + kg). Multiplying and dividing is allowed and gives compound
+  units such as USD/unit.
+
+BATCH MODE:
+  forge calculate \"models/*.yaml\"
+  Calculates every matching file independently, prints a per-file summary,
+  and exits non-zero if any file failed.")]
     /// Calculate all formulas in a YAML file
     Calculate {
-        /// Path to YAML file (can include other files via 'includes' section)
+        /// Path to YAML file, or a quoted glob like "models/*.yaml" to calculate each match
         file: PathBuf,
 
         /// Preview changes without writing to file
@@ -82,15 +103,57 @@ Use --dry-run to preview changes without modifying files.")]
         /// Scenario name to apply (uses variable overrides from 'scenarios' section)
         #[arg(short, long)]
         scenario: Option<String>,
+
+        /// Output format: yaml (write results back to file) or json (print to stdout)
+        #[arg(long, value_parser = ["yaml", "json"], default_value = "yaml")]
+        format: String,
+
+        /// Fail if a formula adds or subtracts values with different units
+        #[arg(long)]
+        check_units: bool,
+
+        /// Print without ANSI colors (e.g. the --dry-run diff)
+        #[arg(long)]
+        no_color: bool,
+
+        /// Write the calculated model to this file instead of updating FILE
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Extra directory to search for included files (repeatable)
+        #[arg(long = "include-path", value_name = "DIR")]
+        include_path: Vec<PathBuf>,
     },
 
+    #[command(long_about = "Audit one variable, or print the whole dependency graph.
+
+EXAMPLES:
+  forge audit model.yaml profit              # How one variable is calculated
+  forge audit model.yaml price --impact      # Everything downstream of price
+  forge audit model.yaml --tree              # Every input and what it feeds
+  forge audit model.yaml --dot | dot -Tsvg > deps.svg
+
+The DOT output has one edge per reference, pointing from an input to the
+scalar or table.column that reads it.")]
     /// Show audit trail for a specific variable
     Audit {
         /// Path to YAML file
         file: PathBuf,
 
-        /// Variable name to audit
-        variable: String,
+        /// Variable name to audit (not needed with --tree or --dot)
+        variable: Option<String>,
+
+        /// List every scalar and column that transitively depends on the variable
+        #[arg(long, conflicts_with_all = ["tree", "dot"])]
+        impact: bool,
+
+        /// Print the whole model's dependency graph as an indented tree
+        #[arg(long, conflicts_with = "dot")]
+        tree: bool,
+
+        /// Print the whole model's dependency graph in Graphviz DOT format
+        #[arg(long)]
+        dot: bool,
     },
 
     #[command(long_about = "Validate formulas without calculating.
@@ -114,12 +177,45 @@ NOTE: Validation checks ALL files in the chain.
 
 BATCH VALIDATION:
   forge validate file1.yaml file2.yaml file3.yaml
-  Validates multiple files in sequence, reporting all errors.")]
+  Validates multiple files in sequence, reporting all errors.
+
+DIFF MODE:
+  forge validate model.yaml --diff
+  Lists every stale value grouped by table/scalar section, showing the
+  stored value, the recalculated value, and the delta.
+
+SCHEMA CHECK:
+  forge validate model.yaml --schema model.schema.json
+  Checks the YAML against a JSON Schema (see 'forge schema') before
+  validating formulas, listing every missing table/column and wrong type.
+
+REPORT:
+  forge validate model.yaml --report report.json
+  Collects every issue of every file instead of stopping at the first one
+  and writes them as JSON for CI annotators: file, line, path, expected,
+  actual, severity, kind and message per issue.
+
+EXIT CODES:
+  0  all files are valid
+  1  stale values: every failing file only needs 'forge calculate'
+  2  broken file: unreadable, unparsable, schema violation or formula error")]
     /// Validate formulas without calculating
     Validate {
         /// Path to YAML file(s) to validate
         #[arg(required = true)]
         files: Vec<PathBuf>,
+
+        /// Show stored vs recalculated values for every stale cell
+        #[arg(long)]
+        diff: bool,
+
+        /// JSON Schema file the YAML must conform to
+        #[arg(long)]
+        schema: Option<PathBuf>,
+
+        /// Write every issue of every file to a JSON report
+        #[arg(long, conflicts_with = "diff")]
+        report: Option<PathBuf>,
     },
 
     #[command(long_about = "Export v1.0.0 array model to Excel .xlsx format.
@@ -127,27 +223,31 @@ BATCH VALIDATION:
 Converts YAML column arrays to Excel worksheets with full formula support.
 Each table becomes a separate worksheet. Formulas are translated to Excel syntax.
 
-SUPPORTED FEATURES (Phase 3.1 - Basic Export):
-  ✅ Table columns → Excel columns (A, B, C, ...)
+SUPPORTED FEATURES:
+  ✅ Table columns → Excel columns (A, B, C, ... in alphabetical order)
   ✅ Data values (Number, Text, Date, Boolean)
   ✅ Multiple tables → Multiple worksheets
   ✅ Scalars → Dedicated \"Scalars\" worksheet
-
-COMING SOON (Phase 3.2+):
-  ⏳ Row formulas → Excel cell formulas (=A2-B2)
-  ⏳ Cross-table references (=Sheet!Column)
-  ⏳ Aggregation formulas (=SUM(Sheet!A:A))
+  ✅ Row formulas → Excel cell formulas (=revenue - cost → =A2-B2)
+  ✅ Cross-table references (=sales.revenue → ='sales'!B2)
+  ✅ Scalar aggregations (=SUM(sales.revenue) → =SUM('sales'!B2:B13)),
+     including SUMIF/COUNTIF/AVERAGEIF criteria
 
 EXAMPLE:
   forge export quarterly_pl.yaml quarterly_pl.xlsx
 
+CSV EXPORT:
+  An output path ending in .csv writes calculated table values as CSV.
+  One table → out.csv; several tables → out_<table>.csv each.
+  forge export quarterly_pl.yaml out.csv
+
 NOTE: Only works with v1.0.0 array models. v0.2.0 scalar models are not supported.")]
     /// Export v1.0.0 array model to Excel .xlsx
     Export {
         /// Path to v1.0.0 YAML file (must have 'tables' section)
         input: PathBuf,
 
-        /// Output Excel file path (.xlsx)
+        /// Output file path (.xlsx, or .csv for per-table CSV files)
         output: PathBuf,
 
         /// Show verbose export steps
@@ -160,12 +260,13 @@ NOTE: Only works with v1.0.0 array models. v0.2.0 scalar models are not supporte
 Converts Excel worksheets to YAML tables with formula preservation.
 Each worksheet becomes a table in the output YAML file.
 
-SUPPORTED FEATURES (Phase 4.1 - Basic Import):
+SUPPORTED FEATURES:
   ✅ Excel worksheets → YAML tables
   ✅ Data values (Number, Text, Boolean)
   ✅ Multiple worksheets → One YAML file (one-to-one)
   ✅ \"Scalars\" sheet → Scalar section
-  ⏳ Formula translation (coming in Phase 4.3)
+  ✅ Formula translation via header rows (=A2-B2 → =revenue-cost)
+  ✅ Absolute refs and column ranges (=SUM(Sales!$B:$B) → =SUM(sales.revenue))
 
 WORKFLOW:
   1. Import existing Excel → YAML
@@ -176,11 +277,15 @@ WORKFLOW:
 EXAMPLE:
   forge import quarterly_pl.xlsx quarterly_pl.yaml
 
-NOTE: Formulas are preserved as Excel syntax (Phase 4.1).
-      Formula translation to YAML syntax coming in Phase 4.3.")]
+CSV IMPORT:
+  A .csv input becomes one table named after the file stem. Column types
+  (Number, Date, Boolean, Text) are inferred; mixed columns become Text.
+  forge import sales.csv sales.yaml
+
+NOTE: A formula column takes its formula from the first data row.")]
     /// Import Excel .xlsx file to YAML v1.0.0
     Import {
-        /// Path to Excel file (.xlsx)
+        /// Path to Excel file (.xlsx) or CSV file (.csv)
         input: PathBuf,
 
         /// Output YAML file path (or directory if --split-files)
@@ -252,12 +357,28 @@ SCENARIOS IN YAML:
     optimistic:
       growth_rate: 0.12
       churn_rate: 0.01
+      sales.revenue: [120, 150, 180]   # replace a whole table column
     pessimistic:
       growth_rate: 0.02
       churn_rate: 0.05
+    stretch:
+      extends: optimistic              # inherit, then override
+      growth_rate: 0.20
 
-EXAMPLE:
+  A table.column override must have one value per row of the table.
+
+EXAMPLES:
   forge compare model.yaml --scenarios base,optimistic,pessimistic
+  forge compare model.yaml --scenarios optimistic,pessimistic --baseline base
+
+  forge compare model.yaml --scenarios base,optimistic --output-vars revenue,profit
+
+  --baseline adds a Δ column after every other scenario with its change from
+  the baseline, e.g. +150 (+12.5%). The percentage is n/a when the baseline
+  value is 0. --output-vars limits the table to the listed variables.
+
+  --output-format json (or yaml) prints the values and deltas as data
+  instead of the table, for scripts and CI pipelines.
 
 OUTPUT:
   Scenario Comparison: model.yaml
@@ -274,11 +395,142 @@ OUTPUT:
         #[arg(short, long, value_delimiter = ',')]
         scenarios: Vec<String>,
 
+        /// Scenario to show every other scenario's change against
+        #[arg(short, long)]
+        baseline: Option<String>,
+
+        /// Only show these variables (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        output_vars: Vec<String>,
+
+        /// Output format: table (default), json or yaml
+        #[arg(long, value_parser = ["table", "json", "yaml"], default_value = "table")]
+        output_format: String,
+
         /// Show verbose output
         #[arg(short, long)]
         verbose: bool,
     },
 
+    #[command(long_about = "Roll several models up into one consolidated model.
+
+Each file is calculated first; the output holds the resulting values.
+
+MERGE RULES:
+  Scalars:  values with the same name are summed; others are copied
+  Tables:   tables with the same name must have the same columns
+            numeric columns are summed row by row (same row count required)
+            text, date and boolean columns must be identical
+  --stack:  append the rows of matching tables instead of summing them
+
+Ratios and percentages are summed like any other scalar, so recompute them
+from the consolidated totals.
+
+EXAMPLES:
+  forge consolidate subsidiary1.yaml subsidiary2.yaml -o group.yaml
+  forge consolidate q1.yaml q2.yaml q3.yaml q4.yaml -o year.yaml --stack")]
+    /// Merge multiple models into a consolidated model
+    Consolidate {
+        /// YAML files to consolidate
+        #[arg(required = true, num_args = 2..)]
+        files: Vec<PathBuf>,
+
+        /// Output YAML file for the consolidated model
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Append rows of matching tables instead of summing aligned columns
+        #[arg(long)]
+        stack: bool,
+    },
+
+    #[command(long_about = "Join two tables on a key column.
+
+Both files are calculated first. Each row of the left table is matched to
+the rows of the right table with the same key, and the right table's other
+columns are added to it. The output is a YAML file with the joined table
+(values only; formulas are not kept).
+
+JOIN KINDS:
+  inner (default): keep only left rows with a matching key
+  left:            keep every left row; unmatched rows get 0, \"\" or false
+
+A right column whose name is already used is prefixed with the right
+table's name (products_name). If a file has several tables with the key
+column, pick one with --left-table / --right-table.
+
+EXAMPLES:
+  forge join orders.yaml products.yaml --on product_id -o joined.yaml
+  forge join orders.yaml products.yaml --on product_id --how left -o joined.yaml")]
+    /// Join two tables on a key column
+    Join {
+        /// YAML file with the left table (e.g. orders)
+        left: PathBuf,
+
+        /// YAML file with the right table (e.g. products)
+        right: PathBuf,
+
+        /// Key column present in both tables
+        #[arg(long)]
+        on: String,
+
+        /// Output YAML file for the joined table
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Join kind: inner (matching rows only) or left (every left row)
+        #[arg(long, value_parser = ["inner", "left"], default_value = "inner")]
+        how: String,
+
+        /// Table to use from the left file
+        #[arg(long)]
+        left_table: Option<String>,
+
+        /// Table to use from the right file
+        #[arg(long)]
+        right_table: Option<String>,
+    },
+
+    #[command(long_about = "Check that a model survives an Excel round-trip.
+
+Exports the model to a temporary .xlsx, imports it back and compares the
+result with the original YAML. Reports:
+  - tables, columns and scalars lost on the way
+  - columns whose type changed (e.g. Date → Text)
+  - data values that drifted
+  - row and scalar formulas that no longer match
+
+Exits with an error when anything changed, so it can run in CI.
+
+EXAMPLE:
+  forge roundtrip model.yaml")]
+    /// Check that a model survives export to Excel and import back
+    Roundtrip {
+        /// Path to YAML file
+        file: PathBuf,
+    },
+
+    #[command(long_about = "Export a model's structure as JSON Schema (draft-07).
+
+The schema lists every table, column and scalar of the model as required
+properties. Data columns are constrained to their value type (number, text,
+date or boolean); formula columns must hold a formula.
+
+Use it to check that data files produced elsewhere keep the model's shape.
+
+EXAMPLES:
+  forge schema model.yaml -o model.schema.json
+  forge schema model.yaml > model.schema.json")]
+    /// Export the model structure as JSON Schema
+    Schema {
+        /// Path to YAML file
+        file: PathBuf,
+
+        /// Output JSON file (prints to stdout if omitted)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
     #[command(long_about = "Compare budget vs actual with variance analysis.
 
 Calculates variances between two YAML files (budget and actual).
@@ -287,6 +539,7 @@ Shows absolute variance, percentage variance, and favorability status.
 INPUTS:
   Both files must be YAML format (use 'forge import' for Excel files first).
   Variables are matched by name across both files.
+  Table columns in both files are compared row by row, plus a column total.
 
 VARIANCE TYPES:
   For revenue/income: actual > budget = favorable (✅)
@@ -300,6 +553,8 @@ OUTPUT FORMATS:
   Terminal table (default)
   YAML: forge variance budget.yaml actual.yaml -o report.yaml
   Excel: forge variance budget.yaml actual.yaml -o report.xlsx
+  Markdown: forge variance budget.yaml actual.yaml -o report.md
+  JSON/YAML to stdout: forge variance budget.yaml actual.yaml --output-format json
 
 EXAMPLES:
   forge variance budget.yaml actual.yaml
@@ -319,10 +574,14 @@ See ADR-002 for design rationale on YAML-only inputs.")]
         #[arg(short, long, default_value = "10")]
         threshold: f64,
 
-        /// Output file (optional: .yaml or .xlsx)
+        /// Output file (optional: .yaml, .xlsx or .md)
         #[arg(short, long)]
         output: Option<PathBuf>,
 
+        /// Output format: table (default), json or yaml
+        #[arg(long, value_parser = ["table", "json", "yaml"], default_value = "table")]
+        output_format: String,
+
         /// Show verbose output
         #[arg(short, long)]
         verbose: bool,
@@ -344,24 +603,39 @@ TWO-VARIABLE ANALYSIS:
 
   Shows a matrix of NPV values for each combination of inputs.
 
+MULTIPLE OUTPUTS:
+  forge sensitivity model.yaml -v price -r 80,120,10 -o profit,margin
+
+  Observes every listed output in one run: one column per output for a
+  one-variable sweep, one matrix per output for a two-variable sweep.
+  --output-vars is an alias of --output. Unknown variable names are an error
+  listing the valid ones.
+
+TORNADO CHART:
+  forge sensitivity model.yaml --tornado -o npv --vars price,volume,cost --swing 0.1
+
+  Varies each listed input ±10% one at a time (others held at base) and
+  ranks the variables by the resulting output range (low, high, range).
+
 RANGE FORMAT:
   start,end,step - e.g., 0.01,0.15,0.02 means 0.01, 0.03, 0.05, ..., 0.15
 
 EXAMPLES:
   forge sensitivity model.yaml -v growth_rate -r 0.05,0.20,0.05 -o profit
-  forge sensitivity model.yaml -v price -v2 volume -r 10,50,10 -r2 100,500,100 -o revenue")]
+  forge sensitivity model.yaml -v price -v2 volume -r 10,50,10 -r2 100,500,100 -o revenue
+  forge sensitivity model.yaml -v price -r 80,120,10 -o profit --output-format json")]
     /// Run sensitivity analysis on model variables
     Sensitivity {
         /// Path to YAML file
         file: PathBuf,
 
         /// Variable to vary (scalar name)
-        #[arg(short, long)]
-        vary: String,
+        #[arg(short, long, required_unless_present = "tornado")]
+        vary: Option<String>,
 
         /// Range for first variable: start,end,step
-        #[arg(short, long)]
-        range: String,
+        #[arg(short, long, required_unless_present = "tornado")]
+        range: Option<String>,
 
         /// Second variable to vary (for 2D analysis)
         #[arg(long)]
@@ -371,10 +645,26 @@ EXAMPLES:
         #[arg(long)]
         range2: Option<String>,
 
-        /// Output variable to observe
-        #[arg(short, long)]
+        /// Output variable(s) to observe (comma-separated)
+        #[arg(short, long, visible_alias = "output-vars")]
         output: String,
 
+        /// Tornado mode: vary each of --vars by ±swing one at a time
+        #[arg(long, conflicts_with_all = ["vary", "range", "vary2", "range2"])]
+        tornado: bool,
+
+        /// Variables for tornado mode (comma-separated)
+        #[arg(long, requires = "tornado")]
+        vars: Option<String>,
+
+        /// Relative swing for tornado mode (0.1 = ±10%)
+        #[arg(long, default_value = "0.1")]
+        swing: f64,
+
+        /// Output format: table (default), json or yaml
+        #[arg(long, value_parser = ["table", "json", "yaml"], default_value = "table")]
+        output_format: String,
+
         /// Show verbose output
         #[arg(long)]
         verbose: bool,
@@ -392,15 +682,25 @@ EXAMPLES:
   forge goal-seek model.yaml --target npv --value 0 --vary discount_rate
   → Find the discount rate that makes NPV = 0 (IRR)
 
+  forge goal-seek model.yaml --target \"SUM(sales.revenue)\" --value 1200 --vary inputs.price
+  → Seek on a table aggregation (or a single cell: \"sales.revenue[2]\")
+
+  forge goal-seek model.yaml --target \"SUM(sales.revenue)\" --value 1500 --vary-column sales.price
+  → Find the factor to scale every sales.price by. The whole column is
+    multiplied proportionally (not solved per cell); the factor is reported.
+
 OPTIONS:
   --min, --max: Override automatic bounds for the search
-  --tolerance: Precision of the result (default: 0.0001)")]
+  --tolerance: Precision of the result (default: 0.0001)
+  --method: bisection (default) or newton; newton falls back to bisection
+            if it diverges or leaves the bounds
+  --output-format: table (default), json or yaml")]
     /// Find input value to achieve target output
     GoalSeek {
         /// Path to YAML file
         file: PathBuf,
 
-        /// Target variable to achieve
+        /// Target scalar, or an expression like "SUM(sales.revenue)"
         #[arg(short, long)]
         target: String,
 
@@ -409,8 +709,12 @@ OPTIONS:
         value: f64,
 
         /// Variable to adjust
-        #[arg(short, long)]
-        vary: String,
+        #[arg(short, long, required_unless_present = "vary_column")]
+        vary: Option<String>,
+
+        /// Table column to scale by a solved factor (e.g. sales.price)
+        #[arg(long, conflicts_with = "vary")]
+        vary_column: Option<String>,
 
         /// Minimum bound for search (optional)
         #[arg(long)]
@@ -424,6 +728,14 @@ OPTIONS:
         #[arg(long, default_value = "0.0001")]
         tolerance: f64,
 
+        /// Solver: bisection (robust, default) or newton (fast on smooth models)
+        #[arg(long, value_parser = ["bisection", "newton"], default_value = "bisection")]
+        method: String,
+
+        /// Output format: table (default), json or yaml
+        #[arg(long, value_parser = ["table", "json", "yaml"], default_value = "table")]
+        output_format: String,
+
         /// Show verbose output
         #[arg(long)]
         verbose: bool,
@@ -439,7 +751,10 @@ EXAMPLES:
   → Find units needed to break even (profit = 0)
 
   forge break-even model.yaml --output net_margin --vary price
-  → Find minimum price for positive margin")]
+  → Find minimum price for positive margin
+
+  forge break-even model.yaml --output profit --vary units --output-format json
+  → Print the solution as JSON (or yaml) for scripts")]
     /// Find break-even point (where output = 0)
     BreakEven {
         /// Path to YAML file
@@ -461,11 +776,107 @@ EXAMPLES:
         #[arg(long)]
         max: Option<f64>,
 
+        /// Output format: table (default), json or yaml
+        #[arg(long, value_parser = ["table", "json", "yaml"], default_value = "table")]
+        output_format: String,
+
         /// Show verbose output
         #[arg(long)]
         verbose: bool,
     },
 
+    #[command(
+        long_about = "Run a Monte Carlo simulation over uncertain inputs.
+
+Samples each --var from its distribution, recalculates the model per trial,
+and reports the output distribution: mean, standard deviation, min, max and
+the 5th/50th/95th percentiles.
+
+DISTRIBUTIONS:
+  name:normal:mean:stdev     e.g. price:normal:100:10
+  name:uniform:min:max       e.g. volume:uniform:900:1100
+
+EXAMPLES:
+  forge monte-carlo model.yaml --var price:normal:100:10 \\
+      --var volume:uniform:900:1100 --output profit --trials 10000
+  forge monte-carlo model.yaml --var price:normal:100:10 -o profit --seed 42
+
+Use --seed for reproducible results.",
+        alias = "montecarlo"
+    )]
+    /// Simulate output distribution from uncertain inputs
+    MonteCarlo {
+        /// Path to YAML file
+        file: PathBuf,
+
+        /// Input distribution: name:normal:mean:stdev or name:uniform:min:max
+        #[arg(long = "var", required = true)]
+        vars: Vec<String>,
+
+        /// Output variable to observe
+        #[arg(short, long)]
+        output: String,
+
+        /// Number of trials
+        #[arg(long, default_value = "10000")]
+        trials: usize,
+
+        /// Random seed for reproducible results
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Show verbose output
+        #[arg(long)]
+        verbose: bool,
+    },
+
+    #[command(long_about = "Print summary statistics for a table column.
+
+Calculates the model, then shows count, sum, mean, median, sample standard
+deviation, min, max and quartiles - no formulas needed.
+
+EXAMPLES:
+  forge stats model.yaml --table sales --column revenue
+  forge stats model.yaml --table sales --column all   # Every numeric column")]
+    /// Show summary statistics for table columns
+    Stats {
+        /// Path to YAML file
+        file: PathBuf,
+
+        /// Table to summarize
+        #[arg(short, long)]
+        table: String,
+
+        /// Column to summarize, or 'all' for every numeric column
+        #[arg(short, long, default_value = "all")]
+        column: String,
+    },
+
+    #[command(long_about = "Rewrite YAML model files in a canonical layout.
+
+Normalizes indentation (two spaces, column arrays as [a, b, c]), orders
+top-level keys as _ metadata, tables, scalar sections, then scenarios, orders
+scalar fields as value, formula, then metadata, and normalizes formulas:
+function names in upper case, one space around operators and after commas,
+no redundant parentheses (=sum( (a),b )*2 becomes =SUM(a, b) * 2). String
+literals are kept verbatim. Values are never changed.
+
+Comments are not preserved. Multi-document files are not supported.
+
+EXAMPLES:
+  forge fmt model.yaml                  # Rewrite in place
+  forge fmt models/*.yaml --check       # CI: fail if any file would change")]
+    /// Rewrite YAML files in canonical layout
+    Fmt {
+        /// Path to YAML file(s) to format
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Don't write; exit non-zero if any file is not formatted
+        #[arg(long)]
+        check: bool,
+    },
+
     #[command(long_about = "Check for updates and optionally self-update the binary.
 
 Downloads the latest release from GitHub and replaces the current binary.
@@ -487,8 +898,8 @@ PLATFORMS:
     #[command(
         long_about = "List all supported Excel-compatible functions by category.
 
-Forge supports 58 Excel functions for financial modeling. Use this command
-to see all available functions organized by category.
+Forge supports Excel-compatible functions for financial modeling. Use this
+command to see all available functions organized by category.
 
 CATEGORIES:
   Financial   - NPV, IRR, XNPV, XIRR, PMT, PV, FV, RATE, NPER (9)
@@ -501,6 +912,13 @@ CATEGORIES:
   Date        - TODAY, DATE, YEAR, MONTH, DAY, DATEDIF, EDATE, EOMONTH (8)
   Logic       - IF, AND, OR (3)
 
+RANGES (scalar formulas only):
+  {10, 20, 30}              Inline array of numbers or quoted text
+  sales.revenue[1:3]        Column slice: 0-based, start inclusive, end
+                            exclusive, so [1:3] is the 2nd and 3rd rows
+  sales.revenue[2:]         Omitted bounds run to the start or end of the column
+  A slice that is empty, reversed or past the end of the column is an error.
+
 EXAMPLES:
   forge functions           # List all functions
   forge functions --json    # Output as JSON (for tooling)"
@@ -569,11 +987,96 @@ fn main() -> ForgeResult<()> {
             dry_run,
             verbose,
             scenario,
-        } => cli::calculate(file, dry_run, verbose, scenario),
+            format,
+            check_units,
+            no_color,
+            output,
+            include_path,
+        } => {
+            if no_color {
+                colored::control::set_override(false);
+            }
+            if check_units && cli::is_glob_pattern(&file) {
+                return Err(ForgeError::Validation(
+                    "--check-units takes a single file, not a glob pattern".to_string(),
+                ));
+            }
+            if output.is_some() && (cli::is_glob_pattern(&file) || format == "json") {
+                return Err(ForgeError::Validation(
+                    "--output takes a single file and YAML format".to_string(),
+                ));
+            }
+            if format == "json" && (dry_run || verbose) {
+                return Err(ForgeError::Validation(
+                    "--format json cannot be combined with --dry-run or --verbose".to_string(),
+                ));
+            }
+            if cli::is_glob_pattern(&file) {
+                cli::calculate_batch(
+                    file.to_string_lossy().to_string(),
+                    dry_run,
+                    verbose,
+                    scenario,
+                    &include_path,
+                )
+            } else if format == "json" {
+                cli::calculate_json(file, scenario, &include_path, check_units)
+            } else {
+                cli::calculate_with_include_paths(
+                    file,
+                    output,
+                    dry_run,
+                    verbose,
+                    scenario,
+                    &include_path,
+                    check_units,
+                )
+            }
+        }
 
-        Commands::Audit { file, variable } => cli::audit(file, variable),
+        Commands::Audit {
+            file,
+            variable,
+            impact,
+            tree,
+            dot,
+        } => {
+            if tree || dot {
+                cli::audit_graph(file, dot)
+            } else {
+                match variable {
+                    Some(variable) if impact => cli::audit_impact(file, variable),
+                    Some(variable) => cli::audit(file, variable),
+                    None => Err(ForgeError::Validation(
+                        "Specify a variable to audit, or --tree / --dot for the whole model"
+                            .to_string(),
+                    )),
+                }
+            }
+        }
 
-        Commands::Validate { files } => cli::validate(files),
+        Commands::Validate {
+            files,
+            diff,
+            schema,
+            report,
+        } => {
+            let result = if let Some(report) = report {
+                cli::validate_report(files, schema, report)
+            } else if let Some(schema) = schema {
+                cli::validate_with_schema(files, schema, diff)
+            } else if diff {
+                cli::validate_diff(files)
+            } else {
+                cli::validate(files)
+            };
+            // Exit codes let CI tell "needs recalc" (1) from "broken file" (2)
+            if let Err(ref e) = result {
+                eprintln!("Error: {:?}", e);
+                std::process::exit(cli::ValidationOutcome::of(&result).exit_code());
+            }
+            Ok(())
+        }
 
         Commands::Export {
             input,
@@ -598,16 +1101,66 @@ fn main() -> ForgeResult<()> {
         Commands::Compare {
             file,
             scenarios,
+            baseline,
+            output_vars,
+            output_format,
             verbose,
-        } => cli::compare(file, scenarios, verbose),
+        } => {
+            let options = cli::CompareOptions {
+                baseline,
+                output_vars,
+            };
+            match cli::ReportFormat::from_flag(&output_format) {
+                Some(format) => {
+                    cli::print_report(&cli::compare_report(file, scenarios, options)?, format)
+                }
+                None => cli::compare_with_options(file, scenarios, options, verbose),
+            }
+        }
+
+        Commands::Consolidate {
+            files,
+            output,
+            stack,
+        } => cli::consolidate(files, output, stack),
+
+        Commands::Join {
+            left,
+            right,
+            on,
+            output,
+            how,
+            left_table,
+            right_table,
+        } => {
+            let kind = if how == "left" {
+                royalbit_forge::core::JoinKind::Left
+            } else {
+                royalbit_forge::core::JoinKind::Inner
+            };
+            cli::join(left, right, on, output, kind, left_table, right_table)
+        }
+
+        Commands::Roundtrip { file } => cli::roundtrip(file),
+
+        Commands::Schema { file, output } => cli::schema(file, output),
 
         Commands::Variance {
             budget,
             actual,
             threshold,
             output,
+            output_format,
             verbose,
-        } => cli::variance(budget, actual, threshold, output, verbose),
+        } => match cli::ReportFormat::from_flag(&output_format) {
+            Some(_) if output.is_some() => Err(ForgeError::Validation(
+                "--output-format json|yaml prints to stdout; drop --output".to_string(),
+            )),
+            Some(format) => {
+                cli::print_report(&cli::variance_report(budget, actual, threshold)?, format)
+            }
+            None => cli::variance(budget, actual, threshold, output, verbose),
+        },
 
         Commands::Sensitivity {
             file,
@@ -616,19 +1169,84 @@ fn main() -> ForgeResult<()> {
             vary2,
             range2,
             output,
+            tornado,
+            vars,
+            swing,
+            output_format,
             verbose,
-        } => cli::sensitivity(file, vary, range, vary2, range2, output, verbose),
+        } => {
+            let format = cli::ReportFormat::from_flag(&output_format);
+            if tornado {
+                let vars = vars.unwrap_or_default();
+                match format {
+                    Some(format) => {
+                        cli::print_report(&cli::tornado_report(file, vars, swing, output)?, format)
+                    }
+                    None => cli::sensitivity_tornado(file, vars, swing, output, verbose),
+                }
+            } else {
+                match (vary, range) {
+                    (Some(vary), Some(range)) => match format {
+                        Some(format) => cli::print_report(
+                            &cli::sensitivity_report(file, vary, range, vary2, range2, output)?,
+                            format,
+                        ),
+                        None => cli::sensitivity(file, vary, range, vary2, range2, output, verbose),
+                    },
+                    _ => Err(royalbit_forge::error::ForgeError::Validation(
+                        "--vary and --range are required unless --tornado is set".to_string(),
+                    )),
+                }
+            }
+        }
 
         Commands::GoalSeek {
             file,
             target,
             value,
             vary,
+            vary_column,
             min,
             max,
             tolerance,
+            method,
+            output_format,
             verbose,
-        } => cli::goal_seek(file, target, value, vary, min, max, tolerance, verbose),
+        } => {
+            let method = if method == "newton" {
+                cli::GoalSeekMethod::Newton
+            } else {
+                cli::GoalSeekMethod::Bisection
+            };
+            let options = cli::GoalSeekOptions {
+                min,
+                max,
+                tolerance,
+                method,
+                verbose,
+                timeout: None,
+            };
+            let format = cli::ReportFormat::from_flag(&output_format);
+            match (vary, vary_column) {
+                (_, Some(column)) => match format {
+                    Some(format) => cli::print_report(
+                        &cli::goal_seek_column_report(file, target, value, column, options)?,
+                        format,
+                    ),
+                    None => cli::goal_seek_column(file, target, value, column, options),
+                },
+                (Some(vary), None) => match format {
+                    Some(format) => cli::print_report(
+                        &cli::goal_seek_report(file, target, value, vary, options)?,
+                        format,
+                    ),
+                    None => cli::goal_seek_with_method(file, target, value, vary, options),
+                },
+                (None, None) => Err(royalbit_forge::error::ForgeError::Validation(
+                    "Either --vary or --vary-column is required".to_string(),
+                )),
+            }
+        }
 
         Commands::BreakEven {
             file,
@@ -636,8 +1254,32 @@ fn main() -> ForgeResult<()> {
             vary,
             min,
             max,
+            output_format,
             verbose,
-        } => cli::break_even(file, output, vary, min, max, verbose),
+        } => match cli::ReportFormat::from_flag(&output_format) {
+            Some(format) => cli::print_report(
+                &cli::break_even_report(file, output, vary, min, max)?,
+                format,
+            ),
+            None => cli::break_even(file, output, vary, min, max, verbose),
+        },
+
+        Commands::MonteCarlo {
+            file,
+            vars,
+            output,
+            trials,
+            seed,
+            verbose,
+        } => cli::monte_carlo(file, vars, output, trials, seed, verbose),
+
+        Commands::Stats {
+            file,
+            table,
+            column,
+        } => cli::stats(file, table, column),
+
+        Commands::Fmt { files, check } => cli::fmt(files, check),
 
         Commands::Update { check } => {
             println!("{}", "🔥 Forge - Update".bold().green());
@@ -0,0 +1,299 @@
+//! Formula explanation for the `forge_explain_formula` tool
+//!
+//! Breaks a formula into the function it calls, its arguments (named after
+//! the parameters in the function registry), the columns and scalars
+//! it references, and a one-line English description. Nothing is evaluated,
+//! so formulas can be explained without a model.
+
+use serde::Serialize;
+
+use crate::core::FunctionRegistry;
+
+/// One argument of the formula's outermost function call
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExplainedArgument {
+    /// Parameter name from the function's signature, if it has one
+    pub parameter: Option<String>,
+    pub value: String,
+}
+
+/// Structured breakdown of a formula
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FormulaExplanation {
+    pub formula: String,
+    /// Function the whole formula calls (`SUMIF` for `=SUMIF(...)`)
+    pub function: Option<String>,
+    /// Catalog category of `function`
+    pub category: Option<String>,
+    pub arguments: Vec<ExplainedArgument>,
+    /// Every function called, in order of appearance
+    pub functions: Vec<String>,
+    /// Column and scalar references, in order of appearance
+    pub references: Vec<String>,
+    pub description: String,
+}
+
+/// Explain a formula such as `=SUMIF(sales.amount, ">100", sales.revenue)`
+pub fn explain_formula(formula: &str) -> FormulaExplanation {
+    let body = formula.trim().trim_start_matches('=').trim();
+    let (functions, references) = scan_names(body);
+
+    let call = outer_call(body);
+    let entry = call
+        .as_ref()
+        .and_then(|(name, _)| FunctionRegistry::get(name));
+    let arguments: Vec<ExplainedArgument> = match &call {
+        Some((_, args)) => {
+            let parameters = entry
+                .map(|f| signature_parameters(f.signature))
+                .unwrap_or_default();
+            args.iter()
+                .enumerate()
+                .map(|(i, value)| ExplainedArgument {
+                    parameter: parameters.get(i).cloned(),
+                    value: value.clone(),
+                })
+                .collect()
+        }
+        None => Vec::new(),
+    };
+
+    let description = match (&call, &entry) {
+        (Some((name, _)), Some(f)) => describe_call(name, f.description, &arguments),
+        (Some((name, args)), None) => format!("Calls {} with {} argument(s)", name, args.len()),
+        (None, _) if references.is_empty() => format!("Constant expression {}", body),
+        (None, _) => format!("Expression using {}", references.join(", ")),
+    };
+
+    FormulaExplanation {
+        formula: formula.trim().to_string(),
+        function: call.as_ref().map(|(name, _)| name.clone()),
+        category: entry.map(|f| f.category.name().to_string()),
+        arguments,
+        functions,
+        references,
+        description,
+    }
+}
+
+/// Function names and references outside string literals
+fn scan_names(body: &str) -> (Vec<String>, Vec<String>) {
+    let chars: Vec<char> = body.chars().collect();
+    let mut functions = Vec::new();
+    let mut references: Vec<String> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += 1;
+            }
+            i += 1;
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+        } else if c.is_alphabetic() || c == '_' || c == '@' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | '.' | '@'))
+            {
+                i += 1;
+            }
+            let name: String = chars[start..i].iter().collect();
+            let mut next = i;
+            while next < chars.len() && chars[next].is_whitespace() {
+                next += 1;
+            }
+            if chars.get(next) == Some(&'(') {
+                functions.push(name.to_uppercase());
+            } else if !name.eq_ignore_ascii_case("TRUE")
+                && !name.eq_ignore_ascii_case("FALSE")
+                && !references.contains(&name)
+            {
+                references.push(name);
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    (functions, references)
+}
+
+/// `NAME(args)` when the whole formula is a single call
+fn outer_call(body: &str) -> Option<(String, Vec<String>)> {
+    let open = body.find('(')?;
+    let name = body[..open].trim();
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+
+    let mut depth = 0;
+    let mut in_quotes = false;
+    for (i, c) in body.char_indices().skip_while(|(i, _)| *i < open) {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '(' if !in_quotes => depth += 1,
+            ')' if !in_quotes => {
+                depth -= 1;
+                if depth == 0 {
+                    if i + 1 != body.len() {
+                        return None;
+                    }
+                    let args = split_arguments(&body[open + 1..i]);
+                    return Some((name.to_uppercase(), args));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split on top-level commas, keeping quoted strings and nested calls whole
+fn split_arguments(inner: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut in_quotes = false;
+
+    for c in inner.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '(' | '{' if !in_quotes => depth += 1,
+            ')' | '}' if !in_quotes => depth -= 1,
+            ',' if !in_quotes && depth == 0 => {
+                args.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() || !args.is_empty() {
+        args.push(current.trim().to_string());
+    }
+    args
+}
+
+/// Parameter names of `=NAME(a, b, [c])`; stops at a variadic `...`
+fn signature_parameters(signature: &str) -> Vec<String> {
+    let inner = match (signature.find('('), signature.find(')')) {
+        (Some(open), Some(close)) if open < close => &signature[open + 1..close],
+        _ => return Vec::new(),
+    };
+    inner
+        .split(',')
+        .map(|p| p.trim().trim_matches(|c| c == '[' || c == ']').to_string())
+        .take_while(|p| p != "...")
+        .filter(|p| !p.is_empty() && p.chars().all(|c| c.is_alphanumeric() || c == '_'))
+        .collect()
+}
+
+/// English description of a call, e.g. "Sum of sales.revenue where sales.amount > 100"
+fn describe_call(name: &str, summary: &str, arguments: &[ExplainedArgument]) -> String {
+    let arg = |i: usize| arguments.get(i).map(|a| a.value.as_str());
+
+    if let ("SUMIF" | "AVERAGEIF" | "COUNTIF", Some(range), Some(criteria)) = (name, arg(0), arg(1))
+    {
+        let condition = describe_criteria(criteria);
+        return match name {
+            "COUNTIF" => format!("Count of {} where {}", range, condition),
+            _ => format!(
+                "{} of {} where {} {}",
+                if name == "SUMIF" { "Sum" } else { "Average" },
+                arg(2).unwrap_or(range),
+                range,
+                condition
+            ),
+        };
+    }
+
+    if arguments.is_empty() {
+        return summary.to_string();
+    }
+    let listed: Vec<String> = arguments
+        .iter()
+        .map(|a| match &a.parameter {
+            Some(parameter) => format!("{} = {}", parameter, a.value),
+            None => a.value.clone(),
+        })
+        .collect();
+    format!("{}: {}", summary, listed.join(", "))
+}
+
+/// `">100"` → `> 100`, `"North"` → `= North`
+fn describe_criteria(criteria: &str) -> String {
+    let text = criteria.trim().trim_matches('"');
+    let operator_len = text
+        .chars()
+        .take_while(|c| matches!(c, '<' | '>' | '='))
+        .count();
+    if operator_len == 0 {
+        format!("= {}", text)
+    } else {
+        format!("{} {}", &text[..operator_len], text[operator_len..].trim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain_sumif() {
+        let explanation = explain_formula("=SUMIF(sales.amount, \">100\", sales.revenue)");
+
+        assert_eq!(explanation.function.as_deref(), Some("SUMIF"));
+        assert_eq!(explanation.category.as_deref(), Some("Conditional"));
+        assert_eq!(
+            explanation.arguments,
+            vec![
+                ExplainedArgument {
+                    parameter: Some("range".to_string()),
+                    value: "sales.amount".to_string(),
+                },
+                ExplainedArgument {
+                    parameter: Some("criteria".to_string()),
+                    value: "\">100\"".to_string(),
+                },
+                ExplainedArgument {
+                    parameter: Some("sum_range".to_string()),
+                    value: "sales.revenue".to_string(),
+                },
+            ]
+        );
+        assert_eq!(explanation.functions, vec!["SUMIF"]);
+        assert_eq!(
+            explanation.references,
+            vec!["sales.amount", "sales.revenue"]
+        );
+        assert_eq!(
+            explanation.description,
+            "Sum of sales.revenue where sales.amount > 100"
+        );
+    }
+
+    #[test]
+    fn test_explain_expression_and_nested_calls() {
+        let explanation = explain_formula("=ROUND(revenue - cost, 2) * tax_rate");
+        assert_eq!(explanation.function, None);
+        assert!(explanation.arguments.is_empty());
+        assert_eq!(explanation.functions, vec!["ROUND"]);
+        assert_eq!(explanation.references, vec!["revenue", "cost", "tax_rate"]);
+        assert_eq!(
+            explanation.description,
+            "Expression using revenue, cost, tax_rate"
+        );
+
+        let explanation = explain_formula("=PMT(rate / 12, 360, loan.amount)");
+        assert_eq!(
+            explanation.description,
+            "Payment for a loan: rate = rate / 12, nper = 360, pv = loan.amount"
+        );
+    }
+}
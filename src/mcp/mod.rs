@@ -9,7 +9,9 @@
 //! ### Core Tools
 //! - `forge_validate` - Validate YAML model files for formula errors
 //! - `forge_calculate` - Calculate formulas and update values
+//! - `forge_calculate_yaml` - Calculate an inline YAML model, returning JSON results
 //! - `forge_audit` - Get dependency tree and value tracing
+//! - `forge_explain_formula` - Break a formula into function, arguments and references
 //! - `forge_export` - Export YAML to Excel
 //! - `forge_import` - Import Excel to YAML
 //!
@@ -20,6 +22,9 @@
 //! - `forge_variance` - Budget vs actual analysis
 //! - `forge_compare` - Multi-scenario comparison
 //!
+//! ### Resources
+//! - `forge://functions` - Catalog of supported functions with signatures
+//!
 //! ## Usage
 //!
 //! Configure in Claude Code settings:
@@ -33,6 +38,7 @@
 //! }
 //! ```
 
+mod explain;
 pub mod server;
 
 pub use server::run_mcp_server_sync;
@@ -5,14 +5,20 @@
 
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::cli::{
-    audit, break_even, calculate, compare, export, goal_seek, import, sensitivity, validate,
-    variance,
+    audit, calculate, compare, export, goal_seek_with_method, import, sensitivity, validate,
+    variance, GoalSeekOptions,
 };
+use crate::core::{ArrayCalculator, FunctionRegistry};
+use crate::error::ForgeResult;
+use crate::{parser, writer};
+
+use super::explain::explain_formula;
 
 /// JSON-RPC request
 #[derive(Debug, Deserialize)]
@@ -124,6 +130,10 @@ fn handle_request(request: &JsonRpcRequest) -> Option<JsonRpcResponse> {
                 "capabilities": {
                     "tools": {
                         "listChanged": false
+                    },
+                    "resources": {
+                        "subscribe": false,
+                        "listChanged": false
                     }
                 },
                 "serverInfo": {
@@ -163,6 +173,52 @@ fn handle_request(request: &JsonRpcRequest) -> Option<JsonRpcResponse> {
                 error: None,
             })
         }
+        "resources/list" => Some(JsonRpcResponse {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: Some(json!({
+                "resources": [{
+                    "uri": FUNCTIONS_RESOURCE_URI,
+                    "name": "functions",
+                    "description": "Every supported formula function with its signature, category and description",
+                    "mimeType": "application/json"
+                }]
+            })),
+            error: None,
+        }),
+        "resources/read" => {
+            let uri = request
+                .params
+                .get("uri")
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            if uri == FUNCTIONS_RESOURCE_URI {
+                Some(JsonRpcResponse {
+                    jsonrpc: "2.0".to_string(),
+                    id,
+                    result: Some(json!({
+                        "contents": [{
+                            "uri": FUNCTIONS_RESOURCE_URI,
+                            "mimeType": "application/json",
+                            "text": serde_json::to_string_pretty(&functions_resource())
+                                .unwrap_or_default()
+                        }]
+                    })),
+                    error: None,
+                })
+            } else {
+                Some(JsonRpcResponse {
+                    jsonrpc: "2.0".to_string(),
+                    id,
+                    result: None,
+                    error: Some(JsonRpcError {
+                        code: -32602,
+                        message: format!("Resource not found: {}", uri),
+                        data: None,
+                    }),
+                })
+            }
+        }
         "ping" => Some(JsonRpcResponse {
             jsonrpc: "2.0".to_string(),
             id,
@@ -182,6 +238,21 @@ fn handle_request(request: &JsonRpcRequest) -> Option<JsonRpcResponse> {
     }
 }
 
+/// Longest a tool may calculate or search before giving up, so one runaway
+/// model cannot stop the server from answering
+const CALCULATION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// URI of the function catalog resource
+const FUNCTIONS_RESOURCE_URI: &str = "forge://functions";
+
+/// Function catalog served as the `functions` resource
+fn functions_resource() -> Value {
+    json!({
+        "total": FunctionRegistry::all().len(),
+        "functions": FunctionRegistry::all(),
+    })
+}
+
 /// Get all available tools
 fn get_tools() -> Vec<Tool> {
     vec![
@@ -223,6 +294,20 @@ fn get_tools() -> Vec<Tool> {
                 "required": ["file_path"]
             }),
         },
+        Tool {
+            name: "forge_calculate_yaml".to_string(),
+            description: "Calculate a Forge model given as an inline YAML string (no file needed) and return the calculated scalars and tables as JSON.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "yaml": {
+                        "type": "string",
+                        "description": "Complete Forge YAML model"
+                    }
+                },
+                "required": ["yaml"]
+            }),
+        },
         Tool {
             name: "forge_audit".to_string(),
             description: "Audit a specific variable to see its dependency tree and calculated value.".to_string(),
@@ -241,6 +326,20 @@ fn get_tools() -> Vec<Tool> {
                 "required": ["file_path", "variable"]
             }),
         },
+        Tool {
+            name: "forge_explain_formula".to_string(),
+            description: "Explain a formula: the function it calls, its named arguments, the columns and scalars it references, and a plain-English description.".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "formula": {
+                        "type": "string",
+                        "description": "Formula to explain, e.g. =SUMIF(sales.amount, \">100\", sales.revenue)"
+                    }
+                },
+                "required": ["formula"]
+            }),
+        },
         Tool {
             name: "forge_export".to_string(),
             description: "Export a Forge YAML model to an Excel workbook.".to_string(),
@@ -487,6 +586,26 @@ fn call_tool(name: &str, arguments: &Value) -> Value {
                 }),
             }
         }
+        "forge_calculate_yaml" => {
+            let yaml = arguments.get("yaml").and_then(|v| v.as_str()).unwrap_or("");
+
+            match calculate_yaml(yaml) {
+                Ok(results) => json!({
+                    "content": [{
+                        "type": "text",
+                        "text": serde_json::to_string_pretty(&results).unwrap_or_default()
+                    }],
+                    "isError": false
+                }),
+                Err(e) => json!({
+                    "content": [{
+                        "type": "text",
+                        "text": format!("Calculation failed: {}", e)
+                    }],
+                    "isError": true
+                }),
+            }
+        }
         "forge_audit" => {
             let file_path = arguments
                 .get("file_path")
@@ -515,6 +634,30 @@ fn call_tool(name: &str, arguments: &Value) -> Value {
                 }),
             }
         }
+        "forge_explain_formula" => {
+            let formula = arguments
+                .get("formula")
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+
+            if formula.trim().trim_start_matches('=').trim().is_empty() {
+                return json!({
+                    "content": [{
+                        "type": "text",
+                        "text": "Explanation failed: formula is empty"
+                    }],
+                    "isError": true
+                });
+            }
+            let explanation = explain_formula(formula);
+            json!({
+                "content": [{
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&explanation).unwrap_or_default()
+                }],
+                "isError": false
+            })
+        }
         "forge_export" => {
             let yaml_path = arguments
                 .get("yaml_path")
@@ -645,16 +788,15 @@ fn call_tool(name: &str, arguments: &Value) -> Value {
                 .unwrap_or(0.0001);
 
             let path = Path::new(file_path).to_path_buf();
-            match goal_seek(
-                path,
-                target.to_string(),
-                value,
-                vary.to_string(),
+            let options = GoalSeekOptions {
                 min,
                 max,
                 tolerance,
-                false,
-            ) {
+                timeout: Some(CALCULATION_TIMEOUT),
+                ..GoalSeekOptions::default()
+            };
+            match goal_seek_with_method(path, target.to_string(), value, vary.to_string(), options)
+            {
                 Ok(()) => json!({
                     "content": [{
                         "type": "text",
@@ -684,8 +826,15 @@ fn call_tool(name: &str, arguments: &Value) -> Value {
             let min = arguments.get("min").and_then(|v| v.as_f64());
             let max = arguments.get("max").and_then(|v| v.as_f64());
 
+            // Break-even is goal-seek with value = 0
             let path = Path::new(file_path).to_path_buf();
-            match break_even(path, output.to_string(), vary.to_string(), min, max, false) {
+            let options = GoalSeekOptions {
+                min,
+                max,
+                timeout: Some(CALCULATION_TIMEOUT),
+                ..GoalSeekOptions::default()
+            };
+            match goal_seek_with_method(path, output.to_string(), 0.0, vary.to_string(), options) {
                 Ok(()) => json!({
                     "content": [{
                         "type": "text",
@@ -778,6 +927,13 @@ fn call_tool(name: &str, arguments: &Value) -> Value {
     }
 }
 
+/// Parse and calculate an inline YAML model, returning the results as JSON
+fn calculate_yaml(yaml: &str) -> ForgeResult<Value> {
+    let model = parser::parse_model_str(yaml)?;
+    let result = ArrayCalculator::new(model).calculate_all_with_deadline(CALCULATION_TIMEOUT)?;
+    Ok(writer::results_to_json(&result))
+}
+
 /// Forge MCP Server struct
 pub struct ForgeMcpServer;
 
@@ -848,13 +1004,15 @@ mod tests {
 
         let result = response.result.unwrap();
         let tools = result["tools"].as_array().unwrap();
-        assert_eq!(tools.len(), 10); // 5 core + 5 financial analysis tools
+        assert_eq!(tools.len(), 12); // 7 core + 5 financial analysis tools
 
         // Check tool names - core tools
         let tool_names: Vec<&str> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
         assert!(tool_names.contains(&"forge_validate"));
         assert!(tool_names.contains(&"forge_calculate"));
+        assert!(tool_names.contains(&"forge_calculate_yaml"));
         assert!(tool_names.contains(&"forge_audit"));
+        assert!(tool_names.contains(&"forge_explain_formula"));
         assert!(tool_names.contains(&"forge_export"));
         assert!(tool_names.contains(&"forge_import"));
         // v3.0.0 financial analysis tools
@@ -865,6 +1023,47 @@ mod tests {
         assert!(tool_names.contains(&"forge_compare"));
     }
 
+    #[test]
+    fn test_functions_resource() {
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(3)),
+            method: "resources/list".to_string(),
+            params: json!({}),
+        };
+        let result = handle_request(&request).unwrap().result.unwrap();
+        assert_eq!(result["resources"][0]["uri"], "forge://functions");
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(4)),
+            method: "resources/read".to_string(),
+            params: json!({ "uri": "forge://functions" }),
+        };
+        let result = handle_request(&request).unwrap().result.unwrap();
+        let catalog: Value =
+            serde_json::from_str(result["contents"][0]["text"].as_str().unwrap()).unwrap();
+        let category = |name: &str| {
+            catalog["functions"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|f| f["name"] == name)
+                .map(|f| f["category"].clone())
+        };
+        assert_eq!(category("SUM"), Some(json!("Aggregation")));
+        assert_eq!(category("XLOOKUP"), Some(json!("Lookup")));
+        assert_eq!(category("PMT"), Some(json!("Financial")));
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(5)),
+            method: "resources/read".to_string(),
+            params: json!({ "uri": "forge://nope" }),
+        };
+        assert!(handle_request(&request).unwrap().error.is_some());
+    }
+
     #[test]
     fn test_ping_request() {
         let request = JsonRpcRequest {
@@ -921,7 +1120,7 @@ mod tests {
     #[test]
     fn test_get_tools_has_correct_schemas() {
         let tools = get_tools();
-        assert_eq!(tools.len(), 10); // 5 core + 5 financial analysis tools
+        assert_eq!(tools.len(), 12); // 7 core + 5 financial analysis tools
 
         // Validate forge_validate schema
         let validate_tool = tools.iter().find(|t| t.name == "forge_validate").unwrap();
@@ -992,6 +1191,43 @@ mod tests {
         assert!(result["isError"].as_bool().unwrap());
     }
 
+    #[test]
+    fn test_call_tool_calculate_yaml() {
+        let result = call_tool(
+            "forge_calculate_yaml",
+            &json!({
+                "yaml": "_forge_version: \"1.0.0\"\nsales:\n  revenue: [100, 200]\n  cost: [60, 120]\n  profit: \"=revenue - cost\"\nsummary:\n  total_profit:\n    value: null\n    formula: \"=SUM(sales.profit)\"\n"
+            }),
+        );
+        assert!(!result["isError"].as_bool().unwrap());
+
+        let text = result["content"][0]["text"].as_str().unwrap();
+        let results: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(results["tables"]["sales"]["profit"], json!([40.0, 80.0]));
+        assert_eq!(results["scalars"]["summary.total_profit"], json!(120.0));
+    }
+
+    #[test]
+    fn test_call_tool_calculate_yaml_errors() {
+        let result = call_tool(
+            "forge_calculate_yaml",
+            &json!({ "yaml": "sales: [unclosed" }),
+        );
+        assert!(result["isError"].as_bool().unwrap());
+
+        let result = call_tool(
+            "forge_calculate_yaml",
+            &json!({
+                "yaml": "_forge_version: \"1.0.0\"\ntotal:\n  value: null\n  formula: \"=missing * 2\"\n"
+            }),
+        );
+        assert!(result["isError"].as_bool().unwrap());
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .starts_with("Calculation failed"));
+    }
+
     #[test]
     fn test_call_tool_audit_with_variable() {
         let result = call_tool(
@@ -1006,6 +1242,34 @@ mod tests {
         assert!(!text.contains("Unknown tool"));
     }
 
+    #[test]
+    fn test_call_tool_explain_formula() {
+        let result = call_tool(
+            "forge_explain_formula",
+            &json!({
+                "formula": "=SUMIF(sales.amount, \">100\", sales.revenue)"
+            }),
+        );
+        assert!(!result["isError"].as_bool().unwrap());
+
+        let text = result["content"][0]["text"].as_str().unwrap();
+        let explanation: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(explanation["function"], "SUMIF");
+        assert_eq!(explanation["category"], "Conditional");
+        assert_eq!(explanation["arguments"][1]["parameter"], "criteria");
+        assert_eq!(
+            explanation["references"],
+            json!(["sales.amount", "sales.revenue"])
+        );
+        assert_eq!(
+            explanation["description"],
+            "Sum of sales.revenue where sales.amount > 100"
+        );
+
+        let result = call_tool("forge_explain_formula", &json!({ "formula": "=" }));
+        assert!(result["isError"].as_bool().unwrap());
+    }
+
     #[test]
     fn test_call_tool_export() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,458 @@
+//! Canonical formula text
+//!
+//! A formula is tokenized, parsed into a small expression tree and printed
+//! back with function names in upper case, one space around binary
+//! operators, one after each argument separator, and only the parentheses
+//! the evaluator needs. String literals, references (including `[...]`
+//! indexes and ranges) and numbers are printed exactly as written.
+
+/// Binary operators and their precedence, lowest first
+const PRECEDENCE: &[(&str, u8)] = &[
+    ("=", 1),
+    ("<>", 1),
+    ("<", 1),
+    (">", 1),
+    ("<=", 1),
+    (">=", 1),
+    ("&", 2),
+    ("+", 3),
+    ("-", 3),
+    ("*", 4),
+    ("/", 4),
+    ("^", 5),
+];
+
+/// Precedence of `^`; a signed operand of `^` keeps its parentheses
+const POWER: u8 = 5;
+
+/// Characters that end an operand
+const DELIMITERS: &[char] = &[
+    '+', '-', '*', '/', '^', '&', '=', '<', '>', '(', ')', '{', '}', '[', ']', ',', ';', '%', '"',
+    '\'',
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Number, reference, boolean or error literal
+    Operand(String),
+    /// String literal with its quotes, as written
+    Text(String),
+    Operator(String),
+    Open(char),
+    Close(char),
+    Separator(char),
+    Percent,
+}
+
+#[derive(Debug)]
+enum Expr {
+    Operand(String),
+    Text(String),
+    /// Left out argument, as in `IF(a, , b)`
+    Missing,
+    Call(String, List),
+    /// Call of a call's result, as in `LAMBDA(x, x * 2)(5)`
+    Apply(Box<Expr>, List),
+    /// `{...}` or `[...]` array literal
+    Array(char, char, List),
+    Paren(Box<Expr>),
+    Unary(char, Box<Expr>),
+    Percent(Box<Expr>),
+    Binary(Box<Expr>, String, Box<Expr>),
+}
+
+/// Items of a call or array with the separator after each but the last
+#[derive(Debug)]
+struct List {
+    items: Vec<Expr>,
+    separators: Vec<char>,
+}
+
+/// Where an expression is printed, for deciding on parentheses
+#[derive(Clone, Copy)]
+enum Context {
+    /// Top level, a call argument or an array item
+    Free,
+    Left(u8),
+    Right(u8),
+    Unary,
+    Percent,
+}
+
+/// Canonical spacing and casing of a formula, e.g. `=sum( a,b )` becomes
+/// `=SUM(a, b)`. Text that is not a formula, or that cannot be parsed, is
+/// returned unchanged.
+pub fn normalize_formula(formula: &str) -> String {
+    let body = match formula.trim().strip_prefix('=') {
+        Some(body) => body,
+        None => return formula.to_string(),
+    };
+
+    let expr = match tokenize(body).and_then(|tokens| Parser { tokens, pos: 0 }.parse()) {
+        Some(expr) => expr,
+        None => return formula.to_string(),
+    };
+    format!("={}", print(&expr, Context::Free))
+}
+
+fn tokenize(body: &str) -> Option<Vec<Token>> {
+    let chars: Vec<char> = body.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '"' => {
+                let end = closing_quote(&chars, i, '"')?;
+                tokens.push(Token::Text(chars[i..=end].iter().collect()));
+                i = end + 1;
+            }
+            // An index right after a reference belongs to it: revenue[-1]
+            '[' if i > 0
+                && !chars[i - 1].is_whitespace()
+                && matches!(tokens.last(), Some(Token::Operand(_))) =>
+            {
+                let end = closing_bracket(&chars, i)?;
+                if let Some(Token::Operand(text)) = tokens.last_mut() {
+                    text.extend(&chars[i..=end]);
+                }
+                i = end + 1;
+            }
+            '(' | '{' | '[' => {
+                tokens.push(Token::Open(c));
+                i += 1;
+            }
+            ')' | '}' | ']' => {
+                tokens.push(Token::Close(c));
+                i += 1;
+            }
+            ',' | ';' => {
+                tokens.push(Token::Separator(c));
+                i += 1;
+            }
+            '%' => {
+                tokens.push(Token::Percent);
+                i += 1;
+            }
+            '+' | '-' | '*' | '/' | '^' | '&' | '=' | '<' | '>' => {
+                let two: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+                let operator = match two.as_str() {
+                    "<=" | ">=" | "<>" => two,
+                    _ => c.to_string(),
+                };
+                i += operator.len();
+                tokens.push(Token::Operator(operator));
+            }
+            _ => {
+                let start = i;
+                if c == '\'' {
+                    // Quoted sheet name: 'P&L 2025'!A2
+                    i = closing_quote(&chars, i, '\'')? + 1;
+                } else if c == '#' {
+                    // Error literal: #N/A, #DIV/0!
+                    i += 1;
+                    while i < chars.len()
+                        && (chars[i].is_ascii_alphanumeric() || matches!(chars[i], '/' | '!' | '?'))
+                    {
+                        i += 1;
+                    }
+                }
+                while i < chars.len()
+                    && !chars[i].is_whitespace()
+                    && !DELIMITERS.contains(&chars[i])
+                {
+                    i += 1;
+                    // Keep the sign of an exponent: 1.5e-3
+                    let is_number = chars[start].is_ascii_digit() || chars[start] == '.';
+                    if is_number
+                        && matches!(chars[i - 1], 'e' | 'E')
+                        && matches!(chars.get(i), Some('+') | Some('-'))
+                    {
+                        i += 1;
+                    }
+                }
+                if i == start {
+                    return None;
+                }
+                tokens.push(Token::Operand(chars[start..i].iter().collect()));
+            }
+        }
+    }
+
+    Some(tokens)
+}
+
+/// Index of the quote closing the one at `start`; doubled quotes are escapes
+fn closing_quote(chars: &[char], start: usize, quote: char) -> Option<usize> {
+    let mut i = start + 1;
+    while i < chars.len() {
+        if chars[i] == quote {
+            if chars.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+fn closing_bracket(chars: &[char], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, &c) in chars.iter().enumerate().skip(start) {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn precedence(operator: &str) -> u8 {
+    PRECEDENCE
+        .iter()
+        .find(|(op, _)| *op == operator)
+        .map(|(_, p)| *p)
+        .unwrap_or(0)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn parse(mut self) -> Option<Expr> {
+        let expr = self.expr(1)?;
+        (self.pos == self.tokens.len()).then_some(expr)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// Binary operators are left-associative
+    fn expr(&mut self, min_precedence: u8) -> Option<Expr> {
+        let mut left = self.unary()?;
+        while let Some(Token::Operator(op)) = self.peek() {
+            let p = precedence(op);
+            if p < min_precedence {
+                break;
+            }
+            let op = op.clone();
+            self.pos += 1;
+            let right = self.expr(p + 1)?;
+            left = Expr::Binary(Box::new(left), op, Box::new(right));
+        }
+        Some(left)
+    }
+
+    fn unary(&mut self) -> Option<Expr> {
+        if let Some(Token::Operator(op)) = self.peek() {
+            let sign = match op.as_str() {
+                "-" => '-',
+                "+" => '+',
+                _ => return None,
+            };
+            self.pos += 1;
+            return Some(Expr::Unary(sign, Box::new(self.unary()?)));
+        }
+
+        let mut expr = self.primary()?;
+        while self.peek() == Some(&Token::Percent) {
+            self.pos += 1;
+            expr = Expr::Percent(Box::new(expr));
+        }
+        Some(expr)
+    }
+
+    fn primary(&mut self) -> Option<Expr> {
+        match self.next()? {
+            Token::Operand(name) if self.peek() == Some(&Token::Open('(')) => {
+                self.pos += 1;
+                let mut call = Expr::Call(name.to_uppercase(), self.list(')')?);
+                while self.peek() == Some(&Token::Open('(')) {
+                    self.pos += 1;
+                    call = Expr::Apply(Box::new(call), self.list(')')?);
+                }
+                Some(call)
+            }
+            Token::Operand(text) => Some(Expr::Operand(text)),
+            Token::Text(text) => Some(Expr::Text(text)),
+            Token::Open('(') => {
+                let inner = self.expr(1)?;
+                (self.next()? == Token::Close(')')).then_some(Expr::Paren(Box::new(inner)))
+            }
+            Token::Open('{') => Some(Expr::Array('{', '}', self.list('}')?)),
+            Token::Open('[') => Some(Expr::Array('[', ']', self.list(']')?)),
+            _ => None,
+        }
+    }
+
+    /// Items up to and including `close`
+    fn list(&mut self, close: char) -> Option<List> {
+        let mut list = List {
+            items: Vec::new(),
+            separators: Vec::new(),
+        };
+        if self.peek() == Some(&Token::Close(close)) {
+            self.pos += 1;
+            return Some(list);
+        }
+
+        loop {
+            let item = match self.peek()? {
+                Token::Separator(_) | Token::Close(_) => Expr::Missing,
+                _ => self.expr(1)?,
+            };
+            list.items.push(item);
+            match self.next()? {
+                Token::Separator(c) => list.separators.push(c),
+                Token::Close(c) if c == close => return Some(list),
+                _ => return None,
+            }
+        }
+    }
+}
+
+fn print(expr: &Expr, context: Context) -> String {
+    match expr {
+        Expr::Operand(text) | Expr::Text(text) => text.clone(),
+        Expr::Missing => String::new(),
+        Expr::Call(name, args) => format!("{}({})", name, print_list(args)),
+        Expr::Apply(callee, args) => {
+            format!("{}({})", print(callee, Context::Free), print_list(args))
+        }
+        Expr::Array(open, close, items) => format!("{}{}{}", open, print_list(items), close),
+        Expr::Paren(inner) => {
+            let mut inner = inner.as_ref();
+            while let Expr::Paren(nested) = inner {
+                inner = nested;
+            }
+            if needs_parens(inner, context) {
+                format!("({})", print(inner, Context::Free))
+            } else {
+                print(inner, context)
+            }
+        }
+        Expr::Unary(sign, operand) => format!("{}{}", sign, print(operand, Context::Unary)),
+        Expr::Percent(operand) => format!("{}%", print(operand, Context::Percent)),
+        Expr::Binary(left, op, right) => {
+            let p = precedence(op);
+            format!(
+                "{} {} {}",
+                print(left, Context::Left(p)),
+                op,
+                print(right, Context::Right(p))
+            )
+        }
+    }
+}
+
+/// Whether parenthesized `inner` must keep its parentheses in `context`.
+/// An operand of the same precedence on the right keeps them, so neither the
+/// grouping nor the floating-point evaluation order changes.
+fn needs_parens(inner: &Expr, context: Context) -> bool {
+    match (inner, context) {
+        (_, Context::Free) => false,
+        (Expr::Binary(_, op, _), Context::Left(p)) => precedence(op) < p,
+        (Expr::Binary(_, op, _), Context::Right(p)) => precedence(op) <= p,
+        (Expr::Binary(..), Context::Unary | Context::Percent) => true,
+        (Expr::Unary(..), Context::Left(p) | Context::Right(p)) => p == POWER,
+        (Expr::Unary(..), Context::Percent) => true,
+        _ => false,
+    }
+}
+
+fn print_list(list: &List) -> String {
+    let mut out = String::new();
+    for (i, item) in list.items.iter().enumerate() {
+        out.push_str(&print(item, Context::Free));
+        if let Some(separator) = list.separators.get(i) {
+            out.push(*separator);
+            out.push(' ');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_formula_spacing_and_casing() {
+        assert_eq!(normalize_formula("=sum( a,b )"), "=SUM(a, b)");
+        assert_eq!(normalize_formula("=a+b"), "=a + b");
+        assert_eq!(normalize_formula("=-a*(b-1)"), "=-a * (b - 1)");
+        assert_eq!(normalize_formula("=if(x>=1,y,z)"), "=IF(x >= 1, y, z)");
+        assert_eq!(
+            normalize_formula("=x*1.5e-3+@pricing.base"),
+            "=x * 1.5e-3 + @pricing.base"
+        );
+        assert_eq!(
+            normalize_formula("=sales.revenue[-1]/sales.revenue[0]"),
+            "=sales.revenue[-1] / sales.revenue[0]"
+        );
+        assert_eq!(
+            normalize_formula("=date(2024,[1,2,3],15)"),
+            "=DATE(2024, [1, 2, 3], 15)"
+        );
+        assert_eq!(normalize_formula("=max({1;5;3})"), "=MAX({1; 5; 3})");
+        assert_eq!(normalize_formula("=now()"), "=NOW()");
+        assert_eq!(
+            normalize_formula("=lambda(x,x*2)(5)"),
+            "=LAMBDA(x, x * 2)(5)"
+        );
+        assert_eq!(normalize_formula("=iferror(x,#N/A)"), "=IFERROR(x, #N/A)");
+        assert_eq!(normalize_formula("plain text"), "plain text");
+    }
+
+    #[test]
+    fn test_normalize_formula_keeps_string_literals() {
+        assert_eq!(
+            normalize_formula("=IF(x, \"a,b\", y)"),
+            "=IF(x, \"a,b\", y)"
+        );
+        assert_eq!(
+            normalize_formula("=concat(\"x+y \",\"say \"\"hi\"\"\")"),
+            "=CONCAT(\"x+y \", \"say \"\"hi\"\"\")"
+        );
+        assert_eq!(normalize_formula("='P&L 2025'!A2*2"), "='P&L 2025'!A2 * 2");
+        // Unterminated strings are left alone
+        assert_eq!(normalize_formula("=IF(x, \"a"), "=IF(x, \"a");
+    }
+
+    #[test]
+    fn test_normalize_formula_removes_redundant_parentheses() {
+        assert_eq!(normalize_formula("=((a+b))*c"), "=(a + b) * c");
+        assert_eq!(normalize_formula("=(a*b)+c"), "=a * b + c");
+        assert_eq!(normalize_formula("=(a+b)"), "=a + b");
+        assert_eq!(normalize_formula("=SUM((a))"), "=SUM(a)");
+        assert_eq!(normalize_formula("=IF((a>b),1,0)"), "=IF(a > b, 1, 0)");
+        // Needed or order-changing parentheses stay
+        assert_eq!(normalize_formula("=a-(b-c)"), "=a - (b - c)");
+        assert_eq!(normalize_formula("=a+(b+c)"), "=a + (b + c)");
+        assert_eq!(normalize_formula("=-(a+b)"), "=-(a + b)");
+        assert_eq!(normalize_formula("=(-a)^2"), "=(-a) ^ 2");
+        assert_eq!(
+            normalize_formula("=(pl.revenue[3]/pl.revenue[0])^(1/3)-1"),
+            "=(pl.revenue[3] / pl.revenue[0]) ^ (1 / 3) - 1"
+        );
+    }
+}
@@ -1,11 +1,18 @@
+use crate::core::array_calculator::RANGE_TABLE;
+use crate::core::FunctionRegistry;
 use crate::error::{ForgeError, ForgeResult};
 use crate::types::{
-    Column, ColumnValue, Include, Metadata, ParsedModel, ResolvedInclude, Scenario, Table, Variable,
+    names_variable, Column, ColumnValue, FormatComparison, FormatRule, Include, Metadata,
+    ParsedModel, ResolvedInclude, Scenario, Table, Variable,
 };
 use jsonschema::JSONSchema;
 use serde_yaml::Value;
-use std::collections::HashSet;
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+mod formula;
+
+pub use formula::normalize_formula;
 
 /// Parse a Forge model file (v1.0.0 array format) and return a ParsedModel.
 ///
@@ -28,25 +35,52 @@ use std::path::Path;
 /// # Ok::<(), royalbit_forge::error::ForgeError>(())
 /// ```
 pub fn parse_model(path: &std::path::Path) -> ForgeResult<ParsedModel> {
+    parse_model_with_include_paths(path, &[])
+}
+
+/// Parse a model file, looking up `_includes` in `include_paths` too
+///
+/// An include is resolved relative to the including file first, then
+/// relative to each search directory in order.
+pub fn parse_model_with_include_paths(
+    path: &Path,
+    include_paths: &[PathBuf],
+) -> ForgeResult<ParsedModel> {
     let content = std::fs::read_to_string(path)?;
+    parse_content(&content, Some(path), include_paths)
+}
 
+/// Parse a model from a YAML string rather than a file
+///
+/// Accepts the same single- and multi-document YAML as [`parse_model`].
+/// Models with `_includes` are rejected: included files are resolved relative
+/// to the model's file, which a string does not have.
+pub fn parse_model_str(content: &str) -> ForgeResult<ParsedModel> {
+    parse_content(content, None, &[])
+}
+
+fn parse_content(
+    content: &str,
+    path: Option<&Path>,
+    include_paths: &[PathBuf],
+) -> ForgeResult<ParsedModel> {
     // Check if this is a multi-document YAML file (v4.4.2)
     // Multi-doc files have at least two document separators (---) on their own lines
     // We need to skip comments and whitespace when detecting
-    let is_multi_doc = detect_multi_document(&content);
+    let is_multi_doc = detect_multi_document(content);
 
     if is_multi_doc {
         // Parse all documents and merge (v4.4.2)
-        parse_multi_document_yaml(&content, path)
+        parse_multi_document_yaml(content, path, include_paths)
     } else {
         // Single document parsing (original behavior)
-        parse_single_document_yaml(&content, path)
+        parse_single_document_yaml(content, path, include_paths)
     }
 }
 
 /// Detect if content is a multi-document YAML file
 /// A multi-document file has at least two document separators (---) on their own lines
-fn detect_multi_document(content: &str) -> bool {
+pub(crate) fn detect_multi_document(content: &str) -> bool {
     let mut separator_count = 0;
     for line in content.lines() {
         let trimmed = line.trim();
@@ -62,7 +96,11 @@ fn detect_multi_document(content: &str) -> bool {
 }
 
 /// Parse a single YAML document
-fn parse_single_document_yaml(content: &str, path: &Path) -> ForgeResult<ParsedModel> {
+fn parse_single_document_yaml(
+    content: &str,
+    path: Option<&Path>,
+    include_paths: &[PathBuf],
+) -> ForgeResult<ParsedModel> {
     // Strip leading document marker if present
     let content = content.trim_start();
     let content = if let Some(remaining) = content.strip_prefix("---") {
@@ -77,7 +115,7 @@ fn parse_single_document_yaml(content: &str, path: &Path) -> ForgeResult<ParsedM
 
     // Resolve includes if any (v4.0)
     if !model.includes.is_empty() {
-        resolve_includes(&mut model, path, &mut HashSet::new())?;
+        resolve_model_includes(&mut model, path, include_paths)?;
     }
 
     Ok(model)
@@ -86,7 +124,11 @@ fn parse_single_document_yaml(content: &str, path: &Path) -> ForgeResult<ParsedM
 /// Parse a multi-document YAML file (v4.4.2)
 /// Each document is parsed and merged into a single model.
 /// Document names come from _name field or are auto-generated as "doc1", "doc2", etc.
-fn parse_multi_document_yaml(content: &str, path: &Path) -> ForgeResult<ParsedModel> {
+fn parse_multi_document_yaml(
+    content: &str,
+    path: Option<&Path>,
+    include_paths: &[PathBuf],
+) -> ForgeResult<ParsedModel> {
     let mut merged_model = ParsedModel::new();
     let mut doc_index = 0;
 
@@ -138,6 +180,11 @@ fn parse_multi_document_yaml(content: &str, path: &Path) -> ForgeResult<ParsedMo
             prefixed_table.name = prefixed_name.clone();
             merged_model.tables.insert(prefixed_name, prefixed_table);
         }
+        for (table_name, formula) in doc_model.table_formulas {
+            merged_model
+                .table_formulas
+                .insert(format!("{}.{}", doc_name, table_name), formula);
+        }
 
         // Merge scalars with document prefix
         for (scalar_name, mut scalar) in doc_model.scalars {
@@ -145,6 +192,13 @@ fn parse_multi_document_yaml(content: &str, path: &Path) -> ForgeResult<ParsedMo
             scalar.path = prefixed_name.clone();
             merged_model.scalars.insert(prefixed_name, scalar);
         }
+        for constant in doc_model.constants {
+            merged_model
+                .constants
+                .insert(format!("{}.{}", doc_name, constant));
+        }
+
+        merged_model.case_sensitive_criteria |= doc_model.case_sensitive_criteria;
 
         // Merge includes (keep original, they'll be resolved with proper paths)
         for include in doc_model.includes {
@@ -163,7 +217,7 @@ fn parse_multi_document_yaml(content: &str, path: &Path) -> ForgeResult<ParsedMo
 
     // Resolve includes if any (v4.0)
     if !merged_model.includes.is_empty() {
-        resolve_includes(&mut merged_model, path, &mut HashSet::new())?;
+        resolve_model_includes(&mut merged_model, path, include_paths)?;
     }
 
     Ok(merged_model)
@@ -201,38 +255,49 @@ fn split_yaml_documents(content: &str) -> Vec<String> {
     documents
 }
 
+/// Resolve includes relative to the model's file, if it was parsed from one
+fn resolve_model_includes(
+    model: &mut ParsedModel,
+    path: Option<&Path>,
+    include_paths: &[PathBuf],
+) -> ForgeResult<()> {
+    match path {
+        Some(path) => resolve_includes(model, path, include_paths, &mut Vec::new()),
+        None => Err(ForgeError::Parse(
+            "_includes cannot be resolved for a model parsed from a string".to_string(),
+        )),
+    }
+}
+
 /// Resolve all includes in a model, loading and parsing referenced files.
-/// Detects circular dependencies.
+/// `stack` holds the files currently being resolved, so a file that includes
+/// one of its own includers is reported as a cycle. The same file may still be
+/// included from two different branches.
 fn resolve_includes(
     model: &mut ParsedModel,
     base_path: &Path,
-    visited: &mut HashSet<std::path::PathBuf>,
+    include_paths: &[PathBuf],
+    stack: &mut Vec<PathBuf>,
 ) -> ForgeResult<()> {
     let base_dir = base_path.parent().unwrap_or_else(|| Path::new("."));
 
-    // Check for circular dependency
+    // Check for circular include
     let canonical = base_path
         .canonicalize()
         .unwrap_or_else(|_| base_path.to_path_buf());
-    if visited.contains(&canonical) {
-        return Err(ForgeError::Parse(format!(
-            "Circular dependency detected: {} is already included",
-            base_path.display()
-        )));
+    if let Some(start) = stack.iter().position(|path| *path == canonical) {
+        let chain = stack[start..]
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|path| include_name(path))
+            .collect();
+        return Err(ForgeError::CircularInclude(chain));
     }
-    visited.insert(canonical);
+    stack.push(canonical);
 
     // Process each include
     for include in model.includes.clone() {
-        let include_path = base_dir.join(&include.file);
-
-        if !include_path.exists() {
-            return Err(ForgeError::Parse(format!(
-                "Included file not found: {} (referenced as '{}')",
-                include_path.display(),
-                include.file
-            )));
-        }
+        let include_path = find_include(&include.file, base_dir, include_paths)?;
 
         // Parse the included file
         let content = std::fs::read_to_string(&include_path)?;
@@ -241,7 +306,11 @@ fn resolve_includes(
 
         // Recursively resolve includes in the included file
         if !included_model.includes.is_empty() {
-            resolve_includes(&mut included_model, &include_path, visited)?;
+            resolve_includes(&mut included_model, &include_path, include_paths, stack)?;
+        }
+
+        if include.only.is_some() {
+            import_only(model, &include, &mut included_model)?;
         }
 
         // Store resolved include
@@ -255,15 +324,119 @@ fn resolve_includes(
             .insert(include.namespace.clone(), resolved);
     }
 
+    stack.pop();
+    Ok(())
+}
+
+/// Keep only the scalars and tables named in the include's `only:` list.
+/// Fails if a listed name does not exist in the included file, or if the
+/// including model references a variable that was left out.
+fn import_only(
+    model: &ParsedModel,
+    include: &Include,
+    included: &mut ParsedModel,
+) -> ForgeResult<()> {
+    let only = include.only.as_deref().unwrap_or_default();
+    for name in only {
+        let exists = included.tables.contains_key(name)
+            || included.scalars.keys().any(|key| names_variable(key, name));
+        if !exists {
+            return Err(ForgeError::Parse(format!(
+                "Include '{}' has no scalar or table '{}' listed in 'only'",
+                include.file, name
+            )));
+        }
+    }
+
+    let mut imported = included.clone();
+    imported.scalars.retain(|key, _| include.imports(key));
+    imported.tables.retain(|name, _| include.imports(name));
+
+    for reference in namespace_refs(model, &include.namespace) {
+        if defines(included, &reference) && !defines(&imported, &reference) {
+            return Err(ForgeError::Parse(format!(
+                "'@{}.{}' is not imported from '{}' (only: {})",
+                include.namespace,
+                reference,
+                include.file,
+                only.join(", ")
+            )));
+        }
+    }
+
+    *included = imported;
     Ok(())
 }
 
+/// Paths referenced as `@namespace.path` in the model's formulas
+fn namespace_refs(model: &ParsedModel, namespace: &str) -> Vec<String> {
+    let prefix = format!("@{}.", namespace);
+    let formulas = model
+        .scalars
+        .values()
+        .filter_map(|var| var.formula.as_deref())
+        .chain(
+            model
+                .tables
+                .values()
+                .flat_map(|table| table.row_formulas.values().map(String::as_str)),
+        );
+
+    let mut refs = Vec::new();
+    for formula in formulas {
+        for (pos, _) in formula.match_indices(&prefix) {
+            let rest = &formula[pos + prefix.len()..];
+            let end = rest
+                .find(|c: char| !c.is_alphanumeric() && c != '_' && c != '.')
+                .unwrap_or(rest.len());
+            refs.push(rest[..end].trim_end_matches('.').to_string());
+        }
+    }
+    refs
+}
+
+/// Whether a `@namespace.path` reference names a scalar or table of `model`
+fn defines(model: &ParsedModel, path: &str) -> bool {
+    let table = path.split('.').next().unwrap_or(path);
+    model.tables.contains_key(table) || model.scalars.keys().any(|key| names_variable(key, path))
+}
+
+/// File name of an included file, as shown in a circular include chain
+fn include_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// Locate an included file: next to the including file, then in each
+/// search directory in order
+fn find_include(file: &str, base_dir: &Path, include_paths: &[PathBuf]) -> ForgeResult<PathBuf> {
+    let candidates: Vec<PathBuf> = std::iter::once(base_dir)
+        .chain(include_paths.iter().map(PathBuf::as_path))
+        .map(|dir| dir.join(file))
+        .collect();
+    if let Some(found) = candidates.iter().find(|path| path.exists()) {
+        return Ok(found.clone());
+    }
+
+    let tried: Vec<String> = candidates
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect();
+    Err(ForgeError::Parse(format!(
+        "Included file not found: '{}' (tried {})",
+        file,
+        tried.join(", ")
+    )))
+}
+
 /// Parse v1.0.0 array model
 fn parse_v1_model(yaml: &Value) -> ForgeResult<ParsedModel> {
     // Validate against JSON Schema - this is mandatory
     validate_against_schema(yaml)?;
 
     let mut model = ParsedModel::new();
+    let mut aliases: Vec<(String, String)> = Vec::new();
 
     // Parse each top-level key as either a table or scalar
     if let Value::Mapping(map) = yaml {
@@ -277,6 +450,20 @@ fn parse_v1_model(yaml: &Value) -> ForgeResult<ParsedModel> {
                 continue;
             }
 
+            if key_str == RANGE_TABLE {
+                return Err(ForgeError::Parse(format!(
+                    "'{}' is a reserved name and cannot be used for a table or scalar",
+                    RANGE_TABLE
+                )));
+            }
+
+            if key_str == "_case_sensitive_criteria" {
+                model.case_sensitive_criteria = value.as_bool().ok_or_else(|| {
+                    ForgeError::Parse("_case_sensitive_criteria must be true or false".to_string())
+                })?;
+                continue;
+            }
+
             // Parse _includes section (v4.0 cross-file references)
             if key_str == "_includes" {
                 if let Value::Sequence(includes_seq) = value {
@@ -285,19 +472,54 @@ fn parse_v1_model(yaml: &Value) -> ForgeResult<ParsedModel> {
                 continue;
             }
 
+            // Parse constants section - named literals (name -> number), unless it
+            // is a table or scalar section named "constants"
+            if key_str == "constants" {
+                if let Value::Mapping(constants_map) = value {
+                    let is_constants_section = !constants_map.is_empty()
+                        && constants_map.iter().all(|(_, v)| v.is_number());
+
+                    if is_constants_section {
+                        parse_constants(constants_map, &mut model)?;
+                        continue;
+                    }
+                }
+            }
+
+            // Parse aliases section - short names for qualified references, unless
+            // it is a table named "aliases" (whose columns are arrays or formulas)
+            if key_str == "aliases" {
+                if let Value::Mapping(aliases_map) = value {
+                    let is_aliases_section = !aliases_map.is_empty()
+                        && aliases_map
+                            .iter()
+                            .all(|(_, v)| v.as_str().is_some_and(|s| !s.starts_with('=')));
+
+                    if is_aliases_section {
+                        aliases = parse_aliases(aliases_map)?;
+                        continue;
+                    }
+                    // Otherwise fall through to parse as table
+                }
+            }
+
             // Parse scenarios section - but only if it looks like scenario overrides
             // (mapping of scenario_name -> {variable: value}), not a table (mapping of column_name -> array)
             if key_str == "scenarios" {
                 if let Value::Mapping(scenarios_map) = value {
                     // Check if this is actually a scenarios section or a table named "scenarios"
                     // Scenarios section has nested mappings with numeric values
-                    // Tables have arrays (sequences) as column values
+                    // or `table.column` arrays; tables have arrays as column values
                     let is_scenarios_section = scenarios_map
                         .iter()
                         .all(|(_, v)| matches!(v, Value::Mapping(_)))
                         && scenarios_map.iter().any(|(_, v)| {
                             if let Value::Mapping(m) = v {
-                                m.iter().any(|(_, vv)| matches!(vv, Value::Number(_)))
+                                m.iter().any(|(k, vv)| {
+                                    matches!(vv, Value::Number(_))
+                                        || (matches!(vv, Value::Sequence(_))
+                                            && k.as_str().is_some_and(|k| k.contains('.')))
+                                })
                             } else {
                                 false
                             }
@@ -311,6 +533,14 @@ fn parse_v1_model(yaml: &Value) -> ForgeResult<ParsedModel> {
                 }
             }
 
+            // A table defined by a whole-table formula (=TRANSPOSE(...))
+            if let Value::String(formula) = value {
+                model
+                    .table_formulas
+                    .insert(key_str.to_string(), formula.clone());
+                continue;
+            }
+
             // Check if this is a table (mapping with arrays) or scalar (mapping with value/formula)
             if let Value::Mapping(inner_map) = value {
                 // Check if it has {value, formula} pattern (scalar)
@@ -337,6 +567,24 @@ fn parse_v1_model(yaml: &Value) -> ForgeResult<ParsedModel> {
             .map_err(|e| ForgeError::Validation(format!("Table '{}': {}", name, e)))?;
     }
 
+    resolve_scenario_inheritance(&mut model)?;
+
+    // Constants are read-only: scenarios cannot override them
+    for (scenario_name, scenario) in &model.scenarios {
+        if let Some(name) = scenario
+            .overrides
+            .keys()
+            .find(|name| model.constants.contains(*name))
+        {
+            return Err(ForgeError::Validation(format!(
+                "Scenario '{}' cannot override constant '{}'",
+                scenario_name, name
+            )));
+        }
+    }
+
+    resolve_aliases(&aliases, &mut model)?;
+
     Ok(model)
 }
 
@@ -368,24 +616,26 @@ fn validate_against_schema(yaml: &Value) -> ForgeResult<()> {
 }
 
 /// Check if a mapping contains nested scalar sections (e.g., summary.total)
-/// Returns false for v4.0 rich table columns (where value is an array)
+/// Returns false for tables: any array column (plain or v4.0 rich `value: [...]`)
+/// marks the mapping as a table, wherever it appears
 fn is_nested_scalar_section(map: &serde_yaml::Mapping) -> bool {
-    // Check if children are mappings with {value, formula} pattern where value is a scalar (not array)
+    let mut has_scalar = false;
     for (_key, value) in map {
-        if let Value::Mapping(child_map) = value {
-            // Check if this child has value or formula keys
-            if child_map.contains_key("value") || child_map.contains_key("formula") {
+        match value {
+            Value::Sequence(_) => return false,
+            Value::Mapping(child_map) => {
                 // If value is an array, this is a v4.0 rich table column, not a scalar
-                if let Some(val) = child_map.get("value") {
-                    if matches!(val, Value::Sequence(_)) {
-                        return false; // v4.0 rich table column
-                    }
+                if matches!(child_map.get("value"), Some(Value::Sequence(_))) {
+                    return false;
+                }
+                if child_map.contains_key("value") || child_map.contains_key("formula") {
+                    has_scalar = true;
                 }
-                return true; // Nested scalar section
             }
+            _ => {}
         }
     }
-    false
+    has_scalar
 }
 
 /// Parse nested scalar variables (e.g., summary.total, summary.average)
@@ -436,6 +686,11 @@ fn parse_table(name: &str, map: &serde_yaml::Mapping) -> ForgeResult<Table> {
 
         // Check for v4.0 rich column format: { value: [...], unit: "...", notes: "..." }
         if let Value::Mapping(col_map) = value {
+            if let Some(rules) = col_map.get("formatting") {
+                table
+                    .formatting
+                    .insert(col_name.to_string(), parse_format_rules(col_name, rules)?);
+            }
             // Check if it has a 'value' key with an array (v4.0 rich format)
             if let Some(Value::Sequence(seq)) = col_map.get("value") {
                 let column_value = parse_array_value(col_name, seq)?;
@@ -449,8 +704,11 @@ fn parse_table(name: &str, map: &serde_yaml::Mapping) -> ForgeResult<Table> {
                 if let Some(formula_str) = formula_val.as_str() {
                     if formula_str.starts_with('=') {
                         // This is a row-wise formula with metadata
-                        // TODO: Store formula metadata when we add formula metadata support
-                        table.add_row_formula(col_name.to_string(), formula_str.to_string());
+                        table.add_row_formula_with_metadata(
+                            col_name.to_string(),
+                            formula_str.to_string(),
+                            parse_metadata(col_map),
+                        );
                         continue;
                     }
                 }
@@ -473,6 +731,80 @@ fn parse_table(name: &str, map: &serde_yaml::Mapping) -> ForgeResult<Table> {
     Ok(table)
 }
 
+/// Parse a column's conditional formatting rules
+/// `formatting: [{ when: "< 0", fill: red }]`
+fn parse_format_rules(col_name: &str, value: &Value) -> ForgeResult<Vec<FormatRule>> {
+    let invalid =
+        |detail: String| ForgeError::Parse(format!("Column '{}' formatting: {}", col_name, detail));
+    let rules = value
+        .as_sequence()
+        .ok_or_else(|| invalid("expected a list of rules".to_string()))?;
+
+    rules
+        .iter()
+        .map(|rule| {
+            let when = rule.get("when").and_then(Value::as_str).ok_or_else(|| {
+                invalid("each rule needs a 'when' condition such as \"< 0\"".to_string())
+            })?;
+            let fill = rule
+                .get("fill")
+                .and_then(Value::as_str)
+                .ok_or_else(|| invalid("each rule needs a 'fill' color".to_string()))?;
+
+            let (comparison, threshold) = parse_format_condition(when).ok_or_else(|| {
+                invalid(format!(
+                    "invalid condition '{}' (expected <, <=, >, >=, = or <> and a number)",
+                    when
+                ))
+            })?;
+            let fill = parse_fill_color(fill).ok_or_else(|| {
+                invalid(format!(
+                    "invalid fill '{}' (expected red, green, yellow or #RRGGBB)",
+                    fill
+                ))
+            })?;
+
+            Ok(FormatRule {
+                comparison,
+                threshold,
+                fill,
+            })
+        })
+        .collect()
+}
+
+/// `"< 0"` → (LessThan, 0.0)
+fn parse_format_condition(when: &str) -> Option<(FormatComparison, f64)> {
+    let operators = [
+        ("<=", FormatComparison::LessThanOrEqual),
+        (">=", FormatComparison::GreaterThanOrEqual),
+        ("<>", FormatComparison::NotEqualTo),
+        ("<", FormatComparison::LessThan),
+        (">", FormatComparison::GreaterThan),
+        ("=", FormatComparison::EqualTo),
+    ];
+    let when = when.trim();
+    let (comparison, threshold) = operators
+        .into_iter()
+        .find_map(|(op, comparison)| when.strip_prefix(op).map(|rest| (comparison, rest)))?;
+    Some((comparison, threshold.trim().parse().ok()?))
+}
+
+/// Named fill (Excel's light red, green and yellow highlights) or `#RRGGBB`
+fn parse_fill_color(fill: &str) -> Option<String> {
+    let hex = match fill.trim().to_lowercase().as_str() {
+        "red" => return Some("#FFC7CE".to_string()),
+        "green" => return Some("#C6EFCE".to_string()),
+        "yellow" => return Some("#FFEB9C".to_string()),
+        other => other.strip_prefix('#')?.to_string(),
+    };
+    if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        Some(format!("#{}", hex.to_uppercase()))
+    } else {
+        None
+    }
+}
+
 /// Parse a scalar variable (v4.0 enhanced with metadata)
 fn parse_scalar_variable(value: &Value, path: &str) -> ForgeResult<Variable> {
     if let Value::Mapping(map) = value {
@@ -499,14 +831,19 @@ fn parse_scalar_variable(value: &Value, path: &str) -> ForgeResult<Variable> {
 }
 
 /// Extract metadata fields from a YAML mapping (v4.0)
+/// `units` is accepted as an alias of `unit`
 fn parse_metadata(map: &serde_yaml::Mapping) -> Metadata {
     Metadata {
         unit: map
             .get("unit")
+            .or_else(|| map.get("units"))
             .and_then(|v| v.as_str().map(std::string::ToString::to_string)),
         notes: map
             .get("notes")
             .and_then(|v| v.as_str().map(std::string::ToString::to_string)),
+        description: map
+            .get("description")
+            .and_then(|v| v.as_str().map(std::string::ToString::to_string)),
         source: map
             .get("source")
             .and_then(|v| v.as_str().map(std::string::ToString::to_string)),
@@ -530,6 +867,10 @@ fn parse_metadata(map: &serde_yaml::Mapping) -> Metadata {
 ///   optimistic:
 ///     growth_rate: 0.12
 ///     churn_rate: 0.01
+///     sales.revenue: [120, 150, 180]   # replaces a whole table column
+///   stretch:
+///     extends: optimistic              # inherits, then overrides
+///     growth_rate: 0.20
 /// ```
 fn parse_scenarios(
     scenarios_map: &serde_yaml::Mapping,
@@ -548,6 +889,29 @@ fn parse_scenarios(
                     ForgeError::Parse("Variable name must be a string".to_string())
                 })?;
 
+                if var_name_str == "extends" {
+                    let parent = var_value.as_str().ok_or_else(|| {
+                        ForgeError::Parse(format!(
+                            "Scenario '{}': 'extends' must be a scenario name",
+                            name
+                        ))
+                    })?;
+                    scenario.extends = Some(parent.to_string());
+                    continue;
+                }
+
+                if let Value::Sequence(seq) = var_value {
+                    if !var_name_str.contains('.') {
+                        return Err(ForgeError::Parse(format!(
+                            "Scenario '{}': column override '{}' must be written as table.column",
+                            name, var_name_str
+                        )));
+                    }
+                    let values = parse_array_value(var_name_str, seq)?;
+                    scenario.add_column_override(var_name_str.to_string(), values);
+                    continue;
+                }
+
                 let value = match var_value {
                     Value::Number(n) => n.as_f64().ok_or_else(|| {
                         ForgeError::Parse(format!(
@@ -578,6 +942,226 @@ fn parse_scenarios(
     Ok(())
 }
 
+/// Merge each scenario's `extends:` chain into its overrides, the child winning
+fn resolve_scenario_inheritance(model: &mut ParsedModel) -> ForgeResult<()> {
+    let mut names: Vec<String> = model.scenarios.keys().cloned().collect();
+    names.sort();
+
+    let mut resolved = HashMap::new();
+    for name in names {
+        // The scenario followed by its ancestors
+        let mut chain = vec![name.clone()];
+        let mut current = &model.scenarios[&name];
+        while let Some(parent) = &current.extends {
+            if chain.contains(parent) {
+                chain.push(parent.clone());
+                return Err(ForgeError::Validation(format!(
+                    "Scenario inheritance cycle: {}",
+                    chain.join(" → ")
+                )));
+            }
+            current = model.scenarios.get(parent).ok_or_else(|| {
+                ForgeError::Validation(format!(
+                    "Scenario '{}' extends unknown scenario '{}'",
+                    chain[chain.len() - 1],
+                    parent
+                ))
+            })?;
+            chain.push(parent.clone());
+        }
+
+        let mut merged = Scenario::new();
+        merged.extends = model.scenarios[&name].extends.clone();
+        for ancestor in chain.iter().rev() {
+            let scenario = &model.scenarios[ancestor];
+            merged.overrides.extend(scenario.overrides.clone());
+            merged
+                .column_overrides
+                .extend(scenario.column_overrides.clone());
+        }
+        resolved.insert(name, merged);
+    }
+
+    model.scenarios = resolved;
+    Ok(())
+}
+
+/// Parse the constants section into read-only `constants.<name>` scalars
+///
+/// Expected format:
+/// ```yaml
+/// constants:
+///   tax_rate: 0.25
+///   days_per_year: 365
+/// ```
+fn parse_constants(
+    constants_map: &serde_yaml::Mapping,
+    model: &mut ParsedModel,
+) -> ForgeResult<()> {
+    for (name, value) in constants_map {
+        let name = name
+            .as_str()
+            .ok_or_else(|| ForgeError::Parse("Constant name must be a string".to_string()))?;
+        let value = value
+            .as_f64()
+            .ok_or_else(|| ForgeError::Parse(format!("Constant '{}' must be a number", name)))?;
+
+        let path = format!("constants.{}", name);
+        model.add_scalar(path.clone(), Variable::new(path.clone(), Some(value), None));
+        model.constants.insert(path);
+    }
+    Ok(())
+}
+
+/// Parse the aliases section: short name -> qualified reference
+///
+/// Expected format:
+/// ```yaml
+/// aliases:
+///   gr: quarterly_financials.gross_revenue
+///   tax: inputs.tax_rate
+/// ```
+fn parse_aliases(aliases_map: &serde_yaml::Mapping) -> ForgeResult<Vec<(String, String)>> {
+    let mut aliases = Vec::new();
+    for (alias, target) in aliases_map {
+        let alias = alias
+            .as_str()
+            .ok_or_else(|| ForgeError::Parse("Alias name must be a string".to_string()))?;
+        let is_plain_name = alias
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphabetic() || c == '_')
+            && alias.chars().all(|c| c.is_alphanumeric() || c == '_');
+        if !is_plain_name {
+            return Err(ForgeError::Parse(format!(
+                "Alias '{}' must be a plain name (letters, digits and '_')",
+                alias
+            )));
+        }
+        // Only string targets reach here (see the aliases section check)
+        let target = target.as_str().unwrap_or_default().trim();
+        aliases.push((alias.to_string(), target.to_string()));
+    }
+    Ok(aliases)
+}
+
+/// Check aliases against the model and rewrite formulas to use their targets
+///
+/// An alias may not shadow a table, column, scalar or function name, and must
+/// point at an existing `table.column` or scalar (or an `_includes` namespace).
+fn resolve_aliases(aliases: &[(String, String)], model: &mut ParsedModel) -> ForgeResult<()> {
+    if aliases.is_empty() {
+        return Ok(());
+    }
+
+    for (alias, target) in aliases {
+        if let Some(shadowed) = shadowed_name(alias, model) {
+            return Err(ForgeError::Validation(format!(
+                "Alias '{}' shadows {}",
+                alias, shadowed
+            )));
+        }
+        if !alias_target_exists(target, model) {
+            return Err(ForgeError::Validation(format!(
+                "Alias '{}' refers to unknown reference '{}'",
+                alias, target
+            )));
+        }
+    }
+
+    let aliases: HashMap<&str, &str> = aliases
+        .iter()
+        .map(|(alias, target)| (alias.as_str(), target.as_str()))
+        .collect();
+    for table in model.tables.values_mut() {
+        for formula in table.row_formulas.values_mut() {
+            *formula = replace_aliases(formula, &aliases);
+        }
+    }
+    for variable in model.scalars.values_mut() {
+        if let Some(formula) = variable.formula.as_mut() {
+            *formula = replace_aliases(formula, &aliases);
+        }
+    }
+    Ok(())
+}
+
+/// Description of the real name an alias would shadow, if any
+fn shadowed_name(alias: &str, model: &ParsedModel) -> Option<String> {
+    if model.tables.contains_key(alias) {
+        return Some(format!("table '{}'", alias));
+    }
+    if model.scalars.contains_key(alias) {
+        return Some(format!("scalar '{}'", alias));
+    }
+    if model
+        .scalars
+        .keys()
+        .any(|path| path.split_once('.').map(|(section, _)| section) == Some(alias))
+    {
+        return Some(format!("scalar section '{}'", alias));
+    }
+    let mut table_names: Vec<&String> = model.tables.keys().collect();
+    table_names.sort();
+    for name in table_names {
+        let table = &model.tables[name];
+        if table.columns.contains_key(alias) || table.row_formulas.contains_key(alias) {
+            return Some(format!("column '{}.{}'", name, alias));
+        }
+    }
+    if FunctionRegistry::get(alias).is_some() {
+        return Some(format!("function '{}'", alias.to_uppercase()));
+    }
+    None
+}
+
+fn alias_target_exists(target: &str, model: &ParsedModel) -> bool {
+    if model.scalars.contains_key(target) {
+        return true;
+    }
+    let namespace = target.trim_start_matches('@').split('.').next();
+    if model
+        .includes
+        .iter()
+        .any(|i| Some(i.namespace.as_str()) == namespace)
+    {
+        return true;
+    }
+    match target.split_once('.') {
+        Some((table, column)) => model
+            .tables
+            .get(table)
+            .is_some_and(|t| t.columns.contains_key(column) || t.row_formulas.contains_key(column)),
+        None => false,
+    }
+}
+
+/// Replace whole-word alias names outside string literals
+fn replace_aliases(formula: &str, aliases: &HashMap<&str, &str>) -> String {
+    let mut result = String::with_capacity(formula.len());
+    let mut word = String::new();
+    let mut in_quotes = false;
+
+    for c in formula.chars() {
+        if !in_quotes && (c.is_alphanumeric() || c == '_' || c == '.') {
+            word.push(c);
+            continue;
+        }
+        if !word.is_empty() {
+            result.push_str(aliases.get(word.as_str()).copied().unwrap_or(&word));
+            word.clear();
+        }
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        result.push(c);
+    }
+    if !word.is_empty() {
+        result.push_str(aliases.get(word.as_str()).copied().unwrap_or(&word));
+    }
+    result
+}
+
 /// Parse _includes section from YAML (v4.0 cross-file references)
 ///
 /// Expected format:
@@ -587,6 +1171,7 @@ fn parse_scenarios(
 ///     as: "sources"
 ///   - file: "pricing.yaml"
 ///     as: "pricing"
+///     only: [base_price, markup]   # optional: import just these names
 /// ```
 fn parse_includes(includes_seq: &[Value], model: &mut ParsedModel) -> ForgeResult<()> {
     for include_val in includes_seq {
@@ -610,7 +1195,33 @@ fn parse_includes(includes_seq: &[Value], model: &mut ParsedModel) -> ForgeResul
                 })?
                 .to_string();
 
-            model.add_include(Include::new(file, namespace));
+            // Extract 'only' field (optional - names to import)
+            let only = match include_map.get("only") {
+                None => None,
+                Some(Value::Sequence(names)) => Some(
+                    names
+                        .iter()
+                        .map(|name| {
+                            name.as_str().map(str::to_string).ok_or_else(|| {
+                                ForgeError::Parse(format!(
+                                    "Include '{}': 'only' must list variable names",
+                                    file
+                                ))
+                            })
+                        })
+                        .collect::<ForgeResult<Vec<String>>>()?,
+                ),
+                Some(_) => {
+                    return Err(ForgeError::Parse(format!(
+                        "Include '{}': 'only' must be a list of variable names",
+                        file
+                    )))
+                }
+            };
+
+            let mut include = Include::new(file, namespace);
+            include.only = only;
+            model.add_include(include);
         } else {
             return Err(ForgeError::Parse(
                 "Each include must be a mapping with 'file' and 'as' fields".to_string(),
@@ -758,7 +1369,7 @@ fn detect_array_type(val: &Value) -> ForgeResult<&'static str> {
 }
 
 /// Check if a string is a valid date format (YYYY-MM or YYYY-MM-DD)
-fn is_valid_date_format(s: &str) -> bool {
+pub(crate) fn is_valid_date_format(s: &str) -> bool {
     // YYYY-MM format
     if s.len() == 7 {
         let parts: Vec<&str> = s.split('-').collect();
@@ -1122,6 +1733,51 @@ scenarios:
         assert_eq!(pessimistic.overrides.get("growth_rate"), Some(&0.02));
     }
 
+    #[test]
+    fn test_scenario_extends_base() {
+        let result = parse_model_str(
+            r#"
+_forge_version: "1.0.0"
+scenarios:
+  base:
+    growth_rate: 0.05
+    churn_rate: 0.02
+    price: 10
+  optimistic:
+    extends: base
+    growth_rate: 0.12
+"#,
+        )
+        .unwrap();
+
+        let optimistic = &result.scenarios["optimistic"];
+        assert_eq!(optimistic.extends, Some("base".to_string()));
+        assert_eq!(optimistic.overrides.len(), 3);
+        assert_eq!(optimistic.overrides["growth_rate"], 0.12);
+        assert_eq!(optimistic.overrides["churn_rate"], 0.02);
+        assert_eq!(optimistic.overrides["price"], 10.0);
+        // The base scenario itself is unchanged
+        assert_eq!(result.scenarios["base"].overrides["growth_rate"], 0.05);
+
+        let err = parse_model_str(
+            r#"
+_forge_version: "1.0.0"
+scenarios:
+  a:
+    extends: b
+    rate: 1
+  b:
+    extends: a
+    rate: 2
+"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Scenario inheritance cycle: a → b → a"
+        );
+    }
+
     #[test]
     fn test_multi_document_yaml_with_leading_separator() {
         use std::io::Write;
@@ -1675,6 +2331,194 @@ data:
         assert!(err_msg.contains("not found") || err_msg.contains("nonexistent"));
     }
 
+    #[test]
+    fn test_parse_includes_from_include_path() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let models_dir = temp_dir.path().join("models");
+        let lib_dir = temp_dir.path().join("lib");
+        let shared_dir = temp_dir.path().join("shared");
+        std::fs::create_dir_all(&models_dir).unwrap();
+        std::fs::create_dir_all(&lib_dir).unwrap();
+        std::fs::create_dir_all(&shared_dir).unwrap();
+
+        std::fs::write(
+            shared_dir.join("rates.yaml"),
+            r#"
+_forge_version: "4.0.0"
+tax_rates:
+  rate: [0.2, 0.25]
+"#,
+        )
+        .unwrap();
+
+        let main_path = models_dir.join("main.yaml");
+        std::fs::write(
+            &main_path,
+            r#"
+_forge_version: "4.0.0"
+_includes:
+  - file: "rates.yaml"
+    as: "rates"
+main_data:
+  values: [1, 2, 3]
+"#,
+        )
+        .unwrap();
+
+        // Not next to main.yaml, so the search path is required
+        let err = parse_model(&main_path).unwrap_err().to_string();
+        assert!(err.contains("not found"));
+
+        let search = vec![lib_dir.clone(), shared_dir.clone()];
+        let result = parse_model_with_include_paths(&main_path, &search).unwrap();
+        assert!(result.resolved_includes.contains_key("rates"));
+
+        // Every attempted location is listed when nothing matches
+        let err = parse_model_with_include_paths(&main_path, &[lib_dir.clone()])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(&models_dir.join("rates.yaml").display().to_string()));
+        assert!(err.contains(&lib_dir.join("rates.yaml").display().to_string()));
+    }
+
+    #[test]
+    fn test_parse_includes_circular_chain() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("a.yaml"),
+            r#"
+_forge_version: "4.0.0"
+_includes:
+  - file: "b.yaml"
+    as: "b"
+a_data:
+  values: [1, 2, 3]
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("b.yaml"),
+            r#"
+_forge_version: "4.0.0"
+_includes:
+  - file: "a.yaml"
+    as: "a"
+b_data:
+  values: [4, 5, 6]
+"#,
+        )
+        .unwrap();
+
+        let err = parse_model(&temp_dir.path().join("a.yaml")).unwrap_err();
+        assert!(matches!(err, ForgeError::CircularInclude(_)));
+        assert_eq!(
+            err.to_string(),
+            "Circular include: a.yaml → b.yaml → a.yaml"
+        );
+    }
+
+    #[test]
+    fn test_parse_includes_shared_file_is_not_circular() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let write = |name: &str, content: &str| {
+            std::fs::write(temp_dir.path().join(name), content).unwrap();
+        };
+        write(
+            "rates.yaml",
+            "_forge_version: \"4.0.0\"\nrates:\n  rate: [0.1, 0.2]\n",
+        );
+        for name in ["left.yaml", "right.yaml"] {
+            write(
+                name,
+                "_forge_version: \"4.0.0\"\n_includes:\n  - file: \"rates.yaml\"\n    as: \"rates\"\n",
+            );
+        }
+        write(
+            "main.yaml",
+            r#"
+_forge_version: "4.0.0"
+_includes:
+  - file: "left.yaml"
+    as: "left"
+  - file: "right.yaml"
+    as: "right"
+main_data:
+  values: [1, 2, 3]
+"#,
+        );
+
+        let result = parse_model(&temp_dir.path().join("main.yaml")).unwrap();
+        assert!(result.resolved_includes.contains_key("left"));
+        assert!(result.resolved_includes.contains_key("right"));
+    }
+
+    #[test]
+    fn test_parse_includes_only_imports_listed_names() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("pricing.yaml"),
+            r#"
+_forge_version: "4.0.0"
+base_price:
+  value: 100
+  formula: null
+markup:
+  value: 0.2
+  formula: null
+discount:
+  value: 0.1
+  formula: null
+"#,
+        )
+        .unwrap();
+
+        let main_model = |formula: &str| {
+            let path = temp_dir.path().join("main.yaml");
+            std::fs::write(
+                &path,
+                format!(
+                    r#"
+_forge_version: "4.0.0"
+_includes:
+  - file: "pricing.yaml"
+    as: "pricing"
+    only: [base_price, markup]
+price:
+  value: null
+  formula: "{}"
+"#,
+                    formula
+                ),
+            )
+            .unwrap();
+            parse_model(&path)
+        };
+
+        let result = main_model("=@pricing.base_price * (1 + @pricing.markup)").unwrap();
+        let pricing = &result.resolved_includes["pricing"];
+        assert_eq!(pricing.model.scalars.len(), 2);
+        assert!(!pricing.model.scalars.contains_key("discount"));
+        assert_eq!(result.resolve_namespace_ref("@pricing.markup"), Some(0.2));
+        assert_eq!(result.resolve_namespace_ref("@pricing.discount"), None);
+
+        let err = main_model("=@pricing.base_price * (1 - @pricing.discount)")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("'@pricing.discount' is not imported"),
+            "{}",
+            err
+        );
+    }
+
     #[test]
     fn test_parse_includes_missing_as_field() {
         use std::io::Write;
@@ -2036,6 +2880,23 @@ scenarios:
         assert!(result.scenarios.is_empty());
     }
 
+    #[test]
+    fn test_parse_case_sensitive_criteria_flag() {
+        let yaml_str = r#"
+_forge_version: "5.0.0"
+_case_sensitive_criteria: true
+sales:
+  region: ["North", "south"]
+"#;
+        let yaml: Value = serde_yaml::from_str(yaml_str).unwrap();
+        let result = parse_v1_model(&yaml).unwrap();
+        assert!(result.case_sensitive_criteria);
+        assert!(!result.tables.contains_key("_case_sensitive_criteria"));
+
+        let default = parse_model_str("_forge_version: \"5.0.0\"\nx:\n  value: 1\n").unwrap();
+        assert!(!default.case_sensitive_criteria);
+    }
+
     #[test]
     fn test_is_nested_scalar_section_empty_child() {
         // Empty child mapping
@@ -2056,4 +2917,114 @@ scenarios:
         let result = parse_table("test", &map);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_model_str() {
+        let model = parse_model_str(
+            "_forge_version: \"1.0.0\"\nsales:\n  revenue: [100, 200]\n  profit: \"=revenue * 2\"\n",
+        )
+        .unwrap();
+        assert!(model.tables["sales"].row_formulas.contains_key("profit"));
+
+        // Includes need a file to resolve against
+        let err = parse_model_str(
+            "_forge_version: \"4.0.0\"\n_includes:\n  - file: other.yaml\n    as: other\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("_includes"));
+    }
+
+    #[test]
+    fn test_reserved_range_table_name() {
+        let err =
+            parse_model_str("_forge_version: \"1.0.0\"\n_ranges:\n  array1: [1, 2]\n").unwrap_err();
+        assert!(err.to_string().contains("'_ranges' is a reserved name"));
+    }
+
+    #[test]
+    fn test_aliases_in_sum() {
+        let yaml = r#"
+_forge_version: "1.0.0"
+aliases:
+  gr: quarterly_financials.gross_revenue
+quarterly_financials:
+  quarter: ["Q1", "Q2", "Q3"]
+  gross_revenue: [100, 200, 300]
+summary:
+  total:
+    value: null
+    formula: "=SUM(gr)"
+"#;
+        let model = parse_model_str(yaml).unwrap();
+        assert_eq!(
+            model.scalars["summary.total"].formula.as_deref(),
+            Some("=SUM(quarterly_financials.gross_revenue)")
+        );
+
+        let result = crate::core::ArrayCalculator::new(model)
+            .calculate_all()
+            .unwrap();
+        assert_eq!(result.scalars["summary.total"].value, Some(600.0));
+
+        // Aliases cannot shadow real names or point at nothing
+        let err =
+            parse_model_str(&yaml.replace("  gr: quarterly", "  quarter: quarterly")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Alias 'quarter' shadows column 'quarterly_financials.quarter'"
+        );
+        let err =
+            parse_model_str(&yaml.replace("  gr: quarterly", "  summary: quarterly")).unwrap_err();
+        assert!(err.to_string().contains("shadows scalar section 'summary'"));
+        let err = parse_model_str(&yaml.replace(".gross_revenue\n", ".net_revenue\n")).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Alias 'gr' refers to unknown reference 'quarterly_financials.net_revenue'"));
+    }
+
+    #[test]
+    fn test_transpose_table_formula() {
+        let yaml = r#"
+_forge_version: "1.0.0"
+sales:
+  month: ["Jan", "Feb", "Mar"]
+  revenue: [100, 200, 300]
+revenue_by_month: "=TRANSPOSE(sales.revenue, sales.month)"
+revenue_row: "=TRANSPOSE(sales.revenue)"
+"#;
+        let model = parse_model_str(yaml).unwrap();
+        assert_eq!(
+            model.table_formulas["revenue_row"],
+            "=TRANSPOSE(sales.revenue)"
+        );
+        assert!(!model.tables.contains_key("revenue_row"));
+
+        let result = crate::core::ArrayCalculator::new(model)
+            .calculate_all()
+            .unwrap();
+
+        // One row, one column per source row, named by header or position
+        let by_month = &result.tables["revenue_by_month"];
+        assert_eq!(by_month.row_count(), 1);
+        let mut names: Vec<&String> = by_month.columns.keys().collect();
+        names.sort();
+        assert_eq!(names, ["Feb", "Jan", "Mar"]);
+        assert_eq!(
+            by_month.columns["Feb"].values,
+            ColumnValue::Number(vec![200.0])
+        );
+
+        let row = &result.tables["revenue_row"];
+        assert_eq!(row.columns.len(), 3);
+        assert_eq!(
+            row.columns["row_3"].values,
+            ColumnValue::Number(vec![300.0])
+        );
+
+        // Only a single TRANSPOSE call can define a table
+        assert!(
+            parse_model_str(&yaml.replace("=TRANSPOSE(sales.revenue)", "=SUM(sales.revenue)"))
+                .is_err()
+        );
+    }
 }
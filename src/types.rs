@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 //==============================================================================
 // Forge v1.0.0 Array Model Types
@@ -88,6 +88,12 @@ pub struct Table {
     pub columns: HashMap<String, Column>,
     /// Row-wise formulas (e.g., "profit: =revenue - expenses")
     pub row_formulas: HashMap<String, String>,
+    /// Metadata of formula columns, applied to their calculated values
+    #[serde(default)]
+    pub formula_metadata: HashMap<String, Metadata>,
+    /// Conditional formatting rules per column, applied on Excel export
+    #[serde(default)]
+    pub formatting: HashMap<String, Vec<FormatRule>>,
 }
 
 impl Table {
@@ -96,6 +102,8 @@ impl Table {
             name,
             columns: HashMap::new(),
             row_formulas: HashMap::new(),
+            formula_metadata: HashMap::new(),
+            formatting: HashMap::new(),
         }
     }
 
@@ -107,6 +115,19 @@ impl Table {
         self.row_formulas.insert(name, formula);
     }
 
+    /// Add a row-wise formula whose calculated column carries `metadata`
+    pub fn add_row_formula_with_metadata(
+        &mut self,
+        name: String,
+        formula: String,
+        metadata: Metadata,
+    ) {
+        if !metadata.is_empty() {
+            self.formula_metadata.insert(name.clone(), metadata);
+        }
+        self.row_formulas.insert(name, formula);
+    }
+
     /// Get the number of rows (length of first column, all should be same)
     pub fn row_count(&self) -> usize {
         self.columns.values().next().map_or(0, |col| col.len())
@@ -129,6 +150,26 @@ impl Table {
     }
 }
 
+/// Comparison of a cell against a conditional formatting threshold
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FormatComparison {
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    EqualTo,
+    NotEqualTo,
+}
+
+/// Conditional formatting rule for a column, e.g. `{ when: "< 0", fill: red }`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormatRule {
+    pub comparison: FormatComparison,
+    pub threshold: f64,
+    /// Fill color as `#RRGGBB`
+    pub fill: String,
+}
+
 //==============================================================================
 // Scalar Variables (for aggregations and summary values)
 //==============================================================================
@@ -140,6 +181,8 @@ pub struct Metadata {
     pub unit: Option<String>,
     /// Human-readable explanation
     pub notes: Option<String>,
+    /// Description of what the value represents
+    pub description: Option<String>,
     /// Source reference (file:field or URL)
     pub source: Option<String>,
     /// Validation status (VALIDATED, PROJECTED, ESTIMATED)
@@ -152,6 +195,7 @@ impl Metadata {
     pub fn is_empty(&self) -> bool {
         self.unit.is_none()
             && self.notes.is_none()
+            && self.description.is_none()
             && self.source.is_none()
             && self.validation_status.is_none()
             && self.last_updated.is_none()
@@ -207,14 +251,35 @@ pub struct Include {
     pub file: String,
     /// Namespace alias for referencing (e.g., "sources" for @sources.field)
     pub namespace: String,
+    /// Scalars and tables to import (`only:`); everything when None
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub only: Option<Vec<String>>,
 }
 
 impl Include {
     pub fn new(file: String, namespace: String) -> Self {
-        Self { file, namespace }
+        Self {
+            file,
+            namespace,
+            only: None,
+        }
+    }
+
+    /// Whether `key` (a scalar path or table name) is imported under `only:`
+    pub fn imports(&self, key: &str) -> bool {
+        match &self.only {
+            Some(only) => only.iter().any(|name| names_variable(key, name)),
+            None => true,
+        }
     }
 }
 
+/// Whether `key` is the variable `name`, either exactly or as its last segments
+/// (e.g. "pricing.base_price" for "base_price")
+pub(crate) fn names_variable(key: &str, name: &str) -> bool {
+    key == name || key.ends_with(&format!(".{}", name))
+}
+
 /// Resolved include with parsed model data
 #[derive(Debug, Clone)]
 pub struct ResolvedInclude {
@@ -235,18 +300,31 @@ pub struct ResolvedInclude {
 pub struct Scenario {
     /// Variable overrides for this scenario (variable_name -> value)
     pub overrides: HashMap<String, f64>,
+    /// Whole-column overrides (`table.column` -> values), e.g. a higher revenue array
+    #[serde(default)]
+    pub column_overrides: HashMap<String, ColumnValue>,
+    /// Scenario this one inherits overrides from (`extends: base`); the
+    /// parser merges the inherited overrides in, so this is informational
+    #[serde(default)]
+    pub extends: Option<String>,
 }
 
 impl Scenario {
     pub fn new() -> Self {
         Self {
             overrides: HashMap::new(),
+            column_overrides: HashMap::new(),
+            extends: None,
         }
     }
 
     pub fn add_override(&mut self, name: String, value: f64) {
         self.overrides.insert(name, value);
     }
+
+    pub fn add_column_override(&mut self, column: String, values: ColumnValue) {
+        self.column_overrides.insert(column, values);
+    }
 }
 
 //==============================================================================
@@ -280,6 +358,21 @@ pub struct ParsedModel {
     /// Empty for single-document files
     #[serde(default)]
     pub documents: Vec<String>,
+
+    /// Scalars loaded from the `constants:` section (e.g. `constants.tax_rate`).
+    /// They are read-only inputs and are never written back by `calculate`
+    #[serde(default)]
+    pub constants: HashSet<String>,
+
+    /// Tables defined by a whole-table formula such as `=TRANSPOSE(sales.revenue)`,
+    /// keyed by table name. They are built after the other tables are calculated
+    #[serde(default)]
+    pub table_formulas: HashMap<String, String>,
+
+    /// Match text criteria (COUNTIF, SUMIF, ...) case-sensitively. Off by default,
+    /// as in Excel; set with `_case_sensitive_criteria: true`
+    #[serde(default)]
+    pub case_sensitive_criteria: bool,
 }
 
 impl ParsedModel {
@@ -292,6 +385,9 @@ impl ParsedModel {
             includes: Vec::new(),
             resolved_includes: HashMap::new(),
             documents: Vec::new(),
+            constants: HashSet::new(),
+            table_formulas: HashMap::new(),
+            case_sensitive_criteria: false,
         }
     }
 
@@ -436,6 +532,7 @@ mod tests {
         let metadata = Metadata {
             unit: Some("CAD".to_string()),
             notes: Some("Revenue projection".to_string()),
+            description: None,
             source: Some("market_research.yaml".to_string()),
             validation_status: Some("PROJECTED".to_string()),
             last_updated: Some("2025-11-26".to_string()),
@@ -638,6 +735,18 @@ mod tests {
         let include = Include::new("data.yaml".to_string(), "data".to_string());
         assert_eq!(include.file, "data.yaml");
         assert_eq!(include.namespace, "data");
+        assert!(include.only.is_none());
+        assert!(include.imports("anything"));
+    }
+
+    #[test]
+    fn test_include_imports_only_listed_names() {
+        let mut include = Include::new("pricing.yaml".to_string(), "pricing".to_string());
+        include.only = Some(vec!["base_price".to_string()]);
+        assert!(include.imports("base_price"));
+        assert!(include.imports("pricing.base_price"));
+        assert!(!include.imports("markup"));
+        assert!(!include.imports("list_base_price"));
     }
 
     // =========================================================================
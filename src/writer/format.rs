@@ -0,0 +1,272 @@
+//! Canonical YAML layout for `forge fmt`
+//!
+//! The document is re-emitted from its YAML tree with two-space indentation
+//! and flow arrays for table columns. Top-level keys are ordered as `_`
+//! metadata keys, tables, scalar sections, then `scenarios`; the fields of a
+//! scalar or rich column as value, formula, then metadata. Formulas go through
+//! [`normalize_formula`]. No value changes, so a formatted model calculates
+//! exactly like the original. Comments are not kept.
+
+use crate::error::{ForgeError, ForgeResult};
+use crate::parser::normalize_formula;
+use serde_yaml::{Mapping, Value};
+
+/// Metadata keys that lead the document, in this order
+const LEADING_KEYS: &[&str] = &["_forge_version", "_name", "_includes"];
+
+/// Field order inside a scalar or rich column; other fields follow as written
+const FIELD_ORDER: &[&str] = &[
+    "value",
+    "formula",
+    "unit",
+    "description",
+    "notes",
+    "source",
+    "validation_status",
+    "last_updated",
+];
+
+/// Render a single-document model in canonical layout
+pub(super) fn format_yaml(content: &str) -> ForgeResult<String> {
+    if crate::parser::detect_multi_document(content) {
+        return Err(ForgeError::Validation(
+            "fmt does not support multi-document YAML files".to_string(),
+        ));
+    }
+
+    let root = match serde_yaml::from_str(content)? {
+        Value::Mapping(map) => map,
+        _ => {
+            return Err(ForgeError::Parse(
+                "Model must be a YAML mapping".to_string(),
+            ))
+        }
+    };
+
+    let mut out = String::new();
+    emit_mapping(&mut out, &canonical_root(root), 0);
+    Ok(out)
+}
+
+/// Reorder top-level sections and canonicalize each of them
+fn canonical_root(root: Mapping) -> Mapping {
+    let mut entries: Vec<(Value, Value)> = root.into_iter().collect();
+    entries.sort_by_key(|(key, value)| section_rank(key, value));
+    entries
+        .into_iter()
+        .map(|(key, value)| {
+            let in_table = is_table(&value);
+            (key, canonical_value(value, in_table))
+        })
+        .collect()
+}
+
+/// Sort key of a top-level section: metadata, tables, scalars, scenarios
+fn section_rank(key: &Value, value: &Value) -> (u8, usize) {
+    let name = key.as_str().unwrap_or_default();
+    if name.starts_with('_') {
+        let position = LEADING_KEYS.iter().position(|k| *k == name);
+        (0, position.unwrap_or(LEADING_KEYS.len()))
+    } else if is_table(value) {
+        (1, 0)
+    } else if name == "scenarios" {
+        (3, 0)
+    } else {
+        (2, 0)
+    }
+}
+
+/// A mapping with an array column (plain or rich `value: [...]`) is a table
+fn is_table(value: &Value) -> bool {
+    match value {
+        Value::Mapping(map) => map.values().any(|column| match column {
+            Value::Sequence(_) => true,
+            Value::Mapping(rich) => matches!(rich.get("value"), Some(Value::Sequence(_))),
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+/// Canonicalize a value; strings directly in a table are row formulas
+fn canonical_value(value: Value, in_table: bool) -> Value {
+    match value {
+        Value::Mapping(map) => Value::Mapping(canonical_mapping(map, in_table)),
+        Value::Sequence(items) => Value::Sequence(
+            items
+                .into_iter()
+                .map(|item| match item {
+                    Value::Mapping(map) => Value::Mapping(canonical_mapping(map, false)),
+                    other => other,
+                })
+                .collect(),
+        ),
+        Value::String(text) if in_table => Value::String(normalize_formula(&text)),
+        other => other,
+    }
+}
+
+fn canonical_mapping(map: Mapping, in_table: bool) -> Mapping {
+    let mut entries: Vec<(Value, Value)> = map.into_iter().collect();
+    let is_field = entries
+        .iter()
+        .any(|(key, _)| key.as_str() == Some("value") || key.as_str() == Some("formula"));
+    // A rich column `{ value: [...], unit: ... }` holds no row formulas
+    let in_table = in_table && !is_field;
+    if is_field {
+        entries.sort_by_key(|(key, _)| {
+            let name = key.as_str().unwrap_or_default();
+            FIELD_ORDER
+                .iter()
+                .position(|field| *field == name)
+                .unwrap_or(FIELD_ORDER.len())
+        });
+    }
+
+    entries
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                Value::String(text) if key.as_str() == Some("formula") => {
+                    Value::String(normalize_formula(&text))
+                }
+                Value::Mapping(_) => {
+                    let child_is_table = is_table(&value);
+                    canonical_value(value, child_is_table)
+                }
+                other => canonical_value(other, in_table),
+            };
+            (key, value)
+        })
+        .collect()
+}
+
+fn emit_mapping(out: &mut String, map: &Mapping, indent: usize) {
+    let pad = " ".repeat(indent);
+    for (key, value) in map {
+        let key = key_text(key);
+        match value {
+            Value::Mapping(child) if !child.is_empty() => {
+                out.push_str(&format!("{}{}:\n", pad, key));
+                emit_mapping(out, child, indent + 2);
+            }
+            Value::Sequence(items) if items.iter().any(is_collection) => {
+                out.push_str(&format!("{}{}:\n", pad, key));
+                emit_sequence(out, items, indent + 2);
+            }
+            _ => out.push_str(&format!("{}{}: {}\n", pad, key, inline(value))),
+        }
+    }
+}
+
+/// A block sequence; mapping items start on the `- ` line
+fn emit_sequence(out: &mut String, items: &[Value], indent: usize) {
+    let pad = " ".repeat(indent);
+    for item in items {
+        match item {
+            Value::Mapping(map) if !map.is_empty() => {
+                let mut nested = String::new();
+                emit_mapping(&mut nested, map, indent + 2);
+                out.push_str(&format!("{}- {}", pad, &nested[indent + 2..]));
+            }
+            _ => out.push_str(&format!("{}- {}\n", pad, inline(item))),
+        }
+    }
+}
+
+fn is_collection(value: &Value) -> bool {
+    matches!(value, Value::Mapping(_) | Value::Sequence(_))
+}
+
+/// A value on one line; text is always double-quoted
+fn inline(value: &Value) -> String {
+    match value {
+        Value::String(text) => quoted(text),
+        Value::Sequence(items) => format!(
+            "[{}]",
+            items.iter().map(inline).collect::<Vec<_>>().join(", ")
+        ),
+        Value::Mapping(map) => format!(
+            "{{{}}}",
+            map.iter()
+                .map(|(k, v)| format!("{}: {}", key_text(k), inline(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        other => serde_yaml::to_string(other)
+            .map(|text| text.trim_end().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// A mapping key: plain where YAML allows it, otherwise double-quoted
+fn key_text(key: &Value) -> String {
+    let text = match key {
+        Value::String(text) => text,
+        other => return inline(other),
+    };
+    let plain = serde_yaml::to_string(key)
+        .map(|rendered| rendered.trim_end().to_string())
+        .unwrap_or_default();
+    if plain.is_empty()
+        || plain.contains('\n')
+        || plain.starts_with(['\'', '"', '|', '>'])
+        || text.contains([',', '[', ']', '{', '}', '#'])
+    {
+        quoted(text)
+    } else {
+        plain
+    }
+}
+
+/// Double-quoted YAML text (JSON string syntax is valid YAML)
+fn quoted(text: &str) -> String {
+    serde_json::Value::String(text.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_yaml_is_idempotent() {
+        let content = "\
+scenarios:
+  high:
+    growth: 0.2
+growth:
+  formula: '=base*2'
+  value: 0.1
+_forge_version: '1.0.0'
+sales:
+  month: ['Jan', 'Feb']
+  revenue:
+    - 100
+    - 200
+  doubled: =revenue*2
+";
+        let formatted = format_yaml(content).unwrap();
+        assert_eq!(
+            formatted,
+            "\
+_forge_version: \"1.0.0\"
+sales:
+  month: [\"Jan\", \"Feb\"]
+  revenue: [100, 200]
+  doubled: \"=revenue * 2\"
+growth:
+  value: 0.1
+  formula: \"=base * 2\"
+scenarios:
+  high:
+    growth: 0.2
+"
+        );
+        assert_eq!(format_yaml(&formatted).unwrap(), formatted);
+
+        let before: Value = serde_yaml::from_str(content).unwrap();
+        let after: Value = serde_yaml::from_str(&formatted).unwrap();
+        assert_eq!(before["sales"]["revenue"], after["sales"]["revenue"]);
+        assert_eq!(before["growth"]["value"], after["growth"]["value"]);
+    }
+}
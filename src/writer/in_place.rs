@@ -0,0 +1,262 @@
+//! In-place edits of YAML text
+//!
+//! Write-back only changes a few values, so rather than re-serializing the
+//! whole document these functions rewrite just those values in the original
+//! text. Comments, key order, quoting and anchors elsewhere in the file are
+//! left as they are.
+//!
+//! Only block mappings (one `key:` per line, nested by indentation) are
+//! navigated. A key inside a flow mapping (`total: { value: 1 }`) or a flow
+//! sequence spanning several lines is not found, and the caller falls back
+//! to re-serializing the document.
+
+use regex::Regex;
+
+/// `content` with the scalar at `path` (e.g. `["summary", "total", "value"]`)
+/// replaced by `value`
+pub(super) fn set_scalar(content: &str, path: &[&str], value: &str) -> Option<String> {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let (index, value_start) = find_key(&lines, path)?;
+
+    let line = &lines[index];
+    let (rest, comment) = split_comment(&line[value_start..]);
+    if rest.trim().starts_with(['[', '{', '|', '>']) {
+        return None;
+    }
+    lines[index] = format!(
+        "{} {}{}{}",
+        &line[..value_start],
+        anchor(rest),
+        value,
+        comment
+    );
+    Some(join_lines(content, lines))
+}
+
+/// `content` with the sequence at `path` (e.g. `["sales", "value"]`) replaced
+/// by `values`, written in the sequence's existing flow (`[1, 2]`) or block
+/// (`- 1`) style
+pub(super) fn set_sequence(content: &str, path: &[&str], values: &[String]) -> Option<String> {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let (index, value_start) = find_key(&lines, path)?;
+
+    let line = &lines[index];
+    let (rest, comment) = split_comment(&line[value_start..]);
+    let rest = rest.trim();
+    let flow = rest.strip_prefix(anchor(rest)).unwrap_or(rest).trim();
+    if flow.starts_with('[') {
+        if !flow.ends_with(']') {
+            return None;
+        }
+        lines[index] = format!(
+            "{} {}[{}]{}",
+            &line[..value_start],
+            anchor(rest),
+            values.join(", "),
+            comment
+        );
+        return Some(join_lines(content, lines));
+    }
+    if !flow.is_empty() {
+        return None;
+    }
+
+    // Block sequence: the `- item` lines that follow the key
+    let key_indent = indent(&lines[index]);
+    let mut items = Vec::new();
+    for (i, line) in lines.iter().enumerate().skip(index + 1) {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if indent(line) < key_indent || !trimmed.starts_with("- ") && trimmed != "-" {
+            break;
+        }
+        items.push(i);
+    }
+    if items.len() != values.len() {
+        return None;
+    }
+    for (i, value) in items.into_iter().zip(values) {
+        let line = &lines[i];
+        let dash = indent(line) + 1;
+        let (rest, comment) = split_comment(&line[dash..]);
+        if rest.trim().starts_with(['[', '{', '|', '>']) || rest.contains(": ") {
+            return None;
+        }
+        lines[i] = format!("{} {}{}{}", &line[..dash], anchor(rest), value, comment);
+    }
+    Some(join_lines(content, lines))
+}
+
+/// 1-based line of the key at `path` (e.g. `["summary", "total"]`)
+pub(super) fn key_line(content: &str, path: &[&str]) -> Option<usize> {
+    let lines: Vec<String> = content.lines().map(str::to_string).collect();
+    find_key(&lines, path).map(|(index, _)| index + 1)
+}
+
+/// Line index of the key at `path`, and the byte offset just after its colon
+fn find_key(lines: &[String], path: &[&str]) -> Option<(usize, usize)> {
+    let key_pattern =
+        Regex::new(r#"^(\s*)("(?:[^"\\]|\\.)*"|'(?:[^']|'')*'|[^\s#'"\-?:][^:#]*?)\s*:(?:\s|$)"#)
+            .ok()?;
+
+    // (indent, key) of the mapping keys enclosing the current line
+    let mut stack: Vec<(usize, String)> = Vec::new();
+    // Lines indented past this belong to a `|` or `>` block scalar
+    let mut block_indent: Option<usize> = None;
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let line_indent = indent(line);
+        if let Some(block) = block_indent {
+            if line_indent > block {
+                continue;
+            }
+            block_indent = None;
+        }
+        if trimmed.starts_with('-') || trimmed.starts_with("---") || trimmed.starts_with("...") {
+            continue;
+        }
+
+        let captures = match key_pattern.captures(line) {
+            Some(captures) => captures,
+            None => continue,
+        };
+        let key = unquote(&captures[2]);
+        let value_start = captures.get(0).unwrap().end();
+        // The match may have consumed the space after the colon
+        let value_start = if line[..value_start].ends_with(':') {
+            value_start
+        } else {
+            value_start - 1
+        };
+
+        while stack.last().is_some_and(|(depth, _)| *depth >= line_indent) {
+            stack.pop();
+        }
+        stack.push((line_indent, key));
+
+        let (rest, _) = split_comment(&line[value_start..]);
+        let rest = rest.trim();
+        let rest = rest.strip_prefix(anchor(rest)).unwrap_or(rest).trim();
+        if rest.starts_with(['|', '>']) {
+            block_indent = Some(line_indent);
+        }
+
+        if stack.len() == path.len() && stack.iter().zip(path).all(|((_, k), p)| k == p) {
+            return Some((i, value_start));
+        }
+    }
+    None
+}
+
+/// Split a value from its trailing `# comment` (kept with its leading spaces)
+fn split_comment(text: &str) -> (&str, &str) {
+    let mut quote = None;
+    let mut prev = ' ';
+    for (i, c) in text.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            ('#', None) if prev.is_whitespace() => {
+                let value = text[..i].trim_end();
+                return (value, &text[value.len()..]);
+            }
+            _ => {}
+        }
+        prev = c;
+    }
+    (text.trim_end(), "")
+}
+
+/// The `&name ` anchor in front of a value, if any
+fn anchor(value: &str) -> &str {
+    let value = value.trim_start();
+    if !value.starts_with('&') {
+        return "";
+    }
+    match value.find(char::is_whitespace) {
+        Some(end) => &value[..=end],
+        None => "",
+    }
+}
+
+fn unquote(key: &str) -> String {
+    if key.len() >= 2 && key.starts_with('"') && key.ends_with('"') {
+        key[1..key.len() - 1].replace("\\\"", "\"")
+    } else if key.len() >= 2 && key.starts_with('\'') && key.ends_with('\'') {
+        key[1..key.len() - 1].replace("''", "'")
+    } else {
+        key.to_string()
+    }
+}
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Rejoin edited lines, keeping the original's trailing newline
+fn join_lines(original: &str, lines: Vec<String>) -> String {
+    let mut joined = lines.join("\n");
+    if original.ends_with('\n') {
+        joined.push('\n');
+    }
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_scalar_keeps_comments_and_anchors() {
+        let yaml = "\
+# Quarterly model
+summary:
+  # Total revenue, recalculated
+  total:
+    value: 0 # stale
+    formula: \"=SUM(sales.revenue)\"
+  note: |
+    value: not a key
+defaults:
+  rate: &rate 0.1
+";
+        let edited = set_scalar(yaml, &["summary", "total", "value"], "300.0").unwrap();
+        assert_eq!(
+            edited,
+            yaml.replace("value: 0 # stale", "value: 300.0 # stale")
+        );
+
+        let edited = set_scalar(yaml, &["defaults", "rate"], "0.2").unwrap();
+        assert!(edited.contains("  rate: &rate 0.2\n"));
+
+        assert_eq!(set_scalar(yaml, &["summary", "note", "value"], "1"), None);
+        assert_eq!(set_scalar(yaml, &["summary", "missing"], "1"), None);
+    }
+
+    #[test]
+    fn test_set_sequence_flow_and_block() {
+        let yaml = "\
+sales:
+  value: [0, 0]  # recalculated
+costs:
+  value:
+    - 0
+    # second row
+    - 0
+";
+        let values = vec!["100".to_string(), "200".to_string()];
+        let edited = set_sequence(yaml, &["sales", "value"], &values).unwrap();
+        assert!(edited.contains("  value: [100, 200]  # recalculated\n"));
+
+        let edited = set_sequence(yaml, &["costs", "value"], &values).unwrap();
+        assert!(edited.ends_with("    - 100\n    # second row\n    - 200\n"));
+
+        assert_eq!(set_sequence(yaml, &["costs", "value"], &values[..1]), None);
+    }
+}
@@ -1,24 +1,27 @@
 use crate::error::ForgeResult;
-use crate::types::{ColumnValue, ParsedModel, Variable};
+use crate::types::{ColumnValue, Metadata, ParsedModel, Table, Variable};
 use serde_yaml::Value;
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+mod format;
+mod in_place;
 
 /// Update YAML file with calculated values (v1.0.0)
 pub fn update_yaml_file(path: &Path, calculated_values: &HashMap<String, f64>) -> ForgeResult<()> {
     // Read original YAML
     let content = fs::read_to_string(path)?;
     let mut yaml: Value = serde_yaml::from_str(&content)?;
+    let mut text = content.clone();
 
     // Update values
     for (var_path, calculated_value) in calculated_values {
-        update_value_in_yaml(&mut yaml, var_path, *calculated_value);
+        update_value(&mut yaml, &mut text, var_path, *calculated_value)?;
     }
 
     // Write back to file
-    let updated_content = serde_yaml::to_string(&yaml)?;
-    fs::write(path, updated_content)?;
+    fs::write(path, preserved_or_serialized(text, &yaml)?)?;
 
     Ok(())
 }
@@ -29,11 +32,9 @@ pub fn update_yaml_file(path: &Path, calculated_values: &HashMap<String, f64>) -
 pub fn write_calculated_results(path: &Path, result: &ParsedModel) -> ForgeResult<bool> {
     // Read original content to check for multi-document YAML
     let content = fs::read_to_string(path)?;
-    let content_trimmed = content.trim_start();
 
-    // Check if this is a multi-document YAML file (v4.4.2)
-    // Multi-doc files cannot be written back directly - skip with warning
-    if content_trimmed.starts_with("---") && content_trimmed[3..].contains("\n---") {
+    // Multi-doc files cannot be written back directly - skip with warning (v4.4.2)
+    if is_multi_document(&content) {
         return Ok(false); // Indicate write was skipped
     }
 
@@ -41,8 +42,37 @@ pub fn write_calculated_results(path: &Path, result: &ParsedModel) -> ForgeResul
     let backup_path = path.with_extension("yaml.bak");
     fs::copy(path, &backup_path)?;
 
-    // Read original YAML to preserve structure/comments
-    let mut yaml: Value = serde_yaml::from_str(&content)?;
+    fs::write(path, calculated_content(&content, result)?)?;
+    Ok(true)
+}
+
+/// Write `path` with calculated results to `output`, leaving `path` untouched
+/// No backup is made. Returns false if skipped (multi-doc)
+pub fn write_calculated_results_to(
+    path: &Path,
+    output: &Path,
+    result: &ParsedModel,
+) -> ForgeResult<bool> {
+    let content = fs::read_to_string(path)?;
+    if is_multi_document(&content) {
+        return Ok(false);
+    }
+
+    fs::write(output, calculated_content(&content, result)?)?;
+    Ok(true)
+}
+
+fn is_multi_document(content: &str) -> bool {
+    let trimmed = content.trim_start();
+    trimmed.starts_with("---") && trimmed[3..].contains("\n---")
+}
+
+/// `content` with calculated table `value` columns and scalar values
+fn calculated_content(content: &str, result: &ParsedModel) -> ForgeResult<String> {
+    // Values are edited in the original text so comments, key order and
+    // anchors survive; the parsed document checks those edits
+    let mut yaml: Value = serde_yaml::from_str(content)?;
+    let mut text = content.to_string();
 
     // Update table value arrays
     if let Value::Mapping(ref mut root) = yaml {
@@ -52,17 +82,19 @@ pub fn write_calculated_results(path: &Path, result: &ParsedModel) -> ForgeResul
                 // Look for "value" column and update it
                 if let Some(col) = table.columns.get("value") {
                     if let ColumnValue::Number(values) = &col.values {
-                        let yaml_values: Vec<Value> = values
+                        let yaml_values: Vec<Value> =
+                            values.iter().map(|v| yaml_number(*v)).collect();
+                        let rendered = yaml_values
                             .iter()
-                            .map(|v| {
-                                // Format nicely: remove unnecessary decimal places
-                                if v.fract() == 0.0 && v.abs() < 1e10 {
-                                    Value::Number(serde_yaml::Number::from(*v as i64))
-                                } else {
-                                    Value::Number(serde_yaml::Number::from(*v))
-                                }
-                            })
-                            .collect();
+                            .map(render_scalar)
+                            .collect::<ForgeResult<Vec<_>>>()?;
+                        if let Some(edited) = in_place::set_sequence(
+                            &text,
+                            &[table_name.as_str(), "value"],
+                            &rendered,
+                        ) {
+                            text = edited;
+                        }
                         table_map.insert(
                             Value::String("value".to_string()),
                             Value::Sequence(yaml_values),
@@ -72,19 +104,18 @@ pub fn write_calculated_results(path: &Path, result: &ParsedModel) -> ForgeResul
             }
         }
 
-        // Update scalar values
+        // Update scalar values; constants are inputs and stay as written
         for (name, var) in &result.scalars {
+            if result.constants.contains(name) {
+                continue;
+            }
             if let Some(value) = var.value {
-                update_value_in_yaml(&mut yaml, name, value);
+                update_value(&mut yaml, &mut text, name, value)?;
             }
         }
     }
 
-    // Write back to file
-    let updated_content = serde_yaml::to_string(&yaml)?;
-    fs::write(path, updated_content)?;
-
-    Ok(true)
+    preserved_or_serialized(text, &yaml)
 }
 
 /// Update scalar values in a model file
@@ -98,6 +129,272 @@ pub fn update_scalars(path: &Path, scalars: &HashMap<String, Variable>) -> Forge
     update_yaml_file(path, &calculated_values)
 }
 
+/// Serialize calculated results as JSON
+/// Scalars become name → value (null when uncalculated), tables become column → array
+pub fn results_to_json(result: &ParsedModel) -> serde_json::Value {
+    let mut scalars = serde_json::Map::new();
+    for (name, var) in &result.scalars {
+        scalars.insert(name.clone(), serde_json::json!(var.value));
+    }
+
+    let mut tables = serde_json::Map::new();
+    for (table_name, table) in &result.tables {
+        let mut columns = serde_json::Map::new();
+        for (col_name, column) in &table.columns {
+            let values = match &column.values {
+                ColumnValue::Number(v) => serde_json::json!(v),
+                ColumnValue::Text(v) | ColumnValue::Date(v) => serde_json::json!(v),
+                ColumnValue::Boolean(v) => serde_json::json!(v),
+            };
+            columns.insert(col_name.clone(), values);
+        }
+        tables.insert(table_name.clone(), serde_json::Value::Object(columns));
+    }
+
+    serde_json::json!({
+        "scalars": scalars,
+        "tables": tables,
+    })
+}
+
+/// Format a number for YAML, removing unnecessary decimal places
+fn yaml_number(v: f64) -> Value {
+    if v.fract() == 0.0 && v.abs() < 1e10 {
+        Value::Number(serde_yaml::Number::from(v as i64))
+    } else {
+        Value::Number(serde_yaml::Number::from(v))
+    }
+}
+
+/// Render a model's tables as a v1.0.0 YAML document: one array per column,
+/// row formulas as strings. Tables and columns are in alphabetical order.
+/// Columns and formulas with metadata use the `{ value/formula, unit, ... }` form.
+pub fn tables_to_yaml(model: &ParsedModel) -> ForgeResult<String> {
+    Ok(serde_yaml::to_string(&Value::Mapping(tables_mapping(
+        model,
+    )))?)
+}
+
+/// Render a whole model as a v1.0.0 YAML document of values: its tables as
+/// in [`tables_to_yaml`], then each scalar as `{ value, unit, ... }`. A scalar
+/// named `section.name` is written under its section; formulas are not kept.
+pub fn model_to_yaml(model: &ParsedModel) -> ForgeResult<String> {
+    let mut root = tables_mapping(model);
+
+    let mut scalar_names: Vec<&String> = model.scalars.keys().collect();
+    scalar_names.sort();
+    for name in scalar_names {
+        let var = &model.scalars[name];
+        let value = var.value.map(yaml_number).unwrap_or(Value::Null);
+        let entry = Value::Mapping(metadata_mapping("value", value, &var.metadata));
+
+        match name.split_once('.') {
+            Some((section, key)) => {
+                let section = root
+                    .entry(Value::String(section.to_string()))
+                    .or_insert_with(|| Value::Mapping(serde_yaml::Mapping::new()));
+                if let Value::Mapping(section) = section {
+                    section.insert(Value::String(key.to_string()), entry);
+                }
+            }
+            None => {
+                root.insert(Value::String(name.clone()), entry);
+            }
+        }
+    }
+
+    Ok(serde_yaml::to_string(&Value::Mapping(root))?)
+}
+
+fn tables_mapping(model: &ParsedModel) -> serde_yaml::Mapping {
+    let mut root = serde_yaml::Mapping::new();
+    root.insert(
+        Value::String("_forge_version".to_string()),
+        Value::String("1.0.0".to_string()),
+    );
+
+    let mut table_names: Vec<&String> = model.tables.keys().collect();
+    table_names.sort();
+    for table_name in table_names {
+        let table = &model.tables[table_name];
+        let mut columns = serde_yaml::Mapping::new();
+
+        let mut column_names: Vec<&String> = table.columns.keys().collect();
+        column_names.sort();
+        for col_name in column_names {
+            let values: Vec<Value> = match &table.columns[col_name].values {
+                ColumnValue::Number(v) => v.iter().map(|n| yaml_number(*n)).collect(),
+                ColumnValue::Text(v) | ColumnValue::Date(v) => {
+                    v.iter().map(|t| Value::String(t.clone())).collect()
+                }
+                ColumnValue::Boolean(v) => v.iter().map(|b| Value::Bool(*b)).collect(),
+            };
+            let metadata = &table.columns[col_name].metadata;
+            columns.insert(
+                Value::String(col_name.clone()),
+                with_metadata("value", Value::Sequence(values), metadata),
+            );
+        }
+
+        let mut formula_names: Vec<&String> = table.row_formulas.keys().collect();
+        formula_names.sort();
+        for name in formula_names {
+            let formula = Value::String(table.row_formulas[name].clone());
+            let value = match table.formula_metadata.get(name) {
+                Some(metadata) => with_metadata("formula", formula, metadata),
+                None => formula,
+            };
+            columns.insert(Value::String(name.clone()), value);
+        }
+
+        root.insert(Value::String(table_name.clone()), Value::Mapping(columns));
+    }
+
+    root
+}
+
+/// Wrap `value` as `{ key: value, unit: ..., ... }` when `metadata` is set
+fn with_metadata(key: &str, value: Value, metadata: &Metadata) -> Value {
+    if metadata.is_empty() {
+        return value;
+    }
+    Value::Mapping(metadata_mapping(key, value, metadata))
+}
+
+/// `{ key: value }` followed by whichever metadata fields are set
+fn metadata_mapping(key: &str, value: Value, metadata: &Metadata) -> serde_yaml::Mapping {
+    let mut map = serde_yaml::Mapping::new();
+    map.insert(Value::String(key.to_string()), value);
+    let fields = [
+        ("unit", &metadata.unit),
+        ("description", &metadata.description),
+        ("notes", &metadata.notes),
+        ("source", &metadata.source),
+        ("validation_status", &metadata.validation_status),
+        ("last_updated", &metadata.last_updated),
+    ];
+    for (name, field) in fields {
+        if let Some(text) = field {
+            map.insert(Value::String(name.to_string()), Value::String(text.clone()));
+        }
+    }
+    map
+}
+
+/// Write each table of a calculated model as CSV
+/// A single table goes to `output`; multiple tables go to `<stem>_<table>.csv`
+/// next to it. Returns the paths written, in table-name order.
+pub fn write_csv_tables(result: &ParsedModel, output: &Path) -> ForgeResult<Vec<PathBuf>> {
+    let mut table_names: Vec<&String> = result.tables.keys().collect();
+    table_names.sort();
+
+    let mut written = Vec::new();
+    for table_name in &table_names {
+        let path = if table_names.len() == 1 {
+            output.to_path_buf()
+        } else {
+            let stem = output
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            output.with_file_name(format!("{}_{}.csv", stem, table_name))
+        };
+
+        fs::write(&path, table_to_csv(&result.tables[*table_name]))?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// Render a table as CSV: header row of column names (alphabetical, as in
+/// Excel export) followed by one line per row
+pub fn table_to_csv(table: &Table) -> String {
+    let mut column_names: Vec<&String> = table.columns.keys().collect();
+    column_names.sort();
+
+    let mut csv = column_names
+        .iter()
+        .map(|name| csv_field(name))
+        .collect::<Vec<_>>()
+        .join(",");
+    csv.push('\n');
+
+    for row_idx in 0..table.row_count() {
+        let row: Vec<String> = column_names
+            .iter()
+            .map(|name| match &table.columns[*name].values {
+                ColumnValue::Number(v) => v.get(row_idx).map(|n| n.to_string()),
+                ColumnValue::Text(v) | ColumnValue::Date(v) => v.get(row_idx).map(|t| csv_field(t)),
+                ColumnValue::Boolean(v) => v
+                    .get(row_idx)
+                    .map(|&b| if b { "TRUE" } else { "FALSE" }.to_string()),
+            })
+            .map(|cell| cell.unwrap_or_default())
+            .collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 1-based line of the key at `path` (e.g. `["summary", "total", "value"]`)
+/// in YAML text, if it is in a block mapping
+pub fn key_line(content: &str, path: &[&str]) -> Option<usize> {
+    in_place::key_line(content, path)
+}
+
+/// YAML text in the canonical `forge fmt` layout: sections ordered as
+/// metadata, tables, scalars, scenarios and formulas normalized.
+/// Values are unchanged; comments are dropped.
+pub fn format_yaml(content: &str) -> ForgeResult<String> {
+    format::format_yaml(content)
+}
+
+/// Update `path`'s `value` field in the parsed document and in its text
+fn update_value(
+    yaml: &mut Value,
+    text: &mut String,
+    path: &str,
+    new_value: f64,
+) -> ForgeResult<()> {
+    update_value_in_yaml(yaml, path, new_value);
+
+    let mut key: Vec<&str> = path.split('.').collect();
+    key.push("value");
+    let rendered = render_scalar(&Value::Number(serde_yaml::Number::from(new_value)))?;
+    if let Some(edited) = in_place::set_scalar(text, &key, &rendered) {
+        *text = edited;
+    }
+    Ok(())
+}
+
+/// A scalar as serde_yaml writes it, e.g. `75.0`
+fn render_scalar(value: &Value) -> ForgeResult<String> {
+    Ok(serde_yaml::to_string(value)?.trim_end().to_string())
+}
+
+/// The in-place edited `text` when it holds exactly the updated document,
+/// otherwise the document re-serialized (a value the text edits could not
+/// reach, e.g. inside a flow mapping, is never dropped)
+fn preserved_or_serialized(text: String, yaml: &Value) -> ForgeResult<String> {
+    if serde_yaml::from_str::<Value>(&text).ok().as_ref() == Some(yaml) {
+        Ok(text)
+    } else {
+        Ok(serde_yaml::to_string(yaml)?)
+    }
+}
+
 /// Recursively update a value in YAML structure by path
 fn update_value_in_yaml(yaml: &mut Value, path: &str, new_value: f64) {
     let parts: Vec<&str> = path.split('.').collect();
@@ -297,6 +594,88 @@ profit:
         let _ = fs::remove_file(path.with_extension("yaml.bak"));
     }
 
+    #[test]
+    fn test_constants_are_not_written_back() {
+        let yaml_content = r#"
+_forge_version: "1.0.0"
+constants:
+  tax_rate: 0.25
+sales:
+  revenue: [100, 200]
+  tax: "=revenue * constants.tax_rate"
+summary:
+  total_tax:
+    value: 0
+    formula: "=SUM(sales.revenue) * constants.tax_rate"
+  net:
+    value: 0
+    formula: "=SUM(sales.revenue) * (1 - constants.tax_rate)"
+"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+        let path = temp_file.path();
+
+        let model = crate::parser::parse_model(path).unwrap();
+        assert!(model.constants.contains("constants.tax_rate"));
+        let result = crate::core::ArrayCalculator::new(model)
+            .calculate_all()
+            .unwrap();
+        assert_eq!(result.scalars["summary.total_tax"].value, Some(75.0));
+        assert_eq!(result.scalars["summary.net"].value, Some(225.0));
+        assert_eq!(
+            result.tables["sales"].columns["tax"].values,
+            ColumnValue::Number(vec![25.0, 50.0])
+        );
+
+        write_calculated_results(path, &result).unwrap();
+
+        let written: Value = serde_yaml::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(written["summary"]["total_tax"]["value"], Value::from(75.0));
+        // The constant keeps its plain form: no `value` mapping, no new keys
+        assert_eq!(
+            written["constants"],
+            serde_yaml::from_str::<Value>("tax_rate: 0.25").unwrap()
+        );
+
+        let _ = fs::remove_file(path.with_extension("yaml.bak"));
+    }
+
+    #[test]
+    fn test_write_back_preserves_comments_and_order() {
+        let yaml_content = r#"_forge_version: "1.0.0"
+# Inputs first, outputs below
+sales:
+  revenue: [100, 200] # from the Q1 export
+summary:
+  # Sum of all revenue, recalculated by forge
+  total:
+    value: 0
+    formula: "=SUM(sales.revenue)"
+  average:
+    value: 0 # per month
+    formula: "=AVERAGE(sales.revenue)"
+"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+        let path = temp_file.path();
+
+        let model = crate::parser::parse_model(path).unwrap();
+        let result = crate::core::ArrayCalculator::new(model)
+            .calculate_all()
+            .unwrap();
+        write_calculated_results(path, &result).unwrap();
+
+        // Only the two values change; comments and key order stay put
+        let expected = yaml_content
+            .replace("    value: 0\n", "    value: 300.0\n")
+            .replace("value: 0 # per month", "value: 150.0 # per month");
+        assert_eq!(fs::read_to_string(path).unwrap(), expected);
+
+        let _ = fs::remove_file(path.with_extension("yaml.bak"));
+    }
+
     #[test]
     fn test_write_calculated_results_with_tables() {
         use crate::types::{Column, ColumnValue, ParsedModel, Table};
@@ -329,6 +708,191 @@ financials:
         let _ = fs::remove_file(path.with_extension("yaml.bak"));
     }
 
+    #[test]
+    fn test_results_to_json_shape() {
+        use crate::types::{Column, ColumnValue, ParsedModel, Table};
+
+        let mut model = ParsedModel::new();
+        let mut table = Table::new("sales".to_string());
+        table.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 200.0]),
+        ));
+        table.add_column(Column::new(
+            "region".to_string(),
+            ColumnValue::Text(vec!["N".to_string(), "S".to_string()]),
+        ));
+        model.tables.insert("sales".to_string(), table);
+        model.scalars.insert(
+            "total".to_string(),
+            Variable::new("total".to_string(), Some(300.0), None),
+        );
+        model.scalars.insert(
+            "pending".to_string(),
+            Variable::new("pending".to_string(), None, Some("=x".to_string())),
+        );
+
+        let json = results_to_json(&model);
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "scalars": { "total": 300.0, "pending": null },
+                "tables": {
+                    "sales": {
+                        "revenue": [100.0, 200.0],
+                        "region": ["N", "S"]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_table_to_csv_rows_and_quoting() {
+        use crate::types::{Column, ColumnValue, Table};
+
+        let mut table = Table::new("sales".to_string());
+        table.add_column(Column::new(
+            "region".to_string(),
+            ColumnValue::Text(vec![
+                "North".to_string(),
+                "South, East".to_string(),
+                "Say \"hi\"\nthere".to_string(),
+            ]),
+        ));
+        table.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 250.5, -3.0]),
+        ));
+        table.add_column(Column::new(
+            "active".to_string(),
+            ColumnValue::Boolean(vec![true, false, true]),
+        ));
+
+        let csv = table_to_csv(&table);
+        let expected = "active,region,revenue\n\
+                        TRUE,North,100\n\
+                        FALSE,\"South, East\",250.5\n\
+                        TRUE,\"Say \"\"hi\"\"\nthere\",-3\n";
+        assert_eq!(csv, expected);
+    }
+
+    #[test]
+    fn test_write_csv_tables_one_file_per_table() {
+        use crate::types::{Column, ColumnValue, ParsedModel, Table};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let output = dir.path().join("out.csv");
+
+        let mut model = ParsedModel::new();
+        for name in ["costs", "sales"] {
+            let mut table = Table::new(name.to_string());
+            table.add_column(Column::new(
+                "amount".to_string(),
+                ColumnValue::Number(vec![1.0, 2.0]),
+            ));
+            model.add_table(table);
+        }
+
+        let written = write_csv_tables(&model, &output).unwrap();
+        assert_eq!(
+            written,
+            vec![
+                dir.path().join("out_costs.csv"),
+                dir.path().join("out_sales.csv")
+            ]
+        );
+        assert_eq!(fs::read_to_string(&written[1]).unwrap(), "amount\n1\n2\n");
+
+        // A single table is written to the output path itself
+        model.tables.remove("costs");
+        let written = write_csv_tables(&model, &output).unwrap();
+        assert_eq!(written, vec![output.clone()]);
+        assert!(output.exists());
+    }
+
+    #[test]
+    fn test_tables_to_yaml_round_trips_through_parser() {
+        use crate::types::{Column, ColumnValue, ParsedModel, Table};
+
+        let mut model = ParsedModel::new();
+        let mut table = Table::new("sales".to_string());
+        table.add_column(Column::new(
+            "month".to_string(),
+            ColumnValue::Date(vec!["2025-01".to_string(), "2025-02".to_string()]),
+        ));
+        table.add_column(Column::new(
+            "revenue".to_string(),
+            ColumnValue::Number(vec![100.0, 250.5]),
+        ));
+        model.add_table(table);
+
+        let yaml = tables_to_yaml(&model).unwrap();
+        assert!(yaml.contains("_forge_version"));
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml.as_bytes()).unwrap();
+        let parsed = crate::parser::parse_model(temp_file.path()).unwrap();
+        let sales = parsed.tables.get("sales").unwrap();
+        assert_eq!(
+            sales.columns.get("revenue").unwrap().values,
+            ColumnValue::Number(vec![100.0, 250.5])
+        );
+        assert_eq!(
+            sales.columns.get("month").unwrap().values,
+            ColumnValue::Date(vec!["2025-01".to_string(), "2025-02".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_column_units_survive_calculate_round_trip() {
+        let yaml = r#"_forge_version: "1.0.0"
+sales:
+  revenue:
+    value: [100, 200]
+    units: "USD"
+    description: "Gross revenue"
+  weight:
+    value: [5, 10]
+    unit: "kg"
+  profit:
+    formula: "=revenue * 0.5"
+    unit: "USD"
+"#;
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml.as_bytes()).unwrap();
+        let model = crate::parser::parse_model(temp_file.path()).unwrap();
+        let result = crate::core::ArrayCalculator::new(model)
+            .calculate_all()
+            .unwrap();
+
+        // Calculated formula columns carry their declared metadata
+        let sales = &result.tables["sales"];
+        assert_eq!(
+            sales.columns["profit"].metadata.unit,
+            Some("USD".to_string())
+        );
+
+        let written = tables_to_yaml(&result).unwrap();
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(written.as_bytes()).unwrap();
+        let reparsed = crate::parser::parse_model(temp_file.path()).unwrap();
+        let sales = &reparsed.tables["sales"];
+
+        let revenue = &sales.columns["revenue"].metadata;
+        assert_eq!(revenue.unit, Some("USD".to_string()));
+        assert_eq!(revenue.description, Some("Gross revenue".to_string()));
+        assert_eq!(
+            sales.columns["weight"].metadata.unit,
+            Some("kg".to_string())
+        );
+        assert_eq!(sales.row_formulas["profit"], "=revenue * 0.5");
+        assert_eq!(
+            sales.formula_metadata["profit"].unit,
+            Some("USD".to_string())
+        );
+    }
+
     #[test]
     fn test_update_value_empty_path() {
         let mut yaml: Value = serde_yaml::from_str("test: 1").unwrap();
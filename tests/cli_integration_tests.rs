@@ -194,6 +194,103 @@ fn test_calculate_verbose() {
     .success();
 }
 
+#[test]
+fn test_calculate_format_json() {
+    let dir = TempDir::new().unwrap();
+    let yaml = dir.path().join("model.yaml");
+    let content = r#"_forge_version: "1.0.0"
+sales:
+  revenue: [100, 200]
+  cost: [40, 50]
+  profit: "=revenue - cost"
+"#;
+    std::fs::write(&yaml, content).unwrap();
+
+    let mut cmd = Command::cargo_bin("forge").unwrap();
+    let output = cmd
+        .args(["calculate", yaml.to_str().unwrap(), "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        json["tables"]["sales"]["profit"],
+        serde_json::json!([60.0, 150.0])
+    );
+    assert!(json["scalars"].is_object());
+
+    // The YAML file is left untouched
+    assert_eq!(std::fs::read_to_string(&yaml).unwrap(), content);
+
+    // JSON output has no preview or progress mode
+    for flag in ["--dry-run", "--verbose"] {
+        let output = Command::cargo_bin("forge")
+            .unwrap()
+            .args([
+                "calculate",
+                yaml.to_str().unwrap(),
+                "--format",
+                "json",
+                flag,
+            ])
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr)
+            .contains("--format json cannot be combined with --dry-run or --verbose"));
+    }
+}
+
+#[test]
+fn test_calculate_glob_batch() {
+    let dir = TempDir::new().unwrap();
+    let fresh = r#"_forge_version: "1.0.0"
+summary:
+  price:
+    value: 50
+    formula: null
+  total:
+    value: 100
+    formula: "=price * 2"
+"#;
+    let stale = r#"_forge_version: "1.0.0"
+summary:
+  price:
+    value: 75
+    formula: null
+  total:
+    value: 999
+    formula: "=price * 2"
+"#;
+    std::fs::write(dir.path().join("fresh.yaml"), fresh).unwrap();
+    std::fs::write(dir.path().join("stale.yaml"), stale).unwrap();
+
+    let pattern = dir.path().join("*.yaml");
+    let mut cmd = Command::cargo_bin("forge").unwrap();
+    cmd.args(["calculate", pattern.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 succeeded"));
+
+    // The stale value was recalculated and written back
+    let updated = std::fs::read_to_string(dir.path().join("stale.yaml")).unwrap();
+    assert!(updated.contains("150"));
+    assert!(!updated.contains("999"));
+
+    // A broken file makes the batch fail while the others still run
+    std::fs::write(
+        dir.path().join("broken.yaml"),
+        "_forge_version: \"1.0.0\"\nsummary:\n  total:\n    value: 1\n    formula: \"=missing * 2\"\n",
+    )
+    .unwrap();
+    let mut cmd = Command::cargo_bin("forge").unwrap();
+    cmd.args(["calculate", pattern.to_str().unwrap(), "--dry-run"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("2 succeeded, 1 failed"));
+}
+
 #[test]
 fn test_validate_file() {
     let mut cmd = Command::cargo_bin("forge").unwrap();
@@ -755,3 +852,76 @@ fn test_calculate_actual_file() {
         .assert()
         .success();
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VALIDATE EXIT CODES (0 valid, 1 stale, 2 broken)
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn write_model(dir: &TempDir, name: &str, doubled: &str) -> String {
+    let path = dir.path().join(name);
+    std::fs::write(
+        &path,
+        format!(
+            r#"_forge_version: "1.0.0"
+summary:
+  price:
+    value: 100
+    formula: null
+  doubled:
+    value: {}
+    formula: "=price * 2"
+"#,
+            doubled
+        ),
+    )
+    .unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn test_validate_exit_code_valid() {
+    let temp_dir = TempDir::new().unwrap();
+    let valid = write_model(&temp_dir, "valid.yaml", "200");
+
+    let mut cmd = Command::cargo_bin("forge").unwrap();
+    cmd.args(["validate", &valid]).assert().code(0);
+}
+
+#[test]
+fn test_validate_exit_code_stale() {
+    let temp_dir = TempDir::new().unwrap();
+    let valid = write_model(&temp_dir, "valid.yaml", "200");
+    let stale = write_model(&temp_dir, "stale.yaml", "150");
+
+    let mut cmd = Command::cargo_bin("forge").unwrap();
+    cmd.args(["validate", &stale])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("needs recalculation"));
+
+    // A batch where every failure is stale still only needs a recalc
+    let mut cmd = Command::cargo_bin("forge").unwrap();
+    cmd.args(["validate", &valid, &stale]).assert().code(1);
+}
+
+#[test]
+fn test_validate_exit_code_broken() {
+    let temp_dir = TempDir::new().unwrap();
+    let stale = write_model(&temp_dir, "stale.yaml", "150");
+    let invalid = temp_dir.path().join("invalid.yaml");
+    std::fs::write(&invalid, "{{invalid yaml content").unwrap();
+    let invalid = invalid.to_str().unwrap();
+
+    let mut cmd = Command::cargo_bin("forge").unwrap();
+    cmd.args(["validate", invalid])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Error"));
+
+    let mut cmd = Command::cargo_bin("forge").unwrap();
+    cmd.args(["validate", "nonexistent.yaml"]).assert().code(2);
+
+    // A broken file outweighs a stale one
+    let mut cmd = Command::cargo_bin("forge").unwrap();
+    cmd.args(["validate", &stale, invalid]).assert().code(2);
+}
@@ -149,11 +149,9 @@ fn test_forge_error_eval() {
 
 #[test]
 fn test_forge_error_circular_dependency() {
-    let err = ForgeError::CircularDependency("A -> B -> A".to_string());
-    assert_eq!(
-        format!("{}", err),
-        "Circular dependency detected: A -> B -> A"
-    );
+    let err =
+        ForgeError::CircularDependency(vec!["A".to_string(), "B".to_string(), "A".to_string()]);
+    assert_eq!(format!("{}", err), "Circular dependency: A → B → A");
 }
 
 #[test]
@@ -249,6 +249,7 @@ fn test_exporter_export_with_metadata() {
     column.metadata = Metadata {
         unit: Some("USD".to_string()),
         notes: Some("Annual budget".to_string()),
+        description: None,
         source: Some("Finance dept".to_string()),
         validation_status: Some("approved".to_string()),
         last_updated: Some("2025-01-01".to_string()),
@@ -768,6 +769,7 @@ fn test_exporter_metadata_partial() {
     column.metadata = Metadata {
         unit: Some("USD".to_string()),
         notes: None,
+        description: None,
         source: None,
         validation_status: None,
         last_updated: None,
@@ -1007,6 +1009,7 @@ fn test_exporter_with_resolved_includes_with_metadata() {
     var.metadata = Metadata {
         unit: Some("USD".to_string()),
         notes: Some("Important rate".to_string()),
+        description: None,
         source: Some("Finance".to_string()),
         validation_status: Some("Approved".to_string()),
         last_updated: Some("2024-01-01".to_string()),
@@ -281,3 +281,114 @@ fn perf_extended_report() {
     println!("╚═══════════════════════════════════════════════════════════════════════╝");
     println!();
 }
+
+#[test]
+fn perf_overrides_match_full_rebuild() {
+    use royalbit_forge::types::{Column, ColumnValue, ParsedModel, Table, Variable};
+    use std::collections::HashMap;
+
+    let rows = 1000;
+    let mut model = ParsedModel::new();
+    let mut sales = Table::new("sales".to_string());
+    sales.add_column(Column::new(
+        "units".to_string(),
+        ColumnValue::Number((0..rows).map(|i| 1.0 + (i % 50) as f64).collect()),
+    ));
+    sales.add_row_formula("revenue".to_string(), "=units * inputs.price".to_string());
+    model.add_table(sales);
+    model.scalars.insert(
+        "inputs.price".to_string(),
+        Variable::new("inputs.price".to_string(), Some(10.0), None),
+    );
+    model.scalars.insert(
+        "inputs.tax_rate".to_string(),
+        Variable::new("inputs.tax_rate".to_string(), Some(0.25), None),
+    );
+    model.scalars.insert(
+        "summary.net".to_string(),
+        Variable::new(
+            "summary.net".to_string(),
+            None,
+            Some("=SUM(sales.revenue) * (1 - inputs.tax_rate)".to_string()),
+        ),
+    );
+
+    let prices: Vec<f64> = (1..=20).map(|i| i as f64 * 2.5).collect();
+
+    // Reused calculator: dependency order resolved once
+    let reuse_start = Instant::now();
+    let calculator = ArrayCalculator::new(model.clone());
+    let reused: Vec<f64> = prices
+        .iter()
+        .map(|price| {
+            let overrides = HashMap::from([
+                ("inputs.price".to_string(), *price),
+                ("inputs.tax_rate".to_string(), 0.3),
+            ]);
+            let result = calculator.calculate_with_overrides(&overrides).unwrap();
+            result.scalars["summary.net"].value.unwrap()
+        })
+        .collect();
+    let reuse_time = reuse_start.elapsed();
+
+    // Full rebuild per evaluation
+    let rebuild_start = Instant::now();
+    let rebuilt: Vec<f64> = prices
+        .iter()
+        .map(|price| {
+            let mut m = model.clone();
+            m.scalars.get_mut("inputs.price").unwrap().value = Some(*price);
+            m.scalars.get_mut("inputs.tax_rate").unwrap().value = Some(0.3);
+            let result = ArrayCalculator::new(m).calculate_all().unwrap();
+            result.scalars["summary.net"].value.unwrap()
+        })
+        .collect();
+    let rebuild_time = rebuild_start.elapsed();
+
+    println!(
+        "\n📊 {} what-if runs on {} rows: reused={:?}, rebuilt={:?}",
+        prices.len(),
+        rows,
+        reuse_time,
+        rebuild_time
+    );
+
+    assert_eq!(reused, rebuilt);
+    // The calculator's own model is untouched by overrides
+    let base = calculator.calculate_all().unwrap();
+    assert_eq!(base.scalars["inputs.price"].value, Some(10.0));
+}
+
+#[test]
+#[ignore] // Run with: cargo test --release perf_arithmetic_fast_path -- --ignored --nocapture
+fn perf_arithmetic_fast_path_100000_rows() {
+    use royalbit_forge::types::{Column, ColumnValue, ParsedModel, Table};
+
+    let rows = 100_000;
+    let mut model = ParsedModel::new();
+    let mut table = Table::new("data".to_string());
+    for (name, offset) in [("a", 1.0), ("b", 2.0), ("c", 3.0)] {
+        table.add_column(Column::new(
+            name.to_string(),
+            ColumnValue::Number((0..rows).map(|i| offset + (i % 97) as f64).collect()),
+        ));
+    }
+    // Pure arithmetic: takes the slice fast path
+    table.add_row_formula("x".to_string(), "=a * b - c".to_string());
+    table.add_row_formula("y".to_string(), "=(x + a) / c".to_string());
+    // A function call forces the per-cell interpreter
+    table.add_row_formula("z".to_string(), "=ROUND(a * b - c, 2)".to_string());
+    model.add_table(table);
+
+    let start = Instant::now();
+    let result = ArrayCalculator::new(model)
+        .calculate_all()
+        .expect("Calculation failed");
+    let elapsed = start.elapsed();
+
+    println!(
+        "\n📊 {} rows, 2 fast-path + 1 general column: {:?}",
+        rows, elapsed
+    );
+    assert_eq!(result.tables["data"].columns["x"].values.len(), rows);
+}